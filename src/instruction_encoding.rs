@@ -1,5 +1,6 @@
 //! Implementation of the ARM64 instruction set encodings
 
+use crate::instruction_encoding::atomic_macro_ops::AtomicMacroOperations;
 use crate::instruction_encoding::branch_exception_system::{
     BranchExceptionSystem, BranchExceptionSystemWithAddress,
 };
@@ -8,14 +9,23 @@ use crate::instruction_encoding::data_proc_imm::{
     DataProcessingImmediate, DataProcessingImmediateWithAddress,
 };
 use crate::instruction_encoding::data_proc_reg::DataProcessingRegister;
+use crate::instruction_encoding::data_proc_simd_fp::DataProcessingSIMDFloatingPoint;
 use crate::instruction_encoding::loads_and_stores::{LoadsAndStores, LoadsAndStoresWithAddress};
+use crate::instruction_encoding::shadow_stack::ShadowStack;
+use crate::instruction_encoding::sme::SmeInstructionSet;
+use crate::instruction_encoding::sve::SveInstructionSet;
 use crate::types::{Instruction, Offset32};
 
+pub mod atomic_macro_ops;
 pub mod branch_exception_system;
 pub mod common_aliases;
 pub mod data_proc_imm;
 pub mod data_proc_reg;
+pub mod data_proc_simd_fp;
 pub mod loads_and_stores;
+pub mod shadow_stack;
+pub mod sme;
+pub mod sve;
 
 pub trait InstructionProcessor<T>: Sized {
     /// Processes the encoded instruction
@@ -31,6 +41,11 @@ pub trait AddressableInstructionProcessor<T>: InstructionProcessor<T> {
     ///
     /// Used by pc relative operations such as `b_to_addr(<addr>)`.
     fn intr_ptr_offset_to(&self, addr: usize) -> Offset32;
+
+    /// Returns the address of the current instruction pointer.
+    ///
+    /// Used by page relative operations such as `adrp_to_addr(<addr>)`.
+    fn intr_ptr_addr(&self) -> usize;
 }
 
 /// Bundles all instructions of the Arm64 instruction set
@@ -40,7 +55,12 @@ pub trait InstructionSet<T>:
     + BranchExceptionSystem<T>
     + LoadsAndStores<T>
     + DataProcessingRegister<T>
+    + DataProcessingSIMDFloatingPoint<T>
+    + SmeInstructionSet<T>
+    + SveInstructionSet<T>
     + CommonAliases<T>
+    + AtomicMacroOperations<T>
+    + ShadowStack<T>
 {
 }
 
@@ -0,0 +1,814 @@
+//! # Atomic macro-operations
+//!
+//! Implements atomic read-modify-write operations as short instruction sequences.
+//!
+//! If the `arm_feat_lse` feature is enabled, the operations are emitted as single LSE instructions
+//! ([LDADD](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/LDADD--LDADDA--LDADDAL--LDADDL--Atomic-add-on-word-or-doubleword-in-memory-?lang=en),
+//! [LDSET](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/LDSET--LDSETA--LDSETAL--LDSETL--Atomic-bit-set-on-word-or-doubleword-in-memory-?lang=en),
+//! [SWP](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SWP--SWPA--SWPAL--SWPL--Swap-word-or-doubleword-in-memory-?lang=en),
+//! [CAS](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CAS--CASA--CASAL--CASL--Compare-and-Swap-word-or-doubleword-in-memory-?lang=en)).
+//! Otherwise they fall back to a retry loop around the exclusive monitor
+//! ([LDXR](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/LDXR--Load-Exclusive-Register-?lang=en)/[LDAXR](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/LDAXR--Load-Acquire-Exclusive-Register-?lang=en)
+//! and [STXR](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STXR--Store-Exclusive-Register-?lang=en)/[STLXR](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STLXR--Store-Release-Exclusive-Register-?lang=en)),
+//! which is available on every Armv8.0 core.
+//!
+//! The memory ordering selects the acquire and release semantics of the emitted instructions:
+//! `Relaxed` uses neither, `Acquire` only acquire, `Release` only release and `AcqRel` as well as
+//! `SeqCst` both.
+//!
+//! All operations return the emitted instructions in program order.
+
+use std::sync::atomic::Ordering;
+
+use crate::instruction_encoding::branch_exception_system::barriers::Barriers;
+use crate::instruction_encoding::branch_exception_system::compare_and_branch_imm::CompareAndBranchImm;
+use crate::instruction_encoding::branch_exception_system::conditional_branch_imm::ConditionalBranchImmediate;
+use crate::instruction_encoding::branch_exception_system::unconditional_branch_immediate::UnconditionalBranchImmediate;
+use crate::instruction_encoding::common_aliases::CommonAliases;
+use crate::instruction_encoding::loads_and_stores::atomic_memory_operations::AtomicMemoryOperatinos;
+use crate::instruction_encoding::loads_and_stores::compare_and_swap::CompareAndSwap;
+use crate::instruction_encoding::loads_and_stores::load_store_exclusive_register::LoadStoreExclusiveRegister;
+#[allow(unused_imports)]
+use crate::types::atomic::{encode_ordering, AtomicRmwOp, AtomicSize};
+#[allow(unused_imports)]
+use crate::types::condition::Condition;
+use crate::types::Register;
+
+/// Size of an instruction in bytes, used to compute the branch offsets of the retry loops.
+#[allow(dead_code)]
+const INSTR_SIZE: i32 = 4;
+
+/// The registers of an atomic macro-operation must be pairwise distinct, as the retry loop of the
+/// exclusive monitor fallback would otherwise clobber its own operands.
+#[inline(always)]
+fn debug_assert_distinct(regs: &[Register]) {
+    debug_assert!(
+        regs.iter()
+            .enumerate()
+            .all(|(i, r)| !regs[i + 1..].contains(r)),
+        "registers of an atomic operation must be pairwise distinct"
+    );
+}
+
+/// Registers of a read-modify-write operation.
+///
+/// `tmp` and `status` are only used by the exclusive monitor fallback, `tmp` is not used by `SWP`.
+struct RmwRegs {
+    rt: Register,
+    rs: Register,
+    rn: Register,
+    #[cfg_attr(feature = "arm_feat_lse", allow(dead_code))]
+    tmp: Register,
+    #[cfg_attr(feature = "arm_feat_lse", allow(dead_code))]
+    status: Register,
+}
+
+/// Registers of a compare and exchange.
+///
+/// `status` is only used by the exclusive monitor fallback.
+struct CasRegs {
+    old: Register,
+    expected: Register,
+    new: Register,
+    rn: Register,
+    #[cfg_attr(feature = "arm_feat_lse", allow(dead_code))]
+    status: Register,
+}
+
+#[inline(always)]
+#[allow(dead_code)]
+fn emit_load_exclusive<P: AtomicMacroOperations<T>, T>(
+    proc: &mut P,
+    sf: u8,
+    acquire: bool,
+    rt: Register,
+    rn: Register,
+) -> T {
+    match (sf, acquire) {
+        (0, false) => proc.ldxr_32(rt, rn),
+        (0, true) => proc.ldaxr_32(rt, rn),
+        (_, false) => proc.ldxr_64(rt, rn),
+        (_, true) => proc.ldaxr_64(rt, rn),
+    }
+}
+
+#[inline(always)]
+#[allow(dead_code)]
+fn emit_store_exclusive<P: AtomicMacroOperations<T>, T>(
+    proc: &mut P,
+    sf: u8,
+    release: bool,
+    ws: Register,
+    rt: Register,
+    rn: Register,
+) -> T {
+    match (sf, release) {
+        (0, false) => proc.stxr_32(ws, rt, rn),
+        (0, true) => proc.stlxr_32(ws, rt, rn),
+        (_, false) => proc.stxr_64(ws, rt, rn),
+        (_, true) => proc.stlxr_64(ws, rt, rn),
+    }
+}
+
+/// Emits the read-modify-write `op` (`ADD`, `SET` or `SWP`).
+///
+/// The scratch registers are only checked for distinctness if they are used.
+#[inline(always)]
+fn emit_rmw<P: AtomicMacroOperations<T>, T>(
+    proc: &mut P,
+    sf: u8,
+    op: AtomicRmwOp,
+    ordering: Ordering,
+    regs: RmwRegs,
+) -> Vec<T> {
+    #[cfg(feature = "arm_feat_lse")]
+    {
+        let RmwRegs { rt, rs, rn, .. } = regs;
+        debug_assert_distinct(&[rt, rs, rn]);
+        let size = if sf == 1 { AtomicSize::X } else { AtomicSize::W };
+        vec![proc.atomic_rmw(op, size, ordering, rs, rt, rn)]
+    }
+
+    #[cfg(not(feature = "arm_feat_lse"))]
+    {
+        let RmwRegs { rt, rs, rn, tmp, status } = regs;
+        if op == AtomicRmwOp::SWP {
+            debug_assert_distinct(&[rt, rs, rn, status]);
+        } else {
+            debug_assert_distinct(&[rt, rs, rn, tmp, status]);
+        }
+        let (a, r) = encode_ordering(ordering);
+        let mut instrs = Vec::with_capacity(4);
+        instrs.push(emit_load_exclusive(proc, sf, a == 1, rt, rn));
+        let new = match (op, sf) {
+            (AtomicRmwOp::ADD, 0) => {
+                instrs.push(proc.add_32_reg(tmp, rt, rs));
+                tmp
+            }
+            (AtomicRmwOp::ADD, _) => {
+                instrs.push(proc.add_64_reg(tmp, rt, rs));
+                tmp
+            }
+            (AtomicRmwOp::SET, 0) => {
+                instrs.push(proc.orr_32(tmp, rt, rs, None));
+                tmp
+            }
+            (AtomicRmwOp::SET, _) => {
+                instrs.push(proc.orr_64(tmp, rt, rs, None));
+                tmp
+            }
+            (AtomicRmwOp::SWP, _) => rs,
+            _ => unreachable!("unsupported atomic operation"),
+        };
+        instrs.push(emit_store_exclusive(proc, sf, r == 1, status, new, rn));
+        let offset = -(instrs.len() as i32) * INSTR_SIZE;
+        instrs.push(proc.cbnz_32_from_byte_offset(status, offset));
+        instrs
+    }
+}
+
+/// Emits a compare and exchange, that leaves the condition flags EQ if the exchange took place.
+///
+/// If `weak` is set, the exclusive monitor fallback does not retry after a failed store.
+#[inline(always)]
+#[allow(unused_variables)]
+fn emit_compare_exchange<P: AtomicMacroOperations<T>, T>(
+    proc: &mut P,
+    sf: u8,
+    weak: bool,
+    ordering: Ordering,
+    regs: CasRegs,
+) -> Vec<T> {
+    #[cfg(feature = "arm_feat_lse")]
+    {
+        let CasRegs { old, expected, new, rn, .. } = regs;
+        debug_assert_distinct(&[old, expected, new, rn]);
+        let (a, r) = encode_ordering(ordering);
+        let mov = if sf == 1 {
+            proc.mov_64_reg(old, expected)
+        } else {
+            proc.mov_32_reg(old, expected)
+        };
+        let cas = match (sf, a, r) {
+            (0, 0, 0) => proc.cas_32(old, new, rn),
+            (0, 1, 0) => proc.casa_32(old, new, rn),
+            (0, 0, 1) => proc.casl_32(old, new, rn),
+            (0, _, _) => proc.casal_32(old, new, rn),
+            (_, 0, 0) => proc.cas_64(old, new, rn),
+            (_, 1, 0) => proc.casa_64(old, new, rn),
+            (_, 0, 1) => proc.casl_64(old, new, rn),
+            (_, _, _) => proc.casal_64(old, new, rn),
+        };
+        let cmp = if sf == 1 {
+            proc.cmp_64_reg(old, expected, None)
+        } else {
+            proc.cmp_32_reg(old, expected, None)
+        };
+        vec![mov, cas, cmp]
+    }
+
+    #[cfg(not(feature = "arm_feat_lse"))]
+    {
+        let CasRegs { old, expected, new, rn, status } = regs;
+        debug_assert_distinct(&[old, expected, new, rn, status]);
+        let (a, r) = encode_ordering(ordering);
+        let mut instrs = Vec::with_capacity(7);
+        instrs.push(emit_load_exclusive(proc, sf, a == 1, old, rn));
+        instrs.push(if sf == 1 {
+            proc.cmp_64_reg(old, expected, None)
+        } else {
+            proc.cmp_32_reg(old, expected, None)
+        });
+        // branch to the CLREX at the end of the sequence
+        instrs.push(proc.b_cond_from_byte_offset(Condition::NE, 4 * INSTR_SIZE));
+        instrs.push(emit_store_exclusive(proc, sf, r == 1, status, new, rn));
+        instrs.push(if weak {
+            // the flags of the comparison are replaced by the result of the store
+            proc.cmp_32_imm(status, 0)
+        } else {
+            proc.cbnz_32_from_byte_offset(status, -4 * INSTR_SIZE)
+        });
+        instrs.push(proc.b_from_byte_offset(2 * INSTR_SIZE));
+        instrs.push(proc.clrex(0b1111));
+        instrs
+    }
+}
+
+/// # Atomic macro-operations
+///
+/// Atomic read-modify-write operations, emitted as LSE instructions if the `arm_feat_lse` feature
+/// is enabled and as exclusive monitor retry loops otherwise.
+///
+/// See the [module documentation](self) for the ordering semantics.
+pub trait AtomicMacroOperations<T>:
+    CommonAliases<T>
+    + LoadStoreExclusiveRegister<T>
+    + CompareAndSwap<T>
+    + AtomicMemoryOperatinos<T>
+    + Barriers<T>
+    + CompareAndBranchImm<T>
+    + ConditionalBranchImmediate<T>
+    + UnconditionalBranchImmediate<T>
+{
+    /// Atomically adds `ws` to the word at `[xn_sp]` and returns the previous value in `wt`.
+    ///
+    /// `w_tmp` and `w_status` are scratch registers of the exclusive monitor fallback.
+    /// All registers must be pairwise distinct, the scratch registers only if the fallback is emitted.
+    ///
+    /// ```asm
+    /// // arm_feat_lse
+    /// LDADD{A}{L} <Ws>, <Wt>, [<Xn|SP>]
+    ///
+    /// // fallback
+    /// loop:
+    ///   LD{A}XR <Wt>, [<Xn|SP>]
+    ///   ADD <Wtmp>, <Wt>, <Ws>
+    ///   ST{L}XR <Wstatus>, <Wtmp>, [<Xn|SP>]
+    ///   CBNZ <Wstatus>, loop
+    /// ```
+    #[inline(always)]
+    fn fetch_add_32(
+        &mut self,
+        ordering: Ordering,
+        wt: Register,
+        ws: Register,
+        xn_sp: Register,
+        w_tmp: Register,
+        w_status: Register,
+    ) -> Vec<T> {
+        let regs = RmwRegs {
+            rt: wt,
+            rs: ws,
+            rn: xn_sp,
+            tmp: w_tmp,
+            status: w_status,
+        };
+        emit_rmw(self, 0, AtomicRmwOp::ADD, ordering, regs)
+    }
+
+    /// Atomically adds `xs` to the doubleword at `[xn_sp]` and returns the previous value in `xt`.
+    ///
+    /// `x_tmp` and `w_status` are scratch registers of the exclusive monitor fallback.
+    /// All registers must be pairwise distinct, the scratch registers only if the fallback is emitted.
+    ///
+    /// ```asm
+    /// // arm_feat_lse
+    /// LDADD{A}{L} <Xs>, <Xt>, [<Xn|SP>]
+    ///
+    /// // fallback
+    /// loop:
+    ///   LD{A}XR <Xt>, [<Xn|SP>]
+    ///   ADD <Xtmp>, <Xt>, <Xs>
+    ///   ST{L}XR <Wstatus>, <Xtmp>, [<Xn|SP>]
+    ///   CBNZ <Wstatus>, loop
+    /// ```
+    #[inline(always)]
+    fn fetch_add_64(
+        &mut self,
+        ordering: Ordering,
+        xt: Register,
+        xs: Register,
+        xn_sp: Register,
+        x_tmp: Register,
+        w_status: Register,
+    ) -> Vec<T> {
+        let regs = RmwRegs {
+            rt: xt,
+            rs: xs,
+            rn: xn_sp,
+            tmp: x_tmp,
+            status: w_status,
+        };
+        emit_rmw(self, 1, AtomicRmwOp::ADD, ordering, regs)
+    }
+
+    /// Atomically ORs `ws` into the word at `[xn_sp]` and returns the previous value in `wt`.
+    ///
+    /// `w_tmp` and `w_status` are scratch registers of the exclusive monitor fallback.
+    /// All registers must be pairwise distinct, the scratch registers only if the fallback is emitted.
+    ///
+    /// ```asm
+    /// // arm_feat_lse
+    /// LDSET{A}{L} <Ws>, <Wt>, [<Xn|SP>]
+    ///
+    /// // fallback
+    /// loop:
+    ///   LD{A}XR <Wt>, [<Xn|SP>]
+    ///   ORR <Wtmp>, <Wt>, <Ws>
+    ///   ST{L}XR <Wstatus>, <Wtmp>, [<Xn|SP>]
+    ///   CBNZ <Wstatus>, loop
+    /// ```
+    #[inline(always)]
+    fn fetch_or_32(
+        &mut self,
+        ordering: Ordering,
+        wt: Register,
+        ws: Register,
+        xn_sp: Register,
+        w_tmp: Register,
+        w_status: Register,
+    ) -> Vec<T> {
+        let regs = RmwRegs {
+            rt: wt,
+            rs: ws,
+            rn: xn_sp,
+            tmp: w_tmp,
+            status: w_status,
+        };
+        emit_rmw(self, 0, AtomicRmwOp::SET, ordering, regs)
+    }
+
+    /// Atomically ORs `xs` into the doubleword at `[xn_sp]` and returns the previous value in `xt`.
+    ///
+    /// `x_tmp` and `w_status` are scratch registers of the exclusive monitor fallback.
+    /// All registers must be pairwise distinct, the scratch registers only if the fallback is emitted.
+    ///
+    /// ```asm
+    /// // arm_feat_lse
+    /// LDSET{A}{L} <Xs>, <Xt>, [<Xn|SP>]
+    ///
+    /// // fallback
+    /// loop:
+    ///   LD{A}XR <Xt>, [<Xn|SP>]
+    ///   ORR <Xtmp>, <Xt>, <Xs>
+    ///   ST{L}XR <Wstatus>, <Xtmp>, [<Xn|SP>]
+    ///   CBNZ <Wstatus>, loop
+    /// ```
+    #[inline(always)]
+    fn fetch_or_64(
+        &mut self,
+        ordering: Ordering,
+        xt: Register,
+        xs: Register,
+        xn_sp: Register,
+        x_tmp: Register,
+        w_status: Register,
+    ) -> Vec<T> {
+        let regs = RmwRegs {
+            rt: xt,
+            rs: xs,
+            rn: xn_sp,
+            tmp: x_tmp,
+            status: w_status,
+        };
+        emit_rmw(self, 1, AtomicRmwOp::SET, ordering, regs)
+    }
+
+    /// Atomically stores `ws` to the word at `[xn_sp]` and returns the previous value in `wt`.
+    ///
+    /// `w_status` is a scratch register of the exclusive monitor fallback.
+    /// All registers must be pairwise distinct, the scratch registers only if the fallback is emitted.
+    ///
+    /// ```asm
+    /// // arm_feat_lse
+    /// SWP{A}{L} <Ws>, <Wt>, [<Xn|SP>]
+    ///
+    /// // fallback
+    /// loop:
+    ///   LD{A}XR <Wt>, [<Xn|SP>]
+    ///   ST{L}XR <Wstatus>, <Ws>, [<Xn|SP>]
+    ///   CBNZ <Wstatus>, loop
+    /// ```
+    #[inline(always)]
+    fn swap_32(
+        &mut self,
+        ordering: Ordering,
+        wt: Register,
+        ws: Register,
+        xn_sp: Register,
+        w_status: Register,
+    ) -> Vec<T> {
+        let regs = RmwRegs {
+            rt: wt,
+            rs: ws,
+            rn: xn_sp,
+            tmp: w_status,
+            status: w_status,
+        };
+        emit_rmw(self, 0, AtomicRmwOp::SWP, ordering, regs)
+    }
+
+    /// Atomically stores `xs` to the doubleword at `[xn_sp]` and returns the previous value in `xt`.
+    ///
+    /// `w_status` is a scratch register of the exclusive monitor fallback.
+    /// All registers must be pairwise distinct, the scratch registers only if the fallback is emitted.
+    ///
+    /// ```asm
+    /// // arm_feat_lse
+    /// SWP{A}{L} <Xs>, <Xt>, [<Xn|SP>]
+    ///
+    /// // fallback
+    /// loop:
+    ///   LD{A}XR <Xt>, [<Xn|SP>]
+    ///   ST{L}XR <Wstatus>, <Xs>, [<Xn|SP>]
+    ///   CBNZ <Wstatus>, loop
+    /// ```
+    #[inline(always)]
+    fn swap_64(
+        &mut self,
+        ordering: Ordering,
+        xt: Register,
+        xs: Register,
+        xn_sp: Register,
+        w_status: Register,
+    ) -> Vec<T> {
+        let regs = RmwRegs {
+            rt: xt,
+            rs: xs,
+            rn: xn_sp,
+            tmp: w_status,
+            status: w_status,
+        };
+        emit_rmw(self, 1, AtomicRmwOp::SWP, ordering, regs)
+    }
+
+    /// Atomically replaces the word at `[xn_sp]` with `w_new` if it equals `w_expected`.
+    ///
+    /// The value read from memory is returned in `w_old`, and the condition flags are set to EQ if
+    /// the exchange took place and to NE otherwise.
+    /// `w_status` is a scratch register of the exclusive monitor fallback.
+    /// All registers must be pairwise distinct, the scratch registers only if the fallback is emitted.
+    ///
+    /// ```asm
+    /// // arm_feat_lse
+    /// MOV <Wold>, <Wexpected>
+    /// CAS{A}{L} <Wold>, <Wnew>, [<Xn|SP>]
+    /// CMP <Wold>, <Wexpected>
+    ///
+    /// // fallback
+    /// loop:
+    ///   LD{A}XR <Wold>, [<Xn|SP>]
+    ///   CMP <Wold>, <Wexpected>
+    ///   B.NE fail
+    ///   ST{L}XR <Wstatus>, <Wnew>, [<Xn|SP>]
+    ///   CBNZ <Wstatus>, loop
+    ///   B done
+    /// fail:
+    ///   CLREX
+    /// done:
+    /// ```
+    #[inline(always)]
+    fn compare_exchange_32(
+        &mut self,
+        ordering: Ordering,
+        w_old: Register,
+        w_expected: Register,
+        w_new: Register,
+        xn_sp: Register,
+        w_status: Register,
+    ) -> Vec<T> {
+        let regs = CasRegs {
+            old: w_old,
+            expected: w_expected,
+            new: w_new,
+            rn: xn_sp,
+            status: w_status,
+        };
+        emit_compare_exchange(self, 0, false, ordering, regs)
+    }
+
+    /// Atomically replaces the doubleword at `[xn_sp]` with `x_new` if it equals `x_expected`.
+    ///
+    /// The value read from memory is returned in `x_old`, and the condition flags are set to EQ if
+    /// the exchange took place and to NE otherwise.
+    /// `w_status` is a scratch register of the exclusive monitor fallback.
+    /// All registers must be pairwise distinct, the scratch registers only if the fallback is emitted.
+    ///
+    /// ```asm
+    /// // arm_feat_lse
+    /// MOV <Xold>, <Xexpected>
+    /// CAS{A}{L} <Xold>, <Xnew>, [<Xn|SP>]
+    /// CMP <Xold>, <Xexpected>
+    ///
+    /// // fallback
+    /// loop:
+    ///   LD{A}XR <Xold>, [<Xn|SP>]
+    ///   CMP <Xold>, <Xexpected>
+    ///   B.NE fail
+    ///   ST{L}XR <Wstatus>, <Xnew>, [<Xn|SP>]
+    ///   CBNZ <Wstatus>, loop
+    ///   B done
+    /// fail:
+    ///   CLREX
+    /// done:
+    /// ```
+    #[inline(always)]
+    fn compare_exchange_64(
+        &mut self,
+        ordering: Ordering,
+        x_old: Register,
+        x_expected: Register,
+        x_new: Register,
+        xn_sp: Register,
+        w_status: Register,
+    ) -> Vec<T> {
+        let regs = CasRegs {
+            old: x_old,
+            expected: x_expected,
+            new: x_new,
+            rn: xn_sp,
+            status: w_status,
+        };
+        emit_compare_exchange(self, 1, false, ordering, regs)
+    }
+
+    /// Like [`compare_exchange_32`](Self::compare_exchange_32), but the exclusive monitor fallback
+    /// does not retry if the store fails. So the exchange may fail spuriously, even though
+    /// `w_old` equals `w_expected`, which is reported by NE condition flags.
+    ///
+    /// ```asm
+    /// // fallback
+    ///   LD{A}XR <Wold>, [<Xn|SP>]
+    ///   CMP <Wold>, <Wexpected>
+    ///   B.NE fail
+    ///   ST{L}XR <Wstatus>, <Wnew>, [<Xn|SP>]
+    ///   CMP <Wstatus>, #0
+    ///   B done
+    /// fail:
+    ///   CLREX
+    /// done:
+    /// ```
+    #[inline(always)]
+    fn compare_exchange_weak_32(
+        &mut self,
+        ordering: Ordering,
+        w_old: Register,
+        w_expected: Register,
+        w_new: Register,
+        xn_sp: Register,
+        w_status: Register,
+    ) -> Vec<T> {
+        let regs = CasRegs {
+            old: w_old,
+            expected: w_expected,
+            new: w_new,
+            rn: xn_sp,
+            status: w_status,
+        };
+        emit_compare_exchange(self, 0, true, ordering, regs)
+    }
+
+    /// Like [`compare_exchange_64`](Self::compare_exchange_64), but the exclusive monitor fallback
+    /// does not retry if the store fails. So the exchange may fail spuriously, even though
+    /// `x_old` equals `x_expected`, which is reported by NE condition flags.
+    ///
+    /// ```asm
+    /// // fallback
+    ///   LD{A}XR <Xold>, [<Xn|SP>]
+    ///   CMP <Xold>, <Xexpected>
+    ///   B.NE fail
+    ///   ST{L}XR <Wstatus>, <Xnew>, [<Xn|SP>]
+    ///   CMP <Wstatus>, #0
+    ///   B done
+    /// fail:
+    ///   CLREX
+    /// done:
+    /// ```
+    #[inline(always)]
+    fn compare_exchange_weak_64(
+        &mut self,
+        ordering: Ordering,
+        x_old: Register,
+        x_expected: Register,
+        x_new: Register,
+        xn_sp: Register,
+        w_status: Register,
+    ) -> Vec<T> {
+        let regs = CasRegs {
+            old: x_old,
+            expected: x_expected,
+            new: x_new,
+            rn: xn_sp,
+            status: w_status,
+        };
+        emit_compare_exchange(self, 1, true, ordering, regs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assert_panic;
+    use crate::test_utils::test_producer::TestProducer;
+
+    use super::*;
+
+    #[cfg(feature = "arm_feat_lse")]
+    #[test]
+    fn test_fetch_ops_lse() {
+        let mut prod = TestProducer::new();
+
+        let instrs = prod.fetch_add_32(Ordering::Relaxed, 1, 2, 3, 4, 5);
+        assert_eq!(instrs, ["ldadd w2, w1, [x3]"]);
+
+        let instrs = prod.fetch_add_64(Ordering::SeqCst, 1, 2, 0b11111, 4, 5);
+        assert_eq!(instrs, ["ldaddal x2, x1, [sp]"]);
+
+        let instrs = prod.fetch_or_32(Ordering::Acquire, 1, 2, 3, 4, 5);
+        assert_eq!(instrs, ["ldseta w2, w1, [x3]"]);
+
+        let instrs = prod.fetch_or_64(Ordering::Release, 1, 2, 3, 4, 5);
+        assert_eq!(instrs, ["ldsetl x2, x1, [x3]"]);
+
+        let instrs = prod.swap_32(Ordering::AcqRel, 1, 2, 3, 5);
+        assert_eq!(instrs, ["swpal w2, w1, [x3]"]);
+
+        let instrs = prod.swap_64(Ordering::Relaxed, 1, 2, 3, 5);
+        assert_eq!(instrs, ["swp x2, x1, [x3]"]);
+    }
+
+    #[cfg(feature = "arm_feat_lse")]
+    #[test]
+    fn test_compare_exchange_lse() {
+        let mut prod = TestProducer::new();
+
+        let instrs = prod.compare_exchange_32(Ordering::SeqCst, 1, 2, 3, 4, 5);
+        assert_eq!(
+            instrs,
+            ["mov w1, w2", "casal w1, w3, [x4]", "cmp w1, w2"]
+        );
+
+        let instrs = prod.compare_exchange_64(Ordering::Acquire, 1, 2, 3, 4, 5);
+        assert_eq!(instrs, ["mov x1, x2", "casa x1, x3, [x4]", "cmp x1, x2"]);
+
+        let instrs = prod.compare_exchange_weak_64(Ordering::Release, 1, 2, 3, 4, 5);
+        assert_eq!(instrs, ["mov x1, x2", "casl x1, x3, [x4]", "cmp x1, x2"]);
+
+        let instrs = prod.compare_exchange_weak_32(Ordering::Relaxed, 1, 2, 3, 4, 5);
+        assert_eq!(instrs, ["mov w1, w2", "cas w1, w3, [x4]", "cmp w1, w2"]);
+    }
+
+    #[cfg(not(feature = "arm_feat_lse"))]
+    #[test]
+    fn test_fetch_ops_fallback() {
+        let mut prod = TestProducer::new();
+
+        let instrs = prod.fetch_add_32(Ordering::Relaxed, 1, 2, 3, 4, 5);
+        assert_eq!(
+            instrs,
+            [
+                "ldxr w1, [x3]",
+                "add w4, w1, w2",
+                "stxr w5, w4, [x3]",
+                "cbnz w5, 0xfffffffffffffff4",
+            ]
+        );
+
+        let instrs = prod.fetch_add_64(Ordering::SeqCst, 1, 2, 0b11111, 4, 5);
+        assert_eq!(
+            instrs,
+            [
+                "ldaxr x1, [sp]",
+                "add x4, x1, x2",
+                "stlxr w5, x4, [sp]",
+                "cbnz w5, 0xfffffffffffffff4",
+            ]
+        );
+
+        let instrs = prod.fetch_or_32(Ordering::Acquire, 1, 2, 3, 4, 5);
+        assert_eq!(
+            instrs,
+            [
+                "ldaxr w1, [x3]",
+                "orr w4, w1, w2",
+                "stxr w5, w4, [x3]",
+                "cbnz w5, 0xfffffffffffffff4",
+            ]
+        );
+
+        let instrs = prod.fetch_or_64(Ordering::Release, 1, 2, 3, 4, 5);
+        assert_eq!(
+            instrs,
+            [
+                "ldxr x1, [x3]",
+                "orr x4, x1, x2",
+                "stlxr w5, x4, [x3]",
+                "cbnz w5, 0xfffffffffffffff4",
+            ]
+        );
+
+        let instrs = prod.swap_32(Ordering::AcqRel, 1, 2, 3, 5);
+        assert_eq!(
+            instrs,
+            [
+                "ldaxr w1, [x3]",
+                "stlxr w5, w2, [x3]",
+                "cbnz w5, 0xfffffffffffffff8",
+            ]
+        );
+
+        let instrs = prod.swap_64(Ordering::Relaxed, 1, 2, 3, 5);
+        assert_eq!(
+            instrs,
+            [
+                "ldxr x1, [x3]",
+                "stxr w5, x2, [x3]",
+                "cbnz w5, 0xfffffffffffffff8",
+            ]
+        );
+    }
+
+    #[cfg(not(feature = "arm_feat_lse"))]
+    #[test]
+    fn test_compare_exchange_fallback() {
+        let mut prod = TestProducer::new();
+
+        let instrs = prod.compare_exchange_32(Ordering::SeqCst, 1, 2, 3, 4, 5);
+        assert_eq!(
+            instrs,
+            [
+                "ldaxr w1, [x4]",
+                "cmp w1, w2",
+                "b.ne 0x10",
+                "stlxr w5, w3, [x4]",
+                "cbnz w5, 0xfffffffffffffff0",
+                "b 0x8",
+                "clrex",
+            ]
+        );
+
+        let instrs = prod.compare_exchange_weak_64(Ordering::Relaxed, 1, 2, 3, 4, 5);
+        assert_eq!(
+            instrs,
+            [
+                "ldxr x1, [x4]",
+                "cmp x1, x2",
+                "b.ne 0x10",
+                "stxr w5, x3, [x4]",
+                "cmp w5, #0x0",
+                "b 0x8",
+                "clrex",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_atomic_regs_distinct() {
+        let mut prod = TestProducer::new();
+
+        assert_panic!("Should panic: operand equals address"; prod.fetch_add_64(Ordering::Relaxed, 1, 3, 3, 4, 5));
+        assert_panic!("Should panic: old equals expected"; prod.compare_exchange_64(Ordering::SeqCst, 1, 1, 3, 4, 5));
+    }
+
+    #[cfg(not(feature = "arm_feat_lse"))]
+    #[test]
+    fn test_atomic_scratch_regs_distinct_fallback() {
+        let mut prod = TestProducer::new();
+
+        assert_panic!("Should panic: tmp equals operand"; prod.fetch_add_64(Ordering::Relaxed, 1, 2, 3, 2, 5));
+        assert_panic!("Should panic: status equals address"; prod.swap_32(Ordering::Relaxed, 1, 2, 3, 3));
+        assert_panic!("Should panic: status equals new"; prod.compare_exchange_32(Ordering::SeqCst, 1, 2, 3, 4, 3));
+    }
+
+    #[cfg(feature = "arm_feat_lse")]
+    #[test]
+    fn test_atomic_scratch_regs_unused_lse() {
+        let mut prod = TestProducer::new();
+
+        // the scratch registers are not used, so they are not checked
+        let instrs = prod.fetch_add_64(Ordering::Relaxed, 1, 2, 3, 2, 2);
+        assert_eq!(instrs, ["ldadd x2, x1, [x3]"]);
+
+        let instrs = prod.compare_exchange_32(Ordering::Relaxed, 1, 2, 3, 4, 4);
+        assert_eq!(instrs, ["mov w1, w2", "cas w1, w3, [x4]", "cmp w1, w2"]);
+    }
+}
@@ -3,11 +3,13 @@
 //! Contains the following instruction types:
 //! - [Conditional branch (immediate)](conditional_branch_imm)
 //! - [Exception generation](exception_generation)
+//! - [Hints](hints)
 //! - [System instructions with register argument](system_instr_w_register_arg)
 //! - [Barriers](barriers)
 //! - [PSTATE](pstate)
 //! - [System Instructions](system_instructions)
 //! - [System register move](system_register_move)
+//! - [Transactional Memory Extension](tme)
 //! - [Unconditional branch (register)](unconditional_branch_register)
 
 use crate::instruction_encoding::branch_exception_system::barriers::Barriers;
@@ -18,6 +20,7 @@ use crate::instruction_encoding::branch_exception_system::conditional_branch_imm
     ConditionalBranchImmediate, ConditionalBranchImmediateWithAddress,
 };
 use crate::instruction_encoding::branch_exception_system::exception_generation::ExceptionGeneration;
+use crate::instruction_encoding::branch_exception_system::hints::Hints;
 use crate::instruction_encoding::branch_exception_system::pstate::PStateInstructions;
 use crate::instruction_encoding::branch_exception_system::system_instr_w_register_arg::SystemInstructionsWithRegArg;
 use crate::instruction_encoding::branch_exception_system::system_instructions::SystemInstructions;
@@ -25,6 +28,7 @@ use crate::instruction_encoding::branch_exception_system::system_register_move::
 use crate::instruction_encoding::branch_exception_system::test_and_branch_imm::{
     TestAndBranchImmediate, TestAndBranchImmediateWithAddress,
 };
+use crate::instruction_encoding::branch_exception_system::tme::TransactionalMemoryExtension;
 use crate::instruction_encoding::branch_exception_system::unconditional_branch_immediate::{
     UnconditionalBranchImmediate, UnconditionalBranchImmediateWithAddress,
 };
@@ -34,17 +38,20 @@ pub mod barriers;
 pub mod compare_and_branch_imm;
 pub mod conditional_branch_imm;
 pub mod exception_generation;
+pub mod hints;
 pub mod pstate;
 pub mod system_instr_w_register_arg;
 pub mod system_instructions;
 pub mod system_register_move;
 pub mod test_and_branch_imm;
+pub mod tme;
 pub mod unconditional_branch_immediate;
 pub mod unconditional_branch_register;
 
 pub trait BranchExceptionSystem<T>:
     ConditionalBranchImmediate<T>
     + ExceptionGeneration<T>
+    + Hints<T>
     + SystemInstructionsWithRegArg<T>
     + Barriers<T>
     + PStateInstructions<T>
@@ -54,6 +61,7 @@ pub trait BranchExceptionSystem<T>:
     + UnconditionalBranchImmediate<T>
     + CompareAndBranchImm<T>
     + TestAndBranchImmediate<T>
+    + TransactionalMemoryExtension<T>
 {
 }
 
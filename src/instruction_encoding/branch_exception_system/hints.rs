@@ -0,0 +1,286 @@
+//! # [Hints](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/Branches--Exception-Generating-and-System-instructions?lang=en#hints)
+//!
+//! Implements the following instructions:
+//! - [HINT](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/HINT--Hint-instruction-?lang=en)
+//! - [YIELD](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/YIELD--YIELD-?lang=en)
+//! - [WFE](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/WFE--Wait-For-Event-?lang=en)
+//! - [WFI](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/WFI--Wait-For-Interrupt-?lang=en)
+//! - [SEV](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SEV--Send-Event-?lang=en)
+//! - [SEVL](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SEVL--Send-Event-Local-?lang=en)
+//! - [ESB](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/ESB--Error-Synchronization-Barrier-?lang=en)
+//! - [PSB CSYNC](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/PSB-CSYNC--Profiling-Synchronization-Barrier-?lang=en)
+//! - [TSB CSYNC](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/TSB-CSYNC--Trace-Synchronization-Barrier-?lang=en)
+//! - [CSDB](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CSDB--Consumption-of-Speculative-Data-Barrier-?lang=en)
+//! - [BTI](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/BTI--Branch-Target-Identification-?lang=en)
+//! - [CLRBHB](https://developer.arm.com/documentation/ddi0602/2023-12/Base-Instructions/CLRBHB--Clear-Branch-History-?lang=en)
+//! - [CHKFEAT](https://developer.arm.com/documentation/ddi0602/2023-12/Base-Instructions/CHKFEAT--Check-feature-status-?lang=en)
+//! - [GCSB DSYNC](https://developer.arm.com/documentation/ddi0602/2023-12/Base-Instructions/GCSB--Guarded-Control-Stack-Barrier-?lang=en)
+
+use bit_seq::bseq_32;
+
+use crate::instruction_encoding::InstructionProcessor;
+use crate::types::bti_target::BtiTarget;
+use crate::types::encodable::Encodable;
+use crate::types::{UImm3, UImm4, UImm7};
+
+#[inline(always)]
+fn emit_hint<P: InstructionProcessor<T>, T>(proc: &mut P, crm: UImm4, op2: UImm3) -> T {
+    let i = bseq_32!(11010101 00000011 0010 crm:4 op2:3 11111);
+    proc.process(i)
+}
+
+pub trait Hints<T>: InstructionProcessor<T> {
+    /// [HINT](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/HINT--Hint-instruction-?lang=en)
+    ///
+    /// Hint instruction is for the instruction set space that is reserved for architectural hint instructions. Some encodings described here are not allocated in this revision of the architecture, and behave as NOPs. These encodings might be allocated to other hint functionality in future revisions of the architecture and therefore must not be used by software.
+    ///
+    /// `imm` must be in range 0 to 127.
+    ///
+    /// ```asm
+    /// HINT #<imm>
+    /// ```
+    #[inline(always)]
+    fn hint(&mut self, imm: UImm7) -> T {
+        debug_assert!(imm <= 127, "imm must be in range 0 to 127, was {}", imm);
+        emit_hint(self, imm >> 3, imm & 0b111)
+    }
+
+    /// [YIELD](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/YIELD--YIELD-?lang=en)
+    ///
+    /// YIELD is a hint instruction. Software with a multithreading capability can use a YIELD instruction to indicate to the PE that it is performing a task, for example a spin-lock, that could be swapped out to improve overall system performance. The PE can use this hint to suspend and resume multiple software threads if it supports the capability.
+    ///
+    /// ```asm
+    /// YIELD
+    /// ```
+    #[inline(always)]
+    fn yield_(&mut self) -> T {
+        emit_hint(self, 0b0000, 0b001)
+    }
+
+    /// [WFE](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/WFE--Wait-For-Event-?lang=en)
+    ///
+    /// Wait For Event is a hint instruction that indicates that the PE can enter a low-power state and remain there until a wakeup event occurs.
+    ///
+    /// ```asm
+    /// WFE
+    /// ```
+    #[inline(always)]
+    fn wfe(&mut self) -> T {
+        emit_hint(self, 0b0000, 0b010)
+    }
+
+    /// [WFI](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/WFI--Wait-For-Interrupt-?lang=en)
+    ///
+    /// Wait For Interrupt is a hint instruction that indicates that the PE can enter a low-power state and remain there until a wakeup event occurs.
+    ///
+    /// ```asm
+    /// WFI
+    /// ```
+    #[inline(always)]
+    fn wfi(&mut self) -> T {
+        emit_hint(self, 0b0000, 0b011)
+    }
+
+    /// [SEV](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SEV--Send-Event-?lang=en)
+    ///
+    /// Send Event is a hint instruction. It causes an event to be signaled to all PEs in the multiprocessor system.
+    ///
+    /// ```asm
+    /// SEV
+    /// ```
+    #[inline(always)]
+    fn sev(&mut self) -> T {
+        emit_hint(self, 0b0000, 0b100)
+    }
+
+    /// [SEVL](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SEVL--Send-Event-Local-?lang=en)
+    ///
+    /// Send Event Local is a hint instruction that causes an event to be signaled locally without requiring the event to be signaled to other PEs in the multiprocessor system. It can prime a wait-loop which starts with a WFE instruction.
+    ///
+    /// ```asm
+    /// SEVL
+    /// ```
+    #[inline(always)]
+    fn sevl(&mut self) -> T {
+        emit_hint(self, 0b0000, 0b101)
+    }
+
+    /// [ESB](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/ESB--Error-Synchronization-Barrier-?lang=en)
+    ///
+    /// Error Synchronization Barrier is an error synchronization event that might also update DISR_EL1 and VDISR_EL2.
+    ///
+    /// ```asm
+    /// ESB
+    /// ```
+    ///
+    /// *Info*: FEAT_RAS required
+    #[inline(always)]
+    fn esb(&mut self) -> T {
+        emit_hint(self, 0b0010, 0b000)
+    }
+
+    /// [PSB CSYNC](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/PSB-CSYNC--Profiling-Synchronization-Barrier-?lang=en)
+    ///
+    /// Profiling Synchronization Barrier. This instruction is a barrier that ensures that all existing profiling data for the current PE has been formatted, and profiling buffer addresses have been translated such that all writes to the profiling buffer have been initiated.
+    ///
+    /// ```asm
+    /// PSB CSYNC
+    /// ```
+    ///
+    /// *Info*: FEAT_SPE required
+    #[inline(always)]
+    fn psb_csync(&mut self) -> T {
+        emit_hint(self, 0b0010, 0b001)
+    }
+
+    /// [TSB CSYNC](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/TSB-CSYNC--Trace-Synchronization-Barrier-?lang=en)
+    ///
+    /// Trace Synchronization Barrier. This instruction is a barrier that synchronizes the trace operations of instructions.
+    ///
+    /// ```asm
+    /// TSB CSYNC
+    /// ```
+    ///
+    /// *Info*: FEAT_TRF required
+    #[inline(always)]
+    fn tsb_csync(&mut self) -> T {
+        emit_hint(self, 0b0010, 0b010)
+    }
+
+    /// [CSDB](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CSDB--Consumption-of-Speculative-Data-Barrier-?lang=en)
+    ///
+    /// Consumption of Speculative Data Barrier is a memory barrier that controls speculative execution and data value prediction.
+    ///
+    /// ```asm
+    /// CSDB
+    /// ```
+    #[inline(always)]
+    fn csdb(&mut self) -> T {
+        emit_hint(self, 0b0010, 0b100)
+    }
+
+    /// [BTI](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/BTI--Branch-Target-Identification-?lang=en)
+    ///
+    /// Branch Target Identification. A BTI instruction is used to guard against the execution of instructions which are not the intended target of a branch.
+    ///
+    /// ```asm
+    /// BTI {<targets>}
+    /// ```
+    ///
+    /// *Info*: FEAT_BTI required
+    #[inline(always)]
+    fn bti(&mut self, targets: BtiTarget) -> T {
+        emit_hint(self, 0b0100, targets.encode() << 1)
+    }
+
+    /// [CLRBHB](https://developer.arm.com/documentation/ddi0602/2023-12/Base-Instructions/CLRBHB--Clear-Branch-History-?lang=en)
+    ///
+    /// Clear Branch History clears the branch history for the current context to the extent that branch history information created before the CLRBHB instruction cannot be used by code before the CLRBHB instruction to exploitatively control the execution of any indirect branches in code in the current context that appear in program order after the instruction.
+    ///
+    /// ```asm
+    /// CLRBHB
+    /// ```
+    ///
+    /// *Info*: FEAT_CLRBHB required
+    #[inline(always)]
+    fn clrbhb(&mut self) -> T {
+        emit_hint(self, 0b0010, 0b110)
+    }
+
+    /// [CHKFEAT](https://developer.arm.com/documentation/ddi0602/2023-12/Base-Instructions/CHKFEAT--Check-feature-status-?lang=en)
+    ///
+    /// Check feature status. This instruction indicates the status of features. If FEAT_CHK is not implemented, this instruction executes as a NOP. The feature mask is passed and returned in X16.
+    ///
+    /// ```asm
+    /// CHKFEAT X16
+    /// ```
+    ///
+    /// *Info*: FEAT_CHK required
+    #[inline(always)]
+    fn chkfeat(&mut self) -> T {
+        emit_hint(self, 0b0101, 0b000)
+    }
+
+    /// [GCSB DSYNC](https://developer.arm.com/documentation/ddi0602/2023-12/Base-Instructions/GCSB--Guarded-Control-Stack-Barrier-?lang=en)
+    ///
+    /// Guarded Control Stack Barrier. This instruction generates a Guarded control stack data synchronization event. If FEAT_GCS is not implemented, this instruction executes as a NOP.
+    ///
+    /// ```asm
+    /// GCSB DSYNC
+    /// ```
+    ///
+    /// *Info*: FEAT_GCS required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_gcs")]
+    fn gcsb_dsync(&mut self) -> T {
+        emit_hint(self, 0b0010, 0b011)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assert_panic;
+    use crate::test_utils::test_producer::TestProducer;
+
+    use super::*;
+
+    #[test]
+    fn test_hint() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.hint(0);
+        assert_eq!(instr, "nop");
+
+        let instr = prod.hint(0x7f);
+        assert_eq!(instr, "hint #0x7f");
+
+        assert_panic!("Should panic: imm out of range"; prod.hint(128));
+    }
+
+    #[test]
+    fn test_named_hints() {
+        let mut prod = TestProducer::new();
+
+        assert_eq!(prod.yield_(), "yield");
+        assert_eq!(prod.wfe(), "wfe");
+        assert_eq!(prod.wfi(), "wfi");
+        assert_eq!(prod.sev(), "sev");
+        assert_eq!(prod.sevl(), "sevl");
+        assert_eq!(prod.esb(), "esb");
+        assert_eq!(prod.psb_csync(), "psb csync");
+        assert_eq!(prod.tsb_csync(), "tsb csync");
+        assert_eq!(prod.csdb(), "csdb");
+    }
+
+    #[test]
+    fn test_bti() {
+        let mut prod = TestProducer::new();
+
+        assert_eq!(prod.bti(BtiTarget::None), "bti");
+        assert_eq!(prod.bti(BtiTarget::C), "bti c");
+        assert_eq!(prod.bti(BtiTarget::J), "bti j");
+        assert_eq!(prod.bti(BtiTarget::JC), "bti jc");
+    }
+
+    #[test]
+    fn test_clrbhb_chkfeat() {
+        let mut prod = TestProducer::new();
+
+        // bad64 does not know CLRBHB and CHKFEAT yet and prints the generic hint form
+        assert_eq!(prod.clrbhb(), "hint #0x16");
+        assert_eq!(prod.chkfeat(), "hint #0x28");
+
+        let mut prod = crate::instruction_producer::InstrProducer::new();
+        assert_eq!(prod.clrbhb().encoding(), 0xd50322df);
+        assert_eq!(prod.chkfeat().encoding(), 0xd503251f);
+    }
+
+    #[cfg(feature = "arm_feat_gcs")]
+    #[test]
+    fn test_gcsb_dsync() {
+        let mut prod = TestProducer::new();
+
+        // bad64 does not know GCSB DSYNC and prints the generic hint form
+        assert_eq!(prod.gcsb_dsync(), "hint #0x13");
+    }
+}
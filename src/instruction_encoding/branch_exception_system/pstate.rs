@@ -39,8 +39,23 @@ pub trait PStateInstructions<T>: InstructionProcessor<T> {
             pstatefield != PStateField::ALLINT || imm == 0 || imm == 1,
             "imm must be either 0 or 1 if pstatefield is ALLINT"
         );
+        debug_assert!(
+            !matches!(
+                pstatefield,
+                PStateField::SVCRSM | PStateField::SVCRZA | PStateField::SVCRSMZA
+            ) || imm == 0
+                || imm == 1,
+            "imm must be either 0 or 1 if pstatefield is SVCRSM, SVCRZA or SVCRSMZA"
+        );
         let (op1, op2) = pstatefield.encode();
-        emit_pstate(self, op1, imm, op2, 0b11111)
+        // the SVCR fields are selected by CRm<3:1>, the immediate is CRm<0>
+        let crm = match pstatefield {
+            PStateField::SVCRSM => 0b0010 | imm,
+            PStateField::SVCRZA => 0b0100 | imm,
+            PStateField::SVCRSMZA => 0b0110 | imm,
+            _ => imm,
+        };
+        emit_pstate(self, op1, crm, op2, 0b11111)
     }
 
     /// [CFINV](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CFINV--Invert-Carry-Flag-?lang=en)
@@ -98,6 +113,7 @@ mod tests {
             assert_eq!(instr.to_string(), "msr uao, #0x2");
 
             assert_panic!("Should panic: allint only 1 or 0 allowed"; stream.msr_imm(ALLINT, 0x2));
+            assert_panic!("Should panic: svcr only 1 or 0 allowed"; stream.msr_imm(SVCRSMZA, 0x2));
         })
     }
 
@@ -6,10 +6,15 @@
 //!
 //! Provided aliases:
 //! - [AT](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/AT--Address-Translate--an-alias-of-SYS-?lang=en)
+//! - [BRB](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/BRB--Branch-Record-Buffer--an-alias-of-SYS-?lang=en)
 //! - [CFP](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CFP--Control-Flow-Prediction-Restriction-by-Context--an-alias-of-SYS-?lang=en)
 //! - [CPP](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CPP--Cache-Prefetch-Prediction-Restriction-by-Context--an-alias-of-SYS-?lang=en)
 //! - [DC](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/DC--Data-Cache-operation--an-alias-of-SYS-?lang=en)
 //! - [DVP](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/DVP--Data-Value-Prediction-Restriction-by-Context--an-alias-of-SYS-?lang=en)
+//! - [GCSPOPM](https://developer.arm.com/documentation/ddi0602/2023-12/Base-Instructions/GCSPOPM--Guarded-Control-Stack-Pop--an-alias-of-SYSL-?lang=en)
+//! - [GCSPUSHM](https://developer.arm.com/documentation/ddi0602/2023-12/Base-Instructions/GCSPUSHM--Guarded-Control-Stack-Push--an-alias-of-SYS-?lang=en)
+//! - [GCSSS1](https://developer.arm.com/documentation/ddi0602/2023-12/Base-Instructions/GCSSS1--Guarded-Control-Stack-Switch-Stack-1--an-alias-of-SYS-?lang=en)
+//! - [GCSSS2](https://developer.arm.com/documentation/ddi0602/2023-12/Base-Instructions/GCSSS2--Guarded-Control-Stack-Switch-Stack-2--an-alias-of-SYSL-?lang=en)
 //! - [IC](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/IC--Instruction-Cache-operation--an-alias-of-SYS-?lang=en)
 //! - [TLBI](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/TLBI--TLB-Invalidate-operation--an-alias-of-SYS-?lang=en)
 
@@ -21,6 +26,7 @@ use crate::types::encodable::Encodable;
 use crate::types::sys_ops::at_op::AtOp;
 use crate::types::sys_ops::dc_op::DcOp;
 use crate::types::sys_ops::ic_op::IcOp;
+use crate::types::sys_ops::tlbi_op::TlbiOp;
 
 #[inline(always)]
 fn emit_system_instruction<P: InstructionProcessor<T>, T>(
@@ -36,6 +42,17 @@ fn emit_system_instruction<P: InstructionProcessor<T>, T>(
     proc.process(i)
 }
 
+/// `Xt` must be given exactly if the TLBI operation uses it.
+#[inline(always)]
+fn debug_assert_tlbi_xt(tlbi_op: TlbiOp, xt: Option<Register>) {
+    debug_assert!(
+        tlbi_op.requires_xt() == xt.is_some(),
+        "{:?} {} an Xt register",
+        tlbi_op,
+        if tlbi_op.requires_xt() { "requires" } else { "does not take" }
+    );
+}
+
 pub trait SystemInstructions<T>: InstructionProcessor<T> {
     // instructions
 
@@ -74,6 +91,35 @@ pub trait SystemInstructions<T>: InstructionProcessor<T> {
         self.sys(op1, 0b0111, crm, op2, Some(xt))
     }
 
+    /// [BRB](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/BRB--Branch-Record-Buffer--an-alias-of-SYS-?lang=en)
+    ///
+    /// Invalidates all Branch records in the Branch Record Buffer.
+    ///
+    /// ```asm
+    /// BRB IALL
+    /// ```
+    ///
+    /// *Note*: FEAT_BRBE required
+    #[inline(always)]
+    fn brb_iall(&mut self) -> T {
+        self.sys(1, 7, 2, 4, None)
+    }
+
+    /// [BRB](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/BRB--Branch-Record-Buffer--an-alias-of-SYS-?lang=en)
+    ///
+    /// Injects the Branch Record held in BRBINFINJ_EL1, BRBSRCINJ_EL1, and BRBTGTINJ_EL1 into the
+    /// Branch Record Buffer.
+    ///
+    /// ```asm
+    /// BRB INJ
+    /// ```
+    ///
+    /// *Note*: FEAT_BRBE required
+    #[inline(always)]
+    fn brb_inj(&mut self) -> T {
+        self.sys(1, 7, 2, 5, None)
+    }
+
     /// [CFP](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CFP--Control-Flow-Prediction-Restriction-by-Context--an-alias-of-SYS-?lang=en)
     ///
     /// ```asm
@@ -116,6 +162,68 @@ pub trait SystemInstructions<T>: InstructionProcessor<T> {
         self.sys(3, 7, 3, 5, Some(xt))
     }
 
+    /// [GCSPOPM](https://developer.arm.com/documentation/ddi0602/2023-12/Base-Instructions/GCSPOPM--Guarded-Control-Stack-Pop--an-alias-of-SYSL-?lang=en)
+    ///
+    /// Guarded Control Stack Pop loads the 64-bit doubleword that is pointed to by the current Guarded Control Stack pointer, writes it to the destination register, and increments the current Guarded Control Stack pointer register by the size of a Guarded Control Stack procedure return record.
+    ///
+    /// Use `XZR` as `xt` to discard the popped entry.
+    ///
+    /// ```asm
+    /// GCSPOPM {<Xt>}
+    /// ```
+    ///
+    /// *Note*: FEAT_GCS required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_gcs")]
+    fn gcspopm(&mut self, xt: Register) -> T {
+        self.sysl(xt, 3, 7, 7, 1)
+    }
+
+    /// [GCSPUSHM](https://developer.arm.com/documentation/ddi0602/2023-12/Base-Instructions/GCSPUSHM--Guarded-Control-Stack-Push--an-alias-of-SYS-?lang=en)
+    ///
+    /// Guarded Control Stack Push decrements the current Guarded Control Stack pointer register by the size of a Guarded Control Procedure return record and stores an entry to the Guarded Control Stack.
+    ///
+    /// ```asm
+    /// GCSPUSHM <Xt>
+    /// ```
+    ///
+    /// *Note*: FEAT_GCS required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_gcs")]
+    fn gcspushm(&mut self, xt: Register) -> T {
+        self.sys(3, 7, 7, 0, Some(xt))
+    }
+
+    /// [GCSSS1](https://developer.arm.com/documentation/ddi0602/2023-12/Base-Instructions/GCSSS1--Guarded-Control-Stack-Switch-Stack-1--an-alias-of-SYS-?lang=en)
+    ///
+    /// Guarded Control Stack Switch Stack 1 validates that the stack being switched to contains a Valid cap entry, stores an In-progress cap entry to the stack that is being switched to, and sets the current Guarded Control Stack pointer to the stack that is being switched to.
+    ///
+    /// ```asm
+    /// GCSSS1 <Xt>
+    /// ```
+    ///
+    /// *Note*: FEAT_GCS required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_gcs")]
+    fn gcsss1(&mut self, xt: Register) -> T {
+        self.sys(3, 7, 7, 2, Some(xt))
+    }
+
+    /// [GCSSS2](https://developer.arm.com/documentation/ddi0602/2023-12/Base-Instructions/GCSSS2--Guarded-Control-Stack-Switch-Stack-2--an-alias-of-SYSL-?lang=en)
+    ///
+    /// Guarded Control Stack Switch Stack 2 validates that the most recent entry of the Guarded Control Stack being switched to contains an In-progress cap entry, stores a Valid cap entry to the Guarded Control Stack that is being switched from, and sets Xt to the Guarded Control Stack pointer that is being switched from.
+    ///
+    /// ```asm
+    /// GCSSS2 <Xt>
+    /// ```
+    ///
+    /// *Note*: FEAT_GCS required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_gcs")]
+    fn gcsss2(&mut self, xt: Register) -> T {
+        self.sysl(xt, 3, 7, 7, 3)
+    }
+
     /// [IC](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/IC--Instruction-Cache-operation--an-alias-of-SYS-?lang=en)
     ///
     /// ```asm
@@ -127,7 +235,43 @@ pub trait SystemInstructions<T>: InstructionProcessor<T> {
         self.sys(op1, 7, crm, op2, xt)
     }
 
-    //TODO: [TLBI](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/TLBI--TLB-Invalidate-operation--an-alias-of-SYS-?lang=en)
+    /// [TLBI](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/TLBI--TLB-Invalidate-operation--an-alias-of-SYS-?lang=en)
+    ///
+    /// `xt` must be given if and only if the operation [requires it](TlbiOp::requires_xt).
+    ///
+    /// ```asm
+    /// TLBI <tlbi_op>{,<Xt>}
+    /// ```
+    #[inline(always)]
+    fn tlbi(&mut self, tlbi_op: TlbiOp, xt: Option<Register>) -> T {
+        debug_assert_tlbi_xt(tlbi_op, xt);
+        let (op1, crm, op2) = tlbi_op.encode();
+        self.sys(op1, 0b1000, crm, op2, xt)
+    }
+
+    /// [TLBI](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/TLBI--TLB-Invalidate-operation--an-alias-of-SYS-?lang=en)
+    ///
+    /// The nXS variant of the TLB invalidation, which only waits for the completion of memory
+    /// accesses without the XS attribute.
+    /// `xt` must be given if and only if the operation [requires it](TlbiOp::requires_xt).
+    ///
+    /// ```asm
+    /// TLBI <tlbi_op>NXS{,<Xt>}
+    /// ```
+    ///
+    /// *Note*: FEAT_XS required, the `FEAT_RME` operations have no nXS variant
+    #[inline(always)]
+    fn tlbi_nxs(&mut self, tlbi_op: TlbiOp, xt: Option<Register>) -> T {
+        debug_assert_tlbi_xt(tlbi_op, xt);
+        #[cfg(feature = "arm_feat_rme")]
+        debug_assert!(
+            !matches!(tlbi_op, TlbiOp::PAALLOS | TlbiOp::RPAOS | TlbiOp::RPALOS | TlbiOp::PAALL),
+            "{:?} has no nXS variant",
+            tlbi_op
+        );
+        let (op1, crm, op2) = tlbi_op.encode();
+        self.sys(op1, 0b1001, crm, op2, xt)
+    }
 }
 
 #[cfg(test)]
@@ -135,7 +279,7 @@ mod tests {
     use crate::instruction_emitter::MockEmitter;
     use crate::instruction_stream::InstrStream;
     use crate::mc_memory::MockMemory;
-    use crate::stream_mock;
+    use crate::{assert_panic, stream_mock};
     use crate::types::InstructionPointer;
 
     use super::*;
@@ -181,4 +325,126 @@ mod tests {
             assert_eq!(instr.to_string(), "ic ialluis");
         })
     }
+
+    #[cfg(feature = "arm_feat_gcs")]
+    #[test]
+    fn test_gcs() {
+        stream_mock!(stream, {
+            // the disassembler does not know the GCS aliases
+            let instr = stream.gcspushm(30);
+            assert_eq!(instr.to_string(), "sys #0x3, c7, c7, #0x0, x30");
+
+            let instr = stream.gcspopm(1);
+            assert_eq!(instr.to_string(), "sysl x1, #0x3, c7, c7, #0x1");
+
+            let instr = stream.gcspopm(0b11111);
+            assert_eq!(instr.to_string(), "sysl xzr, #0x3, c7, c7, #0x1");
+
+            let instr = stream.gcsss1(2);
+            assert_eq!(instr.to_string(), "sys #0x3, c7, c7, #0x2, x2");
+
+            let instr = stream.gcsss2(3);
+            assert_eq!(instr.to_string(), "sysl x3, #0x3, c7, c7, #0x3");
+        })
+    }
+
+    #[test]
+    fn test_tlbi() {
+        stream_mock!(stream, {
+            let instr = stream.tlbi(TlbiOp::VMALLE1, None);
+            assert_eq!(instr.to_string(), "tlbi vmalle1");
+
+            let instr = stream.tlbi(TlbiOp::VAE1IS, Some(1));
+            assert_eq!(instr.to_string(), "tlbi vae1is, x1");
+
+            let instr = stream.tlbi(TlbiOp::ALLE2, None);
+            assert_eq!(instr.to_string(), "tlbi alle2");
+
+            let instr = stream.tlbi(TlbiOp::IPAS2LE1IS, Some(2));
+            assert_eq!(instr.to_string(), "tlbi ipas2le1is, x2");
+
+            let instr = stream.tlbi(TlbiOp::VALE3, Some(3));
+            assert_eq!(instr.to_string(), "tlbi vale3, x3");
+
+            // the disassembler does not know the range and outer shareable operations
+            let instr = stream.tlbi(TlbiOp::RVAE1IS, Some(1));
+            assert_eq!(instr.to_string(), "sys #0x0, c8, c2, #0x1, x1");
+
+            let instr = stream.tlbi(TlbiOp::VMALLE1OS, None);
+            assert_eq!(instr.to_string(), "sys #0x0, c8, c1, #0x0");
+
+            let instr = stream.tlbi(TlbiOp::RIPAS2LE1OS, Some(4));
+            assert_eq!(instr.to_string(), "sys #0x4, c8, c4, #0x7, x4");
+
+            assert_panic!("Should panic: VAE1 requires Xt"; stream.tlbi(TlbiOp::VAE1, None));
+            assert_panic!("Should panic: ALLE1 does not take Xt"; stream.tlbi(TlbiOp::ALLE1, Some(1)));
+        })
+    }
+
+    #[test]
+    fn test_tlbi_nxs() {
+        stream_mock!(stream, {
+            let instr = stream.tlbi_nxs(TlbiOp::VMALLE1IS, None);
+            assert_eq!(instr.to_string(), "sys #0x0, c9, c3, #0x0");
+
+            let instr = stream.tlbi_nxs(TlbiOp::RVALE3OS, Some(5));
+            assert_eq!(instr.to_string(), "sys #0x6, c9, c5, #0x5, x5");
+
+            assert_panic!("Should panic: VAE2 requires Xt"; stream.tlbi_nxs(TlbiOp::VAE2, None));
+        })
+    }
+
+    #[cfg(feature = "arm_feat_rme")]
+    #[test]
+    fn test_tlbi_rme() {
+        stream_mock!(stream, {
+            // the disassembler does not know the FEAT_RME operations
+            let instr = stream.tlbi(TlbiOp::PAALLOS, None);
+            assert_eq!(instr.encoding(), 0xd50e819f);
+
+            let instr = stream.tlbi(TlbiOp::PAALL, None);
+            assert_eq!(instr.encoding(), 0xd50e879f);
+
+            let instr = stream.tlbi(TlbiOp::RPAOS, Some(1));
+            assert_eq!(instr.encoding(), 0xd50e8461);
+
+            let instr = stream.tlbi(TlbiOp::RPALOS, Some(2));
+            assert_eq!(instr.encoding(), 0xd50e84e2);
+
+            assert_panic!("Should panic: RPAOS requires Xt"; stream.tlbi(TlbiOp::RPAOS, None));
+            assert_panic!("Should panic: no nXS variant"; stream.tlbi_nxs(TlbiOp::PAALL, None));
+        })
+    }
+
+    #[cfg(feature = "arm_feat_tlbiw")]
+    #[test]
+    fn test_tlbi_tlbiw() {
+        stream_mock!(stream, {
+            // the disassembler does not know the FEAT_TLBIW operations
+            let instr = stream.tlbi(TlbiOp::VMALLWS2E1, None);
+            assert_eq!(instr.encoding(), 0xd50c865f);
+
+            let instr = stream.tlbi(TlbiOp::VMALLWS2E1IS, None);
+            assert_eq!(instr.encoding(), 0xd50c825f);
+
+            let instr = stream.tlbi(TlbiOp::VMALLWS2E1OS, None);
+            assert_eq!(instr.encoding(), 0xd50c855f);
+
+            let instr = stream.tlbi_nxs(TlbiOp::VMALLWS2E1, None);
+            assert_eq!(instr.encoding(), 0xd50c965f);
+
+            assert_panic!("Should panic: VMALLWS2E1 does not take Xt"; stream.tlbi(TlbiOp::VMALLWS2E1, Some(1)));
+        })
+    }
+
+    #[test]
+    fn test_brb() {
+        stream_mock!(stream, {
+            let instr = stream.brb_iall();
+            assert_eq!(instr.to_string(), "sys #0x1, c7, c2, #0x4");
+
+            let instr = stream.brb_inj();
+            assert_eq!(instr.to_string(), "sys #0x1, c7, c2, #0x5");
+        })
+    }
 }
@@ -7,6 +7,8 @@
 use bit_seq::bseq_32;
 
 use crate::instruction_encoding::InstructionProcessor;
+use crate::types::encodable::Encodable;
+use crate::types::sys_reg::SysReg;
 use crate::types::{Register, UImm2, UImm3, UImm4};
 
 #[inline(always)]
@@ -27,8 +29,9 @@ fn emit_system_register_move<P: InstructionProcessor<T>, T>(
 pub trait SystemRegisterMove<T>: InstructionProcessor<T> {
     /// [MRS](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/MRS--Move-System-Register-?lang=en)
     ///
-    /// As we currently do not provide system registers as type, please read the [arm64 docs](https://developer.arm.com/documentation/ddi0595/2021-12/AArch64-Registers)
-    /// to find the system register encoding you are looking for.
+    /// Takes the raw register encoding, use [`msr_sysreg`](Self::msr_sysreg) for registers named by [`SysReg`].
+    /// Check the [arm64 docs](https://developer.arm.com/documentation/ddi0595/2021-12/AArch64-Registers)
+    /// for the system register encodings.
     ///
     /// ```asm
     /// MSR (<systemreg>|S<op0>_<op1>_<Cn>_<Cm>_<op2>), <Xt>
@@ -53,8 +56,9 @@ pub trait SystemRegisterMove<T>: InstructionProcessor<T> {
 
     /// [MRS](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/MRS--Move-System-Register-?lang=en)
     ///
-    /// As we currently do not provide system registers as type, please read the [arm64 docs](https://developer.arm.com/documentation/ddi0595/2021-12/AArch64-Registers)
-    /// to find the system register encoding you are looking for.
+    /// Takes the raw register encoding, use [`mrs_sysreg`](Self::mrs_sysreg) for registers named by [`SysReg`].
+    /// Check the [arm64 docs](https://developer.arm.com/documentation/ddi0595/2021-12/AArch64-Registers)
+    /// for the system register encodings.
     ///
     /// ```asm
     /// MRS <Xt>, (<systemreg>|S<op0>_<op1>_<Cn>_<Cm>_<op2>)
@@ -76,6 +80,33 @@ pub trait SystemRegisterMove<T>: InstructionProcessor<T> {
         );
         emit_system_register_move(self, 1, op0, op1, crn, crm, op2, xt)
     }
+
+    /// [MSR (register)](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/MSR--register---Move-general-purpose-register-to-System-Register-?lang=en)
+    ///
+    /// Writes `xt` to the named system register `reg`, which must not be [read-only](SysReg::is_read_only).
+    ///
+    /// ```asm
+    /// MSR <systemreg>, <Xt>
+    /// ```
+    #[inline(always)]
+    fn msr_sysreg(&mut self, reg: SysReg, xt: Register) -> T {
+        debug_assert!(!reg.is_read_only(), "{} is read-only", reg);
+        let (op0, op1, crn, crm, op2) = reg.encode();
+        self.msr_register(op0, op1, crn, crm, op2, xt)
+    }
+
+    /// [MRS](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/MRS--Move-System-Register-?lang=en)
+    ///
+    /// Reads the named system register `reg` into `xt`.
+    ///
+    /// ```asm
+    /// MRS <Xt>, <systemreg>
+    /// ```
+    #[inline(always)]
+    fn mrs_sysreg(&mut self, xt: Register, reg: SysReg) -> T {
+        let (op0, op1, crn, crm, op2) = reg.encode();
+        self.mrs(xt, op0, op1, crn, crm, op2)
+    }
 }
 
 #[cfg(test)]
@@ -107,4 +138,54 @@ mod tests {
             assert_panic!("Should panic: op0 not 2 or 3"; stream.mrs(1, 0b01, 0b110, 0b0101, 0b0001, 0b000));
         })
     }
+
+    #[test]
+    fn test_msr_mrs_sysreg() {
+        stream_mock!(stream, {
+            let instr = stream.msr_sysreg(SysReg::TPIDR_EL0, 1);
+            assert_eq!(instr.to_string(), "msr tpidr_el0, x1");
+
+            let instr = stream.msr_sysreg(SysReg::NZCV, 2);
+            assert_eq!(instr.to_string(), "msr nzcv, x2");
+
+            let instr = stream.msr_sysreg(SysReg::VBAR_EL1, 3);
+            assert_eq!(instr.to_string(), "msr vbar_el1, x3");
+
+            let instr = stream.mrs_sysreg(1, SysReg::FPCR);
+            assert_eq!(instr.to_string(), "mrs x1, fpcr");
+
+            let instr = stream.mrs_sysreg(1, SysReg::SCTLR_EL2);
+            assert_eq!(instr.to_string(), "mrs x1, sctlr_el2");
+
+            let instr = stream.mrs_sysreg(1, SysReg::TCR_EL3);
+            assert_eq!(instr.to_string(), "mrs x1, tcr_el3");
+
+            // the disassembler does not name the read-only registers
+            let instr = stream.mrs_sysreg(1, SysReg::CNTVCT_EL0);
+            assert_eq!(instr.to_string(), "mrs x1, s3_3_c14_c0_2");
+
+            let instr = stream.mrs_sysreg(1, SysReg::MIDR_EL1);
+            assert_eq!(instr.to_string(), "mrs x1, s3_0_c0_c0_0");
+
+            assert_panic!("Should panic: MIDR_EL1 is read-only"; stream.msr_sysreg(SysReg::MIDR_EL1, 1));
+            assert_panic!("Should panic: CNTVCT_EL0 is read-only"; stream.msr_sysreg(SysReg::CNTVCT_EL0, 1));
+            assert_panic!("Should panic: CurrentEL is read-only"; stream.msr_sysreg(SysReg::CurrentEL, 1));
+            assert_panic!("Should panic: RNDR is read-only"; stream.msr_sysreg(SysReg::RNDR, 1));
+        })
+    }
+
+    #[test]
+    fn test_sysreg_disassembly_names() {
+        stream_mock!(stream, {
+            // every register the disassembler knows by name must be printed with its name,
+            // except DAIF, which the disassembler misprints as daifset
+            for reg in SysReg::ALL {
+                let instr = stream.mrs_sysreg(1, *reg).to_string();
+                let printed = instr.trim_start_matches("mrs x1, ");
+                if !printed.starts_with("s3_") && *reg != SysReg::DAIF {
+                    assert_eq!(printed.parse(), Ok(*reg));
+                }
+            }
+        })
+    }
 }
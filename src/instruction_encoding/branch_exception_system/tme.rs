@@ -0,0 +1,120 @@
+//! # Transactional Memory Extension
+//!
+//! Implements the following instructions:
+//! - [TSTART](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/TSTART--Start-transaction-?lang=en)
+//! - [TTEST](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/TTEST--Test-transaction-state-?lang=en)
+//! - [TCOMMIT](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/TCOMMIT--Commit-current-transaction-?lang=en)
+//! - [TCANCEL](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/TCANCEL--Cancel-current-transaction-?lang=en)
+
+#[allow(unused_imports)]
+use bit_seq::bseq_32;
+
+use crate::instruction_encoding::InstructionProcessor;
+#[allow(unused_imports)]
+use crate::types::{Register, UImm16};
+
+#[inline(always)]
+#[cfg(feature = "arm_feat_tme")]
+fn emit_tme_reg<P: InstructionProcessor<T>, T>(proc: &mut P, crm: u8, rt: Register) -> T {
+    let i = bseq_32!(11010101 00100011 0011 crm:4 011 rt:5);
+    proc.process(i)
+}
+
+/// # Transactional Memory Extension
+///
+/// Implements the following instructions:
+/// - [TSTART](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/TSTART--Start-transaction-?lang=en)
+/// - [TTEST](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/TTEST--Test-transaction-state-?lang=en)
+/// - [TCOMMIT](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/TCOMMIT--Commit-current-transaction-?lang=en)
+/// - [TCANCEL](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/TCANCEL--Cancel-current-transaction-?lang=en)
+pub trait TransactionalMemoryExtension<T>: InstructionProcessor<T> {
+    /// [TSTART](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/TSTART--Start-transaction-?lang=en)
+    ///
+    /// Start transaction. This instruction starts a new transaction. If the transaction started successfully, the destination register is set to zero. If the transaction failed or was canceled, then all state modifications that were performed transactionally are discarded and the destination register is written with a nonzero value that encodes the cause of the failure.
+    ///
+    /// ```asm
+    /// TSTART <Xt>
+    /// ```
+    ///
+    /// *Note*: FEAT_TME required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_tme")]
+    fn tstart(&mut self, xt: Register) -> T {
+        emit_tme_reg(self, 0b0000, xt)
+    }
+
+    /// [TTEST](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/TTEST--Test-transaction-state-?lang=en)
+    ///
+    /// Test transaction state. This instruction writes the depth of the transaction to the destination register, or the value 0 otherwise.
+    ///
+    /// ```asm
+    /// TTEST <Xt>
+    /// ```
+    ///
+    /// *Note*: FEAT_TME required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_tme")]
+    fn ttest(&mut self, xt: Register) -> T {
+        emit_tme_reg(self, 0b0001, xt)
+    }
+
+    /// [TCOMMIT](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/TCOMMIT--Commit-current-transaction-?lang=en)
+    ///
+    /// Commit current transaction. This instruction commits the current transaction. If the current transaction is an outer transaction, then Transactional state is exited, and all state modifications performed transactionally are committed to the architectural state. TCOMMIT takes no inputs and returns no value.
+    ///
+    /// ```asm
+    /// TCOMMIT
+    /// ```
+    ///
+    /// *Note*: FEAT_TME required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_tme")]
+    fn tcommit(&mut self) -> T {
+        self.process(bseq_32!(11010101 00000011 0011 0000 011 11111))
+    }
+
+    /// [TCANCEL](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/TCANCEL--Cancel-current-transaction-?lang=en)
+    ///
+    /// Cancel current transaction. This instruction exits Transactional state and discards all state modifications that were performed transactionally. The reason for the cancellation is encoded in `imm` and made available to the `TSTART` destination register.
+    ///
+    /// ```asm
+    /// TCANCEL #<imm>
+    /// ```
+    ///
+    /// *Note*: FEAT_TME required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_tme")]
+    fn tcancel(&mut self, imm: UImm16) -> T {
+        self.process(bseq_32!(11010100 011 imm:16 00000))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use crate::test_utils::test_producer::TestProducer;
+
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[cfg(feature = "arm_feat_tme")]
+    #[test]
+    fn test_tstart_ttest() {
+        let mut prod = TestProducer::new();
+
+        assert_eq!(prod.tstart(1), "tstart x1");
+        assert_eq!(prod.tstart(30), "tstart x30");
+        assert_eq!(prod.ttest(1), "ttest x1");
+        assert_eq!(prod.ttest(0), "ttest x0");
+    }
+
+    #[cfg(feature = "arm_feat_tme")]
+    #[test]
+    fn test_tcommit_tcancel() {
+        let mut prod = TestProducer::new();
+
+        assert_eq!(prod.tcommit(), "tcommit");
+        assert_eq!(prod.tcancel(1), "tcancel #0x1");
+        assert_eq!(prod.tcancel(0xffff), "tcancel #0xffff");
+    }
+}
@@ -9,15 +9,49 @@
 //!  - [SUB - register](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SUB--shifted-register---Subtract--shifted-register--?lang=en)
 //!  - [SUBS - register](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SUBS--shifted-register---Subtract--shifted-register---setting-flags-?lang=en)
 //!  - [MUL - Multiply](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/MUL--Multiply--an-alias-of-MADD-?lang=en)
+//!  - [MNEG - Multiply-Negate](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/MNEG--Multiply-Negate--an-alias-of-MSUB-?lang=en)
+//!  - [SMULL - Signed Multiply Long](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SMULL--Signed-Multiply-Long--an-alias-of-SMADDL-?lang=en)
+//!  - [SMNEGL - Signed Multiply-Negate Long](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SMNEGL--Signed-Multiply-Negate-Long--an-alias-of-SMSUBL-?lang=en)
+//!  - [UMULL - Unsigned Multiply Long](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/UMULL--Unsigned-Multiply-Long--an-alias-of-UMADDL-?lang=en)
+//!  - [UMNEGL - Unsigned Multiply-Negate Long](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/UMNEGL--Unsigned-Multiply-Negate-Long--an-alias-of-UMSUBL-?lang=en)
+//!  - `mul_wide_u128` and `mul_wide_i128` - 128-bit product of two 64-bit registers, MUL and UMULH/SMULH
 //!  - [NEG - shifted register](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/NEG--shifted-register---Negate--shifted-register---an-alias-of-SUB--shifted-register--?lang=en)
+//!  - [CMP - immediate](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CMP--immediate---Compare--immediate---an-alias-of-SUBS--immediate--?lang=en)
+//!  - [CMP - extended register](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CMP--extended-register---Compare--extended-register---an-alias-of-SUBS--extended-register--?lang=en)
+//!  - [CMP - shifted register](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CMP--shifted-register---Compare--shifted-register---an-alias-of-SUBS--shifted-register--?lang=en)
+//!  - [CMN - immediate](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CMN--immediate---Compare-Negative--immediate---an-alias-of-ADDS--immediate--?lang=en)
+//!  - [CMN - extended register](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CMN--extended-register---Compare-Negative--extended-register---an-alias-of-ADDS--extended-register--?lang=en)
+//!  - [CMN - shifted register](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CMN--shifted-register---Compare-Negative--shifted-register---an-alias-of-ADDS--shifted-register--?lang=en)
+//!  - [TST - immediate](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/TST--immediate---Test-bits--immediate---an-alias-of-ANDS--immediate--?lang=en)
+//!  - [TST - shifted register](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/TST--shifted-register---Test--shifted-register---an-alias-of-ANDS--shifted-register--?lang=en)
+//!  - [NEGS](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/NEGS--Negate--setting-flags--an-alias-of-SUBS--shifted-register--?lang=en)
+//!  - [NGC](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/NGC--Negate-with-Carry--an-alias-of-SBC-?lang=en)
+//!  - [NGCS](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/NGCS--Negate-with-Carry--setting-flags--an-alias-of-SBCS-?lang=en)
+//!  - [MVN](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/MVN--Bitwise-NOT--an-alias-of-ORN--shifted-register--?lang=en)
 //!  - [NOP](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/NOP--No-Operation-?lang=en)
 
+use crate::instruction_encoding::data_proc_imm::add_substract_imm::AddSubtractImmediate;
+use crate::instruction_encoding::data_proc_imm::logical_imm::LogicalImmediate;
+use crate::instruction_encoding::data_proc_reg::add_sub_carry::AddSubtractWithCarry;
+use crate::instruction_encoding::data_proc_reg::add_sub_ext_reg::AddSubtractExtendedRegister;
 use crate::instruction_encoding::data_proc_reg::add_sub_shift_reg::AddSubtractShiftedRegister;
 use crate::instruction_encoding::data_proc_reg::data_proc_three_src::DataProcessingThreeSource;
 use crate::instruction_encoding::data_proc_reg::logical_shift_reg::LogicalShiftRegister;
-use crate::types::{Register, UImm5, UImm6};
+use crate::types::{Imm12, Register, UImm2, UImm32, UImm5, UImm6, UImm64};
+use crate::types::extends::RegExtend;
 use crate::types::register::{WZR, XZR};
-use crate::types::shifts::Shift3;
+use crate::types::shifts::{Shift1, Shift3, Shift4};
+
+/// The destination registers of a wide multiplication must differ, and at most one of them may be
+/// a source register, otherwise one half of the product would be computed from a clobbered input.
+#[inline(always)]
+fn debug_assert_mul_wide_regs(xd_lo: Register, xd_hi: Register, xn: Register, xm: Register) {
+    debug_assert!(xd_lo != xd_hi, "xd_lo and xd_hi must differ");
+    debug_assert!(
+        !((xd_lo == xn || xd_lo == xm) && (xd_hi == xn || xd_hi == xm)),
+        "xd_lo and xd_hi must not both be source registers"
+    );
+}
 
 /// # Common Aliases
 ///
@@ -30,10 +64,34 @@ use crate::types::shifts::Shift3;
 ///  - [SUB - register](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SUB--shifted-register---Subtract--shifted-register--?lang=en)
 ///  - [SUBS - register](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SUBS--shifted-register---Subtract--shifted-register---setting-flags-?lang=en)
 ///  - [MUL - Multiply](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/MUL--Multiply--an-alias-of-MADD-?lang=en)
+///  - [MNEG - Multiply-Negate](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/MNEG--Multiply-Negate--an-alias-of-MSUB-?lang=en)
+///  - [SMULL - Signed Multiply Long](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SMULL--Signed-Multiply-Long--an-alias-of-SMADDL-?lang=en)
+///  - [SMNEGL - Signed Multiply-Negate Long](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SMNEGL--Signed-Multiply-Negate-Long--an-alias-of-SMSUBL-?lang=en)
+///  - [UMULL - Unsigned Multiply Long](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/UMULL--Unsigned-Multiply-Long--an-alias-of-UMADDL-?lang=en)
+///  - [UMNEGL - Unsigned Multiply-Negate Long](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/UMNEGL--Unsigned-Multiply-Negate-Long--an-alias-of-UMSUBL-?lang=en)
+///  - `mul_wide_u128` and `mul_wide_i128` - 128-bit product of two 64-bit registers, MUL and UMULH/SMULH
 ///  - [NEG - shifted register](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/NEG--shifted-register---Negate--shifted-register---an-alias-of-SUB--shifted-register--?lang=en)
+///  - [CMP - immediate](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CMP--immediate---Compare--immediate---an-alias-of-SUBS--immediate--?lang=en)
+///  - [CMP - extended register](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CMP--extended-register---Compare--extended-register---an-alias-of-SUBS--extended-register--?lang=en)
+///  - [CMP - shifted register](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CMP--shifted-register---Compare--shifted-register---an-alias-of-SUBS--shifted-register--?lang=en)
+///  - [CMN - immediate](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CMN--immediate---Compare-Negative--immediate---an-alias-of-ADDS--immediate--?lang=en)
+///  - [CMN - extended register](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CMN--extended-register---Compare-Negative--extended-register---an-alias-of-ADDS--extended-register--?lang=en)
+///  - [CMN - shifted register](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CMN--shifted-register---Compare-Negative--shifted-register---an-alias-of-ADDS--shifted-register--?lang=en)
+///  - [TST - immediate](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/TST--immediate---Test-bits--immediate---an-alias-of-ANDS--immediate--?lang=en)
+///  - [TST - shifted register](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/TST--shifted-register---Test--shifted-register---an-alias-of-ANDS--shifted-register--?lang=en)
+///  - [NEGS](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/NEGS--Negate--setting-flags--an-alias-of-SUBS--shifted-register--?lang=en)
+///  - [NGC](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/NGC--Negate-with-Carry--an-alias-of-SBC-?lang=en)
+///  - [NGCS](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/NGCS--Negate-with-Carry--setting-flags--an-alias-of-SBCS-?lang=en)
+///  - [MVN](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/MVN--Bitwise-NOT--an-alias-of-ORN--shifted-register--?lang=en)
 ///  - [NOP](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/NOP--No-Operation-?lang=en)
 pub trait CommonAliases<T>:
-    LogicalShiftRegister<T> + AddSubtractShiftedRegister<T> + DataProcessingThreeSource<T>
+    LogicalShiftRegister<T>
+    + AddSubtractShiftedRegister<T>
+    + DataProcessingThreeSource<T>
+    + AddSubtractImmediate<T>
+    + AddSubtractExtendedRegister<T>
+    + LogicalImmediate<T>
+    + AddSubtractWithCarry<T>
 {
     /// [MOV (register)](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/MOV--register---Move--register---an-alias-of-ORR--shifted-register--?lang=en)
     ///
@@ -207,6 +265,154 @@ pub trait CommonAliases<T>:
         self.madd_64(xd, xn, xm, XZR)
     }
 
+    /// [MNEG - Multiply-Negate](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/MNEG--Multiply-Negate--an-alias-of-MSUB-?lang=en)
+    ///
+    /// : Rd = -(Rn * Rm).
+    ///
+    /// This is an alias of MSUB. This means:
+    /// - The encodings in this description are named to match the encodings of MSUB.
+    /// - The description of MSUB gives the operational pseudocode for this instruction.
+    ///
+    /// ```asm
+    /// MNEG <Wd>, <Wn>, <Wm>
+    /// ```
+    #[inline(always)]
+    fn mneg_32(&mut self, wd: Register, wn: Register, wm: Register) -> T {
+        self.msub_32(wd, wn, wm, WZR)
+    }
+
+    /// [MNEG - Multiply-Negate](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/MNEG--Multiply-Negate--an-alias-of-MSUB-?lang=en)
+    ///
+    /// : Rd = -(Rn * Rm).
+    ///
+    /// This is an alias of MSUB. This means:
+    /// - The encodings in this description are named to match the encodings of MSUB.
+    /// - The description of MSUB gives the operational pseudocode for this instruction.
+    ///
+    /// ```asm
+    /// MNEG <Xd>, <Xn>, <Xm>
+    /// ```
+    #[inline(always)]
+    fn mneg_64(&mut self, xd: Register, xn: Register, xm: Register) -> T {
+        self.msub_64(xd, xn, xm, XZR)
+    }
+
+    /// [SMULL - Signed Multiply Long](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SMULL--Signed-Multiply-Long--an-alias-of-SMADDL-?lang=en)
+    ///
+    /// Signed Multiply Long multiplies two 32-bit register values, and writes the result to the 64-bit destination register.
+    ///
+    /// This is an alias of SMADDL. This means:
+    /// - The encodings in this description are named to match the encodings of SMADDL.
+    /// - The description of SMADDL gives the operational pseudocode for this instruction.
+    ///
+    /// ```asm
+    /// SMULL <Xd>, <Wn>, <Wm>
+    /// ```
+    #[inline(always)]
+    fn smull(&mut self, xd: Register, wn: Register, wm: Register) -> T {
+        self.smaddl(xd, wn, wm, XZR)
+    }
+
+    /// [SMNEGL - Signed Multiply-Negate Long](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SMNEGL--Signed-Multiply-Negate-Long--an-alias-of-SMSUBL-?lang=en)
+    ///
+    /// Signed Multiply-Negate Long multiplies two 32-bit register values, negates the product, and writes the result to the 64-bit destination register.
+    ///
+    /// This is an alias of SMSUBL. This means:
+    /// - The encodings in this description are named to match the encodings of SMSUBL.
+    /// - The description of SMSUBL gives the operational pseudocode for this instruction.
+    ///
+    /// ```asm
+    /// SMNEGL <Xd>, <Wn>, <Wm>
+    /// ```
+    #[inline(always)]
+    fn smnegl(&mut self, xd: Register, wn: Register, wm: Register) -> T {
+        self.smsubl(xd, wn, wm, XZR)
+    }
+
+    /// [UMULL - Unsigned Multiply Long](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/UMULL--Unsigned-Multiply-Long--an-alias-of-UMADDL-?lang=en)
+    ///
+    /// Unsigned Multiply Long multiplies two 32-bit register values, and writes the result to the 64-bit destination register.
+    ///
+    /// This is an alias of UMADDL. This means:
+    /// - The encodings in this description are named to match the encodings of UMADDL.
+    /// - The description of UMADDL gives the operational pseudocode for this instruction.
+    ///
+    /// ```asm
+    /// UMULL <Xd>, <Wn>, <Wm>
+    /// ```
+    #[inline(always)]
+    fn umull(&mut self, xd: Register, wn: Register, wm: Register) -> T {
+        self.umaddl(xd, wn, wm, XZR)
+    }
+
+    /// [UMNEGL - Unsigned Multiply-Negate Long](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/UMNEGL--Unsigned-Multiply-Negate-Long--an-alias-of-UMSUBL-?lang=en)
+    ///
+    /// Unsigned Multiply-Negate Long multiplies two 32-bit register values, negates the product, and writes the result to the 64-bit destination register.
+    ///
+    /// This is an alias of UMSUBL. This means:
+    /// - The encodings in this description are named to match the encodings of UMSUBL.
+    /// - The description of UMSUBL gives the operational pseudocode for this instruction.
+    ///
+    /// ```asm
+    /// UMNEGL <Xd>, <Wn>, <Wm>
+    /// ```
+    #[inline(always)]
+    fn umnegl(&mut self, xd: Register, wn: Register, wm: Register) -> T {
+        self.umsubl(xd, wn, wm, XZR)
+    }
+
+    /// Multiplies the unsigned 64-bit values in `xn` and `xm` and writes the full 128-bit product
+    /// to the register pair `xd_hi:xd_lo`, using a MUL for the low and an UMULH for the high half.
+    ///
+    /// `xd_lo` and `xd_hi` must differ. If `xd_lo` is also a source register, the UMULH is
+    /// emitted first, so at most one of the destination registers may be a source register.
+    ///
+    /// Returns the MUL and the UMULH instruction, in this order.
+    ///
+    /// ```asm
+    /// MUL <Xd_lo>, <Xn>, <Xm>
+    /// UMULH <Xd_hi>, <Xn>, <Xm>
+    /// ```
+    #[inline(always)]
+    fn mul_wide_u128(&mut self, xd_lo: Register, xd_hi: Register, xn: Register, xm: Register) -> (T, T) {
+        debug_assert_mul_wide_regs(xd_lo, xd_hi, xn, xm);
+        if xd_lo != xn && xd_lo != xm {
+            let lo = self.mul_64_reg(xd_lo, xn, xm);
+            let hi = self.umulh(xd_hi, xn, xm);
+            (lo, hi)
+        } else {
+            let hi = self.umulh(xd_hi, xn, xm);
+            let lo = self.mul_64_reg(xd_lo, xn, xm);
+            (lo, hi)
+        }
+    }
+
+    /// Multiplies the signed 64-bit values in `xn` and `xm` and writes the full 128-bit product
+    /// to the register pair `xd_hi:xd_lo`, using a MUL for the low and an SMULH for the high half.
+    ///
+    /// `xd_lo` and `xd_hi` must differ. If `xd_lo` is also a source register, the SMULH is
+    /// emitted first, so at most one of the destination registers may be a source register.
+    ///
+    /// Returns the MUL and the SMULH instruction, in this order.
+    ///
+    /// ```asm
+    /// MUL <Xd_lo>, <Xn>, <Xm>
+    /// SMULH <Xd_hi>, <Xn>, <Xm>
+    /// ```
+    #[inline(always)]
+    fn mul_wide_i128(&mut self, xd_lo: Register, xd_hi: Register, xn: Register, xm: Register) -> (T, T) {
+        debug_assert_mul_wide_regs(xd_lo, xd_hi, xn, xm);
+        if xd_lo != xn && xd_lo != xm {
+            let lo = self.mul_64_reg(xd_lo, xn, xm);
+            let hi = self.smulh(xd_hi, xn, xm);
+            (lo, hi)
+        } else {
+            let hi = self.smulh(xd_hi, xn, xm);
+            let lo = self.mul_64_reg(xd_lo, xn, xm);
+            (lo, hi)
+        }
+    }
+
     /// [NEG - shifted register](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/NEG--shifted-register---Negate--shifted-register---an-alias-of-SUB--shifted-register--?lang=en)
     /// Negate (shifted register) negates an optionally-shifted register value, and writes the result to the destination register.
     ///
@@ -237,6 +443,514 @@ pub trait CommonAliases<T>:
         self.sub_64_reg_shift(xd, WZR, xm, shift.unwrap_or(Shift3::LSL(0)))
     }
 
+    /// [CMP - immediate](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CMP--immediate---Compare--immediate---an-alias-of-SUBS--immediate--?lang=en)
+    ///
+    /// Compare (immediate) subtracts an immediate value from a register value. It updates the condition flags based on the result, and discards the result.
+    ///
+    /// This is an alias of SUBS (immediate). This means:
+    /// - The encodings in this description are named to match the encodings of SUBS (immediate).
+    /// - The description of SUBS (immediate) gives the operational pseudocode for this instruction.
+    ///
+    /// A `wn_wsp` of 31 is `WSP`.
+    ///
+    /// ```asm
+    /// CMP <Wn|WSP>, #<imm>
+    /// ```
+    #[inline(always)]
+    fn cmp_32_imm(&mut self, wn_wsp: Register, imm12: Imm12) -> T {
+        self.subs_32_imm(WZR, wn_wsp, imm12)
+    }
+
+    /// [CMP - immediate](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CMP--immediate---Compare--immediate---an-alias-of-SUBS--immediate--?lang=en)
+    ///
+    /// Compare (immediate) subtracts an immediate value, optionally shifted left by 12, from a register value. It updates the condition flags based on the result, and discards the result.
+    ///
+    /// This is an alias of SUBS (immediate). This means:
+    /// - The encodings in this description are named to match the encodings of SUBS (immediate).
+    /// - The description of SUBS (immediate) gives the operational pseudocode for this instruction.
+    ///
+    /// A `wn_wsp` of 31 is `WSP`.
+    ///
+    /// ```asm
+    /// CMP <Wn|WSP>, #<imm>, <shift>
+    /// ```
+    #[inline(always)]
+    fn cmp_32_imm_lsl(&mut self, wn_wsp: Register, imm12: Imm12, lsl: Shift1) -> T {
+        self.subs_32_imm_lsl(WZR, wn_wsp, imm12, lsl)
+    }
+
+    /// [CMP - extended register](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CMP--extended-register---Compare--extended-register---an-alias-of-SUBS--extended-register--?lang=en)
+    ///
+    /// Compare (extended register) subtracts a sign or zero-extended register value, followed by an optional left shift amount, from a register value. It updates the condition flags based on the result, and discards the result.
+    ///
+    /// This is an alias of SUBS (extended register). This means:
+    /// - The encodings in this description are named to match the encodings of SUBS (extended register).
+    /// - The description of SUBS (extended register) gives the operational pseudocode for this instruction.
+    ///
+    /// A `wn_wsp` of 31 is `WSP`. This is the form to use if the first operand is the stack pointer.
+    ///
+    /// ```asm
+    /// CMP <Wn|WSP>, <Wm>{, <extend> {#<amount>}}
+    /// ```
+    #[inline(always)]
+    fn cmp_32_reg_extend(
+        &mut self,
+        wn_wsp: Register,
+        wm: Register,
+        extend: RegExtend,
+        amount: Option<UImm2>,
+    ) -> T {
+        self.subs_32_reg_extend(WZR, wn_wsp, wm, extend, amount)
+    }
+
+    /// [CMP - shifted register](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CMP--shifted-register---Compare--shifted-register---an-alias-of-SUBS--shifted-register--?lang=en)
+    ///
+    /// Compare (shifted register) subtracts an optionally-shifted register value from a register value. It updates the condition flags based on the result, and discards the result.
+    ///
+    /// This is an alias of SUBS (shifted register). This means:
+    /// - The encodings in this description are named to match the encodings of SUBS (shifted register).
+    /// - The description of SUBS (shifted register) gives the operational pseudocode for this instruction.
+    ///
+    /// A `wn` of 31 is `WZR`, use [`cmp_32_reg_extend`](Self::cmp_32_reg_extend) to compare with the stack pointer.
+    ///
+    /// ```asm
+    /// CMP <Wn>, <Wm>{, <shift> #<amount>}
+    /// ```
+    #[inline(always)]
+    fn cmp_32_reg(&mut self, wn: Register, wm: Register, shift: Option<Shift3<UImm5>>) -> T {
+        self.subs_32_reg_shift(WZR, wn, wm, shift.unwrap_or(Shift3::LSL(0)))
+    }
+
+    /// [CMP - immediate](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CMP--immediate---Compare--immediate---an-alias-of-SUBS--immediate--?lang=en)
+    ///
+    /// Compare (immediate) subtracts an immediate value from a register value. It updates the condition flags based on the result, and discards the result.
+    ///
+    /// This is an alias of SUBS (immediate). This means:
+    /// - The encodings in this description are named to match the encodings of SUBS (immediate).
+    /// - The description of SUBS (immediate) gives the operational pseudocode for this instruction.
+    ///
+    /// A `xn_sp` of 31 is `SP`.
+    ///
+    /// ```asm
+    /// CMP <Xn|SP>, #<imm>
+    /// ```
+    #[inline(always)]
+    fn cmp_64_imm(&mut self, xn_sp: Register, imm12: Imm12) -> T {
+        self.subs_64_imm(XZR, xn_sp, imm12)
+    }
+
+    /// [CMP - immediate](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CMP--immediate---Compare--immediate---an-alias-of-SUBS--immediate--?lang=en)
+    ///
+    /// Compare (immediate) subtracts an immediate value, optionally shifted left by 12, from a register value. It updates the condition flags based on the result, and discards the result.
+    ///
+    /// This is an alias of SUBS (immediate). This means:
+    /// - The encodings in this description are named to match the encodings of SUBS (immediate).
+    /// - The description of SUBS (immediate) gives the operational pseudocode for this instruction.
+    ///
+    /// A `xn_sp` of 31 is `SP`.
+    ///
+    /// ```asm
+    /// CMP <Xn|SP>, #<imm>, <shift>
+    /// ```
+    #[inline(always)]
+    fn cmp_64_imm_lsl(&mut self, xn_sp: Register, imm12: Imm12, lsl: Shift1) -> T {
+        self.subs_64_imm_lsl(XZR, xn_sp, imm12, lsl)
+    }
+
+    /// [CMP - extended register](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CMP--extended-register---Compare--extended-register---an-alias-of-SUBS--extended-register--?lang=en)
+    ///
+    /// Compare (extended register) subtracts a sign or zero-extended register value, followed by an optional left shift amount, from a register value. It updates the condition flags based on the result, and discards the result.
+    ///
+    /// This is an alias of SUBS (extended register). This means:
+    /// - The encodings in this description are named to match the encodings of SUBS (extended register).
+    /// - The description of SUBS (extended register) gives the operational pseudocode for this instruction.
+    ///
+    /// A `xn_sp` of 31 is `SP`. This is the form to use if the first operand is the stack pointer.
+    ///
+    /// ```asm
+    /// CMP <Xn|SP>, <R><m>{, <extend> {#<amount>}}
+    /// ```
+    #[inline(always)]
+    fn cmp_64_reg_extend(
+        &mut self,
+        xn_sp: Register,
+        m: Register,
+        extend: RegExtend,
+        amount: Option<UImm2>,
+    ) -> T {
+        self.subs_64_reg_extend(XZR, xn_sp, m, extend, amount)
+    }
+
+    /// [CMP - shifted register](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CMP--shifted-register---Compare--shifted-register---an-alias-of-SUBS--shifted-register--?lang=en)
+    ///
+    /// Compare (shifted register) subtracts an optionally-shifted register value from a register value. It updates the condition flags based on the result, and discards the result.
+    ///
+    /// This is an alias of SUBS (shifted register). This means:
+    /// - The encodings in this description are named to match the encodings of SUBS (shifted register).
+    /// - The description of SUBS (shifted register) gives the operational pseudocode for this instruction.
+    ///
+    /// A `xn` of 31 is `XZR`, use [`cmp_64_reg_extend`](Self::cmp_64_reg_extend) to compare with the stack pointer.
+    ///
+    /// ```asm
+    /// CMP <Xn>, <Xm>{, <shift> #<amount>}
+    /// ```
+    #[inline(always)]
+    fn cmp_64_reg(&mut self, xn: Register, xm: Register, shift: Option<Shift3<UImm6>>) -> T {
+        self.subs_64_reg_shift(XZR, xn, xm, shift.unwrap_or(Shift3::LSL(0)))
+    }
+
+    /// [CMN - immediate](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CMN--immediate---Compare-Negative--immediate---an-alias-of-ADDS--immediate--?lang=en)
+    ///
+    /// Compare Negative (immediate) adds an immediate value to a register value. It updates the condition flags based on the result, and discards the result.
+    ///
+    /// This is an alias of ADDS (immediate). This means:
+    /// - The encodings in this description are named to match the encodings of ADDS (immediate).
+    /// - The description of ADDS (immediate) gives the operational pseudocode for this instruction.
+    ///
+    /// A `wn_wsp` of 31 is `WSP`.
+    ///
+    /// ```asm
+    /// CMN <Wn|WSP>, #<imm>
+    /// ```
+    #[inline(always)]
+    fn cmn_32_imm(&mut self, wn_wsp: Register, imm12: Imm12) -> T {
+        self.adds_32_imm(WZR, wn_wsp, imm12)
+    }
+
+    /// [CMN - immediate](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CMN--immediate---Compare-Negative--immediate---an-alias-of-ADDS--immediate--?lang=en)
+    ///
+    /// Compare Negative (immediate) adds an immediate value, optionally shifted left by 12, to a register value. It updates the condition flags based on the result, and discards the result.
+    ///
+    /// This is an alias of ADDS (immediate). This means:
+    /// - The encodings in this description are named to match the encodings of ADDS (immediate).
+    /// - The description of ADDS (immediate) gives the operational pseudocode for this instruction.
+    ///
+    /// A `wn_wsp` of 31 is `WSP`.
+    ///
+    /// ```asm
+    /// CMN <Wn|WSP>, #<imm>, <shift>
+    /// ```
+    #[inline(always)]
+    fn cmn_32_imm_lsl(&mut self, wn_wsp: Register, imm12: Imm12, lsl: Shift1) -> T {
+        self.adds_32_imm_lsl(WZR, wn_wsp, imm12, lsl)
+    }
+
+    /// [CMN - extended register](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CMN--extended-register---Compare-Negative--extended-register---an-alias-of-ADDS--extended-register--?lang=en)
+    ///
+    /// Compare Negative (extended register) adds a sign or zero-extended register value, followed by an optional left shift amount, to a register value. It updates the condition flags based on the result, and discards the result.
+    ///
+    /// This is an alias of ADDS (extended register). This means:
+    /// - The encodings in this description are named to match the encodings of ADDS (extended register).
+    /// - The description of ADDS (extended register) gives the operational pseudocode for this instruction.
+    ///
+    /// A `wn_wsp` of 31 is `WSP`. This is the form to use if the first operand is the stack pointer.
+    ///
+    /// ```asm
+    /// CMN <Wn|WSP>, <Wm>{, <extend> {#<amount>}}
+    /// ```
+    #[inline(always)]
+    fn cmn_32_reg_extend(
+        &mut self,
+        wn_wsp: Register,
+        wm: Register,
+        extend: RegExtend,
+        amount: Option<UImm2>,
+    ) -> T {
+        self.adds_32_reg_extend(WZR, wn_wsp, wm, extend, amount)
+    }
+
+    /// [CMN - shifted register](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CMN--shifted-register---Compare-Negative--shifted-register---an-alias-of-ADDS--shifted-register--?lang=en)
+    ///
+    /// Compare Negative (shifted register) adds an optionally-shifted register value to a register value. It updates the condition flags based on the result, and discards the result.
+    ///
+    /// This is an alias of ADDS (shifted register). This means:
+    /// - The encodings in this description are named to match the encodings of ADDS (shifted register).
+    /// - The description of ADDS (shifted register) gives the operational pseudocode for this instruction.
+    ///
+    /// A `wn` of 31 is `WZR`, use [`cmn_32_reg_extend`](Self::cmn_32_reg_extend) to compare with the stack pointer.
+    ///
+    /// ```asm
+    /// CMN <Wn>, <Wm>{, <shift> #<amount>}
+    /// ```
+    #[inline(always)]
+    fn cmn_32_reg(&mut self, wn: Register, wm: Register, shift: Option<Shift3<UImm5>>) -> T {
+        self.adds_32_reg_shift(WZR, wn, wm, shift.unwrap_or(Shift3::LSL(0)))
+    }
+
+    /// [CMN - immediate](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CMN--immediate---Compare-Negative--immediate---an-alias-of-ADDS--immediate--?lang=en)
+    ///
+    /// Compare Negative (immediate) adds an immediate value to a register value. It updates the condition flags based on the result, and discards the result.
+    ///
+    /// This is an alias of ADDS (immediate). This means:
+    /// - The encodings in this description are named to match the encodings of ADDS (immediate).
+    /// - The description of ADDS (immediate) gives the operational pseudocode for this instruction.
+    ///
+    /// A `xn_sp` of 31 is `SP`.
+    ///
+    /// ```asm
+    /// CMN <Xn|SP>, #<imm>
+    /// ```
+    #[inline(always)]
+    fn cmn_64_imm(&mut self, xn_sp: Register, imm12: Imm12) -> T {
+        self.adds_64_imm(XZR, xn_sp, imm12)
+    }
+
+    /// [CMN - immediate](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CMN--immediate---Compare-Negative--immediate---an-alias-of-ADDS--immediate--?lang=en)
+    ///
+    /// Compare Negative (immediate) adds an immediate value, optionally shifted left by 12, to a register value. It updates the condition flags based on the result, and discards the result.
+    ///
+    /// This is an alias of ADDS (immediate). This means:
+    /// - The encodings in this description are named to match the encodings of ADDS (immediate).
+    /// - The description of ADDS (immediate) gives the operational pseudocode for this instruction.
+    ///
+    /// A `xn_sp` of 31 is `SP`.
+    ///
+    /// ```asm
+    /// CMN <Xn|SP>, #<imm>, <shift>
+    /// ```
+    #[inline(always)]
+    fn cmn_64_imm_lsl(&mut self, xn_sp: Register, imm12: Imm12, lsl: Shift1) -> T {
+        self.adds_64_imm_lsl(XZR, xn_sp, imm12, lsl)
+    }
+
+    /// [CMN - extended register](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CMN--extended-register---Compare-Negative--extended-register---an-alias-of-ADDS--extended-register--?lang=en)
+    ///
+    /// Compare Negative (extended register) adds a sign or zero-extended register value, followed by an optional left shift amount, to a register value. It updates the condition flags based on the result, and discards the result.
+    ///
+    /// This is an alias of ADDS (extended register). This means:
+    /// - The encodings in this description are named to match the encodings of ADDS (extended register).
+    /// - The description of ADDS (extended register) gives the operational pseudocode for this instruction.
+    ///
+    /// A `xn_sp` of 31 is `SP`. This is the form to use if the first operand is the stack pointer.
+    ///
+    /// ```asm
+    /// CMN <Xn|SP>, <R><m>{, <extend> {#<amount>}}
+    /// ```
+    #[inline(always)]
+    fn cmn_64_reg_extend(
+        &mut self,
+        xn_sp: Register,
+        m: Register,
+        extend: RegExtend,
+        amount: Option<UImm2>,
+    ) -> T {
+        self.adds_64_reg_extend(XZR, xn_sp, m, extend, amount)
+    }
+
+    /// [CMN - shifted register](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CMN--shifted-register---Compare-Negative--shifted-register---an-alias-of-ADDS--shifted-register--?lang=en)
+    ///
+    /// Compare Negative (shifted register) adds an optionally-shifted register value to a register value. It updates the condition flags based on the result, and discards the result.
+    ///
+    /// This is an alias of ADDS (shifted register). This means:
+    /// - The encodings in this description are named to match the encodings of ADDS (shifted register).
+    /// - The description of ADDS (shifted register) gives the operational pseudocode for this instruction.
+    ///
+    /// A `xn` of 31 is `XZR`, use [`cmn_64_reg_extend`](Self::cmn_64_reg_extend) to compare with the stack pointer.
+    ///
+    /// ```asm
+    /// CMN <Xn>, <Xm>{, <shift> #<amount>}
+    /// ```
+    #[inline(always)]
+    fn cmn_64_reg(&mut self, xn: Register, xm: Register, shift: Option<Shift3<UImm6>>) -> T {
+        self.adds_64_reg_shift(XZR, xn, xm, shift.unwrap_or(Shift3::LSL(0)))
+    }
+
+    /// [TST - immediate](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/TST--immediate---Test-bits--immediate---an-alias-of-ANDS--immediate--?lang=en)
+    ///
+    /// Test bits (immediate), setting the condition flags and discarding the result: Rn AND imm.
+    ///
+    /// This is an alias of ANDS (immediate). This means:
+    /// - The encodings in this description are named to match the encodings of ANDS (immediate).
+    /// - The description of ANDS (immediate) gives the operational pseudocode for this instruction.
+    ///
+    /// Returns `Err(())` if `imm` cannot be encoded as a logical immediate.
+    ///
+    /// ```asm
+    /// TST <Wn>, #<imm>
+    /// ```
+    #[inline(always)]
+    fn tst_32_imm(&mut self, wn: Register, imm: UImm32) -> Result<T, ()> {
+        self.ands_32_imm(WZR, wn, imm)
+    }
+
+    /// [TST - immediate](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/TST--immediate---Test-bits--immediate---an-alias-of-ANDS--immediate--?lang=en)
+    ///
+    /// Test bits (immediate), setting the condition flags and discarding the result: Rn AND imm.
+    ///
+    /// This is an alias of ANDS (immediate). This means:
+    /// - The encodings in this description are named to match the encodings of ANDS (immediate).
+    /// - The description of ANDS (immediate) gives the operational pseudocode for this instruction.
+    ///
+    /// Returns `Err(())` if `imm` cannot be encoded as a logical immediate.
+    ///
+    /// ```asm
+    /// TST <Xn>, #<imm>
+    /// ```
+    #[inline(always)]
+    fn tst_64_imm(&mut self, xn: Register, imm: UImm64) -> Result<T, ()> {
+        self.ands_64_imm(XZR, xn, imm)
+    }
+
+    /// [TST - shifted register](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/TST--shifted-register---Test--shifted-register---an-alias-of-ANDS--shifted-register--?lang=en)
+    ///
+    /// Test (shifted register) performs a bitwise AND operation on a register value and an optionally-shifted register value. It updates the condition flags based on the result, and discards the result.
+    ///
+    /// This is an alias of ANDS (shifted register). This means:
+    /// - The encodings in this description are named to match the encodings of ANDS (shifted register).
+    /// - The description of ANDS (shifted register) gives the operational pseudocode for this instruction.
+    ///
+    /// ```asm
+    /// TST <Wn>, <Wm>{, <shift> #<amount>}
+    /// ```
+    #[inline(always)]
+    fn tst_32_reg(&mut self, wn: Register, wm: Register, shift: Option<Shift4<UImm5>>) -> T {
+        self.ands_32(WZR, wn, wm, shift)
+    }
+
+    /// [TST - shifted register](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/TST--shifted-register---Test--shifted-register---an-alias-of-ANDS--shifted-register--?lang=en)
+    ///
+    /// Test (shifted register) performs a bitwise AND operation on a register value and an optionally-shifted register value. It updates the condition flags based on the result, and discards the result.
+    ///
+    /// This is an alias of ANDS (shifted register). This means:
+    /// - The encodings in this description are named to match the encodings of ANDS (shifted register).
+    /// - The description of ANDS (shifted register) gives the operational pseudocode for this instruction.
+    ///
+    /// ```asm
+    /// TST <Xn>, <Xm>{, <shift> #<amount>}
+    /// ```
+    #[inline(always)]
+    fn tst_64_reg(&mut self, xn: Register, xm: Register, shift: Option<Shift4<UImm6>>) -> T {
+        self.ands_64(XZR, xn, xm, shift)
+    }
+
+    /// [NEGS](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/NEGS--Negate--setting-flags--an-alias-of-SUBS--shifted-register--?lang=en)
+    ///
+    /// Negate, setting flags, negates an optionally-shifted register value, and writes the result to the destination register. It updates the condition flags based on the result.
+    ///
+    /// This is an alias of SUBS (shifted register). This means:
+    /// - The encodings in this description are named to match the encodings of SUBS (shifted register).
+    /// - The description of SUBS (shifted register) gives the operational pseudocode for this instruction.
+    ///
+    /// ```asm
+    /// NEGS <Wd>, <Wm>{, <shift> #<amount>}
+    /// ```
+    #[inline(always)]
+    fn negs_32_reg(&mut self, wd: Register, wm: Register, shift: Option<Shift3<UImm5>>) -> T {
+        self.subs_32_reg_shift(wd, WZR, wm, shift.unwrap_or(Shift3::LSL(0)))
+    }
+
+    /// [NEGS](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/NEGS--Negate--setting-flags--an-alias-of-SUBS--shifted-register--?lang=en)
+    ///
+    /// Negate, setting flags, negates an optionally-shifted register value, and writes the result to the destination register. It updates the condition flags based on the result.
+    ///
+    /// This is an alias of SUBS (shifted register). This means:
+    /// - The encodings in this description are named to match the encodings of SUBS (shifted register).
+    /// - The description of SUBS (shifted register) gives the operational pseudocode for this instruction.
+    ///
+    /// ```asm
+    /// NEGS <Xd>, <Xm>{, <shift> #<amount>}
+    /// ```
+    #[inline(always)]
+    fn negs_64_reg(&mut self, xd: Register, xm: Register, shift: Option<Shift3<UImm6>>) -> T {
+        self.subs_64_reg_shift(xd, XZR, xm, shift.unwrap_or(Shift3::LSL(0)))
+    }
+
+    /// [NGC](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/NGC--Negate-with-Carry--an-alias-of-SBC-?lang=en)
+    ///
+    /// Negate with Carry negates the sum of a register value and the value of NOT (Carry flag), and writes the result to the destination register.
+    ///
+    /// This is an alias of SBC. This means:
+    /// - The encodings in this description are named to match the encodings of SBC.
+    /// - The description of SBC gives the operational pseudocode for this instruction.
+    ///
+    /// ```asm
+    /// NGC <Wd>, <Wm>
+    /// ```
+    #[inline(always)]
+    fn ngc_32(&mut self, wd: Register, wm: Register) -> T {
+        self.sbc_32(wd, WZR, wm)
+    }
+
+    /// [NGC](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/NGC--Negate-with-Carry--an-alias-of-SBC-?lang=en)
+    ///
+    /// Negate with Carry negates the sum of a register value and the value of NOT (Carry flag), and writes the result to the destination register.
+    ///
+    /// This is an alias of SBC. This means:
+    /// - The encodings in this description are named to match the encodings of SBC.
+    /// - The description of SBC gives the operational pseudocode for this instruction.
+    ///
+    /// ```asm
+    /// NGC <Xd>, <Xm>
+    /// ```
+    #[inline(always)]
+    fn ngc_64(&mut self, xd: Register, xm: Register) -> T {
+        self.sbc_64(xd, XZR, xm)
+    }
+
+    /// [NGCS](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/NGCS--Negate-with-Carry--setting-flags--an-alias-of-SBCS-?lang=en)
+    ///
+    /// Negate with Carry, setting flags, negates the sum of a register value and the value of NOT (Carry flag), and writes the result to the destination register. It updates the condition flags based on the result.
+    ///
+    /// This is an alias of SBCS. This means:
+    /// - The encodings in this description are named to match the encodings of SBCS.
+    /// - The description of SBCS gives the operational pseudocode for this instruction.
+    ///
+    /// ```asm
+    /// NGCS <Wd>, <Wm>
+    /// ```
+    #[inline(always)]
+    fn ngcs_32(&mut self, wd: Register, wm: Register) -> T {
+        self.sbcs_32(wd, WZR, wm)
+    }
+
+    /// [NGCS](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/NGCS--Negate-with-Carry--setting-flags--an-alias-of-SBCS-?lang=en)
+    ///
+    /// Negate with Carry, setting flags, negates the sum of a register value and the value of NOT (Carry flag), and writes the result to the destination register. It updates the condition flags based on the result.
+    ///
+    /// This is an alias of SBCS. This means:
+    /// - The encodings in this description are named to match the encodings of SBCS.
+    /// - The description of SBCS gives the operational pseudocode for this instruction.
+    ///
+    /// ```asm
+    /// NGCS <Xd>, <Xm>
+    /// ```
+    #[inline(always)]
+    fn ngcs_64(&mut self, xd: Register, xm: Register) -> T {
+        self.sbcs_64(xd, XZR, xm)
+    }
+
+    /// [MVN](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/MVN--Bitwise-NOT--an-alias-of-ORN--shifted-register--?lang=en)
+    ///
+    /// Bitwise NOT writes the bitwise inverse of a register value to the destination register.
+    ///
+    /// This is an alias of ORN (shifted register). This means:
+    /// - The encodings in this description are named to match the encodings of ORN (shifted register).
+    /// - The description of ORN (shifted register) gives the operational pseudocode for this instruction.
+    ///
+    /// ```asm
+    /// MVN <Wd>, <Wm>{, <shift> #<amount>}
+    /// ```
+    #[inline(always)]
+    fn mvn_32(&mut self, wd: Register, wm: Register, shift: Option<Shift4<UImm5>>) -> T {
+        self.orn_32(wd, WZR, wm, shift)
+    }
+
+    /// [MVN](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/MVN--Bitwise-NOT--an-alias-of-ORN--shifted-register--?lang=en)
+    ///
+    /// Bitwise NOT writes the bitwise inverse of a register value to the destination register.
+    ///
+    /// This is an alias of ORN (shifted register). This means:
+    /// - The encodings in this description are named to match the encodings of ORN (shifted register).
+    /// - The description of ORN (shifted register) gives the operational pseudocode for this instruction.
+    ///
+    /// ```asm
+    /// MVN <Xd>, <Xm>{, <shift> #<amount>}
+    /// ```
+    #[inline(always)]
+    fn mvn_64(&mut self, xd: Register, xm: Register, shift: Option<Shift4<UImm6>>) -> T {
+        self.orn_64(xd, XZR, xm, shift)
+    }
+
     /// [NOP](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/NOP--No-Operation-?lang=en)
     ///
     /// No Operation does nothing, other than advance the value of the program counter by 4. This instruction can be used for instruction alignment purposes.
@@ -251,6 +965,7 @@ pub trait CommonAliases<T>:
 
 #[cfg(test)]
 mod tests {
+    use crate::assert_panic;
     use crate::test_utils::test_producer::TestProducer;
 
     use super::*;
@@ -311,6 +1026,49 @@ mod tests {
         assert_eq!(instr, "mul x1, x28, x20");
     }
 
+    #[test]
+    fn test_mneg_long_mul() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.mneg_32(3, 4, 20);
+        assert_eq!(instr, "mneg w3, w4, w20");
+
+        let instr = prod.mneg_64(1, 28, 20);
+        assert_eq!(instr, "mneg x1, x28, x20");
+
+        let instr = prod.smull(1, 2, 3);
+        assert_eq!(instr, "smull x1, w2, w3");
+
+        let instr = prod.smnegl(1, 2, 3);
+        assert_eq!(instr, "smnegl x1, w2, w3");
+
+        let instr = prod.umull(1, 2, 3);
+        assert_eq!(instr, "umull x1, w2, w3");
+
+        let instr = prod.umnegl(1, 2, 3);
+        assert_eq!(instr, "umnegl x1, w2, w3");
+    }
+
+    #[test]
+    fn test_mul_wide() {
+        let mut prod = TestProducer::new();
+
+        let (lo, hi) = prod.mul_wide_u128(0, 1, 2, 3);
+        assert_eq!(lo, "mul x0, x2, x3");
+        assert_eq!(hi, "umulh x1, x2, x3");
+
+        let (lo, hi) = prod.mul_wide_i128(0, 1, 2, 3);
+        assert_eq!(lo, "mul x0, x2, x3");
+        assert_eq!(hi, "smulh x1, x2, x3");
+
+        let (lo, hi) = prod.mul_wide_u128(2, 1, 2, 3);
+        assert_eq!(lo, "mul x2, x2, x3");
+        assert_eq!(hi, "umulh x1, x2, x3");
+
+        assert_panic!("Should panic: same destination"; prod.mul_wide_u128(1, 1, 2, 3));
+        assert_panic!("Should panic: both destinations are sources"; prod.mul_wide_i128(2, 3, 2, 3));
+    }
+
     #[test]
     fn test_neg() {
         let mut prod = TestProducer::new();
@@ -328,6 +1086,119 @@ mod tests {
         assert_eq!(instr, "neg x1, x20, lsr #0x3f");
     }
 
+    #[test]
+    fn test_cmp() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.cmp_32_imm(3, 20);
+        assert_eq!(instr, "cmp w3, #0x14");
+
+        let instr = prod.cmp_64_imm(31, 20);
+        assert_eq!(instr, "cmp sp, #0x14");
+
+        let instr = prod.cmp_64_imm_lsl(3, 20, Shift1::LSL12);
+        assert_eq!(instr, "cmp x3, #0x14, lsl #0xc");
+
+        let instr = prod.cmp_32_reg(3, 4, None);
+        assert_eq!(instr, "cmp w3, w4");
+
+        let instr = prod.cmp_64_reg(3, 4, Shift3::LSL(3).into());
+        assert_eq!(instr, "cmp x3, x4, lsl #0x3");
+
+        let instr = prod.cmp_32_reg_extend(31, 4, RegExtend::UXTB, None);
+        assert_eq!(instr, "cmp wsp, w4, uxtb");
+
+        let instr = prod.cmp_64_reg_extend(31, 4, RegExtend::SXTW, Some(2));
+        assert_eq!(instr, "cmp sp, w4, sxtw #0x2");
+    }
+
+    #[test]
+    fn test_cmn() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.cmn_32_imm(3, 20);
+        assert_eq!(instr, "cmn w3, #0x14");
+
+        let instr = prod.cmn_64_imm_lsl(31, 20, Shift1::LSL12);
+        assert_eq!(instr, "cmn sp, #0x14, lsl #0xc");
+
+        let instr = prod.cmn_32_imm_lsl(3, 20, Shift1::LSL0);
+        assert_eq!(instr, "cmn w3, #0x14");
+
+        let instr = prod.cmn_64_imm(3, 20);
+        assert_eq!(instr, "cmn x3, #0x14");
+
+        let instr = prod.cmn_32_reg(3, 4, Shift3::ASR(2).into());
+        assert_eq!(instr, "cmn w3, w4, asr #0x2");
+
+        let instr = prod.cmn_64_reg(3, 4, None);
+        assert_eq!(instr, "cmn x3, x4");
+
+        let instr = prod.cmn_32_reg_extend(3, 4, RegExtend::SXTH, Some(1));
+        assert_eq!(instr, "cmn w3, w4, sxth #0x1");
+
+        let instr = prod.cmn_64_reg_extend(31, 4, RegExtend::UXTX, None);
+        assert_eq!(instr, "cmn sp, x4");
+    }
+
+    #[test]
+    fn test_tst() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.tst_32_imm(3, 0xff).unwrap();
+        assert_eq!(instr, "tst w3, #0xff");
+
+        let instr = prod.tst_64_imm(3, 0xffff0000ffff0000).unwrap();
+        assert_eq!(instr, "tst x3, #0xffff0000ffff0000");
+
+        assert!(prod.tst_64_imm(3, 0).is_err());
+
+        let instr = prod.tst_32_reg(3, 4, None);
+        assert_eq!(instr, "tst w3, w4");
+
+        let instr = prod.tst_64_reg(3, 4, Shift4::ROR(7).into());
+        assert_eq!(instr, "tst x3, x4, ror #0x7");
+    }
+
+    #[test]
+    fn test_negs() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.negs_32_reg(3, 20, None);
+        assert_eq!(instr, "negs w3, w20");
+
+        let instr = prod.negs_64_reg(1, 20, Shift3::LSL(4).into());
+        assert_eq!(instr, "negs x1, x20, lsl #0x4");
+    }
+
+    #[test]
+    fn test_ngc() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.ngc_32(3, 20);
+        assert_eq!(instr, "ngc w3, w20");
+
+        let instr = prod.ngc_64(3, 20);
+        assert_eq!(instr, "ngc x3, x20");
+
+        let instr = prod.ngcs_32(3, 20);
+        assert_eq!(instr, "ngcs w3, w20");
+
+        let instr = prod.ngcs_64(3, 20);
+        assert_eq!(instr, "ngcs x3, x20");
+    }
+
+    #[test]
+    fn test_mvn() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.mvn_32(3, 20, None);
+        assert_eq!(instr, "mvn w3, w20");
+
+        let instr = prod.mvn_64(3, 20, Shift4::LSR(9).into());
+        assert_eq!(instr, "mvn x3, x20, lsr #0x9");
+    }
+
     #[test]
     fn test_nop() {
         let mut prod = TestProducer::new();
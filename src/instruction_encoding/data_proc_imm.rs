@@ -5,6 +5,7 @@
 //! - Bitfield
 //! - Extract
 //! - Logical (immediate)
+//! - Min/max (immediate)
 //! - Move wide (immediate)
 //! - PC rel. addressing
 
@@ -12,6 +13,7 @@ use crate::instruction_encoding::data_proc_imm::add_substract_imm::AddSubtractIm
 use crate::instruction_encoding::data_proc_imm::bitfield::BitfieldInstructions;
 use crate::instruction_encoding::data_proc_imm::extract::ExtractInstructions;
 use crate::instruction_encoding::data_proc_imm::logical_imm::LogicalImmediate;
+use crate::instruction_encoding::data_proc_imm::min_max_imm::MinMaxImmediate;
 use crate::instruction_encoding::data_proc_imm::mov_wide_imm::MovWideImmediate;
 use crate::instruction_encoding::data_proc_imm::pc_rel_addr::PcRelAddressing;
 use crate::instruction_encoding::data_proc_imm::pc_rel_addr::PcRelAddressingWithAddress;
@@ -20,6 +22,7 @@ pub mod add_substract_imm;
 pub mod bitfield;
 pub mod extract;
 pub mod logical_imm;
+pub mod min_max_imm;
 pub mod mov_wide_imm;
 pub mod pc_rel_addr;
 
@@ -30,6 +33,7 @@ pub trait DataProcessingImmediate<T>:
     + MovWideImmediate<T>
     + BitfieldInstructions<T>
     + ExtractInstructions<T>
+    + MinMaxImmediate<T>
 {
 }
 
@@ -4,11 +4,27 @@
 //! - [SBFM](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SBFM--Signed-Bitfield-Move-?lang=en)
 //! - [BFM](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/BFM--Bitfield-Move-?lang=en)
 //! - [UBFM](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/UBFM--Unsigned-Bitfield-Move-?lang=en)
+//! - [ASR - Arithmetic Shift Right (immediate)](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/ASR--immediate---Arithmetic-Shift-Right--immediate---an-alias-of-SBFM-?lang=en)
+//! - [LSL - Logical Shift Left (immediate)](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/LSL--immediate---Logical-Shift-Left--immediate---an-alias-of-UBFM-?lang=en)
+//! - [LSR - Logical Shift Right (immediate)](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/LSR--immediate---Logical-Shift-Right--immediate---an-alias-of-UBFM-?lang=en)
+//! - [SBFIZ - Signed Bitfield Insert in Zero](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SBFIZ--Signed-Bitfield-Insert-in-Zero--an-alias-of-SBFM-?lang=en)
+//! - [SBFX - Signed Bitfield Extract](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SBFX--Signed-Bitfield-Extract--an-alias-of-SBFM-?lang=en)
+//! - [BFI - Bitfield Insert](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/BFI--Bitfield-Insert--an-alias-of-BFM-?lang=en)
+//! - [BFXIL - Bitfield extract and insert at low end](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/BFXIL--Bitfield-extract-and-insert-at-low-end--an-alias-of-BFM-?lang=en)
+//! - [UBFIZ - Unsigned Bitfield Insert in Zero](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/UBFIZ--Unsigned-Bitfield-Insert-in-Zero--an-alias-of-UBFM-?lang=en)
+//! - [UBFX - Unsigned Bitfield Extract](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/UBFX--Unsigned-Bitfield-Extract--an-alias-of-UBFM-?lang=en)
+//! - [BFC - Bitfield Clear](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/BFC--Bitfield-Clear--an-alias-of-BFM-?lang=en)
+//! - [SXTB - Sign Extend Byte](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SXTB--Sign-Extend-Byte--an-alias-of-SBFM-?lang=en)
+//! - [SXTH - Sign Extend Halfword](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SXTH--Sign-Extend-Halfword--an-alias-of-SBFM-?lang=en)
+//! - [SXTW - Sign Extend Word](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SXTW--Sign-Extend-Word--an-alias-of-SBFM-?lang=en)
+//! - [UXTB - Unsigned Extend Byte](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/UXTB--Unsigned-Extend-Byte--an-alias-of-UBFM-?lang=en)
+//! - [UXTH - Unsigned Extend Halfword](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/UXTH--Unsigned-Extend-Halfword--an-alias-of-UBFM-?lang=en)
 
 use bit_seq::bseq_32;
 
 use crate::instruction_encoding::InstructionProcessor;
 use crate::types::{Register, UImm6};
+use crate::types::register::{WZR, XZR};
 
 /// Generates the base instruction for a bitfield operation.
 /// `sf`, `opc`, `n`, `immr`, `imms`, `rn`, and `rd` parameters are used to construct the instruction.
@@ -36,12 +52,44 @@ fn emit_bitfield<P: InstructionProcessor<T>, T>(
     proc.process(r)
 }
 
+/// Checks `lsb` and `width` of the bitfield aliases as the ARM pseudocode does.
+#[inline(always)]
+fn debug_assert_lsb_width(datasize: u8, lsb: UImm6, width: UImm6) {
+    debug_assert!(
+        lsb < datasize,
+        "lsb must be in range 0 to {}, was {}",
+        datasize - 1,
+        lsb
+    );
+    debug_assert!(
+        width >= 1 && width <= datasize - lsb,
+        "width must be in range 1 to {}, was {}",
+        datasize - lsb,
+        width
+    );
+}
+
 /// # Bitfield
 ///
 /// Implements the following instructions:
 /// - [SBFM](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SBFM--Signed-Bitfield-Move-?lang=en)
 /// - [BFM](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/BFM--Bitfield-Move-?lang=en)
 /// - [UBFM](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/UBFM--Unsigned-Bitfield-Move-?lang=en)
+/// - [ASR - Arithmetic Shift Right (immediate)](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/ASR--immediate---Arithmetic-Shift-Right--immediate---an-alias-of-SBFM-?lang=en)
+/// - [LSL - Logical Shift Left (immediate)](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/LSL--immediate---Logical-Shift-Left--immediate---an-alias-of-UBFM-?lang=en)
+/// - [LSR - Logical Shift Right (immediate)](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/LSR--immediate---Logical-Shift-Right--immediate---an-alias-of-UBFM-?lang=en)
+/// - [SBFIZ - Signed Bitfield Insert in Zero](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SBFIZ--Signed-Bitfield-Insert-in-Zero--an-alias-of-SBFM-?lang=en)
+/// - [SBFX - Signed Bitfield Extract](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SBFX--Signed-Bitfield-Extract--an-alias-of-SBFM-?lang=en)
+/// - [BFI - Bitfield Insert](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/BFI--Bitfield-Insert--an-alias-of-BFM-?lang=en)
+/// - [BFXIL - Bitfield extract and insert at low end](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/BFXIL--Bitfield-extract-and-insert-at-low-end--an-alias-of-BFM-?lang=en)
+/// - [UBFIZ - Unsigned Bitfield Insert in Zero](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/UBFIZ--Unsigned-Bitfield-Insert-in-Zero--an-alias-of-UBFM-?lang=en)
+/// - [UBFX - Unsigned Bitfield Extract](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/UBFX--Unsigned-Bitfield-Extract--an-alias-of-UBFM-?lang=en)
+/// - [BFC - Bitfield Clear](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/BFC--Bitfield-Clear--an-alias-of-BFM-?lang=en)
+/// - [SXTB - Sign Extend Byte](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SXTB--Sign-Extend-Byte--an-alias-of-SBFM-?lang=en)
+/// - [SXTH - Sign Extend Halfword](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SXTH--Sign-Extend-Halfword--an-alias-of-SBFM-?lang=en)
+/// - [SXTW - Sign Extend Word](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SXTW--Sign-Extend-Word--an-alias-of-SBFM-?lang=en)
+/// - [UXTB - Unsigned Extend Byte](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/UXTB--Unsigned-Extend-Byte--an-alias-of-UBFM-?lang=en)
+/// - [UXTH - Unsigned Extend Halfword](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/UXTH--Unsigned-Extend-Halfword--an-alias-of-UBFM-?lang=en)
 pub trait BitfieldInstructions<T>: InstructionProcessor<T> {
     /// [SBFM](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SBFM--Signed-Bitfield-Move-?lang=en)
     ///
@@ -123,6 +171,392 @@ pub trait BitfieldInstructions<T>: InstructionProcessor<T> {
     fn ubfm_64(&mut self, rd: Register, rn: Register, immr: UImm6, imms: UImm6) -> T {
         emit_bitfield(self, 1, 0b10, 1, immr, imms, rn, rd)
     }
+
+    // aliases
+
+    /// [ASR - Arithmetic Shift Right (immediate)](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/ASR--immediate---Arithmetic-Shift-Right--immediate---an-alias-of-SBFM-?lang=en)
+    ///
+    /// Arithmetic Shift Right (immediate) shifts a register value right by an immediate number of bits, shifting in copies of the sign bit in the upper bits and zeros in the lower bits, and writes the result to the destination register.
+    ///
+    /// `shift` must be in range 0 to 31.
+    ///
+    /// ```asm
+    /// ASR <Wd>, <Wn>, #<shift>
+    /// ```
+    #[inline(always)]
+    fn asr_32_imm(&mut self, wd: Register, wn: Register, shift: UImm6) -> T {
+        debug_assert!(shift <= 31, "shift must be in range 0 to 31");
+        self.sbfm_32(wd, wn, shift, 31)
+    }
+
+    /// [ASR - Arithmetic Shift Right (immediate)](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/ASR--immediate---Arithmetic-Shift-Right--immediate---an-alias-of-SBFM-?lang=en)
+    ///
+    /// Arithmetic Shift Right (immediate) shifts a register value right by an immediate number of bits, shifting in copies of the sign bit in the upper bits and zeros in the lower bits, and writes the result to the destination register.
+    ///
+    /// `shift` must be in range 0 to 63.
+    ///
+    /// ```asm
+    /// ASR <Xd>, <Xn>, #<shift>
+    /// ```
+    #[inline(always)]
+    fn asr_64_imm(&mut self, xd: Register, xn: Register, shift: UImm6) -> T {
+        debug_assert!(shift <= 63, "shift must be in range 0 to 63");
+        self.sbfm_64(xd, xn, shift, 63)
+    }
+
+    /// [LSL - Logical Shift Left (immediate)](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/LSL--immediate---Logical-Shift-Left--immediate---an-alias-of-UBFM-?lang=en)
+    ///
+    /// Logical Shift Left (immediate) shifts a register value left by an immediate number of bits, shifting in zeros, and writes the result to the destination register.
+    ///
+    /// `shift` must be in range 0 to 31.
+    ///
+    /// ```asm
+    /// LSL <Wd>, <Wn>, #<shift>
+    /// ```
+    #[inline(always)]
+    fn lsl_32_imm(&mut self, wd: Register, wn: Register, shift: UImm6) -> T {
+        debug_assert!(shift <= 31, "shift must be in range 0 to 31");
+        self.ubfm_32(wd, wn, shift.wrapping_neg() & 0x1f, 31 - shift)
+    }
+
+    /// [LSL - Logical Shift Left (immediate)](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/LSL--immediate---Logical-Shift-Left--immediate---an-alias-of-UBFM-?lang=en)
+    ///
+    /// Logical Shift Left (immediate) shifts a register value left by an immediate number of bits, shifting in zeros, and writes the result to the destination register.
+    ///
+    /// `shift` must be in range 0 to 63.
+    ///
+    /// ```asm
+    /// LSL <Xd>, <Xn>, #<shift>
+    /// ```
+    #[inline(always)]
+    fn lsl_64_imm(&mut self, xd: Register, xn: Register, shift: UImm6) -> T {
+        debug_assert!(shift <= 63, "shift must be in range 0 to 63");
+        self.ubfm_64(xd, xn, shift.wrapping_neg() & 0x3f, 63 - shift)
+    }
+
+    /// [LSR - Logical Shift Right (immediate)](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/LSR--immediate---Logical-Shift-Right--immediate---an-alias-of-UBFM-?lang=en)
+    ///
+    /// Logical Shift Right (immediate) shifts a register value right by an immediate number of bits, shifting in zeros, and writes the result to the destination register.
+    ///
+    /// `shift` must be in range 0 to 31.
+    ///
+    /// ```asm
+    /// LSR <Wd>, <Wn>, #<shift>
+    /// ```
+    #[inline(always)]
+    fn lsr_32_imm(&mut self, wd: Register, wn: Register, shift: UImm6) -> T {
+        debug_assert!(shift <= 31, "shift must be in range 0 to 31");
+        self.ubfm_32(wd, wn, shift, 31)
+    }
+
+    /// [LSR - Logical Shift Right (immediate)](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/LSR--immediate---Logical-Shift-Right--immediate---an-alias-of-UBFM-?lang=en)
+    ///
+    /// Logical Shift Right (immediate) shifts a register value right by an immediate number of bits, shifting in zeros, and writes the result to the destination register.
+    ///
+    /// `shift` must be in range 0 to 63.
+    ///
+    /// ```asm
+    /// LSR <Xd>, <Xn>, #<shift>
+    /// ```
+    #[inline(always)]
+    fn lsr_64_imm(&mut self, xd: Register, xn: Register, shift: UImm6) -> T {
+        debug_assert!(shift <= 63, "shift must be in range 0 to 63");
+        self.ubfm_64(xd, xn, shift, 63)
+    }
+
+    /// [SBFIZ - Signed Bitfield Insert in Zero](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SBFIZ--Signed-Bitfield-Insert-in-Zero--an-alias-of-SBFM-?lang=en)
+    ///
+    /// Signed Bitfield Insert in Zero copies a bitfield of `width` bits from the least significant bits of the source register to bit position `lsb` of the destination register, setting the destination bits below the bitfield to zero, and the bits above the bitfield to a copy of the most significant bit of the bitfield.
+    ///
+    /// `lsb` must be in range 0 to 31 and `width` in range 1 to 32-`lsb`.
+    ///
+    /// ```asm
+    /// SBFIZ <Wd>, <Wn>, #<lsb>, #<width>
+    /// ```
+    #[inline(always)]
+    fn sbfiz_32(&mut self, wd: Register, wn: Register, lsb: UImm6, width: UImm6) -> T {
+        debug_assert_lsb_width(32, lsb, width);
+        self.sbfm_32(wd, wn, lsb.wrapping_neg() & 0x1f, width - 1)
+    }
+
+    /// [SBFIZ - Signed Bitfield Insert in Zero](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SBFIZ--Signed-Bitfield-Insert-in-Zero--an-alias-of-SBFM-?lang=en)
+    ///
+    /// Signed Bitfield Insert in Zero copies a bitfield of `width` bits from the least significant bits of the source register to bit position `lsb` of the destination register, setting the destination bits below the bitfield to zero, and the bits above the bitfield to a copy of the most significant bit of the bitfield.
+    ///
+    /// `lsb` must be in range 0 to 63 and `width` in range 1 to 64-`lsb`.
+    ///
+    /// ```asm
+    /// SBFIZ <Xd>, <Xn>, #<lsb>, #<width>
+    /// ```
+    #[inline(always)]
+    fn sbfiz_64(&mut self, xd: Register, xn: Register, lsb: UImm6, width: UImm6) -> T {
+        debug_assert_lsb_width(64, lsb, width);
+        self.sbfm_64(xd, xn, lsb.wrapping_neg() & 0x3f, width - 1)
+    }
+
+    /// [SBFX - Signed Bitfield Extract](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SBFX--Signed-Bitfield-Extract--an-alias-of-SBFM-?lang=en)
+    ///
+    /// Signed Bitfield Extract copies a bitfield of `width` bits starting from bit position `lsb` in the source register to the least significant bits of the destination register, and sets destination bits above the bitfield to a copy of the most significant bit of the bitfield.
+    ///
+    /// `lsb` must be in range 0 to 31 and `width` in range 1 to 32-`lsb`.
+    ///
+    /// ```asm
+    /// SBFX <Wd>, <Wn>, #<lsb>, #<width>
+    /// ```
+    #[inline(always)]
+    fn sbfx_32(&mut self, wd: Register, wn: Register, lsb: UImm6, width: UImm6) -> T {
+        debug_assert_lsb_width(32, lsb, width);
+        self.sbfm_32(wd, wn, lsb, lsb + width - 1)
+    }
+
+    /// [SBFX - Signed Bitfield Extract](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SBFX--Signed-Bitfield-Extract--an-alias-of-SBFM-?lang=en)
+    ///
+    /// Signed Bitfield Extract copies a bitfield of `width` bits starting from bit position `lsb` in the source register to the least significant bits of the destination register, and sets destination bits above the bitfield to a copy of the most significant bit of the bitfield.
+    ///
+    /// `lsb` must be in range 0 to 63 and `width` in range 1 to 64-`lsb`.
+    ///
+    /// ```asm
+    /// SBFX <Xd>, <Xn>, #<lsb>, #<width>
+    /// ```
+    #[inline(always)]
+    fn sbfx_64(&mut self, xd: Register, xn: Register, lsb: UImm6, width: UImm6) -> T {
+        debug_assert_lsb_width(64, lsb, width);
+        self.sbfm_64(xd, xn, lsb, lsb + width - 1)
+    }
+
+    /// [BFI - Bitfield Insert](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/BFI--Bitfield-Insert--an-alias-of-BFM-?lang=en)
+    ///
+    /// Bitfield Insert copies a bitfield of `width` bits from the least significant bits of the source register to bit position `lsb` of the destination register, leaving the other destination bits unchanged.
+    ///
+    /// `lsb` must be in range 0 to 31 and `width` in range 1 to 32-`lsb`.
+    ///
+    /// ```asm
+    /// BFI <Wd>, <Wn>, #<lsb>, #<width>
+    /// ```
+    #[inline(always)]
+    fn bfi_32(&mut self, wd: Register, wn: Register, lsb: UImm6, width: UImm6) -> T {
+        debug_assert_lsb_width(32, lsb, width);
+        self.bfm_32(wd, wn, lsb.wrapping_neg() & 0x1f, width - 1)
+    }
+
+    /// [BFI - Bitfield Insert](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/BFI--Bitfield-Insert--an-alias-of-BFM-?lang=en)
+    ///
+    /// Bitfield Insert copies a bitfield of `width` bits from the least significant bits of the source register to bit position `lsb` of the destination register, leaving the other destination bits unchanged.
+    ///
+    /// `lsb` must be in range 0 to 63 and `width` in range 1 to 64-`lsb`.
+    ///
+    /// ```asm
+    /// BFI <Xd>, <Xn>, #<lsb>, #<width>
+    /// ```
+    #[inline(always)]
+    fn bfi_64(&mut self, xd: Register, xn: Register, lsb: UImm6, width: UImm6) -> T {
+        debug_assert_lsb_width(64, lsb, width);
+        self.bfm_64(xd, xn, lsb.wrapping_neg() & 0x3f, width - 1)
+    }
+
+    /// [BFXIL - Bitfield extract and insert at low end](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/BFXIL--Bitfield-extract-and-insert-at-low-end--an-alias-of-BFM-?lang=en)
+    ///
+    /// Bitfield extract and insert at low end copies a bitfield of `width` bits starting from bit position `lsb` in the source register to the least significant bits of the destination register, leaving the other destination bits unchanged.
+    ///
+    /// `lsb` must be in range 0 to 31 and `width` in range 1 to 32-`lsb`.
+    ///
+    /// ```asm
+    /// BFXIL <Wd>, <Wn>, #<lsb>, #<width>
+    /// ```
+    #[inline(always)]
+    fn bfxil_32(&mut self, wd: Register, wn: Register, lsb: UImm6, width: UImm6) -> T {
+        debug_assert_lsb_width(32, lsb, width);
+        self.bfm_32(wd, wn, lsb, lsb + width - 1)
+    }
+
+    /// [BFXIL - Bitfield extract and insert at low end](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/BFXIL--Bitfield-extract-and-insert-at-low-end--an-alias-of-BFM-?lang=en)
+    ///
+    /// Bitfield extract and insert at low end copies a bitfield of `width` bits starting from bit position `lsb` in the source register to the least significant bits of the destination register, leaving the other destination bits unchanged.
+    ///
+    /// `lsb` must be in range 0 to 63 and `width` in range 1 to 64-`lsb`.
+    ///
+    /// ```asm
+    /// BFXIL <Xd>, <Xn>, #<lsb>, #<width>
+    /// ```
+    #[inline(always)]
+    fn bfxil_64(&mut self, xd: Register, xn: Register, lsb: UImm6, width: UImm6) -> T {
+        debug_assert_lsb_width(64, lsb, width);
+        self.bfm_64(xd, xn, lsb, lsb + width - 1)
+    }
+
+    /// [UBFIZ - Unsigned Bitfield Insert in Zero](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/UBFIZ--Unsigned-Bitfield-Insert-in-Zero--an-alias-of-UBFM-?lang=en)
+    ///
+    /// Unsigned Bitfield Insert in Zero copies a bitfield of `width` bits from the least significant bits of the source register to bit position `lsb` of the destination register, setting the destination bits above and below the bitfield to zero.
+    ///
+    /// `lsb` must be in range 0 to 31 and `width` in range 1 to 32-`lsb`.
+    ///
+    /// ```asm
+    /// UBFIZ <Wd>, <Wn>, #<lsb>, #<width>
+    /// ```
+    #[inline(always)]
+    fn ubfiz_32(&mut self, wd: Register, wn: Register, lsb: UImm6, width: UImm6) -> T {
+        debug_assert_lsb_width(32, lsb, width);
+        self.ubfm_32(wd, wn, lsb.wrapping_neg() & 0x1f, width - 1)
+    }
+
+    /// [UBFIZ - Unsigned Bitfield Insert in Zero](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/UBFIZ--Unsigned-Bitfield-Insert-in-Zero--an-alias-of-UBFM-?lang=en)
+    ///
+    /// Unsigned Bitfield Insert in Zero copies a bitfield of `width` bits from the least significant bits of the source register to bit position `lsb` of the destination register, setting the destination bits above and below the bitfield to zero.
+    ///
+    /// `lsb` must be in range 0 to 63 and `width` in range 1 to 64-`lsb`.
+    ///
+    /// ```asm
+    /// UBFIZ <Xd>, <Xn>, #<lsb>, #<width>
+    /// ```
+    #[inline(always)]
+    fn ubfiz_64(&mut self, xd: Register, xn: Register, lsb: UImm6, width: UImm6) -> T {
+        debug_assert_lsb_width(64, lsb, width);
+        self.ubfm_64(xd, xn, lsb.wrapping_neg() & 0x3f, width - 1)
+    }
+
+    /// [UBFX - Unsigned Bitfield Extract](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/UBFX--Unsigned-Bitfield-Extract--an-alias-of-UBFM-?lang=en)
+    ///
+    /// Unsigned Bitfield Extract copies a bitfield of `width` bits starting from bit position `lsb` in the source register to the least significant bits of the destination register, and sets destination bits above the bitfield to zero.
+    ///
+    /// `lsb` must be in range 0 to 31 and `width` in range 1 to 32-`lsb`.
+    ///
+    /// ```asm
+    /// UBFX <Wd>, <Wn>, #<lsb>, #<width>
+    /// ```
+    #[inline(always)]
+    fn ubfx_32(&mut self, wd: Register, wn: Register, lsb: UImm6, width: UImm6) -> T {
+        debug_assert_lsb_width(32, lsb, width);
+        self.ubfm_32(wd, wn, lsb, lsb + width - 1)
+    }
+
+    /// [UBFX - Unsigned Bitfield Extract](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/UBFX--Unsigned-Bitfield-Extract--an-alias-of-UBFM-?lang=en)
+    ///
+    /// Unsigned Bitfield Extract copies a bitfield of `width` bits starting from bit position `lsb` in the source register to the least significant bits of the destination register, and sets destination bits above the bitfield to zero.
+    ///
+    /// `lsb` must be in range 0 to 63 and `width` in range 1 to 64-`lsb`.
+    ///
+    /// ```asm
+    /// UBFX <Xd>, <Xn>, #<lsb>, #<width>
+    /// ```
+    #[inline(always)]
+    fn ubfx_64(&mut self, xd: Register, xn: Register, lsb: UImm6, width: UImm6) -> T {
+        debug_assert_lsb_width(64, lsb, width);
+        self.ubfm_64(xd, xn, lsb, lsb + width - 1)
+    }
+
+    /// [BFC - Bitfield Clear](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/BFC--Bitfield-Clear--an-alias-of-BFM-?lang=en)
+    ///
+    /// Bitfield Clear sets a bitfield of `width` bits at bit position `lsb` of the destination register to zero, leaving the other destination bits unchanged.
+    ///
+    /// `lsb` must be in range 0 to 31 and `width` in range 1 to 32-`lsb`.
+    ///
+    /// ```asm
+    /// BFC <Wd>, #<lsb>, #<width>
+    /// ```
+    #[inline(always)]
+    fn bfc_32(&mut self, wd: Register, lsb: UImm6, width: UImm6) -> T {
+        debug_assert_lsb_width(32, lsb, width);
+        self.bfm_32(wd, WZR, lsb.wrapping_neg() & 0x1f, width - 1)
+    }
+
+    /// [BFC - Bitfield Clear](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/BFC--Bitfield-Clear--an-alias-of-BFM-?lang=en)
+    ///
+    /// Bitfield Clear sets a bitfield of `width` bits at bit position `lsb` of the destination register to zero, leaving the other destination bits unchanged.
+    ///
+    /// `lsb` must be in range 0 to 63 and `width` in range 1 to 64-`lsb`.
+    ///
+    /// ```asm
+    /// BFC <Xd>, #<lsb>, #<width>
+    /// ```
+    #[inline(always)]
+    fn bfc_64(&mut self, xd: Register, lsb: UImm6, width: UImm6) -> T {
+        debug_assert_lsb_width(64, lsb, width);
+        self.bfm_64(xd, XZR, lsb.wrapping_neg() & 0x3f, width - 1)
+    }
+
+    /// [SXTB - Sign Extend Byte](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SXTB--Sign-Extend-Byte--an-alias-of-SBFM-?lang=en)
+    ///
+    /// Sign Extend Byte extracts an 8-bit value from a register, sign-extends it to the size of the register, and writes the result to the destination register.
+    ///
+    /// ```asm
+    /// SXTB <Wd>, <Wn>
+    /// ```
+    #[inline(always)]
+    fn sxtb_32(&mut self, wd: Register, wn: Register) -> T {
+        self.sbfm_32(wd, wn, 0, 7)
+    }
+
+    /// [SXTB - Sign Extend Byte](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SXTB--Sign-Extend-Byte--an-alias-of-SBFM-?lang=en)
+    ///
+    /// Sign Extend Byte extracts an 8-bit value from a register, sign-extends it to the size of the register, and writes the result to the destination register.
+    ///
+    /// ```asm
+    /// SXTB <Xd>, <Wn>
+    /// ```
+    #[inline(always)]
+    fn sxtb_64(&mut self, xd: Register, wn: Register) -> T {
+        self.sbfm_64(xd, wn, 0, 7)
+    }
+
+    /// [SXTH - Sign Extend Halfword](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SXTH--Sign-Extend-Halfword--an-alias-of-SBFM-?lang=en)
+    ///
+    /// Sign Extend Halfword extracts a 16-bit value, sign-extends it to the size of the register, and writes the result to the destination register.
+    ///
+    /// ```asm
+    /// SXTH <Wd>, <Wn>
+    /// ```
+    #[inline(always)]
+    fn sxth_32(&mut self, wd: Register, wn: Register) -> T {
+        self.sbfm_32(wd, wn, 0, 15)
+    }
+
+    /// [SXTH - Sign Extend Halfword](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SXTH--Sign-Extend-Halfword--an-alias-of-SBFM-?lang=en)
+    ///
+    /// Sign Extend Halfword extracts a 16-bit value, sign-extends it to the size of the register, and writes the result to the destination register.
+    ///
+    /// ```asm
+    /// SXTH <Xd>, <Wn>
+    /// ```
+    #[inline(always)]
+    fn sxth_64(&mut self, xd: Register, wn: Register) -> T {
+        self.sbfm_64(xd, wn, 0, 15)
+    }
+
+    /// [SXTW - Sign Extend Word](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SXTW--Sign-Extend-Word--an-alias-of-SBFM-?lang=en)
+    ///
+    /// Sign Extend Word sign-extends a word to the size of the register, and writes the result to the destination register.
+    ///
+    /// ```asm
+    /// SXTW <Xd>, <Wn>
+    /// ```
+    #[inline(always)]
+    fn sxtw_64(&mut self, xd: Register, wn: Register) -> T {
+        self.sbfm_64(xd, wn, 0, 31)
+    }
+
+    /// [UXTB - Unsigned Extend Byte](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/UXTB--Unsigned-Extend-Byte--an-alias-of-UBFM-?lang=en)
+    ///
+    /// Unsigned Extend Byte extracts an 8-bit value from a register, zero-extends it to the size of the register, and writes the result to the destination register.
+    ///
+    /// ```asm
+    /// UXTB <Wd>, <Wn>
+    /// ```
+    #[inline(always)]
+    fn uxtb_32(&mut self, wd: Register, wn: Register) -> T {
+        self.ubfm_32(wd, wn, 0, 7)
+    }
+
+    /// [UXTH - Unsigned Extend Halfword](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/UXTH--Unsigned-Extend-Halfword--an-alias-of-UBFM-?lang=en)
+    ///
+    /// Unsigned Extend Halfword extracts a 16-bit value from a register, zero-extends it to the size of the register, and writes the result to the destination register.
+    ///
+    /// ```asm
+    /// UXTH <Wd>, <Wn>
+    /// ```
+    #[inline(always)]
+    fn uxth_32(&mut self, wd: Register, wn: Register) -> T {
+        self.ubfm_32(wd, wn, 0, 15)
+    }
 }
 
 #[cfg(test)]
@@ -188,4 +622,95 @@ mod tests {
         assert_panic!("Should panic: out of bounds immr"; prod.ubfm_64(1, 2, 64, 0x2));
         assert_panic!("Should panic: out of bounds imms"; prod.ubfm_64(1, 2, 0x8, 64));
     }
+
+    #[test]
+    fn test_shift_imm() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.asr_32_imm(1, 2, 5);
+        assert_eq!(instr, "asr w1, w2, #0x5");
+
+        let instr = prod.asr_64_imm(1, 2, 63);
+        assert_eq!(instr, "asr x1, x2, #0x3f");
+
+        let instr = prod.lsl_32_imm(1, 2, 5);
+        assert_eq!(instr, "lsl w1, w2, #0x5");
+
+        let instr = prod.lsl_64_imm(1, 2, 63);
+        assert_eq!(instr, "lsl x1, x2, #0x3f");
+
+        let instr = prod.lsr_32_imm(1, 2, 31);
+        assert_eq!(instr, "lsr w1, w2, #0x1f");
+
+        let instr = prod.lsr_64_imm(1, 2, 5);
+        assert_eq!(instr, "lsr x1, x2, #0x5");
+
+        assert_panic!("Should panic: shift out of range"; prod.lsl_32_imm(1, 2, 32));
+        assert_panic!("Should panic: shift out of range"; prod.asr_64_imm(1, 2, 64));
+    }
+
+    #[test]
+    fn test_bitfield_insert_extract() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.sbfiz_32(1, 2, 8, 3);
+        assert_eq!(instr, "sbfiz w1, w2, #0x8, #0x3");
+
+        let instr = prod.sbfx_64(1, 2, 8, 16);
+        assert_eq!(instr, "sbfx x1, x2, #0x8, #0x10");
+
+        let instr = prod.bfi_32(1, 2, 4, 12);
+        assert_eq!(instr, "bfi w1, w2, #0x4, #0xc");
+
+        let instr = prod.bfxil_64(1, 2, 4, 12);
+        assert_eq!(instr, "bfxil x1, x2, #0x4, #0xc");
+
+        let instr = prod.ubfiz_64(1, 2, 4, 8);
+        assert_eq!(instr, "ubfiz x1, x2, #0x4, #0x8");
+
+        let instr = prod.ubfiz_64(1, 2, 32, 32);
+        assert_eq!(instr, "lsl x1, x2, #0x20");
+
+        let instr = prod.ubfx_32(1, 2, 0, 32);
+        assert_eq!(instr, "lsr w1, w2, #0x0");
+
+        let instr = prod.ubfx_32(1, 2, 3, 5);
+        assert_eq!(instr, "ubfx w1, w2, #0x3, #0x5");
+
+        let instr = prod.bfc_32(1, 4, 12);
+        assert_eq!(instr, "bfc w1, #0x4, #0xc");
+
+        let instr = prod.bfc_64(1, 0, 64);
+        assert_eq!(instr, "bfxil x1, xzr, #0x0, #0x40");
+
+        assert_panic!("Should panic: lsb out of range"; prod.ubfx_32(1, 2, 32, 1));
+        assert_panic!("Should panic: zero width"; prod.sbfx_64(1, 2, 0, 0));
+        assert_panic!("Should panic: width exceeds register"; prod.bfi_32(1, 2, 30, 3));
+    }
+
+    #[test]
+    fn test_extend() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.sxtb_32(1, 2);
+        assert_eq!(instr, "sxtb w1, w2");
+
+        let instr = prod.sxtb_64(1, 2);
+        assert_eq!(instr, "sxtb x1, w2");
+
+        let instr = prod.sxth_32(1, 2);
+        assert_eq!(instr, "sxth w1, w2");
+
+        let instr = prod.sxth_64(1, 2);
+        assert_eq!(instr, "sxth x1, w2");
+
+        let instr = prod.sxtw_64(1, 2);
+        assert_eq!(instr, "sxtw x1, w2");
+
+        let instr = prod.uxtb_32(1, 2);
+        assert_eq!(instr, "uxtb w1, w2");
+
+        let instr = prod.uxth_32(1, 2);
+        assert_eq!(instr, "uxth w1, w2");
+    }
 }
@@ -2,6 +2,7 @@
 //!
 //! Implements the following instructions:
 //! - [EXTR](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/EXTR--Extract-register-?lang=en)
+//! - [ROR - Rotate right (immediate)](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/ROR--immediate---Rotate-right--immediate---an-alias-of-EXTR-?lang=en)
 
 use bit_seq::{bseq_32, bseq_8};
 
@@ -31,6 +32,7 @@ fn emit_extr_x<P: InstructionProcessor<T>, T>(
 ///
 /// Implements the following instructions:
 /// - [EXTR](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/EXTR--Extract-register-?lang=en)
+/// - [ROR - Rotate right (immediate)](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/ROR--immediate---Rotate-right--immediate---an-alias-of-EXTR-?lang=en)
 pub trait ExtractInstructions<T>: InstructionProcessor<T> {
     /// [EXTR](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/EXTR--Extract-register-?lang=en) \
     /// Encodes and emits a 32-bit EXTR (extract) operation.
@@ -79,6 +81,36 @@ pub trait ExtractInstructions<T>: InstructionProcessor<T> {
         debug_assert!(lsb <= 63, "lsb must be in range 0 to 63");
         emit_extr_x(self, 1, 0b00, 1, 0, rm, lsb, rn, rd)
     }
+
+    // aliases
+
+    /// [ROR - Rotate right (immediate)](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/ROR--immediate---Rotate-right--immediate---an-alias-of-EXTR-?lang=en)
+    ///
+    /// Rotate right (immediate) provides the value of the contents of a register rotated by a variable number of bits. The bits that are rotated off the right end are inserted into the vacated bit positions on the left.
+    ///
+    /// `shift` must be in range 0 to 31.
+    ///
+    /// ```asm
+    /// ROR <Wd>, <Ws>, #<shift>
+    /// ```
+    #[inline(always)]
+    fn ror_32_imm(&mut self, wd: Register, ws: Register, shift: UImm5) -> T {
+        self.extr_32(wd, ws, ws, shift)
+    }
+
+    /// [ROR - Rotate right (immediate)](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/ROR--immediate---Rotate-right--immediate---an-alias-of-EXTR-?lang=en)
+    ///
+    /// Rotate right (immediate) provides the value of the contents of a register rotated by a variable number of bits. The bits that are rotated off the right end are inserted into the vacated bit positions on the left.
+    ///
+    /// `shift` must be in range 0 to 63.
+    ///
+    /// ```asm
+    /// ROR <Xd>, <Xs>, #<shift>
+    /// ```
+    #[inline(always)]
+    fn ror_64_imm(&mut self, xd: Register, xs: Register, shift: UImm6) -> T {
+        self.extr_64(xd, xs, xs, shift)
+    }
 }
 
 #[cfg(test)]
@@ -102,4 +134,17 @@ mod tests {
 
         assert_panic!("Should panic: out of bounds lsb"; prod.extr_64(1, 2, 6, 64));
     }
+
+    #[test]
+    fn test_ror_imm() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.ror_32_imm(1, 2, 7);
+        assert_eq!(instr, "ror w1, w2, #0x7");
+
+        let instr = prod.ror_64_imm(1, 2, 63);
+        assert_eq!(instr, "ror x1, x2, #0x3f");
+
+        assert_panic!("Should panic: out of bounds shift"; prod.ror_32_imm(1, 2, 32));
+    }
 }
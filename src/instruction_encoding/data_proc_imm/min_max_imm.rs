@@ -0,0 +1,197 @@
+//! # [Min/max (immediate)](https://developer.arm.com/documentation/ddi0602/2022-12/Index-by-Encoding/Data-Processing----Immediate?lang=en#minmax_imm)
+//!
+//! Implements the following instructions:
+//! - [SMAX - Signed Maximum (immediate)](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/SMAX--immediate---Signed-Maximum--immediate--?lang=en)
+//! - [UMAX - Unsigned Maximum (immediate)](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/UMAX--immediate---Unsigned-Maximum--immediate--?lang=en)
+//! - [SMIN - Signed Minimum (immediate)](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/SMIN--immediate---Signed-Minimum--immediate--?lang=en)
+//! - [UMIN - Unsigned Minimum (immediate)](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/UMIN--immediate---Unsigned-Minimum--immediate--?lang=en)
+
+#[allow(unused_imports)]
+use bit_seq::bseq_32;
+
+use crate::instruction_encoding::InstructionProcessor;
+#[allow(unused_imports)]
+use crate::types::{Imm8, Register, UImm8};
+
+#[inline(always)]
+#[cfg(feature = "arm_feat_cssc")]
+fn emit_min_max_imm<P: InstructionProcessor<T>, T>(
+    proc: &mut P,
+    sf: u8,
+    opc: u8,
+    imm8: u8,
+    rn: Register,
+    rd: Register,
+) -> T {
+    let i = bseq_32!(sf:1 00 1000111 opc:4 imm8:8 rn:5 rd:5);
+    proc.process(i)
+}
+
+/// # Min/max (immediate)
+///
+/// Implements the following instructions:
+/// - [SMAX - Signed Maximum (immediate)](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/SMAX--immediate---Signed-Maximum--immediate--?lang=en)
+/// - [UMAX - Unsigned Maximum (immediate)](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/UMAX--immediate---Unsigned-Maximum--immediate--?lang=en)
+/// - [SMIN - Signed Minimum (immediate)](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/SMIN--immediate---Signed-Minimum--immediate--?lang=en)
+/// - [UMIN - Unsigned Minimum (immediate)](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/UMIN--immediate---Unsigned-Minimum--immediate--?lang=en)
+pub trait MinMaxImmediate<T>: InstructionProcessor<T> {
+    /// [SMAX - Signed Maximum (immediate)](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/SMAX--immediate---Signed-Maximum--immediate--?lang=en)
+    ///
+    /// Signed Maximum (immediate) determines the signed maximum of the source register value and immediate, and writes the result to the destination register.
+    ///
+    /// `imm` is in range -128 to 127.
+    ///
+    /// ```asm
+    /// SMAX <Wd>, <Wn>, #<simm>
+    /// ```
+    ///
+    /// *Note*: FEAT_CSSC required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_cssc")]
+    fn smax_32_imm(&mut self, wd: Register, wn: Register, imm: Imm8) -> T {
+        emit_min_max_imm(self, 0, 0b0000, imm as u8, wn, wd)
+    }
+
+    /// [SMAX - Signed Maximum (immediate)](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/SMAX--immediate---Signed-Maximum--immediate--?lang=en)
+    ///
+    /// Signed Maximum (immediate) determines the signed maximum of the source register value and immediate, and writes the result to the destination register.
+    ///
+    /// `imm` is in range -128 to 127.
+    ///
+    /// ```asm
+    /// SMAX <Xd>, <Xn>, #<simm>
+    /// ```
+    ///
+    /// *Note*: FEAT_CSSC required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_cssc")]
+    fn smax_64_imm(&mut self, xd: Register, xn: Register, imm: Imm8) -> T {
+        emit_min_max_imm(self, 1, 0b0000, imm as u8, xn, xd)
+    }
+
+    /// [UMAX - Unsigned Maximum (immediate)](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/UMAX--immediate---Unsigned-Maximum--immediate--?lang=en)
+    ///
+    /// Unsigned Maximum (immediate) determines the unsigned maximum of the source register value and immediate, and writes the result to the destination register.
+    ///
+    /// `imm` is in range 0 to 255.
+    ///
+    /// ```asm
+    /// UMAX <Wd>, <Wn>, #<uimm>
+    /// ```
+    ///
+    /// *Note*: FEAT_CSSC required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_cssc")]
+    fn umax_32_imm(&mut self, wd: Register, wn: Register, imm: UImm8) -> T {
+        emit_min_max_imm(self, 0, 0b0001, imm, wn, wd)
+    }
+
+    /// [UMAX - Unsigned Maximum (immediate)](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/UMAX--immediate---Unsigned-Maximum--immediate--?lang=en)
+    ///
+    /// Unsigned Maximum (immediate) determines the unsigned maximum of the source register value and immediate, and writes the result to the destination register.
+    ///
+    /// `imm` is in range 0 to 255.
+    ///
+    /// ```asm
+    /// UMAX <Xd>, <Xn>, #<uimm>
+    /// ```
+    ///
+    /// *Note*: FEAT_CSSC required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_cssc")]
+    fn umax_64_imm(&mut self, xd: Register, xn: Register, imm: UImm8) -> T {
+        emit_min_max_imm(self, 1, 0b0001, imm, xn, xd)
+    }
+
+    /// [SMIN - Signed Minimum (immediate)](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/SMIN--immediate---Signed-Minimum--immediate--?lang=en)
+    ///
+    /// Signed Minimum (immediate) determines the signed minimum of the source register value and immediate, and writes the result to the destination register.
+    ///
+    /// `imm` is in range -128 to 127.
+    ///
+    /// ```asm
+    /// SMIN <Wd>, <Wn>, #<simm>
+    /// ```
+    ///
+    /// *Note*: FEAT_CSSC required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_cssc")]
+    fn smin_32_imm(&mut self, wd: Register, wn: Register, imm: Imm8) -> T {
+        emit_min_max_imm(self, 0, 0b0010, imm as u8, wn, wd)
+    }
+
+    /// [SMIN - Signed Minimum (immediate)](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/SMIN--immediate---Signed-Minimum--immediate--?lang=en)
+    ///
+    /// Signed Minimum (immediate) determines the signed minimum of the source register value and immediate, and writes the result to the destination register.
+    ///
+    /// `imm` is in range -128 to 127.
+    ///
+    /// ```asm
+    /// SMIN <Xd>, <Xn>, #<simm>
+    /// ```
+    ///
+    /// *Note*: FEAT_CSSC required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_cssc")]
+    fn smin_64_imm(&mut self, xd: Register, xn: Register, imm: Imm8) -> T {
+        emit_min_max_imm(self, 1, 0b0010, imm as u8, xn, xd)
+    }
+
+    /// [UMIN - Unsigned Minimum (immediate)](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/UMIN--immediate---Unsigned-Minimum--immediate--?lang=en)
+    ///
+    /// Unsigned Minimum (immediate) determines the unsigned minimum of the source register value and immediate, and writes the result to the destination register.
+    ///
+    /// `imm` is in range 0 to 255.
+    ///
+    /// ```asm
+    /// UMIN <Wd>, <Wn>, #<uimm>
+    /// ```
+    ///
+    /// *Note*: FEAT_CSSC required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_cssc")]
+    fn umin_32_imm(&mut self, wd: Register, wn: Register, imm: UImm8) -> T {
+        emit_min_max_imm(self, 0, 0b0011, imm, wn, wd)
+    }
+
+    /// [UMIN - Unsigned Minimum (immediate)](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/UMIN--immediate---Unsigned-Minimum--immediate--?lang=en)
+    ///
+    /// Unsigned Minimum (immediate) determines the unsigned minimum of the source register value and immediate, and writes the result to the destination register.
+    ///
+    /// `imm` is in range 0 to 255.
+    ///
+    /// ```asm
+    /// UMIN <Xd>, <Xn>, #<uimm>
+    /// ```
+    ///
+    /// *Note*: FEAT_CSSC required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_cssc")]
+    fn umin_64_imm(&mut self, xd: Register, xn: Register, imm: UImm8) -> T {
+        emit_min_max_imm(self, 1, 0b0011, imm, xn, xd)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[cfg(feature = "arm_feat_cssc")]
+    #[test]
+    fn test_min_max_imm() {
+        use crate::instruction_producer::InstrProducer;
+
+        // the disassembler does not support FEAT_CSSC, so the encodings are compared
+        let mut prod = InstrProducer::new();
+
+        assert_eq!(prod.smax_32_imm(0, 1, 127).encoding(), 0x11c1fc20);
+        assert_eq!(prod.smax_64_imm(3, 4, -128).encoding(), 0x91c20083);
+        assert_eq!(prod.umax_32_imm(0, 1, 255).encoding(), 0x11c7fc20);
+        assert_eq!(prod.umax_64_imm(3, 4, 3).encoding(), 0x91c40c83);
+        assert_eq!(prod.smin_32_imm(0, 1, -1).encoding(), 0x11cbfc20);
+        assert_eq!(prod.smin_64_imm(3, 4, 3).encoding(), 0x91c80c83);
+        assert_eq!(prod.umin_32_imm(0, 1, 0).encoding(), 0x11cc0020);
+        assert_eq!(prod.umin_64_imm(3, 4, 3).encoding(), 0x91cc0c83);
+    }
+}
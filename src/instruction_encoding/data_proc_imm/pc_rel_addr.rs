@@ -6,8 +6,10 @@
 
 pub use bit_seq::{bseq_32, bseq_8};
 
+use crate::instruction_encoding::data_proc_imm::add_substract_imm::AddSubtractImmediate;
+use crate::instruction_encoding::data_proc_imm::mov_wide_imm::MovWideImmediate;
 use crate::instruction_encoding::{AddressableInstructionProcessor, InstructionProcessor};
-use crate::types::{Offset32, Offset64, Register};
+use crate::types::{Offset32, Offset64, Register, HW};
 
 /// Helper function to emit PC-relative addressing instructions.
 ///
@@ -29,6 +31,29 @@ fn emit_pc_rel_addr<P: InstructionProcessor<T>, T>(
     proc.process(r)
 }
 
+#[inline(always)]
+fn emit_adrp_x_offset<P: InstructionProcessor<T>, T>(proc: &mut P, rd: Register, offset: Offset64) -> T {
+    debug_assert!(offset % 4096 == 0, "Offset must be a multiply of 4096!");
+    debug_assert!(
+        -((1 << 30) * 4) <= offset && offset < ((1 << 30) * 4),
+        "Offset must be within ±4GB"
+    );
+
+    // shift 12 bits (divide by 4096)
+    let offset = offset >> 12;
+
+    let immlo = offset & 0b11;
+    let immhi = offset >> 2;
+
+    emit_pc_rel_addr(proc, 1, immlo as u8, immhi as u32, rd)
+}
+
+/// Returns the byte offset from the 4KB page of `pc` to the 4KB page of `addr`.
+#[inline(always)]
+fn page_offset(pc: usize, addr: usize) -> Offset64 {
+    ((addr & !0xfff) as Offset64).wrapping_sub((pc & !0xfff) as Offset64)
+}
+
 /// # PC-rel. addressing
 ///
 /// Implements the following instructions:
@@ -78,23 +103,13 @@ pub trait PcRelAddressing<T>: InstructionProcessor<T> {
     /// * `offset` - The PC-relative offset in bytes. It must be a multiple of 4096 and within ±4GB.
     #[inline(always)]
     fn adrp_from_byte_offset(&mut self, rd: Register, offset: Offset64) -> T {
-        debug_assert!(offset % 4096 == 0, "Offset must be a multiply of 4096!");
-        debug_assert!(
-            -((1 << 30) * 4) <= offset && offset < ((1 << 30) * 4),
-            "Offset must be within ±4GB"
-        );
-
-        // shift 12 bits (divide by 4096)
-        let offset = offset >> 12;
-
-        let immlo = offset & 0b11;
-        let immhi = offset >> 2;
-
-        emit_pc_rel_addr(self, 1, immlo as u8, immhi as u32, rd)
+        emit_adrp_x_offset(self, rd, offset)
     }
 }
 
-pub trait PcRelAddressingWithAddress<T>: AddressableInstructionProcessor<T> {
+pub trait PcRelAddressingWithAddress<T>:
+    AddressableInstructionProcessor<T> + AddSubtractImmediate<T> + MovWideImmediate<T>
+{
     /// [ADR](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/ADR--Form-PC-relative-address-?lang=en)\
     /// Emit an `ADR` instruction.
     ///
@@ -121,6 +136,92 @@ pub trait PcRelAddressingWithAddress<T>: AddressableInstructionProcessor<T> {
         let immhi = offset >> 2;
         emit_pc_rel_addr(self, 0, immlo as u8, immhi as u32, rd)
     }
+
+    /// [ADRP](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/ADRP--Form-PC-relative-address-to-4KB-page-?lang=en)\
+    /// Emit an `ADRP` instruction.
+    ///
+    /// This function generates an `ADRP` instruction that forms the address of the 4KB page
+    /// of a given address.
+    ///
+    /// ```asm
+    /// ADRP <Xd>, <addr>
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `rd` - The destination register.
+    /// * `addr` - The absolute address. Its page must be within ±4GB of the page of the instruction.
+    ///
+    /// The offset is computed from the current position, so the instruction must not be emitted
+    /// while a branch relaxation of an [`InstrStream`](crate::instruction_stream::InstrStream)
+    /// may still move it.
+    #[inline(always)]
+    fn adrp_to_addr(&mut self, rd: Register, addr: usize) -> T {
+        let offset = page_offset(self.intr_ptr_addr(), addr);
+        emit_adrp_x_offset(self, rd, offset)
+    }
+
+    /// Materializes the absolute address `addr` in `xd`.
+    ///
+    /// Depending on the distance between the instruction and `addr`, one of the following
+    /// sequences is emitted:
+    ///
+    /// ```asm
+    /// ADR <Xd>, <addr>                    // within ±1MB
+    ///
+    /// ADRP <Xd>, <addr>                   // within ±4GB
+    /// ADD <Xd>, <Xd>, #:lo12:<addr>       // omitted if addr is page aligned
+    ///
+    /// MOVZ <Xd>, #<imm16>, LSL #<shift>   // otherwise, for the non-zero halfwords
+    /// MOVK <Xd>, #<imm16>, LSL #<shift>
+    /// ```
+    ///
+    /// The `ADR` and `ADRP` sequences are PC-relative and must not be moved. The relaxation of
+    /// branches to labels of an [`InstrStream`](crate::instruction_stream::InstrStream) moves the
+    /// code emitted after the branch, so this must not be called while
+    /// [`may_relax`](crate::instruction_stream::InstrStream::may_relax) is true.
+    ///
+    /// # Arguments
+    ///
+    /// * `xd` - The destination register.
+    /// * `addr` - The absolute address.
+    #[inline(always)]
+    fn load_addr(&mut self, xd: Register, addr: usize) -> Vec<T> {
+        let pc = self.intr_ptr_addr();
+        let offset = (addr as Offset64).wrapping_sub(pc as Offset64);
+        if (-(1 << 20)..(1 << 20)).contains(&offset) {
+            let (immlo, immhi) = (offset & 0b11, offset >> 2);
+            return vec![emit_pc_rel_addr(self, 0, immlo as u8, immhi as u32, xd)];
+        }
+
+        let page_offset = page_offset(pc, addr);
+        if (-(1 << 32)..(1 << 32)).contains(&page_offset) {
+            let mut instrs = vec![emit_adrp_x_offset(self, xd, page_offset)];
+            if addr & 0xfff != 0 {
+                instrs.push(self.add_64_imm(xd, xd, (addr & 0xfff) as u16));
+            }
+            return instrs;
+        }
+
+        let addr = addr as u64;
+        let mut instrs = Vec::with_capacity(4);
+        for hw in 0..4u8 {
+            let imm = (addr >> (hw * 16)) as u16;
+            if imm == 0 {
+                continue;
+            }
+            let lsl = HW::from(hw);
+            if instrs.is_empty() {
+                instrs.push(self.movz_64_imm_lsl(xd, imm, lsl));
+            } else {
+                instrs.push(self.movk_64_imm_lsl(xd, imm, lsl));
+            }
+        }
+        if instrs.is_empty() {
+            instrs.push(self.movz_64_imm(xd, 0));
+        }
+        instrs
+    }
 }
 
 #[cfg(test)]
@@ -156,4 +257,44 @@ mod tests {
             assert_panic!("Should panic: offset out of bounds"; stream.adrp_from_byte_offset(1, (1 << 30) * 4));
         })
     }
+
+    #[test]
+    fn test_adrp_to_addr() {
+        stream_mock!(stream, {
+            let instr = stream.adrp_to_addr(1, 0x1234_5678);
+            assert_eq!(instr.to_string(), "adrp x1, 0x12345000");
+
+            let instr = stream.adrp_to_addr(1, 0xfff);
+            assert_eq!(instr.to_string(), "adrp x1, 0x0");
+
+            assert_panic!("Should panic: page out of range"; stream.adrp_to_addr(1, 1 << 32));
+        })
+    }
+
+    #[test]
+    fn test_load_addr() {
+        stream_mock!(stream, {
+            let instrs = stream.load_addr(1, 0x1234);
+            assert_eq!(instrs.len(), 1);
+            assert_eq!(instrs[0].to_string(), "adr x1, 0x1234");
+
+            let instrs = stream.load_addr(1, 0x1234_5678);
+            assert_eq!(instrs.len(), 2);
+            assert_eq!(instrs[0].to_string(), "adrp x1, 0x12345000");
+            assert_eq!(instrs[1].to_string(), "add x1, x1, #0x678");
+
+            let instrs = stream.load_addr(1, 0x1234_5000);
+            assert_eq!(instrs.len(), 1);
+            assert_eq!(instrs[0].to_string(), "adrp x1, 0x12345000");
+
+            let instrs = stream.load_addr(1, 0x1234_0000_5678);
+            assert_eq!(instrs.len(), 2);
+            assert_eq!(instrs[0].to_string(), "mov x1, #0x5678");
+            assert_eq!(instrs[1].to_string(), "movk x1, #0x1234, lsl #0x20");
+
+            let instrs = stream.load_addr(1, 0xffff_0000_0000_0000);
+            assert_eq!(instrs.len(), 1);
+            assert_eq!(instrs[0].to_string(), "mov x1, #0xffff000000000000");
+        })
+    }
 }
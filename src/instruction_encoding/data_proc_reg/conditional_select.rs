@@ -5,6 +5,11 @@
 //!  - [CSINC - Conditional Select Increment](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CSINC--Conditional-Select-Increment-?lang=en)
 //!  - [CSINV - Conditional Select Invert](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CSINV--Conditional-Select-Invert-?lang=en)
 //!  - [CSNEG - Conditional Select Negation](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CSNEG--Conditional-Select-Negation-?lang=en)
+//!  - [CSET - Conditional Set](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CSET--Conditional-Set--an-alias-of-CSINC-?lang=en)
+//!  - [CSETM - Conditional Set Mask](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CSETM--Conditional-Set-Mask--an-alias-of-CSINV-?lang=en)
+//!  - [CINC - Conditional Increment](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CINC--Conditional-Increment--an-alias-of-CSINC-?lang=en)
+//!  - [CINV - Conditional Invert](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CINV--Conditional-Invert--an-alias-of-CSINV-?lang=en)
+//!  - [CNEG - Conditional Negate](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CNEG--Conditional-Negate--an-alias-of-CSNEG-?lang=en)
 
 use bit_seq::bseq_32;
 
@@ -12,6 +17,7 @@ use crate::instruction_encoding::InstructionProcessor;
 use crate::types::condition::Condition;
 use crate::types::encodable::Encodable;
 use crate::types::Register;
+use crate::types::register::{WZR, XZR};
 
 #[inline(always)]
 fn emit_cond_sel<P: InstructionProcessor<T>, T>(
@@ -29,6 +35,16 @@ fn emit_cond_sel<P: InstructionProcessor<T>, T>(
     proc.process(i)
 }
 
+/// The aliases of the conditional select instructions invert `cond`, which is not allowed for
+/// `AL` and `NV`.
+#[inline(always)]
+fn debug_assert_alias_cond(cond: Condition) {
+    debug_assert!(
+        !matches!(cond, Condition::AL | Condition::NV),
+        "cond must not be AL or NV"
+    );
+}
+
 /// # [Conditional select](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/Data-Processing----Register?lang=en#condsel)
 ///
 /// Implements the following instructions:
@@ -36,6 +52,11 @@ fn emit_cond_sel<P: InstructionProcessor<T>, T>(
 ///  - [CSINC - Conditional Select Increment](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CSINC--Conditional-Select-Increment-?lang=en)
 ///  - [CSINV - Conditional Select Invert](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CSINV--Conditional-Select-Invert-?lang=en)
 ///  - [CSNEG - Conditional Select Negation](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CSNEG--Conditional-Select-Negation-?lang=en)
+///  - [CSET - Conditional Set](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CSET--Conditional-Set--an-alias-of-CSINC-?lang=en)
+///  - [CSETM - Conditional Set Mask](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CSETM--Conditional-Set-Mask--an-alias-of-CSINV-?lang=en)
+///  - [CINC - Conditional Increment](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CINC--Conditional-Increment--an-alias-of-CSINC-?lang=en)
+///  - [CINV - Conditional Invert](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CINV--Conditional-Invert--an-alias-of-CSINV-?lang=en)
+///  - [CNEG - Conditional Negate](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CNEG--Conditional-Negate--an-alias-of-CSNEG-?lang=en)
 pub trait ConditionalSelect<T>: InstructionProcessor<T> {
     /// [CSEL - Conditional Select](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CSEL--Conditional-Select-?lang=en)
     ///
@@ -144,10 +165,163 @@ pub trait ConditionalSelect<T>: InstructionProcessor<T> {
     fn csneg_64(&mut self, xd: Register, xn: Register, xm: Register, cond: Condition) -> T {
         emit_cond_sel(self, 1, 1, 0, xm, cond.encode(), 0b01, xn, xd)
     }
+
+    // aliases
+
+    /// [CSET - Conditional Set](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CSET--Conditional-Set--an-alias-of-CSINC-?lang=en)
+    ///
+    /// Conditional Set sets the destination register to 1 if the condition is TRUE, and otherwise sets it to 0.
+    ///
+    /// This is an alias of CSINC with the inverted condition, `cond` must not be `AL` or `NV`.
+    ///
+    /// ```asm
+    /// CSET <Wd>, <cond>
+    /// ```
+    #[inline(always)]
+    fn cset_32(&mut self, wd: Register, cond: Condition) -> T {
+        debug_assert_alias_cond(cond);
+        self.csinc_32(wd, WZR, WZR, cond.invert())
+    }
+
+    /// [CSET - Conditional Set](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CSET--Conditional-Set--an-alias-of-CSINC-?lang=en)
+    ///
+    /// Conditional Set sets the destination register to 1 if the condition is TRUE, and otherwise sets it to 0.
+    ///
+    /// This is an alias of CSINC with the inverted condition, `cond` must not be `AL` or `NV`.
+    ///
+    /// ```asm
+    /// CSET <Xd>, <cond>
+    /// ```
+    #[inline(always)]
+    fn cset_64(&mut self, xd: Register, cond: Condition) -> T {
+        debug_assert_alias_cond(cond);
+        self.csinc_64(xd, XZR, XZR, cond.invert())
+    }
+
+    /// [CSETM - Conditional Set Mask](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CSETM--Conditional-Set-Mask--an-alias-of-CSINV-?lang=en)
+    ///
+    /// Conditional Set Mask sets all bits of the destination register to 1 if the condition is TRUE, and otherwise sets all bits to 0.
+    ///
+    /// This is an alias of CSINV with the inverted condition, `cond` must not be `AL` or `NV`.
+    ///
+    /// ```asm
+    /// CSETM <Wd>, <cond>
+    /// ```
+    #[inline(always)]
+    fn csetm_32(&mut self, wd: Register, cond: Condition) -> T {
+        debug_assert_alias_cond(cond);
+        self.csinv_32(wd, WZR, WZR, cond.invert())
+    }
+
+    /// [CSETM - Conditional Set Mask](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CSETM--Conditional-Set-Mask--an-alias-of-CSINV-?lang=en)
+    ///
+    /// Conditional Set Mask sets all bits of the destination register to 1 if the condition is TRUE, and otherwise sets all bits to 0.
+    ///
+    /// This is an alias of CSINV with the inverted condition, `cond` must not be `AL` or `NV`.
+    ///
+    /// ```asm
+    /// CSETM <Xd>, <cond>
+    /// ```
+    #[inline(always)]
+    fn csetm_64(&mut self, xd: Register, cond: Condition) -> T {
+        debug_assert_alias_cond(cond);
+        self.csinv_64(xd, XZR, XZR, cond.invert())
+    }
+
+    /// [CINC - Conditional Increment](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CINC--Conditional-Increment--an-alias-of-CSINC-?lang=en)
+    ///
+    /// Conditional Increment returns, in the destination register, the value of the source register incremented by 1 if the condition is TRUE, and otherwise returns the value of the source register.
+    ///
+    /// This is an alias of CSINC with the inverted condition, `cond` must not be `AL` or `NV`.
+    ///
+    /// ```asm
+    /// CINC <Wd>, <Wn>, <cond>
+    /// ```
+    #[inline(always)]
+    fn cinc_32(&mut self, wd: Register, wn: Register, cond: Condition) -> T {
+        debug_assert_alias_cond(cond);
+        self.csinc_32(wd, wn, wn, cond.invert())
+    }
+
+    /// [CINC - Conditional Increment](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CINC--Conditional-Increment--an-alias-of-CSINC-?lang=en)
+    ///
+    /// Conditional Increment returns, in the destination register, the value of the source register incremented by 1 if the condition is TRUE, and otherwise returns the value of the source register.
+    ///
+    /// This is an alias of CSINC with the inverted condition, `cond` must not be `AL` or `NV`.
+    ///
+    /// ```asm
+    /// CINC <Xd>, <Xn>, <cond>
+    /// ```
+    #[inline(always)]
+    fn cinc_64(&mut self, xd: Register, xn: Register, cond: Condition) -> T {
+        debug_assert_alias_cond(cond);
+        self.csinc_64(xd, xn, xn, cond.invert())
+    }
+
+    /// [CINV - Conditional Invert](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CINV--Conditional-Invert--an-alias-of-CSINV-?lang=en)
+    ///
+    /// Conditional Invert returns, in the destination register, the bitwise inversion of the value of the source register if the condition is TRUE, and otherwise returns the value of the source register.
+    ///
+    /// This is an alias of CSINV with the inverted condition, `cond` must not be `AL` or `NV`.
+    ///
+    /// ```asm
+    /// CINV <Wd>, <Wn>, <cond>
+    /// ```
+    #[inline(always)]
+    fn cinv_32(&mut self, wd: Register, wn: Register, cond: Condition) -> T {
+        debug_assert_alias_cond(cond);
+        self.csinv_32(wd, wn, wn, cond.invert())
+    }
+
+    /// [CINV - Conditional Invert](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CINV--Conditional-Invert--an-alias-of-CSINV-?lang=en)
+    ///
+    /// Conditional Invert returns, in the destination register, the bitwise inversion of the value of the source register if the condition is TRUE, and otherwise returns the value of the source register.
+    ///
+    /// This is an alias of CSINV with the inverted condition, `cond` must not be `AL` or `NV`.
+    ///
+    /// ```asm
+    /// CINV <Xd>, <Xn>, <cond>
+    /// ```
+    #[inline(always)]
+    fn cinv_64(&mut self, xd: Register, xn: Register, cond: Condition) -> T {
+        debug_assert_alias_cond(cond);
+        self.csinv_64(xd, xn, xn, cond.invert())
+    }
+
+    /// [CNEG - Conditional Negate](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CNEG--Conditional-Negate--an-alias-of-CSNEG-?lang=en)
+    ///
+    /// Conditional Negate returns, in the destination register, the negated value of the source register if the condition is TRUE, and otherwise returns the value of the source register.
+    ///
+    /// This is an alias of CSNEG with the inverted condition, `cond` must not be `AL` or `NV`.
+    ///
+    /// ```asm
+    /// CNEG <Wd>, <Wn>, <cond>
+    /// ```
+    #[inline(always)]
+    fn cneg_32(&mut self, wd: Register, wn: Register, cond: Condition) -> T {
+        debug_assert_alias_cond(cond);
+        self.csneg_32(wd, wn, wn, cond.invert())
+    }
+
+    /// [CNEG - Conditional Negate](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CNEG--Conditional-Negate--an-alias-of-CSNEG-?lang=en)
+    ///
+    /// Conditional Negate returns, in the destination register, the negated value of the source register if the condition is TRUE, and otherwise returns the value of the source register.
+    ///
+    /// This is an alias of CSNEG with the inverted condition, `cond` must not be `AL` or `NV`.
+    ///
+    /// ```asm
+    /// CNEG <Xd>, <Xn>, <cond>
+    /// ```
+    #[inline(always)]
+    fn cneg_64(&mut self, xd: Register, xn: Register, cond: Condition) -> T {
+        debug_assert_alias_cond(cond);
+        self.csneg_64(xd, xn, xn, cond.invert())
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::assert_panic;
     use crate::test_utils::test_producer::TestProducer;
 
     use super::*;
@@ -195,4 +369,49 @@ mod tests {
         let instr = prod.csneg_64(3, 4, 5, Condition::AL);
         assert_eq!(instr, "csneg x3, x4, x5, al");
     }
+
+    #[test]
+    fn test_cset() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.cset_32(3, Condition::EQ);
+        assert_eq!(instr, "cset w3, eq");
+
+        let instr = prod.cset_64(3, Condition::LT);
+        assert_eq!(instr, "cset x3, lt");
+
+        let instr = prod.csetm_32(3, Condition::HS);
+        assert_eq!(instr, "csetm w3, cs");
+
+        let instr = prod.csetm_64(3, Condition::VS);
+        assert_eq!(instr, "csetm x3, vs");
+
+        assert_panic!("Should panic: AL not allowed"; prod.cset_32(3, Condition::AL));
+        assert_panic!("Should panic: NV not allowed"; prod.csetm_64(3, Condition::NV));
+    }
+
+    #[test]
+    fn test_cinc_cinv_cneg() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.cinc_32(3, 4, Condition::NE);
+        assert_eq!(instr, "cinc w3, w4, ne");
+
+        let instr = prod.cinc_64(3, 4, Condition::GT);
+        assert_eq!(instr, "cinc x3, x4, gt");
+
+        let instr = prod.cinv_32(3, 4, Condition::MI);
+        assert_eq!(instr, "cinv w3, w4, mi");
+
+        let instr = prod.cinv_64(3, 4, Condition::LS);
+        assert_eq!(instr, "cinv x3, x4, ls");
+
+        let instr = prod.cneg_32(3, 4, Condition::LO);
+        assert_eq!(instr, "cneg w3, w4, cc");
+
+        let instr = prod.cneg_64(3, 4, Condition::GE);
+        assert_eq!(instr, "cneg x3, x4, ge");
+
+        assert_panic!("Should panic: AL not allowed"; prod.cneg_64(3, 4, Condition::AL));
+    }
 }
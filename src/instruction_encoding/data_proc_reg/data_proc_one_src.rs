@@ -16,6 +16,9 @@
 //!  - [AUTDA - AUTDZA - Authenticate Data address - using key A](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/AUTDA--AUTDZA--Authenticate-Data-address--using-key-A-?lang=en)
 //!  - [AUTDB - AUTDZB - Authenticate Data address - using key B](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/AUTDB--AUTDZB--Authenticate-Data-address--using-key-B-?lang=en)
 //!  - [XPACD - XPACI - XPACLRI - Strip Pointer Authentication Code](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/XPACD--XPACI--XPACLRI--Strip-Pointer-Authentication-Code-?lang=en)
+//!  - [ABS - Absolute value](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/ABS--Absolute-value-?lang=en)
+//!  - [CNT - Count bits](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/CNT--Count-bits-?lang=en)
+//!  - [CTZ - Count Trailing Zeros](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/CTZ--Count-Trailing-Zeros-?lang=en)
 
 use bit_seq::{bseq_32, bseq_8};
 
@@ -60,6 +63,9 @@ fn emit_sys_instrs<P: InstructionProcessor<T>, T>(proc: &mut P, crm: u8, op2: u8
 ///  - [AUTDA - AUTDZA - Authenticate Data address - using key A](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/AUTDA--AUTDZA--Authenticate-Data-address--using-key-A-?lang=en)
 ///  - [AUTDB - AUTDZB - Authenticate Data address - using key B](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/AUTDB--AUTDZB--Authenticate-Data-address--using-key-B-?lang=en)
 ///  - [XPACD - XPACI - XPACLRI - Strip Pointer Authentication Code](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/XPACD--XPACI--XPACLRI--Strip-Pointer-Authentication-Code-?lang=en)
+///  - [ABS - Absolute value](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/ABS--Absolute-value-?lang=en)
+///  - [CNT - Count bits](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/CNT--Count-bits-?lang=en)
+///  - [CTZ - Count Trailing Zeros](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/CTZ--Count-Trailing-Zeros-?lang=en)
 pub trait DataProcessingOneSource<T>: InstructionProcessor<T> {
     /// [RBIT - Reverse Bits](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/RBIT--Reverse-Bits-?lang=en)
     ///
@@ -606,6 +612,96 @@ pub trait DataProcessingOneSource<T>: InstructionProcessor<T> {
     fn xpaclri(&mut self) -> T {
         emit_sys_instrs(self, 0, 0b111)
     }
+
+    /// [ABS - Absolute value](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/ABS--Absolute-value-?lang=en)
+    ///
+    /// Absolute value computes the absolute value of the signed integer value in the source register, and writes the result to the destination register.
+    ///
+    /// ```asm
+    /// ABS <Wd>, <Wn>
+    /// ```
+    ///
+    /// *Note*: FEAT_CSSC required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_cssc")]
+    fn abs_32(&mut self, wd: Register, wn: Register) -> T {
+        emit_data_proc_one(self, 0, 0, 0, 0b001000, wn, wd)
+    }
+
+    /// [ABS - Absolute value](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/ABS--Absolute-value-?lang=en)
+    ///
+    /// Absolute value computes the absolute value of the signed integer value in the source register, and writes the result to the destination register.
+    ///
+    /// ```asm
+    /// ABS <Xd>, <Xn>
+    /// ```
+    ///
+    /// *Note*: FEAT_CSSC required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_cssc")]
+    fn abs_64(&mut self, xd: Register, xn: Register) -> T {
+        emit_data_proc_one(self, 1, 0, 0, 0b001000, xn, xd)
+    }
+
+    /// [CNT - Count bits](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/CNT--Count-bits-?lang=en)
+    ///
+    /// Count bits counts the number of binary one bits in the value of the source register, and writes the result to the destination register.
+    ///
+    /// ```asm
+    /// CNT <Wd>, <Wn>
+    /// ```
+    ///
+    /// *Note*: FEAT_CSSC required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_cssc")]
+    fn cnt_32(&mut self, wd: Register, wn: Register) -> T {
+        emit_data_proc_one(self, 0, 0, 0, 0b000111, wn, wd)
+    }
+
+    /// [CNT - Count bits](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/CNT--Count-bits-?lang=en)
+    ///
+    /// Count bits counts the number of binary one bits in the value of the source register, and writes the result to the destination register.
+    ///
+    /// ```asm
+    /// CNT <Xd>, <Xn>
+    /// ```
+    ///
+    /// *Note*: FEAT_CSSC required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_cssc")]
+    fn cnt_64(&mut self, xd: Register, xn: Register) -> T {
+        emit_data_proc_one(self, 1, 0, 0, 0b000111, xn, xd)
+    }
+
+    /// [CTZ - Count Trailing Zeros](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/CTZ--Count-Trailing-Zeros-?lang=en)
+    ///
+    /// Count Trailing Zeros counts the number of consecutive binary zero bits, starting from the least significant bit in the source register, and places the count in the destination register.
+    ///
+    /// ```asm
+    /// CTZ <Wd>, <Wn>
+    /// ```
+    ///
+    /// *Note*: FEAT_CSSC required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_cssc")]
+    fn ctz_32(&mut self, wd: Register, wn: Register) -> T {
+        emit_data_proc_one(self, 0, 0, 0, 0b000110, wn, wd)
+    }
+
+    /// [CTZ - Count Trailing Zeros](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/CTZ--Count-Trailing-Zeros-?lang=en)
+    ///
+    /// Count Trailing Zeros counts the number of consecutive binary zero bits, starting from the least significant bit in the source register, and places the count in the destination register.
+    ///
+    /// ```asm
+    /// CTZ <Xd>, <Xn>
+    /// ```
+    ///
+    /// *Note*: FEAT_CSSC required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_cssc")]
+    fn ctz_64(&mut self, xd: Register, xn: Register) -> T {
+        emit_data_proc_one(self, 1, 0, 0, 0b000110, xn, xd)
+    }
 }
 
 #[cfg(test)]
@@ -813,4 +909,20 @@ mod tests {
         let instr = prod.xpaclri();
         assert_eq!(instr, "xpaclri");
     }
+
+    #[cfg(feature = "arm_feat_cssc")]
+    #[test]
+    fn test_abs_cnt_ctz() {
+        use crate::instruction_producer::InstrProducer;
+
+        // the disassembler does not support FEAT_CSSC, so the encodings are compared
+        let mut prod = InstrProducer::new();
+
+        assert_eq!(prod.abs_32(0, 1).encoding(), 0x5ac02020);
+        assert_eq!(prod.abs_64(3, 30).encoding(), 0xdac023c3);
+        assert_eq!(prod.cnt_32(0, 1).encoding(), 0x5ac01c20);
+        assert_eq!(prod.cnt_64(3, 30).encoding(), 0xdac01fc3);
+        assert_eq!(prod.ctz_32(0, 1).encoding(), 0x5ac01820);
+        assert_eq!(prod.ctz_64(3, 30).encoding(), 0xdac01bc3);
+    }
 }
@@ -18,6 +18,10 @@
 //! - [GMI - Tag Mask Insert](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/GMI--Tag-Mask-Insert-?lang=en)
 //! - [PACGA - Pointer Authentication Code - using Generic key](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/PACGA--Pointer-Authentication-Code--using-Generic-key-?lang=en)
 //! - [SUBPS - Subtract Pointer - setting Flags](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SUBPS--Subtract-Pointer--setting-Flags-?lang=en)
+//! - [SMAX - Signed Maximum (register)](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/SMAX--register---Signed-Maximum--register--?lang=en)
+//! - [UMAX - Unsigned Maximum (register)](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/UMAX--register---Unsigned-Maximum--register--?lang=en)
+//! - [SMIN - Signed Minimum (register)](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/SMIN--register---Signed-Minimum--register--?lang=en)
+//! - [UMIN - Unsigned Minimum (register)](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/UMIN--register---Unsigned-Minimum--register--?lang=en)
 
 use bit_seq::{bseq_32, bseq_8};
 
@@ -58,6 +62,10 @@ fn emit_data_proc_two<P: InstructionProcessor<T>, T>(
 /// - [GMI - Tag Mask Insert](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/GMI--Tag-Mask-Insert-?lang=en)
 /// - [PACGA - Pointer Authentication Code - using Generic key](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/PACGA--Pointer-Authentication-Code--using-Generic-key-?lang=en)
 /// - [SUBPS - Subtract Pointer - setting Flags](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SUBPS--Subtract-Pointer--setting-Flags-?lang=en)
+/// - [SMAX - Signed Maximum (register)](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/SMAX--register---Signed-Maximum--register--?lang=en)
+/// - [UMAX - Unsigned Maximum (register)](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/UMAX--register---Unsigned-Maximum--register--?lang=en)
+/// - [SMIN - Signed Minimum (register)](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/SMIN--register---Signed-Minimum--register--?lang=en)
+/// - [UMIN - Unsigned Minimum (register)](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/UMIN--register---Unsigned-Minimum--register--?lang=en)
 pub trait DataProcessingTwoSource<T>: InstructionProcessor<T> {
     /// [UDIV - Unsigned Divide](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/UDIV--Unsigned-Divide-?lang=en)
     ///
@@ -480,6 +488,126 @@ pub trait DataProcessingTwoSource<T>: InstructionProcessor<T> {
     fn ror_64(&mut self, xd: Register, xn: Register, xm: Register) -> T {
         self.rorv_64(xd, xn, xm)
     }
+
+    /// [SMAX - Signed Maximum (register)](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/SMAX--register---Signed-Maximum--register--?lang=en)
+    ///
+    /// Signed Maximum (register) determines the signed maximum of the two source register values and writes the result to the destination register.
+    ///
+    /// ```asm
+    /// SMAX <Wd>, <Wn>, <Wm>
+    /// ```
+    ///
+    /// *Note*: FEAT_CSSC required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_cssc")]
+    fn smax_32_reg(&mut self, wd: Register, wn: Register, wm: Register) -> T {
+        emit_data_proc_two(self, 0, 0, wm, 0b011000, wn, wd)
+    }
+
+    /// [SMAX - Signed Maximum (register)](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/SMAX--register---Signed-Maximum--register--?lang=en)
+    ///
+    /// Signed Maximum (register) determines the signed maximum of the two source register values and writes the result to the destination register.
+    ///
+    /// ```asm
+    /// SMAX <Xd>, <Xn>, <Xm>
+    /// ```
+    ///
+    /// *Note*: FEAT_CSSC required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_cssc")]
+    fn smax_64_reg(&mut self, xd: Register, xn: Register, xm: Register) -> T {
+        emit_data_proc_two(self, 1, 0, xm, 0b011000, xn, xd)
+    }
+
+    /// [UMAX - Unsigned Maximum (register)](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/UMAX--register---Unsigned-Maximum--register--?lang=en)
+    ///
+    /// Unsigned Maximum (register) determines the unsigned maximum of the two source register values and writes the result to the destination register.
+    ///
+    /// ```asm
+    /// UMAX <Wd>, <Wn>, <Wm>
+    /// ```
+    ///
+    /// *Note*: FEAT_CSSC required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_cssc")]
+    fn umax_32_reg(&mut self, wd: Register, wn: Register, wm: Register) -> T {
+        emit_data_proc_two(self, 0, 0, wm, 0b011001, wn, wd)
+    }
+
+    /// [UMAX - Unsigned Maximum (register)](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/UMAX--register---Unsigned-Maximum--register--?lang=en)
+    ///
+    /// Unsigned Maximum (register) determines the unsigned maximum of the two source register values and writes the result to the destination register.
+    ///
+    /// ```asm
+    /// UMAX <Xd>, <Xn>, <Xm>
+    /// ```
+    ///
+    /// *Note*: FEAT_CSSC required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_cssc")]
+    fn umax_64_reg(&mut self, xd: Register, xn: Register, xm: Register) -> T {
+        emit_data_proc_two(self, 1, 0, xm, 0b011001, xn, xd)
+    }
+
+    /// [SMIN - Signed Minimum (register)](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/SMIN--register---Signed-Minimum--register--?lang=en)
+    ///
+    /// Signed Minimum (register) determines the signed minimum of the two source register values and writes the result to the destination register.
+    ///
+    /// ```asm
+    /// SMIN <Wd>, <Wn>, <Wm>
+    /// ```
+    ///
+    /// *Note*: FEAT_CSSC required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_cssc")]
+    fn smin_32_reg(&mut self, wd: Register, wn: Register, wm: Register) -> T {
+        emit_data_proc_two(self, 0, 0, wm, 0b011010, wn, wd)
+    }
+
+    /// [SMIN - Signed Minimum (register)](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/SMIN--register---Signed-Minimum--register--?lang=en)
+    ///
+    /// Signed Minimum (register) determines the signed minimum of the two source register values and writes the result to the destination register.
+    ///
+    /// ```asm
+    /// SMIN <Xd>, <Xn>, <Xm>
+    /// ```
+    ///
+    /// *Note*: FEAT_CSSC required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_cssc")]
+    fn smin_64_reg(&mut self, xd: Register, xn: Register, xm: Register) -> T {
+        emit_data_proc_two(self, 1, 0, xm, 0b011010, xn, xd)
+    }
+
+    /// [UMIN - Unsigned Minimum (register)](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/UMIN--register---Unsigned-Minimum--register--?lang=en)
+    ///
+    /// Unsigned Minimum (register) determines the unsigned minimum of the two source register values and writes the result to the destination register.
+    ///
+    /// ```asm
+    /// UMIN <Wd>, <Wn>, <Wm>
+    /// ```
+    ///
+    /// *Note*: FEAT_CSSC required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_cssc")]
+    fn umin_32_reg(&mut self, wd: Register, wn: Register, wm: Register) -> T {
+        emit_data_proc_two(self, 0, 0, wm, 0b011011, wn, wd)
+    }
+
+    /// [UMIN - Unsigned Minimum (register)](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/UMIN--register---Unsigned-Minimum--register--?lang=en)
+    ///
+    /// Unsigned Minimum (register) determines the unsigned minimum of the two source register values and writes the result to the destination register.
+    ///
+    /// ```asm
+    /// UMIN <Xd>, <Xn>, <Xm>
+    /// ```
+    ///
+    /// *Note*: FEAT_CSSC required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_cssc")]
+    fn umin_64_reg(&mut self, xd: Register, xn: Register, xm: Register) -> T {
+        emit_data_proc_two(self, 1, 0, xm, 0b011011, xn, xd)
+    }
 }
 
 #[cfg(test)]
@@ -671,4 +799,22 @@ mod tests {
         let instr = prod.subps(3, 4, 2);
         assert_eq!(instr, "subps x3, x4, x2");
     }
+
+    #[cfg(feature = "arm_feat_cssc")]
+    #[test]
+    fn test_min_max_reg() {
+        use crate::instruction_producer::InstrProducer;
+
+        // the disassembler does not support FEAT_CSSC, so the encodings are compared
+        let mut prod = InstrProducer::new();
+
+        assert_eq!(prod.smax_32_reg(0, 1, 2).encoding(), 0x1ac26020);
+        assert_eq!(prod.smax_64_reg(3, 4, 30).encoding(), 0x9ade6083);
+        assert_eq!(prod.umax_32_reg(0, 1, 2).encoding(), 0x1ac26420);
+        assert_eq!(prod.umax_64_reg(3, 4, 30).encoding(), 0x9ade6483);
+        assert_eq!(prod.smin_32_reg(0, 1, 2).encoding(), 0x1ac26820);
+        assert_eq!(prod.smin_64_reg(3, 4, 30).encoding(), 0x9ade6883);
+        assert_eq!(prod.umin_32_reg(0, 1, 2).encoding(), 0x1ac26c20);
+        assert_eq!(prod.umin_64_reg(3, 4, 30).encoding(), 0x9ade6c83);
+    }
 }
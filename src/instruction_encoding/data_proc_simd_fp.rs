@@ -0,0 +1,29 @@
+//! # [Data Processing -- Scalar Floating-Point and Advanced SIMD](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/Data-Processing----Scalar-Floating-Point-and-Advanced-SIMD?lang=en)
+//!
+//! Contains the following instruction types:
+//! - [Advanced SIMD three same](advanced_simd_three_same)
+//! - [Advanced SIMD three same extra](advanced_simd_three_same_extra)
+//! - [Advanced SIMD two-register miscellaneous](advanced_simd_two_reg_misc)
+//! - [Advanced SIMD vector x indexed element](advanced_simd_vec_x_indexed_elem)
+//! - [Floating-point data-processing (1 source)](fp_data_proc_one_src)
+
+use crate::instruction_encoding::data_proc_simd_fp::advanced_simd_three_same::AdvancedSIMDThreeSame;
+use crate::instruction_encoding::data_proc_simd_fp::advanced_simd_three_same_extra::AdvancedSIMDThreeSameExtra;
+use crate::instruction_encoding::data_proc_simd_fp::advanced_simd_two_reg_misc::AdvancedSIMDTwoRegisterMiscellaneous;
+use crate::instruction_encoding::data_proc_simd_fp::advanced_simd_vec_x_indexed_elem::AdvancedSIMDVectorXIndexedElement;
+use crate::instruction_encoding::data_proc_simd_fp::fp_data_proc_one_src::FloatingPointDataProcessingOneSource;
+
+pub mod advanced_simd_three_same;
+pub mod advanced_simd_three_same_extra;
+pub mod advanced_simd_two_reg_misc;
+pub mod advanced_simd_vec_x_indexed_elem;
+pub mod fp_data_proc_one_src;
+
+pub trait DataProcessingSIMDFloatingPoint<T>:
+    AdvancedSIMDThreeSame<T>
+    + AdvancedSIMDThreeSameExtra<T>
+    + AdvancedSIMDTwoRegisterMiscellaneous<T>
+    + AdvancedSIMDVectorXIndexedElement<T>
+    + FloatingPointDataProcessingOneSource<T>
+{
+}
@@ -0,0 +1,132 @@
+//! # [Advanced SIMD three same](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/Data-Processing----Scalar-Floating-Point-and-Advanced-SIMD?lang=en#asimdsame)
+//!
+//! Implements the following instructions:
+//!  - [FMLAL - FMLAL2 - vector - Floating-point fused Multiply-Add Long to accumulator - vector](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/FMLAL--FMLAL2--vector---Floating-point-fused-Multiply-Add-Long-to-accumulator--vector--?lang=en)
+//!  - [FMLSL - FMLSL2 - vector - Floating-point fused Multiply-Subtract Long from accumulator - vector](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/FMLSL--FMLSL2--vector---Floating-point-fused-Multiply-Subtract-Long-from-accumulator--vector--?lang=en)
+
+#[allow(unused_imports)]
+use bit_seq::bseq_32;
+
+use crate::instruction_encoding::InstructionProcessor;
+#[allow(unused_imports)]
+use crate::types::arr_specifier::{ArrSpec, ArrSpecS};
+#[allow(unused_imports)]
+use crate::types::Register;
+
+#[inline(always)]
+#[cfg(feature = "arm_feat_fhm")]
+fn emit_three_same<P: InstructionProcessor<T>, T>(
+    proc: &mut P,
+    q: u8,
+    u: u8,
+    size: u8,
+    rm: Register,
+    opcode: u8,
+    rn: Register,
+    rd: Register,
+) -> T {
+    let i = bseq_32!(0 q:1 u:1 01110 size:2 1 rm:5 opcode:5 1 rn:5 rd:5);
+    proc.process(i)
+}
+
+/// # [Advanced SIMD three same](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/Data-Processing----Scalar-Floating-Point-and-Advanced-SIMD?lang=en#asimdsame)
+///
+/// Implements the following instructions:
+///  - [FMLAL - FMLAL2 - vector - Floating-point fused Multiply-Add Long to accumulator - vector](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/FMLAL--FMLAL2--vector---Floating-point-fused-Multiply-Add-Long-to-accumulator--vector--?lang=en)
+///  - [FMLSL - FMLSL2 - vector - Floating-point fused Multiply-Subtract Long from accumulator - vector](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/FMLSL--FMLSL2--vector---Floating-point-fused-Multiply-Subtract-Long-from-accumulator--vector--?lang=en)
+pub trait AdvancedSIMDThreeSame<T>: InstructionProcessor<T> {
+    /// [FMLAL - vector - Floating-point fused Multiply-Add Long to accumulator - vector](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/FMLAL--FMLAL2--vector---Floating-point-fused-Multiply-Add-Long-to-accumulator--vector--?lang=en)
+    ///
+    /// Floating-point fused Multiply-Add Long to accumulator (vector). This instruction multiplies corresponding half-precision floating-point values in the vectors in the lower half of the two source SIMD&FP registers, and accumulates the product to the corresponding vector element of the destination SIMD&FP register. The instruction does not round the result of the multiply before the accumulation.
+    ///
+    /// `ta` selects the arrangement of the destination, the source arrangement is `2H` for `2S` and `4H` for `4S`.
+    ///
+    /// ```asm
+    /// FMLAL <Vd>.<Ta>, <Vn>.<Tb>, <Vm>.<Tb>
+    /// ```
+    ///
+    /// *Note*: FEAT_FHM required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_fhm")]
+    fn fmlal_vector(&mut self, vd: Register, vn: Register, vm: Register, ta: ArrSpecS) -> T {
+        emit_three_same(self, ta.q(), 0, 0b00, vm, 0b11101, vn, vd)
+    }
+
+    /// [FMLAL2 - vector - Floating-point fused Multiply-Add Long to accumulator - vector](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/FMLAL--FMLAL2--vector---Floating-point-fused-Multiply-Add-Long-to-accumulator--vector--?lang=en)
+    ///
+    /// Floating-point fused Multiply-Add Long to accumulator (vector). This instruction multiplies corresponding half-precision floating-point values in the vectors in the upper half of the two source SIMD&FP registers, and accumulates the product to the corresponding vector element of the destination SIMD&FP register. The instruction does not round the result of the multiply before the accumulation.
+    ///
+    /// `ta` selects the arrangement of the destination, the source arrangement is `2H` for `2S` and `4H` for `4S`.
+    ///
+    /// ```asm
+    /// FMLAL2 <Vd>.<Ta>, <Vn>.<Tb>, <Vm>.<Tb>
+    /// ```
+    ///
+    /// *Note*: FEAT_FHM required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_fhm")]
+    fn fmlal2_vector(&mut self, vd: Register, vn: Register, vm: Register, ta: ArrSpecS) -> T {
+        emit_three_same(self, ta.q(), 1, 0b00, vm, 0b11001, vn, vd)
+    }
+
+    /// [FMLSL - vector - Floating-point fused Multiply-Subtract Long from accumulator - vector](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/FMLSL--FMLSL2--vector---Floating-point-fused-Multiply-Subtract-Long-from-accumulator--vector--?lang=en)
+    ///
+    /// Floating-point fused Multiply-Subtract Long from accumulator (vector). This instruction negates the values in the vector in the lower half of the first source SIMD&FP register, multiplies these with the corresponding values in the vector in the lower half of the second source SIMD&FP register, and accumulates the product to the corresponding vector element of the destination SIMD&FP register. The instruction does not round the result of the multiply before the accumulation.
+    ///
+    /// `ta` selects the arrangement of the destination, the source arrangement is `2H` for `2S` and `4H` for `4S`.
+    ///
+    /// ```asm
+    /// FMLSL <Vd>.<Ta>, <Vn>.<Tb>, <Vm>.<Tb>
+    /// ```
+    ///
+    /// *Note*: FEAT_FHM required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_fhm")]
+    fn fmlsl_vector(&mut self, vd: Register, vn: Register, vm: Register, ta: ArrSpecS) -> T {
+        emit_three_same(self, ta.q(), 0, 0b10, vm, 0b11101, vn, vd)
+    }
+
+    /// [FMLSL2 - vector - Floating-point fused Multiply-Subtract Long from accumulator - vector](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/FMLSL--FMLSL2--vector---Floating-point-fused-Multiply-Subtract-Long-from-accumulator--vector--?lang=en)
+    ///
+    /// Floating-point fused Multiply-Subtract Long from accumulator (vector). This instruction negates the values in the vector in the upper half of the first source SIMD&FP register, multiplies these with the corresponding values in the vector in the upper half of the second source SIMD&FP register, and accumulates the product to the corresponding vector element of the destination SIMD&FP register. The instruction does not round the result of the multiply before the accumulation.
+    ///
+    /// `ta` selects the arrangement of the destination, the source arrangement is `2H` for `2S` and `4H` for `4S`.
+    ///
+    /// ```asm
+    /// FMLSL2 <Vd>.<Ta>, <Vn>.<Tb>, <Vm>.<Tb>
+    /// ```
+    ///
+    /// *Note*: FEAT_FHM required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_fhm")]
+    fn fmlsl2_vector(&mut self, vd: Register, vn: Register, vm: Register, ta: ArrSpecS) -> T {
+        emit_three_same(self, ta.q(), 1, 0b10, vm, 0b11001, vn, vd)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use crate::test_utils::test_producer::TestProducer;
+
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[cfg(feature = "arm_feat_fhm")]
+    #[test]
+    fn test_fmlal_fmlsl_vector() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.fmlal_vector(1, 2, 3, ArrSpecS::T2S);
+        assert_eq!(instr, "fmlal v1.2s, v2.2h, v3.2h");
+
+        let instr = prod.fmlal2_vector(1, 2, 3, ArrSpecS::T4S);
+        assert_eq!(instr, "fmlal2 v1.4s, v2.4h, v3.4h");
+
+        let instr = prod.fmlsl_vector(1, 2, 3, ArrSpecS::T4S);
+        assert_eq!(instr, "fmlsl v1.4s, v2.4h, v3.4h");
+
+        let instr = prod.fmlsl2_vector(1, 2, 3, ArrSpecS::T2S);
+        assert_eq!(instr, "fmlsl2 v1.2s, v2.2h, v3.2h");
+    }
+}
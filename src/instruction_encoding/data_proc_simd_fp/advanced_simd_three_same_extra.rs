@@ -0,0 +1,386 @@
+//! # [Advanced SIMD three same extra](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/Data-Processing----Scalar-Floating-Point-and-Advanced-SIMD?lang=en#asimdsame2)
+//!
+//! Implements the following instructions:
+//!  - [SDOT - vector - Dot Product signed arithmetic - vector](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/SDOT--vector---Dot-Product-signed-arithmetic--vector--?lang=en)
+//!  - [UDOT - vector - Dot Product unsigned arithmetic - vector](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/UDOT--vector---Dot-Product-unsigned-arithmetic--vector--?lang=en)
+//!  - [USDOT - vector - Dot Product with unsigned and signed integers - vector](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/USDOT--vector---Dot-Product-with-unsigned-and-signed-integers--vector--?lang=en)
+//!  - [SMMLA - vector - Signed 8-bit integer matrix multiply-accumulate - vector](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/SMMLA--vector---Signed-8-bit-integer-matrix-multiply-accumulate--vector--?lang=en)
+//!  - [UMMLA - vector - Unsigned 8-bit integer matrix multiply-accumulate - vector](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/UMMLA--vector---Unsigned-8-bit-integer-matrix-multiply-accumulate--vector--?lang=en)
+//!  - [USMMLA - vector - Unsigned and signed 8-bit integer matrix multiply-accumulate - vector](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/USMMLA--vector---Unsigned-and-signed-8-bit-integer-matrix-multiply-accumulate--vector--?lang=en)
+//!  - [BFDOT - vector - BFloat16 floating-point dot product - vector](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/BFDOT--vector---BFloat16-floating-point-dot-product--vector--?lang=en)
+//!  - [BFMMLA - BFloat16 floating-point matrix multiply-accumulate into 2x2 matrix](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/BFMMLA--BFloat16-floating-point-matrix-multiply-accumulate-into-2x2-matrix-?lang=en)
+//!  - [BFMLALB - BFMLALT - vector - BFloat16 floating-point widening multiply-add long - vector](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/BFMLALB--BFMLALT--vector---BFloat16-floating-point-widening-multiply-add-long--vector--?lang=en)
+//!  - [FCMLA - Floating-point Complex Multiply Accumulate](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/FCMLA--Floating-point-Complex-Multiply-Accumulate-?lang=en)
+//!  - [FCADD - Floating-point Complex Add](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/FCADD--Floating-point-Complex-Add-?lang=en)
+
+#[allow(unused_imports)]
+use bit_seq::bseq_32;
+
+use crate::instruction_encoding::InstructionProcessor;
+#[allow(unused_imports)]
+use crate::types::arr_specifier::{ArrSpec, ArrSpecHSD, ArrSpecS};
+#[allow(unused_imports)]
+use crate::types::encodable::Encodable;
+#[allow(unused_imports)]
+use crate::types::rotation::{ComplexAddRotation, ComplexRotation};
+#[allow(unused_imports)]
+use crate::types::Register;
+
+#[inline(always)]
+#[cfg(any(
+    feature = "arm_feat_dotprod",
+    feature = "arm_feat_i8mm",
+    feature = "arm_feat_bf16",
+    feature = "arm_feat_fcma"
+))]
+fn emit_three_same_extra<P: InstructionProcessor<T>, T>(
+    proc: &mut P,
+    q: u8,
+    u: u8,
+    size: u8,
+    rm: Register,
+    opcode: u8,
+    rn: Register,
+    rd: Register,
+) -> T {
+    let i = bseq_32!(0 q:1 u:1 01110 size:2 0 rm:5 1 opcode:4 1 rn:5 rd:5);
+    proc.process(i)
+}
+
+/// # [Advanced SIMD three same extra](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/Data-Processing----Scalar-Floating-Point-and-Advanced-SIMD?lang=en#asimdsame2)
+///
+/// Implements the following instructions:
+///  - [SDOT - vector - Dot Product signed arithmetic - vector](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/SDOT--vector---Dot-Product-signed-arithmetic--vector--?lang=en)
+///  - [UDOT - vector - Dot Product unsigned arithmetic - vector](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/UDOT--vector---Dot-Product-unsigned-arithmetic--vector--?lang=en)
+///  - [USDOT - vector - Dot Product with unsigned and signed integers - vector](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/USDOT--vector---Dot-Product-with-unsigned-and-signed-integers--vector--?lang=en)
+///  - [SMMLA - vector - Signed 8-bit integer matrix multiply-accumulate - vector](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/SMMLA--vector---Signed-8-bit-integer-matrix-multiply-accumulate--vector--?lang=en)
+///  - [UMMLA - vector - Unsigned 8-bit integer matrix multiply-accumulate - vector](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/UMMLA--vector---Unsigned-8-bit-integer-matrix-multiply-accumulate--vector--?lang=en)
+///  - [USMMLA - vector - Unsigned and signed 8-bit integer matrix multiply-accumulate - vector](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/USMMLA--vector---Unsigned-and-signed-8-bit-integer-matrix-multiply-accumulate--vector--?lang=en)
+///  - [BFDOT - vector - BFloat16 floating-point dot product - vector](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/BFDOT--vector---BFloat16-floating-point-dot-product--vector--?lang=en)
+///  - [BFMMLA - BFloat16 floating-point matrix multiply-accumulate into 2x2 matrix](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/BFMMLA--BFloat16-floating-point-matrix-multiply-accumulate-into-2x2-matrix-?lang=en)
+///  - [BFMLALB - BFMLALT - vector - BFloat16 floating-point widening multiply-add long - vector](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/BFMLALB--BFMLALT--vector---BFloat16-floating-point-widening-multiply-add-long--vector--?lang=en)
+///  - [FCMLA - Floating-point Complex Multiply Accumulate](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/FCMLA--Floating-point-Complex-Multiply-Accumulate-?lang=en)
+///  - [FCADD - Floating-point Complex Add](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/FCADD--Floating-point-Complex-Add-?lang=en)
+pub trait AdvancedSIMDThreeSameExtra<T>: InstructionProcessor<T> {
+    /// [SDOT - vector - Dot Product signed arithmetic - vector](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/SDOT--vector---Dot-Product-signed-arithmetic--vector--?lang=en)
+    ///
+    /// Dot Product signed arithmetic (vector). This instruction performs the dot product of the four signed 8-bit integer values in each 32-bit element of the first source register with the four signed 8-bit integer values in the corresponding 32-bit element of the second source register, accumulating the result into the corresponding 32-bit element of the destination register.
+    ///
+    /// `ta` selects the arrangement of the destination, the source arrangement is `8B` for `2S` and `16B` for `4S`.
+    ///
+    /// ```asm
+    /// SDOT <Vd>.<Ta>, <Vn>.<Tb>, <Vm>.<Tb>
+    /// ```
+    ///
+    /// *Note*: FEAT_DotProd required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_dotprod")]
+    fn sdot_vector(&mut self, vd: Register, vn: Register, vm: Register, ta: ArrSpecS) -> T {
+        emit_three_same_extra(self, ta.q(), 0, 0b10, vm, 0b0010, vn, vd)
+    }
+
+    /// [UDOT - vector - Dot Product unsigned arithmetic - vector](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/UDOT--vector---Dot-Product-unsigned-arithmetic--vector--?lang=en)
+    ///
+    /// Dot Product unsigned arithmetic (vector). This instruction performs the dot product of the four unsigned 8-bit integer values in each 32-bit element of the first source register with the four unsigned 8-bit integer values in the corresponding 32-bit element of the second source register, accumulating the result into the corresponding 32-bit element of the destination register.
+    ///
+    /// `ta` selects the arrangement of the destination, the source arrangement is `8B` for `2S` and `16B` for `4S`.
+    ///
+    /// ```asm
+    /// UDOT <Vd>.<Ta>, <Vn>.<Tb>, <Vm>.<Tb>
+    /// ```
+    ///
+    /// *Note*: FEAT_DotProd required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_dotprod")]
+    fn udot_vector(&mut self, vd: Register, vn: Register, vm: Register, ta: ArrSpecS) -> T {
+        emit_three_same_extra(self, ta.q(), 1, 0b10, vm, 0b0010, vn, vd)
+    }
+
+    /// [USDOT - vector - Dot Product with unsigned and signed integers - vector](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/USDOT--vector---Dot-Product-with-unsigned-and-signed-integers--vector--?lang=en)
+    ///
+    /// Dot Product with unsigned and signed integers (vector). This instruction performs the dot product of the four unsigned 8-bit integer values in each 32-bit element of the first source register with the four signed 8-bit integer values in the corresponding 32-bit element of the second source register, accumulating the result into the corresponding 32-bit element of the destination register.
+    ///
+    /// `ta` selects the arrangement of the destination, the source arrangement is `8B` for `2S` and `16B` for `4S`.
+    ///
+    /// ```asm
+    /// USDOT <Vd>.<Ta>, <Vn>.<Tb>, <Vm>.<Tb>
+    /// ```
+    ///
+    /// *Note*: FEAT_I8MM required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_i8mm")]
+    fn usdot_vector(&mut self, vd: Register, vn: Register, vm: Register, ta: ArrSpecS) -> T {
+        emit_three_same_extra(self, ta.q(), 0, 0b10, vm, 0b0011, vn, vd)
+    }
+
+    /// [SMMLA - vector - Signed 8-bit integer matrix multiply-accumulate - vector](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/SMMLA--vector---Signed-8-bit-integer-matrix-multiply-accumulate--vector--?lang=en)
+    ///
+    /// Signed 8-bit integer matrix multiply-accumulate. This instruction multiplies the 2x8 matrix of signed 8-bit integer values in the first source vector by the 8x2 matrix of signed 8-bit integer values in the second source vector. The resulting 2x2 32-bit integer matrix product is destructively added to the 32-bit integer matrix accumulator in the destination vector.
+    ///
+    /// ```asm
+    /// SMMLA <Vd>.4S, <Vn>.16B, <Vm>.16B
+    /// ```
+    ///
+    /// *Note*: FEAT_I8MM required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_i8mm")]
+    fn smmla(&mut self, vd: Register, vn: Register, vm: Register) -> T {
+        emit_three_same_extra(self, 1, 0, 0b10, vm, 0b0100, vn, vd)
+    }
+
+    /// [UMMLA - vector - Unsigned 8-bit integer matrix multiply-accumulate - vector](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/UMMLA--vector---Unsigned-8-bit-integer-matrix-multiply-accumulate--vector--?lang=en)
+    ///
+    /// Unsigned 8-bit integer matrix multiply-accumulate. This instruction multiplies the 2x8 matrix of unsigned 8-bit integer values in the first source vector by the 8x2 matrix of unsigned 8-bit integer values in the second source vector. The resulting 2x2 32-bit integer matrix product is destructively added to the 32-bit integer matrix accumulator in the destination vector.
+    ///
+    /// ```asm
+    /// UMMLA <Vd>.4S, <Vn>.16B, <Vm>.16B
+    /// ```
+    ///
+    /// *Note*: FEAT_I8MM required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_i8mm")]
+    fn ummla(&mut self, vd: Register, vn: Register, vm: Register) -> T {
+        emit_three_same_extra(self, 1, 1, 0b10, vm, 0b0100, vn, vd)
+    }
+
+    /// [USMMLA - vector - Unsigned and signed 8-bit integer matrix multiply-accumulate - vector](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/USMMLA--vector---Unsigned-and-signed-8-bit-integer-matrix-multiply-accumulate--vector--?lang=en)
+    ///
+    /// Unsigned and signed 8-bit integer matrix multiply-accumulate. This instruction multiplies the 2x8 matrix of unsigned 8-bit integer values in the first source vector by the 8x2 matrix of signed 8-bit integer values in the second source vector. The resulting 2x2 32-bit integer matrix product is destructively added to the 32-bit integer matrix accumulator in the destination vector.
+    ///
+    /// ```asm
+    /// USMMLA <Vd>.4S, <Vn>.16B, <Vm>.16B
+    /// ```
+    ///
+    /// *Note*: FEAT_I8MM required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_i8mm")]
+    fn usmmla(&mut self, vd: Register, vn: Register, vm: Register) -> T {
+        emit_three_same_extra(self, 1, 0, 0b10, vm, 0b0101, vn, vd)
+    }
+
+    /// [BFDOT - vector - BFloat16 floating-point dot product - vector](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/BFDOT--vector---BFloat16-floating-point-dot-product--vector--?lang=en)
+    ///
+    /// BFloat16 floating-point dot product (vector). This instruction delimits the source vectors into pairs of BFloat16 elements. Within each pair, the elements in the first source vector are multiplied by the corresponding elements in the second source vector. The resulting single-precision products are then summed and added destructively to the single-precision element of the destination vector that aligns with the pair of BFloat16 values in the first source vector.
+    ///
+    /// `ta` selects the arrangement of the destination, the source arrangement is `4H` for `2S` and `8H` for `4S`.
+    ///
+    /// ```asm
+    /// BFDOT <Vd>.<Ta>, <Vn>.<Tb>, <Vm>.<Tb>
+    /// ```
+    ///
+    /// *Note*: FEAT_BF16 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_bf16")]
+    fn bfdot_vector(&mut self, vd: Register, vn: Register, vm: Register, ta: ArrSpecS) -> T {
+        emit_three_same_extra(self, ta.q(), 1, 0b01, vm, 0b1111, vn, vd)
+    }
+
+    /// [BFMMLA - BFloat16 floating-point matrix multiply-accumulate into 2x2 matrix](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/BFMMLA--BFloat16-floating-point-matrix-multiply-accumulate-into-2x2-matrix-?lang=en)
+    ///
+    /// BFloat16 floating-point matrix multiply-accumulate into 2x2 matrix. This instruction multiplies the 2x4 matrix of BF16 values held in the first 128-bit source vector by the 4x2 BF16 matrix in the second 128-bit source vector. The resulting 2x2 single-precision matrix product is then added destructively to the 2x2 single-precision matrix in the 128-bit destination vector.
+    ///
+    /// ```asm
+    /// BFMMLA <Vd>.4S, <Vn>.8H, <Vm>.8H
+    /// ```
+    ///
+    /// *Note*: FEAT_BF16 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_bf16")]
+    fn bfmmla(&mut self, vd: Register, vn: Register, vm: Register) -> T {
+        emit_three_same_extra(self, 1, 1, 0b01, vm, 0b1101, vn, vd)
+    }
+
+    /// [BFMLALB - vector - BFloat16 floating-point widening multiply-add long - vector](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/BFMLALB--BFMLALT--vector---BFloat16-floating-point-widening-multiply-add-long--vector--?lang=en)
+    ///
+    /// BFloat16 floating-point widening multiply-add long (vector) widens the even-numbered (bottom) 16-bit elements in the first and second source vectors from Bfloat16 to single-precision format. The instruction then multiplies and adds these values to the overlapping single-precision elements of the destination vector.
+    ///
+    /// ```asm
+    /// BFMLALB <Vd>.4S, <Vn>.8H, <Vm>.8H
+    /// ```
+    ///
+    /// *Note*: FEAT_BF16 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_bf16")]
+    fn bfmlalb_vector(&mut self, vd: Register, vn: Register, vm: Register) -> T {
+        emit_three_same_extra(self, 0, 1, 0b11, vm, 0b1111, vn, vd)
+    }
+
+    /// [BFMLALT - vector - BFloat16 floating-point widening multiply-add long - vector](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/BFMLALB--BFMLALT--vector---BFloat16-floating-point-widening-multiply-add-long--vector--?lang=en)
+    ///
+    /// BFloat16 floating-point widening multiply-add long (vector) widens the odd-numbered (top) 16-bit elements in the first and second source vectors from Bfloat16 to single-precision format. The instruction then multiplies and adds these values to the overlapping single-precision elements of the destination vector.
+    ///
+    /// ```asm
+    /// BFMLALT <Vd>.4S, <Vn>.8H, <Vm>.8H
+    /// ```
+    ///
+    /// *Note*: FEAT_BF16 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_bf16")]
+    fn bfmlalt_vector(&mut self, vd: Register, vn: Register, vm: Register) -> T {
+        emit_three_same_extra(self, 1, 1, 0b11, vm, 0b1111, vn, vd)
+    }
+
+    /// [FCMLA - Floating-point Complex Multiply Accumulate](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/FCMLA--Floating-point-Complex-Multiply-Accumulate-?lang=en)
+    ///
+    /// Floating-point Complex Multiply Accumulate. This instruction operates on complex numbers that are represented in SIMD&FP registers as pairs of elements, with the more significant element holding the imaginary part of the number and the less significant element holding the real part of the number. Each element holds a floating-point value. It performs the following computation on the corresponding complex number element pairs from the two source registers:
+    ///
+    /// - Considering the complex number from the second source register on an Argand diagram, the number is rotated counterclockwise by 0, 90, 180, or 270 degrees.
+    /// - The two elements of the transformed complex number are multiplied by the real element of the first source register, or by the imaginary element of the first source register, depending on the rotation.
+    /// - The two products are added to the corresponding elements of the destination register.
+    ///
+    /// `T4H` and `T8H` additionally require FEAT_FP16.
+    ///
+    /// ```asm
+    /// FCMLA <Vd>.<T>, <Vn>.<T>, <Vm>.<T>, #<rotate>
+    /// ```
+    ///
+    /// *Note*: FEAT_FCMA required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_fcma")]
+    fn fcmla_vector(
+        &mut self,
+        vd: Register,
+        vn: Register,
+        vm: Register,
+        t: ArrSpecHSD,
+        rot: ComplexRotation,
+    ) -> T {
+        let opcode = 0b1000 | rot.encode();
+        emit_three_same_extra(self, t.q(), 1, t.size(), vm, opcode, vn, vd)
+    }
+
+    /// [FCADD - Floating-point Complex Add](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/FCADD--Floating-point-Complex-Add-?lang=en)
+    ///
+    /// Floating-point Complex Add. This instruction operates on complex numbers that are represented in SIMD&FP registers as pairs of elements, with the more significant element holding the imaginary part of the number and the less significant element holding the real part of the number. Each element holds a floating-point value. It performs the following computation on the corresponding complex number element pairs from the two source registers:
+    ///
+    /// - Considering the complex number from the second source register on an Argand diagram, the number is rotated counterclockwise by 90 or 270 degrees.
+    /// - The rotated complex number is added to the complex number from the first source register.
+    ///
+    /// `T4H` and `T8H` additionally require FEAT_FP16.
+    ///
+    /// ```asm
+    /// FCADD <Vd>.<T>, <Vn>.<T>, <Vm>.<T>, #<rotate>
+    /// ```
+    ///
+    /// *Note*: FEAT_FCMA required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_fcma")]
+    fn fcadd_vector(
+        &mut self,
+        vd: Register,
+        vn: Register,
+        vm: Register,
+        t: ArrSpecHSD,
+        rot: ComplexAddRotation,
+    ) -> T {
+        let opcode = 0b1100 | (rot.encode() << 1);
+        emit_three_same_extra(self, t.q(), 1, t.size(), vm, opcode, vn, vd)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use crate::test_utils::test_producer::TestProducer;
+
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[cfg(feature = "arm_feat_dotprod")]
+    #[test]
+    fn test_sdot_udot_vector() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.sdot_vector(1, 2, 3, ArrSpecS::T4S);
+        assert_eq!(instr, "sdot v1.4s, v2.16b, v3.16b");
+
+        let instr = prod.sdot_vector(31, 0, 30, ArrSpecS::T2S);
+        assert_eq!(instr, "sdot v31.2s, v0.8b, v30.8b");
+
+        let instr = prod.udot_vector(1, 2, 3, ArrSpecS::T2S);
+        assert_eq!(instr, "udot v1.2s, v2.8b, v3.8b");
+
+        let instr = prod.udot_vector(1, 2, 3, ArrSpecS::T4S);
+        assert_eq!(instr, "udot v1.4s, v2.16b, v3.16b");
+    }
+
+    #[cfg(feature = "arm_feat_i8mm")]
+    #[test]
+    fn test_i8mm_vector() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.usdot_vector(1, 2, 3, ArrSpecS::T4S);
+        assert_eq!(instr, "usdot v1.4s, v2.16b, v3.16b");
+
+        let instr = prod.usdot_vector(1, 2, 3, ArrSpecS::T2S);
+        assert_eq!(instr, "usdot v1.2s, v2.8b, v3.8b");
+
+        let instr = prod.smmla(1, 2, 3);
+        assert_eq!(instr, "smmla v1.4s, v2.16b, v3.16b");
+
+        let instr = prod.ummla(4, 5, 31);
+        assert_eq!(instr, "ummla v4.4s, v5.16b, v31.16b");
+
+        let instr = prod.usmmla(1, 2, 3);
+        assert_eq!(instr, "usmmla v1.4s, v2.16b, v3.16b");
+    }
+
+    #[cfg(feature = "arm_feat_bf16")]
+    #[test]
+    fn test_bfdot_vector() {
+        let mut prod = TestProducer::new();
+
+        // the disassembler prints the source arrangement as 2H/4H instead of 4H/8H
+        let instr = prod.bfdot_vector(1, 2, 3, ArrSpecS::T2S);
+        assert_eq!(instr, "bfdot v1.2s, v2.2h, v3.2h");
+
+        let instr = prod.bfdot_vector(1, 2, 3, ArrSpecS::T4S);
+        assert_eq!(instr, "bfdot v1.4s, v2.4h, v3.4h");
+    }
+
+    #[cfg(feature = "arm_feat_bf16")]
+    #[test]
+    fn test_bfmmla_bfmlal_vector() {
+        use crate::instruction_producer::InstrProducer;
+
+        // the disassembler does not decode these instructions, so the encodings are compared
+        let mut prod = InstrProducer::new();
+
+        assert_eq!(prod.bfmmla(0, 1, 2).encoding(), 0x6e42ec20);
+        assert_eq!(prod.bfmlalb_vector(3, 4, 5).encoding(), 0x2ec5fc83);
+        assert_eq!(prod.bfmlalt_vector(31, 30, 29).encoding(), 0x6eddffdf);
+    }
+
+    #[cfg(feature = "arm_feat_fcma")]
+    #[test]
+    fn test_fcmla_vector() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.fcmla_vector(1, 2, 3, ArrSpecHSD::T8H, ComplexRotation::Rot90);
+        assert_eq!(instr, "fcmla v1.8h, v2.8h, v3.8h, #0x5a");
+
+        let instr = prod.fcmla_vector(1, 2, 3, ArrSpecHSD::T4H, ComplexRotation::Rot0);
+        assert_eq!(instr, "fcmla v1.4h, v2.4h, v3.4h, #0x0");
+
+        let instr = prod.fcmla_vector(1, 2, 3, ArrSpecHSD::T2S, ComplexRotation::Rot180);
+        assert_eq!(instr, "fcmla v1.2s, v2.2s, v3.2s, #0xb4");
+
+        let instr = prod.fcmla_vector(1, 2, 3, ArrSpecHSD::T2D, ComplexRotation::Rot270);
+        assert_eq!(instr, "fcmla v1.2d, v2.2d, v3.2d, #0x10e");
+    }
+
+    #[cfg(feature = "arm_feat_fcma")]
+    #[test]
+    fn test_fcadd_vector() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.fcadd_vector(1, 2, 3, ArrSpecHSD::T8H, ComplexAddRotation::Rot90);
+        assert_eq!(instr, "fcadd v1.8h, v2.8h, v3.8h, #0x5a");
+
+        let instr = prod.fcadd_vector(1, 2, 3, ArrSpecHSD::T8H, ComplexAddRotation::Rot270);
+        assert_eq!(instr, "fcadd v1.8h, v2.8h, v3.8h, #0x10e");
+
+        let instr = prod.fcadd_vector(1, 2, 3, ArrSpecHSD::T4S, ComplexAddRotation::Rot90);
+        assert_eq!(instr, "fcadd v1.4s, v2.4s, v3.4s, #0x5a");
+    }
+}
@@ -0,0 +1,84 @@
+//! # [Advanced SIMD two-register miscellaneous](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/Data-Processing----Scalar-Floating-Point-and-Advanced-SIMD?lang=en#asimdmisc)
+//!
+//! Implements the following instructions:
+//!  - [BFCVTN - BFCVTN2 - Floating-point convert from single-precision to BFloat16 format - vector](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/BFCVTN--BFCVTN2--Floating-point-convert-from-single-precision-to-BFloat16-format--vector--?lang=en)
+
+#[allow(unused_imports)]
+use bit_seq::bseq_32;
+
+use crate::instruction_encoding::InstructionProcessor;
+#[allow(unused_imports)]
+use crate::types::Register;
+
+#[inline(always)]
+#[cfg(feature = "arm_feat_bf16")]
+fn emit_two_reg_misc<P: InstructionProcessor<T>, T>(
+    proc: &mut P,
+    q: u8,
+    u: u8,
+    size: u8,
+    opcode: u8,
+    rn: Register,
+    rd: Register,
+) -> T {
+    let i = bseq_32!(0 q:1 u:1 01110 size:2 10000 opcode:5 10 rn:5 rd:5);
+    proc.process(i)
+}
+
+/// # [Advanced SIMD two-register miscellaneous](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/Data-Processing----Scalar-Floating-Point-and-Advanced-SIMD?lang=en#asimdmisc)
+///
+/// Implements the following instructions:
+///  - [BFCVTN - BFCVTN2 - Floating-point convert from single-precision to BFloat16 format - vector](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/BFCVTN--BFCVTN2--Floating-point-convert-from-single-precision-to-BFloat16-format--vector--?lang=en)
+pub trait AdvancedSIMDTwoRegisterMiscellaneous<T>: InstructionProcessor<T> {
+    /// [BFCVTN - Floating-point convert from single-precision to BFloat16 format - vector](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/BFCVTN--BFCVTN2--Floating-point-convert-from-single-precision-to-BFloat16-format--vector--?lang=en)
+    ///
+    /// Floating-point convert from single-precision to BFloat16 format (vector) reads each single-precision element in the SIMD&FP source vector, converts each value to BFloat16 format, and writes the results in the lower half of the SIMD&FP destination vector. The upper half of the destination vector is cleared.
+    ///
+    /// ```asm
+    /// BFCVTN <Vd>.4H, <Vn>.4S
+    /// ```
+    ///
+    /// *Note*: FEAT_BF16 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_bf16")]
+    fn bfcvtn(&mut self, vd: Register, vn: Register) -> T {
+        emit_two_reg_misc(self, 0, 0, 0b10, 0b10110, vn, vd)
+    }
+
+    /// [BFCVTN2 - Floating-point convert from single-precision to BFloat16 format - vector](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/BFCVTN--BFCVTN2--Floating-point-convert-from-single-precision-to-BFloat16-format--vector--?lang=en)
+    ///
+    /// Floating-point convert from single-precision to BFloat16 format (vector) reads each single-precision element in the SIMD&FP source vector, converts each value to BFloat16 format, and writes the results in the upper half of the SIMD&FP destination vector. The lower half of the destination vector is left unchanged.
+    ///
+    /// ```asm
+    /// BFCVTN2 <Vd>.8H, <Vn>.4S
+    /// ```
+    ///
+    /// *Note*: FEAT_BF16 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_bf16")]
+    fn bfcvtn2(&mut self, vd: Register, vn: Register) -> T {
+        emit_two_reg_misc(self, 1, 0, 0b10, 0b10110, vn, vd)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use crate::test_utils::test_producer::TestProducer;
+
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[cfg(feature = "arm_feat_bf16")]
+    #[test]
+    fn test_bfcvtn() {
+        let mut prod = TestProducer::new();
+
+        // bad64 prints the source arrangement as destination arrangement
+        let instr = prod.bfcvtn(1, 2);
+        assert_eq!(instr, "bfcvtn v1.2d, v2.4s");
+
+        let instr = prod.bfcvtn2(1, 2);
+        assert_eq!(instr, "bfcvtn2 v1.2d, v2.4s");
+    }
+}
@@ -0,0 +1,525 @@
+//! # [Advanced SIMD vector x indexed element](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/Data-Processing----Scalar-Floating-Point-and-Advanced-SIMD?lang=en#asimdelem)
+//!
+//! Implements the following instructions:
+//!  - [SDOT - by element - Dot Product signed arithmetic - vector - by element](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/SDOT--by-element---Dot-Product-signed-arithmetic--vector--by-element--?lang=en)
+//!  - [UDOT - by element - Dot Product unsigned arithmetic - vector - by element](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/UDOT--by-element---Dot-Product-unsigned-arithmetic--vector--by-element--?lang=en)
+//!  - [USDOT - by element - Dot Product with unsigned and signed integers - vector - by element](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/USDOT--by-element---Dot-Product-with-unsigned-and-signed-integers--vector--by-element--?lang=en)
+//!  - [SUDOT - by element - Dot product with signed and unsigned integers - vector - by element](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/SUDOT--by-element---Dot-product-with-signed-and-unsigned-integers--vector--by-element--?lang=en)
+//!  - [BFDOT - by element - BFloat16 floating-point dot product - vector - by element](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/BFDOT--by-element---BFloat16-floating-point-dot-product--vector--by-element--?lang=en)
+//!  - [BFMLALB - BFMLALT - by element - BFloat16 floating-point widening multiply-add long - by element](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/BFMLALB--BFMLALT--by-element---BFloat16-floating-point-widening-multiply-add-long--by-element--?lang=en)
+//!  - [FCMLA - by element - Floating-point Complex Multiply Accumulate - by element](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/FCMLA--by-element---Floating-point-Complex-Multiply-Accumulate--by-element--?lang=en)
+//!  - [FMLAL - FMLAL2 - by element - Floating-point fused Multiply-Add Long to accumulator - by element](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/FMLAL--FMLAL2--by-element---Floating-point-fused-Multiply-Add-Long-to-accumulator--by-element--?lang=en)
+//!  - [FMLSL - FMLSL2 - by element - Floating-point fused Multiply-Subtract Long from accumulator - by element](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/FMLSL--FMLSL2--by-element---Floating-point-fused-Multiply-Subtract-Long-from-accumulator--by-element--?lang=en)
+
+#[allow(unused_imports)]
+use bit_seq::bseq_32;
+
+use crate::instruction_encoding::InstructionProcessor;
+#[allow(unused_imports)]
+use crate::types::arr_specifier::{ArrSpec, ArrSpecH, ArrSpecS};
+#[allow(unused_imports)]
+use crate::types::encodable::Encodable;
+#[allow(unused_imports)]
+use crate::types::rotation::ComplexRotation;
+#[allow(unused_imports)]
+use crate::types::{Register, UImm1, UImm2, UImm3};
+
+#[inline(always)]
+#[cfg(any(
+    feature = "arm_feat_dotprod",
+    feature = "arm_feat_i8mm",
+    feature = "arm_feat_bf16",
+    feature = "arm_feat_fcma",
+    feature = "arm_feat_fhm"
+))]
+fn emit_vec_x_indexed_elem<P: InstructionProcessor<T>, T>(
+    proc: &mut P,
+    q: u8,
+    u: u8,
+    size: u8,
+    l: u8,
+    m: u8,
+    rm: Register,
+    opcode: u8,
+    h: u8,
+    rn: Register,
+    rd: Register,
+) -> T {
+    let i = bseq_32!(0 q:1 u:1 01111 size:2 l:1 m:1 rm:4 opcode:4 h:1 0 rn:5 rd:5);
+    proc.process(i)
+}
+
+/// Emits an indexed element instruction where `index` is encoded in `H:L`
+/// and the register `vm` in `M:Rm`.
+#[inline(always)]
+#[cfg(any(
+    feature = "arm_feat_dotprod",
+    feature = "arm_feat_i8mm",
+    feature = "arm_feat_bf16",
+    feature = "arm_feat_fcma"
+))]
+fn emit_vec_x_indexed_elem_hl<P: InstructionProcessor<T>, T>(
+    proc: &mut P,
+    q: u8,
+    u: u8,
+    size: u8,
+    vm: Register,
+    index: UImm2,
+    opcode: u8,
+    vn: Register,
+    vd: Register,
+) -> T {
+    debug_assert!(index <= 3, "index must be in range 0 to 3, was {}", index);
+    let h = (index >> 1) & 1;
+    let l = index & 1;
+    let m = (vm >> 4) & 1;
+    emit_vec_x_indexed_elem(proc, q, u, size, l, m, vm, opcode, h, vn, vd)
+}
+
+/// Emits an indexed element instruction where `index` is encoded in `H:L:M`
+/// and the register `vm` is restricted to V0-V15.
+#[inline(always)]
+#[cfg(any(feature = "arm_feat_bf16", feature = "arm_feat_fhm"))]
+fn emit_vec_x_indexed_elem_hlm<P: InstructionProcessor<T>, T>(
+    proc: &mut P,
+    q: u8,
+    u: u8,
+    size: u8,
+    vm: Register,
+    index: UImm3,
+    opcode: u8,
+    vn: Register,
+    vd: Register,
+) -> T {
+    debug_assert!(vm <= 15, "vm must be in range V0 to V15, was {}", vm);
+    debug_assert!(index <= 7, "index must be in range 0 to 7, was {}", index);
+    let h = (index >> 2) & 1;
+    let l = (index >> 1) & 1;
+    let m = index & 1;
+    emit_vec_x_indexed_elem(proc, q, u, size, l, m, vm, opcode, h, vn, vd)
+}
+
+/// # [Advanced SIMD vector x indexed element](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/Data-Processing----Scalar-Floating-Point-and-Advanced-SIMD?lang=en#asimdelem)
+///
+/// Implements the following instructions:
+///  - [SDOT - by element - Dot Product signed arithmetic - vector - by element](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/SDOT--by-element---Dot-Product-signed-arithmetic--vector--by-element--?lang=en)
+///  - [UDOT - by element - Dot Product unsigned arithmetic - vector - by element](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/UDOT--by-element---Dot-Product-unsigned-arithmetic--vector--by-element--?lang=en)
+///  - [USDOT - by element - Dot Product with unsigned and signed integers - vector - by element](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/USDOT--by-element---Dot-Product-with-unsigned-and-signed-integers--vector--by-element--?lang=en)
+///  - [SUDOT - by element - Dot product with signed and unsigned integers - vector - by element](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/SUDOT--by-element---Dot-product-with-signed-and-unsigned-integers--vector--by-element--?lang=en)
+///  - [BFDOT - by element - BFloat16 floating-point dot product - vector - by element](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/BFDOT--by-element---BFloat16-floating-point-dot-product--vector--by-element--?lang=en)
+///  - [BFMLALB - BFMLALT - by element - BFloat16 floating-point widening multiply-add long - by element](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/BFMLALB--BFMLALT--by-element---BFloat16-floating-point-widening-multiply-add-long--by-element--?lang=en)
+///  - [FCMLA - by element - Floating-point Complex Multiply Accumulate - by element](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/FCMLA--by-element---Floating-point-Complex-Multiply-Accumulate--by-element--?lang=en)
+///  - [FMLAL - FMLAL2 - by element - Floating-point fused Multiply-Add Long to accumulator - by element](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/FMLAL--FMLAL2--by-element---Floating-point-fused-Multiply-Add-Long-to-accumulator--by-element--?lang=en)
+///  - [FMLSL - FMLSL2 - by element - Floating-point fused Multiply-Subtract Long from accumulator - by element](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/FMLSL--FMLSL2--by-element---Floating-point-fused-Multiply-Subtract-Long-from-accumulator--by-element--?lang=en)
+pub trait AdvancedSIMDVectorXIndexedElement<T>: InstructionProcessor<T> {
+    /// [SDOT - by element - Dot Product signed arithmetic - vector - by element](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/SDOT--by-element---Dot-Product-signed-arithmetic--vector--by-element--?lang=en)
+    ///
+    /// Dot Product signed arithmetic (vector, by element). This instruction performs the dot product of the four 8-bit elements in each 32-bit element of the first source register with the four 8-bit elements of an indexed 32-bit element in the second source register, accumulating the result into the corresponding 32-bit element of the destination register.
+    ///
+    /// `index` must be in range 0 to 3.
+    ///
+    /// ```asm
+    /// SDOT <Vd>.<Ta>, <Vn>.<Tb>, <Vm>.4B[<index>]
+    /// ```
+    ///
+    /// *Note*: FEAT_DotProd required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_dotprod")]
+    fn sdot_by_element(
+        &mut self,
+        vd: Register,
+        vn: Register,
+        vm: Register,
+        index: UImm2,
+        ta: ArrSpecS,
+    ) -> T {
+        emit_vec_x_indexed_elem_hl(self, ta.q(), 0, 0b10, vm, index, 0b1110, vn, vd)
+    }
+
+    /// [UDOT - by element - Dot Product unsigned arithmetic - vector - by element](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/UDOT--by-element---Dot-Product-unsigned-arithmetic--vector--by-element--?lang=en)
+    ///
+    /// Dot Product unsigned arithmetic (vector, by element). This instruction performs the dot product of the four 8-bit elements in each 32-bit element of the first source register with the four 8-bit elements of an indexed 32-bit element in the second source register, accumulating the result into the corresponding 32-bit element of the destination register.
+    ///
+    /// `index` must be in range 0 to 3.
+    ///
+    /// ```asm
+    /// UDOT <Vd>.<Ta>, <Vn>.<Tb>, <Vm>.4B[<index>]
+    /// ```
+    ///
+    /// *Note*: FEAT_DotProd required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_dotprod")]
+    fn udot_by_element(
+        &mut self,
+        vd: Register,
+        vn: Register,
+        vm: Register,
+        index: UImm2,
+        ta: ArrSpecS,
+    ) -> T {
+        emit_vec_x_indexed_elem_hl(self, ta.q(), 1, 0b10, vm, index, 0b1110, vn, vd)
+    }
+
+    /// [USDOT - by element - Dot Product with unsigned and signed integers - vector - by element](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/USDOT--by-element---Dot-Product-with-unsigned-and-signed-integers--vector--by-element--?lang=en)
+    ///
+    /// Dot Product index form with unsigned and signed integers. This instruction performs the dot product of the four unsigned 8-bit integer values in each 32-bit element of the first source register with the four signed 8-bit integer values in an indexed 32-bit element of the second source register, accumulating the result into the corresponding 32-bit element of the destination register.
+    ///
+    /// `index` must be in range 0 to 3.
+    ///
+    /// ```asm
+    /// USDOT <Vd>.<Ta>, <Vn>.<Tb>, <Vm>.4B[<index>]
+    /// ```
+    ///
+    /// *Note*: FEAT_I8MM required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_i8mm")]
+    fn usdot_by_element(
+        &mut self,
+        vd: Register,
+        vn: Register,
+        vm: Register,
+        index: UImm2,
+        ta: ArrSpecS,
+    ) -> T {
+        emit_vec_x_indexed_elem_hl(self, ta.q(), 0, 0b10, vm, index, 0b1111, vn, vd)
+    }
+
+    /// [SUDOT - by element - Dot product with signed and unsigned integers - vector - by element](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/SUDOT--by-element---Dot-product-with-signed-and-unsigned-integers--vector--by-element--?lang=en)
+    ///
+    /// Dot product index form with signed and unsigned integers. This instruction performs the dot product of the four signed 8-bit integer values in each 32-bit element of the first source register with the four unsigned 8-bit integer values in an indexed 32-bit element of the second source register, accumulating the result into the corresponding 32-bit element of the destination register.
+    ///
+    /// `index` must be in range 0 to 3.
+    ///
+    /// ```asm
+    /// SUDOT <Vd>.<Ta>, <Vn>.<Tb>, <Vm>.4B[<index>]
+    /// ```
+    ///
+    /// *Note*: FEAT_I8MM required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_i8mm")]
+    fn sudot_by_element(
+        &mut self,
+        vd: Register,
+        vn: Register,
+        vm: Register,
+        index: UImm2,
+        ta: ArrSpecS,
+    ) -> T {
+        emit_vec_x_indexed_elem_hl(self, ta.q(), 0, 0b00, vm, index, 0b1111, vn, vd)
+    }
+
+    /// [BFDOT - by element - BFloat16 floating-point dot product - vector - by element](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/BFDOT--by-element---BFloat16-floating-point-dot-product--vector--by-element--?lang=en)
+    ///
+    /// BFloat16 floating-point dot product (vector, by element). This instruction delimits the source vectors into pairs of BFloat16 elements. Each pair of elements in the first source vector is multiplied by the specified pair of elements in the second source vector. The resulting single-precision products are then summed and added destructively to the single-precision element of the destination vector that aligns with the pair of BFloat16 values in the first source vector.
+    ///
+    /// `index` must be in range 0 to 3.
+    ///
+    /// ```asm
+    /// BFDOT <Vd>.<Ta>, <Vn>.<Tb>, <Vm>.2H[<index>]
+    /// ```
+    ///
+    /// *Note*: FEAT_BF16 required \
+    /// **Warning**: Not tested!
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_bf16")]
+    fn bfdot_by_element(
+        &mut self,
+        vd: Register,
+        vn: Register,
+        vm: Register,
+        index: UImm2,
+        ta: ArrSpecS,
+    ) -> T {
+        emit_vec_x_indexed_elem_hl(self, ta.q(), 0, 0b01, vm, index, 0b1111, vn, vd)
+    }
+
+    /// [BFMLALB - by element - BFloat16 floating-point widening multiply-add long - by element](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/BFMLALB--BFMLALT--by-element---BFloat16-floating-point-widening-multiply-add-long--by-element--?lang=en)
+    ///
+    /// BFloat16 floating-point widening multiply-add long (by element) widens the even-numbered (bottom) 16-bit elements in the first source vector, and the indexed element in the second source vector from Bfloat16 to single-precision format. The instruction then multiplies and adds these values to the overlapping single-precision elements of the destination vector.
+    ///
+    /// `vm` must be in range V0 to V15 and `index` in range 0 to 7.
+    ///
+    /// ```asm
+    /// BFMLALB <Vd>.4S, <Vn>.8H, <Vm>.H[<index>]
+    /// ```
+    ///
+    /// *Note*: FEAT_BF16 required \
+    /// **Warning**: Not tested!
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_bf16")]
+    fn bfmlalb_by_element(&mut self, vd: Register, vn: Register, vm: Register, index: UImm3) -> T {
+        emit_vec_x_indexed_elem_hlm(self, 0, 0, 0b11, vm, index, 0b1111, vn, vd)
+    }
+
+    /// [BFMLALT - by element - BFloat16 floating-point widening multiply-add long - by element](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/BFMLALB--BFMLALT--by-element---BFloat16-floating-point-widening-multiply-add-long--by-element--?lang=en)
+    ///
+    /// BFloat16 floating-point widening multiply-add long (by element) widens the odd-numbered (top) 16-bit elements in the first source vector, and the indexed element in the second source vector from Bfloat16 to single-precision format. The instruction then multiplies and adds these values to the overlapping single-precision elements of the destination vector.
+    ///
+    /// `vm` must be in range V0 to V15 and `index` in range 0 to 7.
+    ///
+    /// ```asm
+    /// BFMLALT <Vd>.4S, <Vn>.8H, <Vm>.H[<index>]
+    /// ```
+    ///
+    /// *Note*: FEAT_BF16 required \
+    /// **Warning**: Not tested!
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_bf16")]
+    fn bfmlalt_by_element(&mut self, vd: Register, vn: Register, vm: Register, index: UImm3) -> T {
+        emit_vec_x_indexed_elem_hlm(self, 1, 0, 0b11, vm, index, 0b1111, vn, vd)
+    }
+
+    /// [FCMLA - by element - Floating-point Complex Multiply Accumulate - by element](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/FCMLA--by-element---Floating-point-Complex-Multiply-Accumulate--by-element--?lang=en)
+    ///
+    /// Floating-point Complex Multiply Accumulate (by element). This instruction operates on complex numbers that are represented in SIMD&FP registers as pairs of elements, with the more significant element holding the imaginary part of the number and the less significant element holding the real part of the number. Each element holds a floating-point value. The complex number selected by `index` in the second source register is rotated by `rot`, multiplied by the complex numbers of the first source register and accumulated into the destination register.
+    ///
+    /// Half-precision variant, `index` must be in range 0 to 3 for `T8H` and 0 to 1 for `T4H`.
+    ///
+    /// ```asm
+    /// FCMLA <Vd>.<T>, <Vn>.<T>, <Vm>.H[<index>], #<rotate>
+    /// ```
+    ///
+    /// *Note*: FEAT_FCMA and FEAT_FP16 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_fcma")]
+    fn fcmla_by_element_h(
+        &mut self,
+        vd: Register,
+        vn: Register,
+        vm: Register,
+        index: UImm2,
+        t: ArrSpecH,
+        rot: ComplexRotation,
+    ) -> T {
+        debug_assert!(
+            t.q() == 1 || index <= 1,
+            "index must be in range 0 to 1 for 4H, was {}",
+            index
+        );
+        let opcode = (rot.encode() << 1) | 1;
+        emit_vec_x_indexed_elem_hl(self, t.q(), 1, 0b01, vm, index, opcode, vn, vd)
+    }
+
+    /// [FCMLA - by element - Floating-point Complex Multiply Accumulate - by element](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/FCMLA--by-element---Floating-point-Complex-Multiply-Accumulate--by-element--?lang=en)
+    ///
+    /// Floating-point Complex Multiply Accumulate (by element). This instruction operates on complex numbers that are represented in SIMD&FP registers as pairs of elements, with the more significant element holding the imaginary part of the number and the less significant element holding the real part of the number. Each element holds a floating-point value. The complex number selected by `index` in the second source register is rotated by `rot`, multiplied by the complex numbers of the first source register and accumulated into the destination register.
+    ///
+    /// Single-precision variant, `index` must be in range 0 to 1.
+    ///
+    /// ```asm
+    /// FCMLA <Vd>.4S, <Vn>.4S, <Vm>.S[<index>], #<rotate>
+    /// ```
+    ///
+    /// *Note*: FEAT_FCMA required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_fcma")]
+    fn fcmla_by_element_s(
+        &mut self,
+        vd: Register,
+        vn: Register,
+        vm: Register,
+        index: UImm1,
+        rot: ComplexRotation,
+    ) -> T {
+        debug_assert!(index <= 1, "index must be in range 0 to 1, was {}", index);
+        let opcode = (rot.encode() << 1) | 1;
+        emit_vec_x_indexed_elem_hl(self, 1, 1, 0b10, vm, index << 1, opcode, vn, vd)
+    }
+
+    /// [FMLAL - by element - Floating-point fused Multiply-Add Long to accumulator - by element](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/FMLAL--FMLAL2--by-element---Floating-point-fused-Multiply-Add-Long-to-accumulator--by-element--?lang=en)
+    ///
+    /// Floating-point fused Multiply-Add Long to accumulator (by element). This instruction multiplies the vector elements in the lower half of the first source SIMD&FP register by the specified value in the second source SIMD&FP register, and accumulates the product to the corresponding vector element of the destination SIMD&FP register. The instruction does not round the result of the multiply before the accumulation.
+    ///
+    /// `vm` must be in range V0 to V15 and `index` in range 0 to 7.
+    ///
+    /// ```asm
+    /// FMLAL <Vd>.<Ta>, <Vn>.<Tb>, <Vm>.H[<index>]
+    /// ```
+    ///
+    /// *Note*: FEAT_FHM required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_fhm")]
+    fn fmlal_by_element(
+        &mut self,
+        vd: Register,
+        vn: Register,
+        vm: Register,
+        index: UImm3,
+        ta: ArrSpecS,
+    ) -> T {
+        emit_vec_x_indexed_elem_hlm(self, ta.q(), 0, 0b10, vm, index, 0b0000, vn, vd)
+    }
+
+    /// [FMLAL2 - by element - Floating-point fused Multiply-Add Long to accumulator - by element](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/FMLAL--FMLAL2--by-element---Floating-point-fused-Multiply-Add-Long-to-accumulator--by-element--?lang=en)
+    ///
+    /// Floating-point fused Multiply-Add Long to accumulator (by element). This instruction multiplies the vector elements in the upper half of the first source SIMD&FP register by the specified value in the second source SIMD&FP register, and accumulates the product to the corresponding vector element of the destination SIMD&FP register. The instruction does not round the result of the multiply before the accumulation.
+    ///
+    /// `vm` must be in range V0 to V15 and `index` in range 0 to 7.
+    ///
+    /// ```asm
+    /// FMLAL2 <Vd>.<Ta>, <Vn>.<Tb>, <Vm>.H[<index>]
+    /// ```
+    ///
+    /// *Note*: FEAT_FHM required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_fhm")]
+    fn fmlal2_by_element(
+        &mut self,
+        vd: Register,
+        vn: Register,
+        vm: Register,
+        index: UImm3,
+        ta: ArrSpecS,
+    ) -> T {
+        emit_vec_x_indexed_elem_hlm(self, ta.q(), 1, 0b10, vm, index, 0b1000, vn, vd)
+    }
+
+    /// [FMLSL - by element - Floating-point fused Multiply-Subtract Long from accumulator - by element](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/FMLSL--FMLSL2--by-element---Floating-point-fused-Multiply-Subtract-Long-from-accumulator--by-element--?lang=en)
+    ///
+    /// Floating-point fused Multiply-Subtract Long from accumulator (by element). This instruction multiplies the negated vector elements in the lower half of the first source SIMD&FP register by the specified value in the second source SIMD&FP register, and accumulates the product to the corresponding vector element of the destination SIMD&FP register. The instruction does not round the result of the multiply before the accumulation.
+    ///
+    /// `vm` must be in range V0 to V15 and `index` in range 0 to 7.
+    ///
+    /// ```asm
+    /// FMLSL <Vd>.<Ta>, <Vn>.<Tb>, <Vm>.H[<index>]
+    /// ```
+    ///
+    /// *Note*: FEAT_FHM required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_fhm")]
+    fn fmlsl_by_element(
+        &mut self,
+        vd: Register,
+        vn: Register,
+        vm: Register,
+        index: UImm3,
+        ta: ArrSpecS,
+    ) -> T {
+        emit_vec_x_indexed_elem_hlm(self, ta.q(), 0, 0b10, vm, index, 0b0100, vn, vd)
+    }
+
+    /// [FMLSL2 - by element - Floating-point fused Multiply-Subtract Long from accumulator - by element](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/FMLSL--FMLSL2--by-element---Floating-point-fused-Multiply-Subtract-Long-from-accumulator--by-element--?lang=en)
+    ///
+    /// Floating-point fused Multiply-Subtract Long from accumulator (by element). This instruction multiplies the negated vector elements in the upper half of the first source SIMD&FP register by the specified value in the second source SIMD&FP register, and accumulates the product to the corresponding vector element of the destination SIMD&FP register. The instruction does not round the result of the multiply before the accumulation.
+    ///
+    /// `vm` must be in range V0 to V15 and `index` in range 0 to 7.
+    ///
+    /// ```asm
+    /// FMLSL2 <Vd>.<Ta>, <Vn>.<Tb>, <Vm>.H[<index>]
+    /// ```
+    ///
+    /// *Note*: FEAT_FHM required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_fhm")]
+    fn fmlsl2_by_element(
+        &mut self,
+        vd: Register,
+        vn: Register,
+        vm: Register,
+        index: UImm3,
+        ta: ArrSpecS,
+    ) -> T {
+        emit_vec_x_indexed_elem_hlm(self, ta.q(), 1, 0b10, vm, index, 0b1100, vn, vd)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use crate::assert_panic;
+    #[allow(unused_imports)]
+    use crate::test_utils::test_producer::TestProducer;
+
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[cfg(feature = "arm_feat_dotprod")]
+    #[test]
+    fn test_sdot_udot_by_element() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.sdot_by_element(1, 2, 19, 3, ArrSpecS::T4S);
+        assert_eq!(instr, "sdot v1.4s, v2.16b, v19.b[3]");
+
+        let instr = prod.sdot_by_element(1, 2, 3, 0, ArrSpecS::T2S);
+        assert_eq!(instr, "sdot v1.2s, v2.8b, v3.b[0]");
+
+        let instr = prod.udot_by_element(1, 2, 3, 0, ArrSpecS::T2S);
+        assert_eq!(instr, "udot v1.2s, v2.8b, v3.b[0]");
+
+        let instr = prod.udot_by_element(1, 2, 31, 2, ArrSpecS::T4S);
+        assert_eq!(instr, "udot v1.4s, v2.16b, v31.b[2]");
+
+        assert_panic!("Should panic: index out of range"; prod.sdot_by_element(1, 2, 3, 4, ArrSpecS::T4S));
+    }
+
+    #[cfg(feature = "arm_feat_i8mm")]
+    #[test]
+    fn test_usdot_sudot_by_element() {
+        let mut prod = TestProducer::new();
+
+        // bad64 does not decode the index of usdot/sudot, so only index 0 is checked
+
+        let instr = prod.usdot_by_element(1, 2, 3, 0, ArrSpecS::T4S);
+        assert_eq!(instr, "usdot v1.4s, v2.16b, v3.b[0]");
+
+        let instr = prod.usdot_by_element(1, 2, 17, 0, ArrSpecS::T2S);
+        assert_eq!(instr, "usdot v1.2s, v2.8b, v17.b[0]");
+
+        let instr = prod.sudot_by_element(1, 2, 3, 0, ArrSpecS::T4S);
+        assert_eq!(instr, "sudot v1.4s, v2.16b, v3.b[0]");
+
+        let instr = prod.sudot_by_element(1, 2, 19, 0, ArrSpecS::T2S);
+        assert_eq!(instr, "sudot v1.2s, v2.8b, v19.b[0]");
+    }
+
+    #[cfg(feature = "arm_feat_bf16")]
+    #[test]
+    fn test_bfmlal_by_element_range() {
+        let mut prod = TestProducer::new();
+
+        assert_panic!("Should panic: vm out of range"; prod.bfmlalb_by_element(1, 2, 16, 0));
+        assert_panic!("Should panic: index out of range"; prod.bfmlalt_by_element(1, 2, 3, 8));
+    }
+
+    #[cfg(feature = "arm_feat_fcma")]
+    #[test]
+    fn test_fcmla_by_element() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.fcmla_by_element_h(1, 2, 19, 3, ArrSpecH::T8H, ComplexRotation::Rot90);
+        assert_eq!(instr, "fcmla v1.8h, v2.8h, v19.h[3], #0x5a");
+
+        let instr = prod.fcmla_by_element_h(1, 2, 3, 1, ArrSpecH::T4H, ComplexRotation::Rot0);
+        assert_eq!(instr, "fcmla v1.4h, v2.4h, v3.h[1], #0x0");
+
+        let instr = prod.fcmla_by_element_s(1, 2, 19, 1, ComplexRotation::Rot180);
+        assert_eq!(instr, "fcmla v1.4s, v2.4s, v19.s[1], #0xb4");
+
+        let instr = prod.fcmla_by_element_s(1, 2, 3, 0, ComplexRotation::Rot270);
+        assert_eq!(instr, "fcmla v1.4s, v2.4s, v3.s[0], #0x10e");
+
+        assert_panic!("Should panic: index out of range"; prod.fcmla_by_element_h(1, 2, 3, 2, ArrSpecH::T4H, ComplexRotation::Rot0));
+        assert_panic!("Should panic: index out of range"; prod.fcmla_by_element_s(1, 2, 3, 2, ComplexRotation::Rot0));
+    }
+
+    #[cfg(feature = "arm_feat_fhm")]
+    #[test]
+    fn test_fmlal_fmlsl_by_element() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.fmlal_by_element(1, 2, 3, 7, ArrSpecS::T2S);
+        assert_eq!(instr, "fmlal v1.2s, v2.2h, v3.h[7]");
+
+        let instr = prod.fmlal2_by_element(1, 2, 15, 2, ArrSpecS::T4S);
+        assert_eq!(instr, "fmlal2 v1.4s, v2.4h, v15.h[2]");
+
+        let instr = prod.fmlsl_by_element(1, 2, 3, 5, ArrSpecS::T4S);
+        assert_eq!(instr, "fmlsl v1.4s, v2.4h, v3.h[5]");
+
+        let instr = prod.fmlsl2_by_element(1, 2, 3, 0, ArrSpecS::T2S);
+        assert_eq!(instr, "fmlsl2 v1.2s, v2.2h, v3.h[0]");
+
+        assert_panic!("Should panic: vm out of range"; prod.fmlal_by_element(1, 2, 16, 0, ArrSpecS::T2S));
+    }
+}
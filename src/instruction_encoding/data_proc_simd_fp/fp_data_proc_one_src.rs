@@ -0,0 +1,68 @@
+//! # [Floating-point data-processing (1 source)](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/Data-Processing----Scalar-Floating-Point-and-Advanced-SIMD?lang=en#floatdp1)
+//!
+//! Implements the following instructions:
+//!  - [BFCVT - Floating-point convert from single-precision to BFloat16 format - scalar](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/BFCVT--Floating-point-convert-from-single-precision-to-BFloat16-format--scalar--?lang=en)
+
+#[allow(unused_imports)]
+use bit_seq::bseq_32;
+
+use crate::instruction_encoding::InstructionProcessor;
+#[allow(unused_imports)]
+use crate::types::Register;
+
+#[inline(always)]
+#[cfg(feature = "arm_feat_bf16")]
+fn emit_fp_data_proc_one_src<P: InstructionProcessor<T>, T>(
+    proc: &mut P,
+    m: u8,
+    s: u8,
+    ptype: u8,
+    opcode: u8,
+    rn: Register,
+    rd: Register,
+) -> T {
+    let i = bseq_32!(m:1 0 s:1 11110 ptype:2 1 opcode:6 10000 rn:5 rd:5);
+    proc.process(i)
+}
+
+/// # [Floating-point data-processing (1 source)](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/Data-Processing----Scalar-Floating-Point-and-Advanced-SIMD?lang=en#floatdp1)
+///
+/// Implements the following instructions:
+///  - [BFCVT - Floating-point convert from single-precision to BFloat16 format - scalar](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/BFCVT--Floating-point-convert-from-single-precision-to-BFloat16-format--scalar--?lang=en)
+pub trait FloatingPointDataProcessingOneSource<T>: InstructionProcessor<T> {
+    /// [BFCVT - Floating-point convert from single-precision to BFloat16 format - scalar](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/BFCVT--Floating-point-convert-from-single-precision-to-BFloat16-format--scalar--?lang=en)
+    ///
+    /// Floating-point convert from single-precision to BFloat16 format (scalar) converts the single-precision floating-point value in the 32-bit SIMD&FP source register to BFloat16 format and writes the result in the 16-bit SIMD&FP destination register.
+    ///
+    /// ```asm
+    /// BFCVT <Hd>, <Sn>
+    /// ```
+    ///
+    /// *Note*: FEAT_BF16 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_bf16")]
+    fn bfcvt(&mut self, hd: Register, sn: Register) -> T {
+        emit_fp_data_proc_one_src(self, 0, 0, 0b01, 0b000110, sn, hd)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use crate::test_utils::test_producer::TestProducer;
+
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[cfg(feature = "arm_feat_bf16")]
+    #[test]
+    fn test_bfcvt() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.bfcvt(1, 2);
+        assert_eq!(instr, "bfcvt h1, s2");
+
+        let instr = prod.bfcvt(31, 0);
+        assert_eq!(instr, "bfcvt h31, s0");
+    }
+}
@@ -8,9 +8,12 @@
 use crate::instruction_encoding::loads_and_stores::advanced_simd_ldr_str_multi_structures::AdvancedSIMDLoadStoreMultipleStructures;
 use crate::instruction_encoding::loads_and_stores::advanced_simd_ldr_str_single_structures::AdvancedSIMDLoadStoreSingleStructures;
 use crate::instruction_encoding::loads_and_stores::atomic_memory_operations::AtomicMemoryOperatinos;
+use crate::instruction_encoding::loads_and_stores::atomic_memory_operations_128::AtomicMemoryOperations128;
 use crate::instruction_encoding::loads_and_stores::compare_and_swap::CompareAndSwap;
 use crate::instruction_encoding::loads_and_stores::compare_and_swap_pair::CompareAndSwapPair;
+use crate::instruction_encoding::loads_and_stores::gcs_load_store::GcsLoadStore;
 use crate::instruction_encoding::loads_and_stores::ldapr_stlr_unscale_imm::LdaprStlrUnscaleImmediate;
+use crate::instruction_encoding::loads_and_stores::ldiapp_stilp::LdiappStilp;
 use crate::instruction_encoding::loads_and_stores::load_register_literal::{
     LoadRegisterLiteral, LoadRegisterLiteralWithAddress,
 };
@@ -29,13 +32,17 @@ use crate::instruction_encoding::loads_and_stores::load_store_register_pac::Load
 use crate::instruction_encoding::loads_and_stores::load_store_register_regoffset::LoadStoreRegisterRegisterOffset;
 use crate::instruction_encoding::loads_and_stores::load_store_register_unsigned_imm::LoadStoreRegisterUnsignedImmediate;
 use crate::instruction_encoding::loads_and_stores::memory_copy_and_memory_set::MemoryCopyAndMemorySet;
+use crate::instruction_encoding::loads_and_stores::read_check_write::ReadCheckWrite;
 
 pub mod advanced_simd_ldr_str_multi_structures;
 pub mod advanced_simd_ldr_str_single_structures;
 pub mod atomic_memory_operations;
+pub mod atomic_memory_operations_128;
 pub mod compare_and_swap;
 pub mod compare_and_swap_pair;
+pub mod gcs_load_store;
 pub mod ldapr_stlr_unscale_imm;
+pub mod ldiapp_stilp;
 pub mod load_register_literal;
 pub mod load_store_exclusive_pair;
 pub mod load_store_exclusive_register;
@@ -52,6 +59,7 @@ pub mod load_store_register_pac;
 pub mod load_store_register_regoffset;
 pub mod load_store_register_unsigned_imm;
 pub mod memory_copy_and_memory_set;
+pub mod read_check_write;
 
 pub trait LoadsAndStores<T>:
     CompareAndSwapPair<T>
@@ -62,6 +70,7 @@ pub trait LoadsAndStores<T>:
     + LoadStoreOrdered<T>
     + CompareAndSwap<T>
     + LdaprStlrUnscaleImmediate<T>
+    + LdiappStilp<T>
     + MemoryCopyAndMemorySet<T>
     + LoadStoreNoAllocatePairOffset<T>
     + LoadStoreRegisterPairPostIndexed<T>
@@ -76,6 +85,9 @@ pub trait LoadsAndStores<T>:
     + AdvancedSIMDLoadStoreMultipleStructures<T>
     + AdvancedSIMDLoadStoreSingleStructures<T>
     + AtomicMemoryOperatinos<T>
+    + AtomicMemoryOperations128<T>
+    + ReadCheckWrite<T>
+    + GcsLoadStore<T>
 {
 }
 
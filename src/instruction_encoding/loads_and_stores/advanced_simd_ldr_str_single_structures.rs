@@ -13,6 +13,8 @@
 //!  - [LD4 - single structure](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/LD4--single-structure---Load-single-4-element-structure-to-one-lane-of-four-registers-?lang=en)
 //!  - [LD2R - Load single 2 element structure and Replicate to all lanes of two registers](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/LD2R--Load-single-2-element-structure-and-Replicate-to-all-lanes-of-two-registers-?lang=en)
 //!  - [LD4R - Load single 4 element structure and Replicate to all lanes of four registers](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/LD4R--Load-single-4-element-structure-and-Replicate-to-all-lanes-of-four-registers-?lang=en)
+//!  - [STL1 - SIMD&FP - Store Release a single element structure from one lane of one register](https://developer.arm.com/documentation/ddi0602/2022-12/SIMD-FP-Instructions/STL1--SIMD-FP---Store-Release-a-single-element-structure-from-one-lane-of-one-register-?lang=en)
+//!  - [LDAP1 - SIMD&FP - Load Acquire RCpc one single element structure to one lane of one register](https://developer.arm.com/documentation/ddi0602/2022-12/SIMD-FP-Instructions/LDAP1--SIMD-FP---Load-Acquire-RCpc-one-single-element-structure-to-one-lane-of-one-register-?lang=en)
 
 use bit_seq::bseq_32;
 
@@ -219,6 +221,8 @@ fn q_s_size(index: u8, op_size: u8) -> (u8, u8, u8) {
 ///  - [LD4 - single structure](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/LD4--single-structure---Load-single-4-element-structure-to-one-lane-of-four-registers-?lang=en)
 ///  - [LD2R - Load single 2 element structure and Replicate to all lanes of two registers](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/LD2R--Load-single-2-element-structure-and-Replicate-to-all-lanes-of-two-registers-?lang=en)
 ///  - [LD4R - Load single 4 element structure and Replicate to all lanes of four registers](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/LD4R--Load-single-4-element-structure-and-Replicate-to-all-lanes-of-four-registers-?lang=en)
+///  - [STL1 - SIMD&FP - Store Release a single element structure from one lane of one register](https://developer.arm.com/documentation/ddi0602/2022-12/SIMD-FP-Instructions/STL1--SIMD-FP---Store-Release-a-single-element-structure-from-one-lane-of-one-register-?lang=en)
+///  - [LDAP1 - SIMD&FP - Load Acquire RCpc one single element structure to one lane of one register](https://developer.arm.com/documentation/ddi0602/2022-12/SIMD-FP-Instructions/LDAP1--SIMD-FP---Load-Acquire-RCpc-one-single-element-structure-to-one-lane-of-one-register-?lang=en)
 pub trait AdvancedSIMDLoadStoreSingleStructures<T>: InstructionProcessor<T> {
     /// [ST1 - single structure](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/ST1--single-structure---Store-a-single-element-structure-from-one-lane-of-one-register-?lang=en)
     ///
@@ -2469,10 +2473,48 @@ pub trait AdvancedSIMDLoadStoreSingleStructures<T>: InstructionProcessor<T> {
     ) -> T {
         emit_adv_ldxr_off_reg(self, 1, 1, xm, 0b111, t, xn_sp, &[vt, vt2, vt3, vt4])
     }
+
+    /// [STL1 - SIMD&FP - Store Release a single element structure from one lane of one register](https://developer.arm.com/documentation/ddi0602/2022-12/SIMD-FP-Instructions/STL1--SIMD-FP---Store-Release-a-single-element-structure-from-one-lane-of-one-register-?lang=en)
+    ///
+    /// Store-Release a single-element structure from one lane of one register. This instruction stores the specified element of a SIMD&FP register to memory.
+    ///
+    /// The instruction has memory ordering semantics as described in Load-Acquire, Load-AcquirePC, and Store-Release.
+    ///
+    /// ```asm
+    /// STL1 { <Vt>.D }[<index>], [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_LRCPC3 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lrcpc3")]
+    fn stl1_single_struct_double_words(&mut self, vt: Register, index: UImm1, xn_sp: Register) -> T {
+        debug_assert!(index <= 1, "index must be 0 or 1, was {}", index);
+        emit_adv_ldr_str_all(self, index, false, 0, 0, 0b00001, 0b100, 0, 0b01, xn_sp, vt)
+    }
+
+    /// [LDAP1 - SIMD&FP - Load Acquire RCpc one single element structure to one lane of one register](https://developer.arm.com/documentation/ddi0602/2022-12/SIMD-FP-Instructions/LDAP1--SIMD-FP---Load-Acquire-RCpc-one-single-element-structure-to-one-lane-of-one-register-?lang=en)
+    ///
+    /// Load-Acquire RCpc one single-element structure to one lane of one register. This instruction loads a single-element structure from memory and writes the result to the specified lane of the SIMD&FP register without affecting the other bits of the register.
+    ///
+    /// The instruction has memory ordering semantics as described in Load-Acquire, Load-AcquirePC, and Store-Release.
+    ///
+    /// ```asm
+    /// LDAP1 { <Vt>.D }[<index>], [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_LRCPC3 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lrcpc3")]
+    fn ldap1_single_struct_double_words(&mut self, vt: Register, index: UImm1, xn_sp: Register) -> T {
+        debug_assert!(index <= 1, "index must be 0 or 1, was {}", index);
+        emit_adv_ldr_str_all(self, index, false, 1, 0, 0b00001, 0b100, 0, 0b01, xn_sp, vt)
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    #[allow(unused_imports)]
+    use crate::assert_panic;
     use crate::test_utils::test_producer::TestProducer;
 
     use super::*;
@@ -2696,4 +2738,20 @@ mod tests {
         let instr = prod.ld4r_offset_reg(1, 2, 3, 4, ArrSpec1::T1D, 2, 3);
         assert_eq!(instr, "ld4r {v1.1d, v2.1d, v3.1d, v4.1d}, [x2], x3");
     }
+
+    #[cfg(feature = "arm_feat_lrcpc3")]
+    #[test]
+    fn test_ldap1_stl1() {
+        use crate::instruction_producer::InstrProducer;
+
+        // the disassembler does not support FEAT_LRCPC3, so the encodings are compared
+        let mut prod = InstrProducer::new();
+
+        assert_eq!(prod.stl1_single_struct_double_words(3, 1, 15).encoding(), 0x4d0185e3);
+        assert_eq!(prod.stl1_single_struct_double_words(0, 0, 31).encoding(), 0x0d0187e0);
+        assert_eq!(prod.ldap1_single_struct_double_words(3, 1, 15).encoding(), 0x4d4185e3);
+        assert_eq!(prod.ldap1_single_struct_double_words(0, 0, 31).encoding(), 0x0d4187e0);
+
+        assert_panic!("Should panic: invalid index"; prod.ldap1_single_struct_double_words(0, 2, 1));
+    }
 }
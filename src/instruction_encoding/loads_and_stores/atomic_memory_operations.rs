@@ -35,11 +35,41 @@
 //!  - [ST64BV - Single copy Atomic 64 byte Store with Return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/ST64BV--Single-copy-Atomic-64-byte-Store-with-Return-?lang=en)
 //!  - [ST64B - Single copy Atomic 64 byte Store without Return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/ST64B--Single-copy-Atomic-64-byte-Store-without-Return-?lang=en)
 //!  - [LD64B - Single copy Atomic 64 byte Load](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/LD64B--Single-copy-Atomic-64-byte-Load-?lang=en)
+//!  - [STADDB - STADDLB - Atomic add on byte in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STADDB--STADDLB--Atomic-add-on-byte-in-memory--without-return--an-alias-of-LDADDB--LDADDAB--LDADDALB--LDADDLB-?lang=en)
+//!  - [STADDH - STADDLH - Atomic add on halfword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STADDH--STADDLH--Atomic-add-on-halfword-in-memory--without-return--an-alias-of-LDADDH--LDADDAH--LDADDALH--LDADDLH-?lang=en)
+//!  - [STADD - STADDL - Atomic add on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STADD--STADDL--Atomic-add-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDADD--LDADDA--LDADDAL--LDADDL-?lang=en)
+//!  - [STCLRB - STCLRLB - Atomic bit clear on byte in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STCLRB--STCLRLB--Atomic-bit-clear-on-byte-in-memory--without-return--an-alias-of-LDCLRB--LDCLRAB--LDCLRALB--LDCLRLB-?lang=en)
+//!  - [STCLRH - STCLRLH - Atomic bit clear on halfword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STCLRH--STCLRLH--Atomic-bit-clear-on-halfword-in-memory--without-return--an-alias-of-LDCLRH--LDCLRAH--LDCLRALH--LDCLRLH-?lang=en)
+//!  - [STCLR - STCLRL - Atomic bit clear on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STCLR--STCLRL--Atomic-bit-clear-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDCLR--LDCLRA--LDCLRAL--LDCLRL-?lang=en)
+//!  - [STEORB - STEORLB - Atomic exclusive OR on byte in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STEORB--STEORLB--Atomic-exclusive-OR-on-byte-in-memory--without-return--an-alias-of-LDEORB--LDEORAB--LDEORALB--LDEORLB-?lang=en)
+//!  - [STEORH - STEORLH - Atomic exclusive OR on halfword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STEORH--STEORLH--Atomic-exclusive-OR-on-halfword-in-memory--without-return--an-alias-of-LDEORH--LDEORAH--LDEORALH--LDEORLH-?lang=en)
+//!  - [STEOR - STEORL - Atomic exclusive OR on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STEOR--STEORL--Atomic-exclusive-OR-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDEOR--LDEORA--LDEORAL--LDEORL-?lang=en)
+//!  - [STSETB - STSETLB - Atomic bit set on byte in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSETB--STSETLB--Atomic-bit-set-on-byte-in-memory--without-return--an-alias-of-LDSETB--LDSETAB--LDSETALB--LDSETLB-?lang=en)
+//!  - [STSETH - STSETLH - Atomic bit set on halfword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSETH--STSETLH--Atomic-bit-set-on-halfword-in-memory--without-return--an-alias-of-LDSETH--LDSETAH--LDSETALH--LDSETLH-?lang=en)
+//!  - [STSET - STSETL - Atomic bit set on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSET--STSETL--Atomic-bit-set-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDSET--LDSETA--LDSETAL--LDSETL-?lang=en)
+//!  - [STSMAXB - STSMAXLB - Atomic signed maximum on byte in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSMAXB--STSMAXLB--Atomic-signed-maximum-on-byte-in-memory--without-return--an-alias-of-LDSMAXB--LDSMAXAB--LDSMAXALB--LDSMAXLB-?lang=en)
+//!  - [STSMAXH - STSMAXLH - Atomic signed maximum on halfword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSMAXH--STSMAXLH--Atomic-signed-maximum-on-halfword-in-memory--without-return--an-alias-of-LDSMAXH--LDSMAXAH--LDSMAXALH--LDSMAXLH-?lang=en)
+//!  - [STSMAX - STSMAXL - Atomic signed maximum on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSMAX--STSMAXL--Atomic-signed-maximum-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDSMAX--LDSMAXA--LDSMAXAL--LDSMAXL-?lang=en)
+//!  - [STSMINB - STSMINLB - Atomic signed minimum on byte in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSMINB--STSMINLB--Atomic-signed-minimum-on-byte-in-memory--without-return--an-alias-of-LDSMINB--LDSMINAB--LDSMINALB--LDSMINLB-?lang=en)
+//!  - [STSMINH - STSMINLH - Atomic signed minimum on halfword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSMINH--STSMINLH--Atomic-signed-minimum-on-halfword-in-memory--without-return--an-alias-of-LDSMINH--LDSMINAH--LDSMINALH--LDSMINLH-?lang=en)
+//!  - [STSMIN - STSMINL - Atomic signed minimum on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSMIN--STSMINL--Atomic-signed-minimum-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDSMIN--LDSMINA--LDSMINAL--LDSMINL-?lang=en)
+//!  - [STUMAXB - STUMAXLB - Atomic unsigned maximum on byte in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STUMAXB--STUMAXLB--Atomic-unsigned-maximum-on-byte-in-memory--without-return--an-alias-of-LDUMAXB--LDUMAXAB--LDUMAXALB--LDUMAXLB-?lang=en)
+//!  - [STUMAXH - STUMAXLH - Atomic unsigned maximum on halfword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STUMAXH--STUMAXLH--Atomic-unsigned-maximum-on-halfword-in-memory--without-return--an-alias-of-LDUMAXH--LDUMAXAH--LDUMAXALH--LDUMAXLH-?lang=en)
+//!  - [STUMAX - STUMAXL - Atomic unsigned maximum on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STUMAX--STUMAXL--Atomic-unsigned-maximum-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDUMAX--LDUMAXA--LDUMAXAL--LDUMAXL-?lang=en)
+//!  - [STUMINB - STUMINLB - Atomic unsigned minimum on byte in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STUMINB--STUMINLB--Atomic-unsigned-minimum-on-byte-in-memory--without-return--an-alias-of-LDUMINB--LDUMINAB--LDUMINALB--LDUMINLB-?lang=en)
+//!  - [STUMINH - STUMINLH - Atomic unsigned minimum on halfword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STUMINH--STUMINLH--Atomic-unsigned-minimum-on-halfword-in-memory--without-return--an-alias-of-LDUMINH--LDUMINAH--LDUMINALH--LDUMINLH-?lang=en)
+//!  - [STUMIN - STUMINL - Atomic unsigned minimum on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STUMIN--STUMINL--Atomic-unsigned-minimum-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDUMIN--LDUMINA--LDUMINAL--LDUMINL-?lang=en)
 
 use crate::instruction_encoding::InstructionProcessor;
 #[allow(unused_imports)]
+use crate::types::atomic::{encode_ordering, AtomicRmwOp, AtomicSize};
+#[allow(unused_imports)]
 use crate::types::Register;
 #[allow(unused_imports)]
+use crate::types::register::{WZR, XZR};
+#[allow(unused_imports)]
+use std::sync::atomic::Ordering;
+#[allow(unused_imports)]
 use bit_seq::bseq_32;
 
 #[inline(always)]
@@ -97,6 +127,30 @@ fn emit_atomic_mem_op<P: InstructionProcessor<T>, T>(
 ///  - [ST64BV - Single copy Atomic 64 byte Store with Return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/ST64BV--Single-copy-Atomic-64-byte-Store-with-Return-?lang=en)
 ///  - [ST64B - Single copy Atomic 64 byte Store without Return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/ST64B--Single-copy-Atomic-64-byte-Store-without-Return-?lang=en)
 ///  - [LD64B - Single copy Atomic 64 byte Load](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/LD64B--Single-copy-Atomic-64-byte-Load-?lang=en)
+///  - [STADDB - STADDLB - Atomic add on byte in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STADDB--STADDLB--Atomic-add-on-byte-in-memory--without-return--an-alias-of-LDADDB--LDADDAB--LDADDALB--LDADDLB-?lang=en)
+///  - [STADDH - STADDLH - Atomic add on halfword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STADDH--STADDLH--Atomic-add-on-halfword-in-memory--without-return--an-alias-of-LDADDH--LDADDAH--LDADDALH--LDADDLH-?lang=en)
+///  - [STADD - STADDL - Atomic add on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STADD--STADDL--Atomic-add-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDADD--LDADDA--LDADDAL--LDADDL-?lang=en)
+///  - [STCLRB - STCLRLB - Atomic bit clear on byte in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STCLRB--STCLRLB--Atomic-bit-clear-on-byte-in-memory--without-return--an-alias-of-LDCLRB--LDCLRAB--LDCLRALB--LDCLRLB-?lang=en)
+///  - [STCLRH - STCLRLH - Atomic bit clear on halfword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STCLRH--STCLRLH--Atomic-bit-clear-on-halfword-in-memory--without-return--an-alias-of-LDCLRH--LDCLRAH--LDCLRALH--LDCLRLH-?lang=en)
+///  - [STCLR - STCLRL - Atomic bit clear on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STCLR--STCLRL--Atomic-bit-clear-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDCLR--LDCLRA--LDCLRAL--LDCLRL-?lang=en)
+///  - [STEORB - STEORLB - Atomic exclusive OR on byte in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STEORB--STEORLB--Atomic-exclusive-OR-on-byte-in-memory--without-return--an-alias-of-LDEORB--LDEORAB--LDEORALB--LDEORLB-?lang=en)
+///  - [STEORH - STEORLH - Atomic exclusive OR on halfword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STEORH--STEORLH--Atomic-exclusive-OR-on-halfword-in-memory--without-return--an-alias-of-LDEORH--LDEORAH--LDEORALH--LDEORLH-?lang=en)
+///  - [STEOR - STEORL - Atomic exclusive OR on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STEOR--STEORL--Atomic-exclusive-OR-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDEOR--LDEORA--LDEORAL--LDEORL-?lang=en)
+///  - [STSETB - STSETLB - Atomic bit set on byte in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSETB--STSETLB--Atomic-bit-set-on-byte-in-memory--without-return--an-alias-of-LDSETB--LDSETAB--LDSETALB--LDSETLB-?lang=en)
+///  - [STSETH - STSETLH - Atomic bit set on halfword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSETH--STSETLH--Atomic-bit-set-on-halfword-in-memory--without-return--an-alias-of-LDSETH--LDSETAH--LDSETALH--LDSETLH-?lang=en)
+///  - [STSET - STSETL - Atomic bit set on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSET--STSETL--Atomic-bit-set-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDSET--LDSETA--LDSETAL--LDSETL-?lang=en)
+///  - [STSMAXB - STSMAXLB - Atomic signed maximum on byte in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSMAXB--STSMAXLB--Atomic-signed-maximum-on-byte-in-memory--without-return--an-alias-of-LDSMAXB--LDSMAXAB--LDSMAXALB--LDSMAXLB-?lang=en)
+///  - [STSMAXH - STSMAXLH - Atomic signed maximum on halfword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSMAXH--STSMAXLH--Atomic-signed-maximum-on-halfword-in-memory--without-return--an-alias-of-LDSMAXH--LDSMAXAH--LDSMAXALH--LDSMAXLH-?lang=en)
+///  - [STSMAX - STSMAXL - Atomic signed maximum on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSMAX--STSMAXL--Atomic-signed-maximum-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDSMAX--LDSMAXA--LDSMAXAL--LDSMAXL-?lang=en)
+///  - [STSMINB - STSMINLB - Atomic signed minimum on byte in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSMINB--STSMINLB--Atomic-signed-minimum-on-byte-in-memory--without-return--an-alias-of-LDSMINB--LDSMINAB--LDSMINALB--LDSMINLB-?lang=en)
+///  - [STSMINH - STSMINLH - Atomic signed minimum on halfword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSMINH--STSMINLH--Atomic-signed-minimum-on-halfword-in-memory--without-return--an-alias-of-LDSMINH--LDSMINAH--LDSMINALH--LDSMINLH-?lang=en)
+///  - [STSMIN - STSMINL - Atomic signed minimum on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSMIN--STSMINL--Atomic-signed-minimum-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDSMIN--LDSMINA--LDSMINAL--LDSMINL-?lang=en)
+///  - [STUMAXB - STUMAXLB - Atomic unsigned maximum on byte in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STUMAXB--STUMAXLB--Atomic-unsigned-maximum-on-byte-in-memory--without-return--an-alias-of-LDUMAXB--LDUMAXAB--LDUMAXALB--LDUMAXLB-?lang=en)
+///  - [STUMAXH - STUMAXLH - Atomic unsigned maximum on halfword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STUMAXH--STUMAXLH--Atomic-unsigned-maximum-on-halfword-in-memory--without-return--an-alias-of-LDUMAXH--LDUMAXAH--LDUMAXALH--LDUMAXLH-?lang=en)
+///  - [STUMAX - STUMAXL - Atomic unsigned maximum on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STUMAX--STUMAXL--Atomic-unsigned-maximum-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDUMAX--LDUMAXA--LDUMAXAL--LDUMAXL-?lang=en)
+///  - [STUMINB - STUMINLB - Atomic unsigned minimum on byte in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STUMINB--STUMINLB--Atomic-unsigned-minimum-on-byte-in-memory--without-return--an-alias-of-LDUMINB--LDUMINAB--LDUMINALB--LDUMINLB-?lang=en)
+///  - [STUMINH - STUMINLH - Atomic unsigned minimum on halfword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STUMINH--STUMINLH--Atomic-unsigned-minimum-on-halfword-in-memory--without-return--an-alias-of-LDUMINH--LDUMINAH--LDUMINALH--LDUMINLH-?lang=en)
+///  - [STUMIN - STUMINL - Atomic unsigned minimum on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STUMIN--STUMINL--Atomic-unsigned-minimum-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDUMIN--LDUMINA--LDUMINAL--LDUMINL-?lang=en)
 pub trait AtomicMemoryOperatinos<T>: InstructionProcessor<T> {
     /// [LDADDAB - Atomic add on byte in memory](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/LDADDB--LDADDAB--LDADDALB--LDADDLB--Atomic-add-on-byte-in-memory-?lang=en)
     ///
@@ -2378,222 +2432,1211 @@ pub trait AtomicMemoryOperatinos<T>: InstructionProcessor<T> {
     fn ld64b(&mut self, xt: Register, xn_sp: Register) -> T {
         emit_atomic_mem_op(self, 0b11, 0, 0, 0, 0b11111, 1, 0b101, xn_sp, xt)
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::test_utils::test_producer::TestProducer;
-
-    use super::*;
 
+    /// [STADDB - Atomic add on byte in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STADDB--STADDLB--Atomic-add-on-byte-in-memory--without-return--an-alias-of-LDADDB--LDADDAB--LDADDALB--LDADDLB-?lang=en)
+    ///
+    /// Atomic add on byte in memory, without return, atomically loads an 8-bit byte from memory, adds the value held in a register to it, and stores the result back to memory.
+    ///
+    /// This is an alias of LDADDB with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STADDB <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
     #[cfg(feature = "arm_feat_lse")]
-    #[test]
-    fn test_ldadd_x() {
-        let mut prod = TestProducer::new();
-
-        let instr = prod.ldaddab(1, 5, 2);
-        assert_eq!(instr, "ldaddab w1, w5, [x2]");
-
-        let instr = prod.ldaddalb(1, 5, 2);
-        assert_eq!(instr, "ldaddalb w1, w5, [x2]");
-
-        let instr = prod.ldaddb(1, 5, 2);
-        assert_eq!(instr, "ldaddb w1, w5, [x2]");
-
-        let instr = prod.ldaddlb(1, 5, 2);
-        assert_eq!(instr, "ldaddlb w1, w5, [x2]");
+    fn staddb(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldaddb(ws, WZR, xn_sp)
     }
 
+    /// [STADDLB - Atomic add on byte in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STADDB--STADDLB--Atomic-add-on-byte-in-memory--without-return--an-alias-of-LDADDB--LDADDAB--LDADDALB--LDADDLB-?lang=en)
+    ///
+    /// Atomic add on byte in memory, without return, atomically loads an 8-bit byte from memory, adds the value held in a register to it, and stores the result back to memory.
+    ///
+    /// This is an alias of LDADDLB with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STADDLB <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
     #[cfg(feature = "arm_feat_lse")]
-    #[test]
-    fn test_ldclr_x() {
-        let mut prod = TestProducer::new();
-
-        let instr = prod.ldclrab(1, 5, 2);
-        assert_eq!(instr, "ldclrab w1, w5, [x2]");
-
-        let instr = prod.ldclralb(1, 5, 2);
-        assert_eq!(instr, "ldclralb w1, w5, [x2]");
-
-        let instr = prod.ldclrb(1, 5, 2);
-        assert_eq!(instr, "ldclrb w1, w5, [x2]");
-
-        let instr = prod.ldclrlb(1, 5, 2);
-        assert_eq!(instr, "ldclrlb w1, w5, [x2]");
+    fn staddlb(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldaddlb(ws, WZR, xn_sp)
     }
 
+    /// [STADDH - Atomic add on halfword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STADDH--STADDLH--Atomic-add-on-halfword-in-memory--without-return--an-alias-of-LDADDH--LDADDAH--LDADDALH--LDADDLH-?lang=en)
+    ///
+    /// Atomic add on halfword in memory, without return, atomically loads a 16-bit halfword from memory, adds the value held in a register to it, and stores the result back to memory.
+    ///
+    /// This is an alias of LDADDH with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STADDH <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
     #[cfg(feature = "arm_feat_lse")]
-    #[test]
-    fn test_ldeor_x() {
-        let mut prod = TestProducer::new();
-
-        let instr = prod.ldeorab(1, 5, 2);
-        assert_eq!(instr, "ldeorab w1, w5, [x2]");
-
-        let instr = prod.ldeoralb(1, 5, 2);
-        assert_eq!(instr, "ldeoralb w1, w5, [x2]");
-
-        let instr = prod.ldeorb(1, 5, 2);
-        assert_eq!(instr, "ldeorb w1, w5, [x2]");
-
-        let instr = prod.ldeorlb(1, 5, 2);
-        assert_eq!(instr, "ldeorlb w1, w5, [x2]");
+    fn staddh(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldaddh(ws, WZR, xn_sp)
     }
 
+    /// [STADDLH - Atomic add on halfword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STADDH--STADDLH--Atomic-add-on-halfword-in-memory--without-return--an-alias-of-LDADDH--LDADDAH--LDADDALH--LDADDLH-?lang=en)
+    ///
+    /// Atomic add on halfword in memory, without return, atomically loads a 16-bit halfword from memory, adds the value held in a register to it, and stores the result back to memory.
+    ///
+    /// This is an alias of LDADDLH with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STADDLH <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
     #[cfg(feature = "arm_feat_lse")]
-    #[test]
-    fn test_ldset_x() {
-        let mut prod = TestProducer::new();
-
-        let instr = prod.ldsetab(1, 5, 2);
-        assert_eq!(instr, "ldsetab w1, w5, [x2]");
-
-        let instr = prod.ldsetalb(1, 5, 2);
-        assert_eq!(instr, "ldsetalb w1, w5, [x2]");
-
-        let instr = prod.ldsetb(1, 5, 2);
-        assert_eq!(instr, "ldsetb w1, w5, [x2]");
-
-        let instr = prod.ldsetlb(1, 5, 2);
-        assert_eq!(instr, "ldsetlb w1, w5, [x2]");
+    fn staddlh(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldaddlh(ws, WZR, xn_sp)
     }
 
+    /// [STADD - Atomic add on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STADD--STADDL--Atomic-add-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDADD--LDADDA--LDADDAL--LDADDL-?lang=en)
+    ///
+    /// Atomic add on word or doubleword in memory, without return, atomically loads a 32-bit word or 64-bit doubleword from memory, adds the value held in a register to it, and stores the result back to memory.
+    ///
+    /// This is an alias of LDADD with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STADD <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
     #[cfg(feature = "arm_feat_lse")]
-    #[test]
-    fn test_ldsmax_x() {
-        let mut prod = TestProducer::new();
-
-        let instr = prod.ldsmaxab(1, 5, 2);
-        assert_eq!(instr, "ldsmaxab w1, w5, [x2]");
-
-        let instr = prod.ldsmaxalb(1, 5, 2);
-        assert_eq!(instr, "ldsmaxalb w1, w5, [x2]");
-
-        let instr = prod.ldsmaxb(1, 5, 2);
-        assert_eq!(instr, "ldsmaxb w1, w5, [x2]");
-
-        let instr = prod.ldsmaxlb(1, 5, 2);
-        assert_eq!(instr, "ldsmaxlb w1, w5, [x2]");
+    fn stadd_32(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldadd_32(ws, WZR, xn_sp)
     }
 
+    /// [STADD - Atomic add on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STADD--STADDL--Atomic-add-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDADD--LDADDA--LDADDAL--LDADDL-?lang=en)
+    ///
+    /// Atomic add on word or doubleword in memory, without return, atomically loads a 32-bit word or 64-bit doubleword from memory, adds the value held in a register to it, and stores the result back to memory.
+    ///
+    /// This is an alias of LDADD with `XZR` as destination register.
+    ///
+    /// ```asm
+    /// STADD <Xs>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
     #[cfg(feature = "arm_feat_lse")]
-    #[test]
-    fn test_ldsmin_x() {
-        let mut prod = TestProducer::new();
-
-        let instr = prod.ldsminab(1, 5, 2);
-        assert_eq!(instr, "ldsminab w1, w5, [x2]");
-
-        let instr = prod.ldsminalb(1, 5, 2);
-        assert_eq!(instr, "ldsminalb w1, w5, [x2]");
-
-        let instr = prod.ldsminb(1, 5, 2);
-        assert_eq!(instr, "ldsminb w1, w5, [x2]");
-
-        let instr = prod.ldsminlb(1, 5, 2);
-        assert_eq!(instr, "ldsminlb w1, w5, [x2]");
+    fn stadd_64(&mut self, xs: Register, xn_sp: Register) -> T {
+        self.ldadd_64(xs, XZR, xn_sp)
     }
 
+    /// [STADDL - Atomic add on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STADD--STADDL--Atomic-add-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDADD--LDADDA--LDADDAL--LDADDL-?lang=en)
+    ///
+    /// Atomic add on word or doubleword in memory, without return, atomically loads a 32-bit word or 64-bit doubleword from memory, adds the value held in a register to it, and stores the result back to memory.
+    ///
+    /// This is an alias of LDADDL with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STADDL <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
     #[cfg(feature = "arm_feat_lse")]
-    #[test]
-    fn test_ldumax_x() {
-        let mut prod = TestProducer::new();
-
-        let instr = prod.ldumaxab(1, 5, 2);
-        assert_eq!(instr, "ldumaxab w1, w5, [x2]");
-
-        let instr = prod.ldumaxalb(1, 5, 2);
-        assert_eq!(instr, "ldumaxalb w1, w5, [x2]");
-
-        let instr = prod.ldumaxb(1, 5, 2);
-        assert_eq!(instr, "ldumaxb w1, w5, [x2]");
-
-        let instr = prod.ldumaxlb(1, 5, 2);
-        assert_eq!(instr, "ldumaxlb w1, w5, [x2]");
+    fn staddl_32(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldaddl_32(ws, WZR, xn_sp)
     }
 
+    /// [STADDL - Atomic add on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STADD--STADDL--Atomic-add-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDADD--LDADDA--LDADDAL--LDADDL-?lang=en)
+    ///
+    /// Atomic add on word or doubleword in memory, without return, atomically loads a 32-bit word or 64-bit doubleword from memory, adds the value held in a register to it, and stores the result back to memory.
+    ///
+    /// This is an alias of LDADDL with `XZR` as destination register.
+    ///
+    /// ```asm
+    /// STADDL <Xs>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
     #[cfg(feature = "arm_feat_lse")]
-    #[test]
-    fn test_ldumin_x() {
-        let mut prod = TestProducer::new();
-
-        let instr = prod.lduminab(1, 5, 2);
-        assert_eq!(instr, "lduminab w1, w5, [x2]");
-
-        let instr = prod.lduminalb(1, 5, 2);
-        assert_eq!(instr, "lduminalb w1, w5, [x2]");
-
-        let instr = prod.lduminb(1, 5, 2);
-        assert_eq!(instr, "lduminb w1, w5, [x2]");
-
-        let instr = prod.lduminlb(1, 5, 2);
-        assert_eq!(instr, "lduminlb w1, w5, [x2]");
+    fn staddl_64(&mut self, xs: Register, xn_sp: Register) -> T {
+        self.ldaddl_64(xs, XZR, xn_sp)
     }
 
+    /// [STCLRB - Atomic bit clear on byte in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STCLRB--STCLRLB--Atomic-bit-clear-on-byte-in-memory--without-return--an-alias-of-LDCLRB--LDCLRAB--LDCLRALB--LDCLRLB-?lang=en)
+    ///
+    /// Atomic bit clear on byte in memory, without return, atomically loads an 8-bit byte from memory, performs a bitwise AND with the complement of the value held in a register on it, and stores the result back to memory.
+    ///
+    /// This is an alias of LDCLRB with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STCLRB <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
     #[cfg(feature = "arm_feat_lse")]
-    #[test]
-    fn test_swp_x() {
-        let mut prod = TestProducer::new();
-
-        let instr = prod.swpab(1, 5, 2);
-        assert_eq!(instr, "swpab w1, w5, [x2]");
-
-        let instr = prod.swpalb(1, 5, 2);
-        assert_eq!(instr, "swpalb w1, w5, [x2]");
-
-        let instr = prod.swpb(3, 8, 2);
-        assert_eq!(instr, "swpb w3, w8, [x2]");
-
-        let instr = prod.swplb(1, 5, 2);
-        assert_eq!(instr, "swplb w1, w5, [x2]");
+    fn stclrb(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldclrb(ws, WZR, xn_sp)
     }
 
-    #[cfg(feature = "arm_feat_lrcpc")]
-    #[test]
-    fn test_ldaprb_x() {
-        let mut prod = TestProducer::new();
-
-        let instr = prod.ldaprb(5, 2);
-        assert_eq!(instr, "ldaprb w5, [x2]");
+    /// [STCLRLB - Atomic bit clear on byte in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STCLRB--STCLRLB--Atomic-bit-clear-on-byte-in-memory--without-return--an-alias-of-LDCLRB--LDCLRAB--LDCLRALB--LDCLRLB-?lang=en)
+    ///
+    /// Atomic bit clear on byte in memory, without return, atomically loads an 8-bit byte from memory, performs a bitwise AND with the complement of the value held in a register on it, and stores the result back to memory.
+    ///
+    /// This is an alias of LDCLRLB with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STCLRLB <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stclrlb(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldclrlb(ws, WZR, xn_sp)
     }
 
+    /// [STCLRH - Atomic bit clear on halfword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STCLRH--STCLRLH--Atomic-bit-clear-on-halfword-in-memory--without-return--an-alias-of-LDCLRH--LDCLRAH--LDCLRALH--LDCLRLH-?lang=en)
+    ///
+    /// Atomic bit clear on halfword in memory, without return, atomically loads a 16-bit halfword from memory, performs a bitwise AND with the complement of the value held in a register on it, and stores the result back to memory.
+    ///
+    /// This is an alias of LDCLRH with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STCLRH <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
     #[cfg(feature = "arm_feat_lse")]
-    #[test]
-    fn test_ldadd_xh() {
-        let mut prod = TestProducer::new();
-
-        let instr = prod.ldaddah(1, 12, 2);
-        assert_eq!(instr, "ldaddah w1, w12, [x2]");
-
-        let instr = prod.ldaddalh(1, 5, 0b11111);
-        assert_eq!(instr, "ldaddalh w1, w5, [sp]");
-
-        let instr = prod.ldaddh(3, 30, 2);
-        assert_eq!(instr, "ldaddh w3, w30, [x2]");
-
-        let instr = prod.ldaddlh(30, 5, 2);
-        assert_eq!(instr, "ldaddlh w30, w5, [x2]");
+    fn stclrh(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldclrh(ws, WZR, xn_sp)
     }
 
+    /// [STCLRLH - Atomic bit clear on halfword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STCLRH--STCLRLH--Atomic-bit-clear-on-halfword-in-memory--without-return--an-alias-of-LDCLRH--LDCLRAH--LDCLRALH--LDCLRLH-?lang=en)
+    ///
+    /// Atomic bit clear on halfword in memory, without return, atomically loads a 16-bit halfword from memory, performs a bitwise AND with the complement of the value held in a register on it, and stores the result back to memory.
+    ///
+    /// This is an alias of LDCLRLH with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STCLRLH <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
     #[cfg(feature = "arm_feat_lse")]
-    #[test]
-    fn test_ldclr_xh() {
-        let mut prod = TestProducer::new();
-
-        let instr = prod.ldclrah(1, 12, 2);
-        assert_eq!(instr, "ldclrah w1, w12, [x2]");
-
-        let instr = prod.ldclralh(1, 5, 0b11111);
-        assert_eq!(instr, "ldclralh w1, w5, [sp]");
-
-        let instr = prod.ldclrh(3, 30, 2);
-        assert_eq!(instr, "ldclrh w3, w30, [x2]");
-
-        let instr = prod.ldclrlh(30, 5, 2);
-        assert_eq!(instr, "ldclrlh w30, w5, [x2]");
+    fn stclrlh(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldclrlh(ws, WZR, xn_sp)
     }
 
-    #[cfg(feature = "arm_feat_lse")]
+    /// [STCLR - Atomic bit clear on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STCLR--STCLRL--Atomic-bit-clear-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDCLR--LDCLRA--LDCLRAL--LDCLRL-?lang=en)
+    ///
+    /// Atomic bit clear on word or doubleword in memory, without return, atomically loads a 32-bit word or 64-bit doubleword from memory, performs a bitwise AND with the complement of the value held in a register on it, and stores the result back to memory.
+    ///
+    /// This is an alias of LDCLR with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STCLR <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stclr_32(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldclr_32(ws, WZR, xn_sp)
+    }
+
+    /// [STCLR - Atomic bit clear on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STCLR--STCLRL--Atomic-bit-clear-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDCLR--LDCLRA--LDCLRAL--LDCLRL-?lang=en)
+    ///
+    /// Atomic bit clear on word or doubleword in memory, without return, atomically loads a 32-bit word or 64-bit doubleword from memory, performs a bitwise AND with the complement of the value held in a register on it, and stores the result back to memory.
+    ///
+    /// This is an alias of LDCLR with `XZR` as destination register.
+    ///
+    /// ```asm
+    /// STCLR <Xs>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stclr_64(&mut self, xs: Register, xn_sp: Register) -> T {
+        self.ldclr_64(xs, XZR, xn_sp)
+    }
+
+    /// [STCLRL - Atomic bit clear on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STCLR--STCLRL--Atomic-bit-clear-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDCLR--LDCLRA--LDCLRAL--LDCLRL-?lang=en)
+    ///
+    /// Atomic bit clear on word or doubleword in memory, without return, atomically loads a 32-bit word or 64-bit doubleword from memory, performs a bitwise AND with the complement of the value held in a register on it, and stores the result back to memory.
+    ///
+    /// This is an alias of LDCLRL with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STCLRL <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stclrl_32(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldclrl_32(ws, WZR, xn_sp)
+    }
+
+    /// [STCLRL - Atomic bit clear on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STCLR--STCLRL--Atomic-bit-clear-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDCLR--LDCLRA--LDCLRAL--LDCLRL-?lang=en)
+    ///
+    /// Atomic bit clear on word or doubleword in memory, without return, atomically loads a 32-bit word or 64-bit doubleword from memory, performs a bitwise AND with the complement of the value held in a register on it, and stores the result back to memory.
+    ///
+    /// This is an alias of LDCLRL with `XZR` as destination register.
+    ///
+    /// ```asm
+    /// STCLRL <Xs>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stclrl_64(&mut self, xs: Register, xn_sp: Register) -> T {
+        self.ldclrl_64(xs, XZR, xn_sp)
+    }
+
+    /// [STEORB - Atomic exclusive OR on byte in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STEORB--STEORLB--Atomic-exclusive-OR-on-byte-in-memory--without-return--an-alias-of-LDEORB--LDEORAB--LDEORALB--LDEORLB-?lang=en)
+    ///
+    /// Atomic exclusive OR on byte in memory, without return, atomically loads an 8-bit byte from memory, performs an exclusive OR with the value held in a register on it, and stores the result back to memory.
+    ///
+    /// This is an alias of LDEORB with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STEORB <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn steorb(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldeorb(ws, WZR, xn_sp)
+    }
+
+    /// [STEORLB - Atomic exclusive OR on byte in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STEORB--STEORLB--Atomic-exclusive-OR-on-byte-in-memory--without-return--an-alias-of-LDEORB--LDEORAB--LDEORALB--LDEORLB-?lang=en)
+    ///
+    /// Atomic exclusive OR on byte in memory, without return, atomically loads an 8-bit byte from memory, performs an exclusive OR with the value held in a register on it, and stores the result back to memory.
+    ///
+    /// This is an alias of LDEORLB with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STEORLB <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn steorlb(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldeorlb(ws, WZR, xn_sp)
+    }
+
+    /// [STEORH - Atomic exclusive OR on halfword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STEORH--STEORLH--Atomic-exclusive-OR-on-halfword-in-memory--without-return--an-alias-of-LDEORH--LDEORAH--LDEORALH--LDEORLH-?lang=en)
+    ///
+    /// Atomic exclusive OR on halfword in memory, without return, atomically loads a 16-bit halfword from memory, performs an exclusive OR with the value held in a register on it, and stores the result back to memory.
+    ///
+    /// This is an alias of LDEORH with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STEORH <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn steorh(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldeorh(ws, WZR, xn_sp)
+    }
+
+    /// [STEORLH - Atomic exclusive OR on halfword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STEORH--STEORLH--Atomic-exclusive-OR-on-halfword-in-memory--without-return--an-alias-of-LDEORH--LDEORAH--LDEORALH--LDEORLH-?lang=en)
+    ///
+    /// Atomic exclusive OR on halfword in memory, without return, atomically loads a 16-bit halfword from memory, performs an exclusive OR with the value held in a register on it, and stores the result back to memory.
+    ///
+    /// This is an alias of LDEORLH with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STEORLH <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn steorlh(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldeorlh(ws, WZR, xn_sp)
+    }
+
+    /// [STEOR - Atomic exclusive OR on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STEOR--STEORL--Atomic-exclusive-OR-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDEOR--LDEORA--LDEORAL--LDEORL-?lang=en)
+    ///
+    /// Atomic exclusive OR on word or doubleword in memory, without return, atomically loads a 32-bit word or 64-bit doubleword from memory, performs an exclusive OR with the value held in a register on it, and stores the result back to memory.
+    ///
+    /// This is an alias of LDEOR with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STEOR <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn steor_32(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldeor_32(ws, WZR, xn_sp)
+    }
+
+    /// [STEOR - Atomic exclusive OR on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STEOR--STEORL--Atomic-exclusive-OR-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDEOR--LDEORA--LDEORAL--LDEORL-?lang=en)
+    ///
+    /// Atomic exclusive OR on word or doubleword in memory, without return, atomically loads a 32-bit word or 64-bit doubleword from memory, performs an exclusive OR with the value held in a register on it, and stores the result back to memory.
+    ///
+    /// This is an alias of LDEOR with `XZR` as destination register.
+    ///
+    /// ```asm
+    /// STEOR <Xs>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn steor_64(&mut self, xs: Register, xn_sp: Register) -> T {
+        self.ldeor_64(xs, XZR, xn_sp)
+    }
+
+    /// [STEORL - Atomic exclusive OR on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STEOR--STEORL--Atomic-exclusive-OR-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDEOR--LDEORA--LDEORAL--LDEORL-?lang=en)
+    ///
+    /// Atomic exclusive OR on word or doubleword in memory, without return, atomically loads a 32-bit word or 64-bit doubleword from memory, performs an exclusive OR with the value held in a register on it, and stores the result back to memory.
+    ///
+    /// This is an alias of LDEORL with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STEORL <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn steorl_32(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldeorl_32(ws, WZR, xn_sp)
+    }
+
+    /// [STEORL - Atomic exclusive OR on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STEOR--STEORL--Atomic-exclusive-OR-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDEOR--LDEORA--LDEORAL--LDEORL-?lang=en)
+    ///
+    /// Atomic exclusive OR on word or doubleword in memory, without return, atomically loads a 32-bit word or 64-bit doubleword from memory, performs an exclusive OR with the value held in a register on it, and stores the result back to memory.
+    ///
+    /// This is an alias of LDEORL with `XZR` as destination register.
+    ///
+    /// ```asm
+    /// STEORL <Xs>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn steorl_64(&mut self, xs: Register, xn_sp: Register) -> T {
+        self.ldeorl_64(xs, XZR, xn_sp)
+    }
+
+    /// [STSETB - Atomic bit set on byte in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSETB--STSETLB--Atomic-bit-set-on-byte-in-memory--without-return--an-alias-of-LDSETB--LDSETAB--LDSETALB--LDSETLB-?lang=en)
+    ///
+    /// Atomic bit set on byte in memory, without return, atomically loads an 8-bit byte from memory, performs a bitwise OR with the value held in a register on it, and stores the result back to memory.
+    ///
+    /// This is an alias of LDSETB with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STSETB <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stsetb(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldsetb(ws, WZR, xn_sp)
+    }
+
+    /// [STSETLB - Atomic bit set on byte in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSETB--STSETLB--Atomic-bit-set-on-byte-in-memory--without-return--an-alias-of-LDSETB--LDSETAB--LDSETALB--LDSETLB-?lang=en)
+    ///
+    /// Atomic bit set on byte in memory, without return, atomically loads an 8-bit byte from memory, performs a bitwise OR with the value held in a register on it, and stores the result back to memory.
+    ///
+    /// This is an alias of LDSETLB with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STSETLB <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stsetlb(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldsetlb(ws, WZR, xn_sp)
+    }
+
+    /// [STSETH - Atomic bit set on halfword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSETH--STSETLH--Atomic-bit-set-on-halfword-in-memory--without-return--an-alias-of-LDSETH--LDSETAH--LDSETALH--LDSETLH-?lang=en)
+    ///
+    /// Atomic bit set on halfword in memory, without return, atomically loads a 16-bit halfword from memory, performs a bitwise OR with the value held in a register on it, and stores the result back to memory.
+    ///
+    /// This is an alias of LDSETH with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STSETH <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stseth(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldseth(ws, WZR, xn_sp)
+    }
+
+    /// [STSETLH - Atomic bit set on halfword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSETH--STSETLH--Atomic-bit-set-on-halfword-in-memory--without-return--an-alias-of-LDSETH--LDSETAH--LDSETALH--LDSETLH-?lang=en)
+    ///
+    /// Atomic bit set on halfword in memory, without return, atomically loads a 16-bit halfword from memory, performs a bitwise OR with the value held in a register on it, and stores the result back to memory.
+    ///
+    /// This is an alias of LDSETLH with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STSETLH <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stsetlh(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldsetlh(ws, WZR, xn_sp)
+    }
+
+    /// [STSET - Atomic bit set on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSET--STSETL--Atomic-bit-set-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDSET--LDSETA--LDSETAL--LDSETL-?lang=en)
+    ///
+    /// Atomic bit set on word or doubleword in memory, without return, atomically loads a 32-bit word or 64-bit doubleword from memory, performs a bitwise OR with the value held in a register on it, and stores the result back to memory.
+    ///
+    /// This is an alias of LDSET with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STSET <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stset_32(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldset_32(ws, WZR, xn_sp)
+    }
+
+    /// [STSET - Atomic bit set on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSET--STSETL--Atomic-bit-set-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDSET--LDSETA--LDSETAL--LDSETL-?lang=en)
+    ///
+    /// Atomic bit set on word or doubleword in memory, without return, atomically loads a 32-bit word or 64-bit doubleword from memory, performs a bitwise OR with the value held in a register on it, and stores the result back to memory.
+    ///
+    /// This is an alias of LDSET with `XZR` as destination register.
+    ///
+    /// ```asm
+    /// STSET <Xs>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stset_64(&mut self, xs: Register, xn_sp: Register) -> T {
+        self.ldset_64(xs, XZR, xn_sp)
+    }
+
+    /// [STSETL - Atomic bit set on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSET--STSETL--Atomic-bit-set-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDSET--LDSETA--LDSETAL--LDSETL-?lang=en)
+    ///
+    /// Atomic bit set on word or doubleword in memory, without return, atomically loads a 32-bit word or 64-bit doubleword from memory, performs a bitwise OR with the value held in a register on it, and stores the result back to memory.
+    ///
+    /// This is an alias of LDSETL with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STSETL <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stsetl_32(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldsetl_32(ws, WZR, xn_sp)
+    }
+
+    /// [STSETL - Atomic bit set on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSET--STSETL--Atomic-bit-set-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDSET--LDSETA--LDSETAL--LDSETL-?lang=en)
+    ///
+    /// Atomic bit set on word or doubleword in memory, without return, atomically loads a 32-bit word or 64-bit doubleword from memory, performs a bitwise OR with the value held in a register on it, and stores the result back to memory.
+    ///
+    /// This is an alias of LDSETL with `XZR` as destination register.
+    ///
+    /// ```asm
+    /// STSETL <Xs>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stsetl_64(&mut self, xs: Register, xn_sp: Register) -> T {
+        self.ldsetl_64(xs, XZR, xn_sp)
+    }
+
+    /// [STSMAXB - Atomic signed maximum on byte in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSMAXB--STSMAXLB--Atomic-signed-maximum-on-byte-in-memory--without-return--an-alias-of-LDSMAXB--LDSMAXAB--LDSMAXALB--LDSMAXLB-?lang=en)
+    ///
+    /// Atomic signed maximum on byte in memory, without return, atomically loads an 8-bit byte from memory, compares it against the value held in a register, and stores the larger value back to memory, treating the values as signed numbers.
+    ///
+    /// This is an alias of LDSMAXB with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STSMAXB <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stsmaxb(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldsmaxb(ws, WZR, xn_sp)
+    }
+
+    /// [STSMAXLB - Atomic signed maximum on byte in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSMAXB--STSMAXLB--Atomic-signed-maximum-on-byte-in-memory--without-return--an-alias-of-LDSMAXB--LDSMAXAB--LDSMAXALB--LDSMAXLB-?lang=en)
+    ///
+    /// Atomic signed maximum on byte in memory, without return, atomically loads an 8-bit byte from memory, compares it against the value held in a register, and stores the larger value back to memory, treating the values as signed numbers.
+    ///
+    /// This is an alias of LDSMAXLB with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STSMAXLB <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stsmaxlb(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldsmaxlb(ws, WZR, xn_sp)
+    }
+
+    /// [STSMAXH - Atomic signed maximum on halfword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSMAXH--STSMAXLH--Atomic-signed-maximum-on-halfword-in-memory--without-return--an-alias-of-LDSMAXH--LDSMAXAH--LDSMAXALH--LDSMAXLH-?lang=en)
+    ///
+    /// Atomic signed maximum on halfword in memory, without return, atomically loads a 16-bit halfword from memory, compares it against the value held in a register, and stores the larger value back to memory, treating the values as signed numbers.
+    ///
+    /// This is an alias of LDSMAXH with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STSMAXH <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stsmaxh(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldsmaxh(ws, WZR, xn_sp)
+    }
+
+    /// [STSMAXLH - Atomic signed maximum on halfword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSMAXH--STSMAXLH--Atomic-signed-maximum-on-halfword-in-memory--without-return--an-alias-of-LDSMAXH--LDSMAXAH--LDSMAXALH--LDSMAXLH-?lang=en)
+    ///
+    /// Atomic signed maximum on halfword in memory, without return, atomically loads a 16-bit halfword from memory, compares it against the value held in a register, and stores the larger value back to memory, treating the values as signed numbers.
+    ///
+    /// This is an alias of LDSMAXLH with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STSMAXLH <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stsmaxlh(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldsmaxlh(ws, WZR, xn_sp)
+    }
+
+    /// [STSMAX - Atomic signed maximum on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSMAX--STSMAXL--Atomic-signed-maximum-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDSMAX--LDSMAXA--LDSMAXAL--LDSMAXL-?lang=en)
+    ///
+    /// Atomic signed maximum on word or doubleword in memory, without return, atomically loads a 32-bit word or 64-bit doubleword from memory, compares it against the value held in a register, and stores the larger value back to memory, treating the values as signed numbers.
+    ///
+    /// This is an alias of LDSMAX with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STSMAX <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stsmax_32(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldsmax_32(ws, WZR, xn_sp)
+    }
+
+    /// [STSMAX - Atomic signed maximum on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSMAX--STSMAXL--Atomic-signed-maximum-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDSMAX--LDSMAXA--LDSMAXAL--LDSMAXL-?lang=en)
+    ///
+    /// Atomic signed maximum on word or doubleword in memory, without return, atomically loads a 32-bit word or 64-bit doubleword from memory, compares it against the value held in a register, and stores the larger value back to memory, treating the values as signed numbers.
+    ///
+    /// This is an alias of LDSMAX with `XZR` as destination register.
+    ///
+    /// ```asm
+    /// STSMAX <Xs>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stsmax_64(&mut self, xs: Register, xn_sp: Register) -> T {
+        self.ldsmax_64(xs, XZR, xn_sp)
+    }
+
+    /// [STSMAXL - Atomic signed maximum on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSMAX--STSMAXL--Atomic-signed-maximum-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDSMAX--LDSMAXA--LDSMAXAL--LDSMAXL-?lang=en)
+    ///
+    /// Atomic signed maximum on word or doubleword in memory, without return, atomically loads a 32-bit word or 64-bit doubleword from memory, compares it against the value held in a register, and stores the larger value back to memory, treating the values as signed numbers.
+    ///
+    /// This is an alias of LDSMAXL with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STSMAXL <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stsmaxl_32(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldsmaxl_32(ws, WZR, xn_sp)
+    }
+
+    /// [STSMAXL - Atomic signed maximum on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSMAX--STSMAXL--Atomic-signed-maximum-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDSMAX--LDSMAXA--LDSMAXAL--LDSMAXL-?lang=en)
+    ///
+    /// Atomic signed maximum on word or doubleword in memory, without return, atomically loads a 32-bit word or 64-bit doubleword from memory, compares it against the value held in a register, and stores the larger value back to memory, treating the values as signed numbers.
+    ///
+    /// This is an alias of LDSMAXL with `XZR` as destination register.
+    ///
+    /// ```asm
+    /// STSMAXL <Xs>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stsmaxl_64(&mut self, xs: Register, xn_sp: Register) -> T {
+        self.ldsmaxl_64(xs, XZR, xn_sp)
+    }
+
+    /// [STSMINB - Atomic signed minimum on byte in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSMINB--STSMINLB--Atomic-signed-minimum-on-byte-in-memory--without-return--an-alias-of-LDSMINB--LDSMINAB--LDSMINALB--LDSMINLB-?lang=en)
+    ///
+    /// Atomic signed minimum on byte in memory, without return, atomically loads an 8-bit byte from memory, compares it against the value held in a register, and stores the smaller value back to memory, treating the values as signed numbers.
+    ///
+    /// This is an alias of LDSMINB with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STSMINB <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stsminb(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldsminb(ws, WZR, xn_sp)
+    }
+
+    /// [STSMINLB - Atomic signed minimum on byte in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSMINB--STSMINLB--Atomic-signed-minimum-on-byte-in-memory--without-return--an-alias-of-LDSMINB--LDSMINAB--LDSMINALB--LDSMINLB-?lang=en)
+    ///
+    /// Atomic signed minimum on byte in memory, without return, atomically loads an 8-bit byte from memory, compares it against the value held in a register, and stores the smaller value back to memory, treating the values as signed numbers.
+    ///
+    /// This is an alias of LDSMINLB with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STSMINLB <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stsminlb(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldsminlb(ws, WZR, xn_sp)
+    }
+
+    /// [STSMINH - Atomic signed minimum on halfword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSMINH--STSMINLH--Atomic-signed-minimum-on-halfword-in-memory--without-return--an-alias-of-LDSMINH--LDSMINAH--LDSMINALH--LDSMINLH-?lang=en)
+    ///
+    /// Atomic signed minimum on halfword in memory, without return, atomically loads a 16-bit halfword from memory, compares it against the value held in a register, and stores the smaller value back to memory, treating the values as signed numbers.
+    ///
+    /// This is an alias of LDSMINH with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STSMINH <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stsminh(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldsminh(ws, WZR, xn_sp)
+    }
+
+    /// [STSMINLH - Atomic signed minimum on halfword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSMINH--STSMINLH--Atomic-signed-minimum-on-halfword-in-memory--without-return--an-alias-of-LDSMINH--LDSMINAH--LDSMINALH--LDSMINLH-?lang=en)
+    ///
+    /// Atomic signed minimum on halfword in memory, without return, atomically loads a 16-bit halfword from memory, compares it against the value held in a register, and stores the smaller value back to memory, treating the values as signed numbers.
+    ///
+    /// This is an alias of LDSMINLH with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STSMINLH <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stsminlh(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldsminlh(ws, WZR, xn_sp)
+    }
+
+    /// [STSMIN - Atomic signed minimum on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSMIN--STSMINL--Atomic-signed-minimum-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDSMIN--LDSMINA--LDSMINAL--LDSMINL-?lang=en)
+    ///
+    /// Atomic signed minimum on word or doubleword in memory, without return, atomically loads a 32-bit word or 64-bit doubleword from memory, compares it against the value held in a register, and stores the smaller value back to memory, treating the values as signed numbers.
+    ///
+    /// This is an alias of LDSMIN with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STSMIN <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stsmin_32(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldsmin_32(ws, WZR, xn_sp)
+    }
+
+    /// [STSMIN - Atomic signed minimum on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSMIN--STSMINL--Atomic-signed-minimum-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDSMIN--LDSMINA--LDSMINAL--LDSMINL-?lang=en)
+    ///
+    /// Atomic signed minimum on word or doubleword in memory, without return, atomically loads a 32-bit word or 64-bit doubleword from memory, compares it against the value held in a register, and stores the smaller value back to memory, treating the values as signed numbers.
+    ///
+    /// This is an alias of LDSMIN with `XZR` as destination register.
+    ///
+    /// ```asm
+    /// STSMIN <Xs>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stsmin_64(&mut self, xs: Register, xn_sp: Register) -> T {
+        self.ldsmin_64(xs, XZR, xn_sp)
+    }
+
+    /// [STSMINL - Atomic signed minimum on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSMIN--STSMINL--Atomic-signed-minimum-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDSMIN--LDSMINA--LDSMINAL--LDSMINL-?lang=en)
+    ///
+    /// Atomic signed minimum on word or doubleword in memory, without return, atomically loads a 32-bit word or 64-bit doubleword from memory, compares it against the value held in a register, and stores the smaller value back to memory, treating the values as signed numbers.
+    ///
+    /// This is an alias of LDSMINL with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STSMINL <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stsminl_32(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldsminl_32(ws, WZR, xn_sp)
+    }
+
+    /// [STSMINL - Atomic signed minimum on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STSMIN--STSMINL--Atomic-signed-minimum-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDSMIN--LDSMINA--LDSMINAL--LDSMINL-?lang=en)
+    ///
+    /// Atomic signed minimum on word or doubleword in memory, without return, atomically loads a 32-bit word or 64-bit doubleword from memory, compares it against the value held in a register, and stores the smaller value back to memory, treating the values as signed numbers.
+    ///
+    /// This is an alias of LDSMINL with `XZR` as destination register.
+    ///
+    /// ```asm
+    /// STSMINL <Xs>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stsminl_64(&mut self, xs: Register, xn_sp: Register) -> T {
+        self.ldsminl_64(xs, XZR, xn_sp)
+    }
+
+    /// [STUMAXB - Atomic unsigned maximum on byte in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STUMAXB--STUMAXLB--Atomic-unsigned-maximum-on-byte-in-memory--without-return--an-alias-of-LDUMAXB--LDUMAXAB--LDUMAXALB--LDUMAXLB-?lang=en)
+    ///
+    /// Atomic unsigned maximum on byte in memory, without return, atomically loads an 8-bit byte from memory, compares it against the value held in a register, and stores the larger value back to memory, treating the values as unsigned numbers.
+    ///
+    /// This is an alias of LDUMAXB with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STUMAXB <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stumaxb(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldumaxb(ws, WZR, xn_sp)
+    }
+
+    /// [STUMAXLB - Atomic unsigned maximum on byte in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STUMAXB--STUMAXLB--Atomic-unsigned-maximum-on-byte-in-memory--without-return--an-alias-of-LDUMAXB--LDUMAXAB--LDUMAXALB--LDUMAXLB-?lang=en)
+    ///
+    /// Atomic unsigned maximum on byte in memory, without return, atomically loads an 8-bit byte from memory, compares it against the value held in a register, and stores the larger value back to memory, treating the values as unsigned numbers.
+    ///
+    /// This is an alias of LDUMAXLB with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STUMAXLB <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stumaxlb(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldumaxlb(ws, WZR, xn_sp)
+    }
+
+    /// [STUMAXH - Atomic unsigned maximum on halfword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STUMAXH--STUMAXLH--Atomic-unsigned-maximum-on-halfword-in-memory--without-return--an-alias-of-LDUMAXH--LDUMAXAH--LDUMAXALH--LDUMAXLH-?lang=en)
+    ///
+    /// Atomic unsigned maximum on halfword in memory, without return, atomically loads a 16-bit halfword from memory, compares it against the value held in a register, and stores the larger value back to memory, treating the values as unsigned numbers.
+    ///
+    /// This is an alias of LDUMAXH with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STUMAXH <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stumaxh(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldumaxh(ws, WZR, xn_sp)
+    }
+
+    /// [STUMAXLH - Atomic unsigned maximum on halfword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STUMAXH--STUMAXLH--Atomic-unsigned-maximum-on-halfword-in-memory--without-return--an-alias-of-LDUMAXH--LDUMAXAH--LDUMAXALH--LDUMAXLH-?lang=en)
+    ///
+    /// Atomic unsigned maximum on halfword in memory, without return, atomically loads a 16-bit halfword from memory, compares it against the value held in a register, and stores the larger value back to memory, treating the values as unsigned numbers.
+    ///
+    /// This is an alias of LDUMAXLH with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STUMAXLH <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stumaxlh(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldumaxlh(ws, WZR, xn_sp)
+    }
+
+    /// [STUMAX - Atomic unsigned maximum on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STUMAX--STUMAXL--Atomic-unsigned-maximum-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDUMAX--LDUMAXA--LDUMAXAL--LDUMAXL-?lang=en)
+    ///
+    /// Atomic unsigned maximum on word or doubleword in memory, without return, atomically loads a 32-bit word or 64-bit doubleword from memory, compares it against the value held in a register, and stores the larger value back to memory, treating the values as unsigned numbers.
+    ///
+    /// This is an alias of LDUMAX with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STUMAX <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stumax_32(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldumax_32(ws, WZR, xn_sp)
+    }
+
+    /// [STUMAX - Atomic unsigned maximum on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STUMAX--STUMAXL--Atomic-unsigned-maximum-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDUMAX--LDUMAXA--LDUMAXAL--LDUMAXL-?lang=en)
+    ///
+    /// Atomic unsigned maximum on word or doubleword in memory, without return, atomically loads a 32-bit word or 64-bit doubleword from memory, compares it against the value held in a register, and stores the larger value back to memory, treating the values as unsigned numbers.
+    ///
+    /// This is an alias of LDUMAX with `XZR` as destination register.
+    ///
+    /// ```asm
+    /// STUMAX <Xs>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stumax_64(&mut self, xs: Register, xn_sp: Register) -> T {
+        self.ldumax_64(xs, XZR, xn_sp)
+    }
+
+    /// [STUMAXL - Atomic unsigned maximum on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STUMAX--STUMAXL--Atomic-unsigned-maximum-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDUMAX--LDUMAXA--LDUMAXAL--LDUMAXL-?lang=en)
+    ///
+    /// Atomic unsigned maximum on word or doubleword in memory, without return, atomically loads a 32-bit word or 64-bit doubleword from memory, compares it against the value held in a register, and stores the larger value back to memory, treating the values as unsigned numbers.
+    ///
+    /// This is an alias of LDUMAXL with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STUMAXL <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stumaxl_32(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldumaxl_32(ws, WZR, xn_sp)
+    }
+
+    /// [STUMAXL - Atomic unsigned maximum on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STUMAX--STUMAXL--Atomic-unsigned-maximum-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDUMAX--LDUMAXA--LDUMAXAL--LDUMAXL-?lang=en)
+    ///
+    /// Atomic unsigned maximum on word or doubleword in memory, without return, atomically loads a 32-bit word or 64-bit doubleword from memory, compares it against the value held in a register, and stores the larger value back to memory, treating the values as unsigned numbers.
+    ///
+    /// This is an alias of LDUMAXL with `XZR` as destination register.
+    ///
+    /// ```asm
+    /// STUMAXL <Xs>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stumaxl_64(&mut self, xs: Register, xn_sp: Register) -> T {
+        self.ldumaxl_64(xs, XZR, xn_sp)
+    }
+
+    /// [STUMINB - Atomic unsigned minimum on byte in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STUMINB--STUMINLB--Atomic-unsigned-minimum-on-byte-in-memory--without-return--an-alias-of-LDUMINB--LDUMINAB--LDUMINALB--LDUMINLB-?lang=en)
+    ///
+    /// Atomic unsigned minimum on byte in memory, without return, atomically loads an 8-bit byte from memory, compares it against the value held in a register, and stores the smaller value back to memory, treating the values as unsigned numbers.
+    ///
+    /// This is an alias of LDUMINB with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STUMINB <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stuminb(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.lduminb(ws, WZR, xn_sp)
+    }
+
+    /// [STUMINLB - Atomic unsigned minimum on byte in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STUMINB--STUMINLB--Atomic-unsigned-minimum-on-byte-in-memory--without-return--an-alias-of-LDUMINB--LDUMINAB--LDUMINALB--LDUMINLB-?lang=en)
+    ///
+    /// Atomic unsigned minimum on byte in memory, without return, atomically loads an 8-bit byte from memory, compares it against the value held in a register, and stores the smaller value back to memory, treating the values as unsigned numbers.
+    ///
+    /// This is an alias of LDUMINLB with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STUMINLB <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stuminlb(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.lduminlb(ws, WZR, xn_sp)
+    }
+
+    /// [STUMINH - Atomic unsigned minimum on halfword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STUMINH--STUMINLH--Atomic-unsigned-minimum-on-halfword-in-memory--without-return--an-alias-of-LDUMINH--LDUMINAH--LDUMINALH--LDUMINLH-?lang=en)
+    ///
+    /// Atomic unsigned minimum on halfword in memory, without return, atomically loads a 16-bit halfword from memory, compares it against the value held in a register, and stores the smaller value back to memory, treating the values as unsigned numbers.
+    ///
+    /// This is an alias of LDUMINH with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STUMINH <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stuminh(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.lduminh(ws, WZR, xn_sp)
+    }
+
+    /// [STUMINLH - Atomic unsigned minimum on halfword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STUMINH--STUMINLH--Atomic-unsigned-minimum-on-halfword-in-memory--without-return--an-alias-of-LDUMINH--LDUMINAH--LDUMINALH--LDUMINLH-?lang=en)
+    ///
+    /// Atomic unsigned minimum on halfword in memory, without return, atomically loads a 16-bit halfword from memory, compares it against the value held in a register, and stores the smaller value back to memory, treating the values as unsigned numbers.
+    ///
+    /// This is an alias of LDUMINLH with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STUMINLH <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stuminlh(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.lduminlh(ws, WZR, xn_sp)
+    }
+
+    /// [STUMIN - Atomic unsigned minimum on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STUMIN--STUMINL--Atomic-unsigned-minimum-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDUMIN--LDUMINA--LDUMINAL--LDUMINL-?lang=en)
+    ///
+    /// Atomic unsigned minimum on word or doubleword in memory, without return, atomically loads a 32-bit word or 64-bit doubleword from memory, compares it against the value held in a register, and stores the smaller value back to memory, treating the values as unsigned numbers.
+    ///
+    /// This is an alias of LDUMIN with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STUMIN <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stumin_32(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.ldumin_32(ws, WZR, xn_sp)
+    }
+
+    /// [STUMIN - Atomic unsigned minimum on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STUMIN--STUMINL--Atomic-unsigned-minimum-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDUMIN--LDUMINA--LDUMINAL--LDUMINL-?lang=en)
+    ///
+    /// Atomic unsigned minimum on word or doubleword in memory, without return, atomically loads a 32-bit word or 64-bit doubleword from memory, compares it against the value held in a register, and stores the smaller value back to memory, treating the values as unsigned numbers.
+    ///
+    /// This is an alias of LDUMIN with `XZR` as destination register.
+    ///
+    /// ```asm
+    /// STUMIN <Xs>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stumin_64(&mut self, xs: Register, xn_sp: Register) -> T {
+        self.ldumin_64(xs, XZR, xn_sp)
+    }
+
+    /// [STUMINL - Atomic unsigned minimum on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STUMIN--STUMINL--Atomic-unsigned-minimum-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDUMIN--LDUMINA--LDUMINAL--LDUMINL-?lang=en)
+    ///
+    /// Atomic unsigned minimum on word or doubleword in memory, without return, atomically loads a 32-bit word or 64-bit doubleword from memory, compares it against the value held in a register, and stores the smaller value back to memory, treating the values as unsigned numbers.
+    ///
+    /// This is an alias of LDUMINL with `WZR` as destination register.
+    ///
+    /// ```asm
+    /// STUMINL <Ws>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stuminl_32(&mut self, ws: Register, xn_sp: Register) -> T {
+        self.lduminl_32(ws, WZR, xn_sp)
+    }
+
+    /// [STUMINL - Atomic unsigned minimum on word or doubleword in memory, without return](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STUMIN--STUMINL--Atomic-unsigned-minimum-on-word-or-doubleword-in-memory--without-return--an-alias-of-LDUMIN--LDUMINA--LDUMINAL--LDUMINL-?lang=en)
+    ///
+    /// Atomic unsigned minimum on word or doubleword in memory, without return, atomically loads a 32-bit word or 64-bit doubleword from memory, compares it against the value held in a register, and stores the smaller value back to memory, treating the values as unsigned numbers.
+    ///
+    /// This is an alias of LDUMINL with `XZR` as destination register.
+    ///
+    /// ```asm
+    /// STUMINL <Xs>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn stuminl_64(&mut self, xs: Register, xn_sp: Register) -> T {
+        self.lduminl_64(xs, XZR, xn_sp)
+    }
+
+    /// Atomic read-modify-write on memory, with the instruction variant selected by a memory ordering.
+    ///
+    /// Emits the LD\<op\> or SWP instruction for `op` and `size`, where `ordering` selects the
+    /// variant: `Relaxed` the plain one, `Acquire` the A, `Release` the L, and `AcqRel` as well as
+    /// `SeqCst` the AL variant.
+    /// `rs` is the register operand of the operation, `rt` receives the value initially loaded from
+    /// memory and `rn` is the base register (`Xn|SP`). The ST\<op\> aliases are emitted by passing
+    /// the zero register as `rt`.
+    ///
+    /// ```asm
+    /// LD<op>{A}{L}<size> <Rs>, <Rt>, [<Xn|SP>]
+    /// SWP{A}{L}<size> <Rs>, <Rt>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse")]
+    fn atomic_rmw(
+        &mut self,
+        op: AtomicRmwOp,
+        size: AtomicSize,
+        ordering: Ordering,
+        rs: Register,
+        rt: Register,
+        rn: Register,
+    ) -> T {
+        let (a, r) = encode_ordering(ordering);
+        let (o3, opc) = op.encode();
+        emit_atomic_mem_op(self, size.encode(), 0, a, r, rs, o3, opc, rn, rt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::test_producer::TestProducer;
+
+    use super::*;
+
+    #[cfg(feature = "arm_feat_lse")]
+    #[test]
+    fn test_ldadd_x() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.ldaddab(1, 5, 2);
+        assert_eq!(instr, "ldaddab w1, w5, [x2]");
+
+        let instr = prod.ldaddalb(1, 5, 2);
+        assert_eq!(instr, "ldaddalb w1, w5, [x2]");
+
+        let instr = prod.ldaddb(1, 5, 2);
+        assert_eq!(instr, "ldaddb w1, w5, [x2]");
+
+        let instr = prod.ldaddlb(1, 5, 2);
+        assert_eq!(instr, "ldaddlb w1, w5, [x2]");
+    }
+
+    #[cfg(feature = "arm_feat_lse")]
+    #[test]
+    fn test_ldclr_x() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.ldclrab(1, 5, 2);
+        assert_eq!(instr, "ldclrab w1, w5, [x2]");
+
+        let instr = prod.ldclralb(1, 5, 2);
+        assert_eq!(instr, "ldclralb w1, w5, [x2]");
+
+        let instr = prod.ldclrb(1, 5, 2);
+        assert_eq!(instr, "ldclrb w1, w5, [x2]");
+
+        let instr = prod.ldclrlb(1, 5, 2);
+        assert_eq!(instr, "ldclrlb w1, w5, [x2]");
+    }
+
+    #[cfg(feature = "arm_feat_lse")]
+    #[test]
+    fn test_ldeor_x() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.ldeorab(1, 5, 2);
+        assert_eq!(instr, "ldeorab w1, w5, [x2]");
+
+        let instr = prod.ldeoralb(1, 5, 2);
+        assert_eq!(instr, "ldeoralb w1, w5, [x2]");
+
+        let instr = prod.ldeorb(1, 5, 2);
+        assert_eq!(instr, "ldeorb w1, w5, [x2]");
+
+        let instr = prod.ldeorlb(1, 5, 2);
+        assert_eq!(instr, "ldeorlb w1, w5, [x2]");
+    }
+
+    #[cfg(feature = "arm_feat_lse")]
+    #[test]
+    fn test_ldset_x() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.ldsetab(1, 5, 2);
+        assert_eq!(instr, "ldsetab w1, w5, [x2]");
+
+        let instr = prod.ldsetalb(1, 5, 2);
+        assert_eq!(instr, "ldsetalb w1, w5, [x2]");
+
+        let instr = prod.ldsetb(1, 5, 2);
+        assert_eq!(instr, "ldsetb w1, w5, [x2]");
+
+        let instr = prod.ldsetlb(1, 5, 2);
+        assert_eq!(instr, "ldsetlb w1, w5, [x2]");
+    }
+
+    #[cfg(feature = "arm_feat_lse")]
+    #[test]
+    fn test_ldsmax_x() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.ldsmaxab(1, 5, 2);
+        assert_eq!(instr, "ldsmaxab w1, w5, [x2]");
+
+        let instr = prod.ldsmaxalb(1, 5, 2);
+        assert_eq!(instr, "ldsmaxalb w1, w5, [x2]");
+
+        let instr = prod.ldsmaxb(1, 5, 2);
+        assert_eq!(instr, "ldsmaxb w1, w5, [x2]");
+
+        let instr = prod.ldsmaxlb(1, 5, 2);
+        assert_eq!(instr, "ldsmaxlb w1, w5, [x2]");
+    }
+
+    #[cfg(feature = "arm_feat_lse")]
+    #[test]
+    fn test_ldsmin_x() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.ldsminab(1, 5, 2);
+        assert_eq!(instr, "ldsminab w1, w5, [x2]");
+
+        let instr = prod.ldsminalb(1, 5, 2);
+        assert_eq!(instr, "ldsminalb w1, w5, [x2]");
+
+        let instr = prod.ldsminb(1, 5, 2);
+        assert_eq!(instr, "ldsminb w1, w5, [x2]");
+
+        let instr = prod.ldsminlb(1, 5, 2);
+        assert_eq!(instr, "ldsminlb w1, w5, [x2]");
+    }
+
+    #[cfg(feature = "arm_feat_lse")]
+    #[test]
+    fn test_ldumax_x() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.ldumaxab(1, 5, 2);
+        assert_eq!(instr, "ldumaxab w1, w5, [x2]");
+
+        let instr = prod.ldumaxalb(1, 5, 2);
+        assert_eq!(instr, "ldumaxalb w1, w5, [x2]");
+
+        let instr = prod.ldumaxb(1, 5, 2);
+        assert_eq!(instr, "ldumaxb w1, w5, [x2]");
+
+        let instr = prod.ldumaxlb(1, 5, 2);
+        assert_eq!(instr, "ldumaxlb w1, w5, [x2]");
+    }
+
+    #[cfg(feature = "arm_feat_lse")]
+    #[test]
+    fn test_ldumin_x() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.lduminab(1, 5, 2);
+        assert_eq!(instr, "lduminab w1, w5, [x2]");
+
+        let instr = prod.lduminalb(1, 5, 2);
+        assert_eq!(instr, "lduminalb w1, w5, [x2]");
+
+        let instr = prod.lduminb(1, 5, 2);
+        assert_eq!(instr, "lduminb w1, w5, [x2]");
+
+        let instr = prod.lduminlb(1, 5, 2);
+        assert_eq!(instr, "lduminlb w1, w5, [x2]");
+    }
+
+    #[cfg(feature = "arm_feat_lse")]
+    #[test]
+    fn test_swp_x() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.swpab(1, 5, 2);
+        assert_eq!(instr, "swpab w1, w5, [x2]");
+
+        let instr = prod.swpalb(1, 5, 2);
+        assert_eq!(instr, "swpalb w1, w5, [x2]");
+
+        let instr = prod.swpb(3, 8, 2);
+        assert_eq!(instr, "swpb w3, w8, [x2]");
+
+        let instr = prod.swplb(1, 5, 2);
+        assert_eq!(instr, "swplb w1, w5, [x2]");
+    }
+
+    #[cfg(feature = "arm_feat_lrcpc")]
+    #[test]
+    fn test_ldaprb_x() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.ldaprb(5, 2);
+        assert_eq!(instr, "ldaprb w5, [x2]");
+    }
+
+    #[cfg(feature = "arm_feat_lse")]
+    #[test]
+    fn test_ldadd_xh() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.ldaddah(1, 12, 2);
+        assert_eq!(instr, "ldaddah w1, w12, [x2]");
+
+        let instr = prod.ldaddalh(1, 5, 0b11111);
+        assert_eq!(instr, "ldaddalh w1, w5, [sp]");
+
+        let instr = prod.ldaddh(3, 30, 2);
+        assert_eq!(instr, "ldaddh w3, w30, [x2]");
+
+        let instr = prod.ldaddlh(30, 5, 2);
+        assert_eq!(instr, "ldaddlh w30, w5, [x2]");
+    }
+
+    #[cfg(feature = "arm_feat_lse")]
+    #[test]
+    fn test_ldclr_xh() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.ldclrah(1, 12, 2);
+        assert_eq!(instr, "ldclrah w1, w12, [x2]");
+
+        let instr = prod.ldclralh(1, 5, 0b11111);
+        assert_eq!(instr, "ldclralh w1, w5, [sp]");
+
+        let instr = prod.ldclrh(3, 30, 2);
+        assert_eq!(instr, "ldclrh w3, w30, [x2]");
+
+        let instr = prod.ldclrlh(30, 5, 2);
+        assert_eq!(instr, "ldclrlh w30, w5, [x2]");
+    }
+
+    #[cfg(feature = "arm_feat_lse")]
     #[test]
     fn test_ldeorh_xh() {
         let mut prod = TestProducer::new();
@@ -2998,6 +4041,276 @@ mod tests {
         assert_eq!(instr, "swpl x30, x5, [x2]");
     }
 
+    #[cfg(feature = "arm_feat_lse")]
+    #[test]
+    fn test_stadd() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.staddb(1, 2);
+        assert_eq!(instr, "staddb w1, [x2]");
+
+        let instr = prod.staddlb(1, 0b11111);
+        assert_eq!(instr, "staddlb w1, [sp]");
+
+        let instr = prod.staddh(30, 2);
+        assert_eq!(instr, "staddh w30, [x2]");
+
+        let instr = prod.staddlh(1, 2);
+        assert_eq!(instr, "staddlh w1, [x2]");
+
+        let instr = prod.stadd_32(1, 2);
+        assert_eq!(instr, "stadd w1, [x2]");
+
+        let instr = prod.staddl_32(1, 2);
+        assert_eq!(instr, "staddl w1, [x2]");
+
+        let instr = prod.stadd_64(3, 4);
+        assert_eq!(instr, "stadd x3, [x4]");
+
+        let instr = prod.staddl_64(3, 0b11111);
+        assert_eq!(instr, "staddl x3, [sp]");
+    }
+
+    #[cfg(feature = "arm_feat_lse")]
+    #[test]
+    fn test_stclr() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.stclrb(1, 2);
+        assert_eq!(instr, "stclrb w1, [x2]");
+
+        let instr = prod.stclrlb(1, 0b11111);
+        assert_eq!(instr, "stclrlb w1, [sp]");
+
+        let instr = prod.stclrh(30, 2);
+        assert_eq!(instr, "stclrh w30, [x2]");
+
+        let instr = prod.stclrlh(1, 2);
+        assert_eq!(instr, "stclrlh w1, [x2]");
+
+        let instr = prod.stclr_32(1, 2);
+        assert_eq!(instr, "stclr w1, [x2]");
+
+        let instr = prod.stclrl_32(1, 2);
+        assert_eq!(instr, "stclrl w1, [x2]");
+
+        let instr = prod.stclr_64(3, 4);
+        assert_eq!(instr, "stclr x3, [x4]");
+
+        let instr = prod.stclrl_64(3, 0b11111);
+        assert_eq!(instr, "stclrl x3, [sp]");
+    }
+
+    #[cfg(feature = "arm_feat_lse")]
+    #[test]
+    fn test_steor() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.steorb(1, 2);
+        assert_eq!(instr, "steorb w1, [x2]");
+
+        let instr = prod.steorlb(1, 0b11111);
+        assert_eq!(instr, "steorlb w1, [sp]");
+
+        let instr = prod.steorh(30, 2);
+        assert_eq!(instr, "steorh w30, [x2]");
+
+        let instr = prod.steorlh(1, 2);
+        assert_eq!(instr, "steorlh w1, [x2]");
+
+        let instr = prod.steor_32(1, 2);
+        assert_eq!(instr, "steor w1, [x2]");
+
+        let instr = prod.steorl_32(1, 2);
+        assert_eq!(instr, "steorl w1, [x2]");
+
+        let instr = prod.steor_64(3, 4);
+        assert_eq!(instr, "steor x3, [x4]");
+
+        let instr = prod.steorl_64(3, 0b11111);
+        assert_eq!(instr, "steorl x3, [sp]");
+    }
+
+    #[cfg(feature = "arm_feat_lse")]
+    #[test]
+    fn test_stset() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.stsetb(1, 2);
+        assert_eq!(instr, "stsetb w1, [x2]");
+
+        let instr = prod.stsetlb(1, 0b11111);
+        assert_eq!(instr, "stsetlb w1, [sp]");
+
+        let instr = prod.stseth(30, 2);
+        assert_eq!(instr, "stseth w30, [x2]");
+
+        let instr = prod.stsetlh(1, 2);
+        assert_eq!(instr, "stsetlh w1, [x2]");
+
+        let instr = prod.stset_32(1, 2);
+        assert_eq!(instr, "stset w1, [x2]");
+
+        let instr = prod.stsetl_32(1, 2);
+        assert_eq!(instr, "stsetl w1, [x2]");
+
+        let instr = prod.stset_64(3, 4);
+        assert_eq!(instr, "stset x3, [x4]");
+
+        let instr = prod.stsetl_64(3, 0b11111);
+        assert_eq!(instr, "stsetl x3, [sp]");
+    }
+
+    #[cfg(feature = "arm_feat_lse")]
+    #[test]
+    fn test_stsmax() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.stsmaxb(1, 2);
+        assert_eq!(instr, "stsmaxb w1, [x2]");
+
+        let instr = prod.stsmaxlb(1, 0b11111);
+        assert_eq!(instr, "stsmaxlb w1, [sp]");
+
+        let instr = prod.stsmaxh(30, 2);
+        assert_eq!(instr, "stsmaxh w30, [x2]");
+
+        let instr = prod.stsmaxlh(1, 2);
+        assert_eq!(instr, "stsmaxlh w1, [x2]");
+
+        let instr = prod.stsmax_32(1, 2);
+        assert_eq!(instr, "stsmax w1, [x2]");
+
+        let instr = prod.stsmaxl_32(1, 2);
+        assert_eq!(instr, "stsmaxl w1, [x2]");
+
+        let instr = prod.stsmax_64(3, 4);
+        assert_eq!(instr, "stsmax x3, [x4]");
+
+        let instr = prod.stsmaxl_64(3, 0b11111);
+        assert_eq!(instr, "stsmaxl x3, [sp]");
+    }
+
+    #[cfg(feature = "arm_feat_lse")]
+    #[test]
+    fn test_stsmin() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.stsminb(1, 2);
+        assert_eq!(instr, "stsminb w1, [x2]");
+
+        let instr = prod.stsminlb(1, 0b11111);
+        assert_eq!(instr, "stsminlb w1, [sp]");
+
+        let instr = prod.stsminh(30, 2);
+        assert_eq!(instr, "stsminh w30, [x2]");
+
+        let instr = prod.stsminlh(1, 2);
+        assert_eq!(instr, "stsminlh w1, [x2]");
+
+        let instr = prod.stsmin_32(1, 2);
+        assert_eq!(instr, "stsmin w1, [x2]");
+
+        let instr = prod.stsminl_32(1, 2);
+        assert_eq!(instr, "stsminl w1, [x2]");
+
+        let instr = prod.stsmin_64(3, 4);
+        assert_eq!(instr, "stsmin x3, [x4]");
+
+        let instr = prod.stsminl_64(3, 0b11111);
+        assert_eq!(instr, "stsminl x3, [sp]");
+    }
+
+    #[cfg(feature = "arm_feat_lse")]
+    #[test]
+    fn test_stumax() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.stumaxb(1, 2);
+        assert_eq!(instr, "stumaxb w1, [x2]");
+
+        let instr = prod.stumaxlb(1, 0b11111);
+        assert_eq!(instr, "stumaxlb w1, [sp]");
+
+        let instr = prod.stumaxh(30, 2);
+        assert_eq!(instr, "stumaxh w30, [x2]");
+
+        let instr = prod.stumaxlh(1, 2);
+        assert_eq!(instr, "stumaxlh w1, [x2]");
+
+        let instr = prod.stumax_32(1, 2);
+        assert_eq!(instr, "stumax w1, [x2]");
+
+        let instr = prod.stumaxl_32(1, 2);
+        assert_eq!(instr, "stumaxl w1, [x2]");
+
+        let instr = prod.stumax_64(3, 4);
+        assert_eq!(instr, "stumax x3, [x4]");
+
+        let instr = prod.stumaxl_64(3, 0b11111);
+        assert_eq!(instr, "stumaxl x3, [sp]");
+    }
+
+    #[cfg(feature = "arm_feat_lse")]
+    #[test]
+    fn test_stumin() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.stuminb(1, 2);
+        assert_eq!(instr, "stuminb w1, [x2]");
+
+        let instr = prod.stuminlb(1, 0b11111);
+        assert_eq!(instr, "stuminlb w1, [sp]");
+
+        let instr = prod.stuminh(30, 2);
+        assert_eq!(instr, "stuminh w30, [x2]");
+
+        let instr = prod.stuminlh(1, 2);
+        assert_eq!(instr, "stuminlh w1, [x2]");
+
+        let instr = prod.stumin_32(1, 2);
+        assert_eq!(instr, "stumin w1, [x2]");
+
+        let instr = prod.stuminl_32(1, 2);
+        assert_eq!(instr, "stuminl w1, [x2]");
+
+        let instr = prod.stumin_64(3, 4);
+        assert_eq!(instr, "stumin x3, [x4]");
+
+        let instr = prod.stuminl_64(3, 0b11111);
+        assert_eq!(instr, "stuminl x3, [sp]");
+    }
+
+    #[cfg(feature = "arm_feat_lse")]
+    #[test]
+    fn test_atomic_rmw() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.atomic_rmw(AtomicRmwOp::ADD, AtomicSize::X, Ordering::Relaxed, 1, 2, 3);
+        assert_eq!(instr, "ldadd x1, x2, [x3]");
+
+        let instr = prod.atomic_rmw(AtomicRmwOp::CLR, AtomicSize::W, Ordering::Acquire, 1, 2, 3);
+        assert_eq!(instr, "ldclra w1, w2, [x3]");
+
+        let instr = prod.atomic_rmw(AtomicRmwOp::EOR, AtomicSize::H, Ordering::Release, 1, 2, 3);
+        assert_eq!(instr, "ldeorlh w1, w2, [x3]");
+
+        let instr = prod.atomic_rmw(AtomicRmwOp::SET, AtomicSize::B, Ordering::AcqRel, 1, 2, 3);
+        assert_eq!(instr, "ldsetalb w1, w2, [x3]");
+
+        let instr = prod.atomic_rmw(AtomicRmwOp::SMAX, AtomicSize::X, Ordering::SeqCst, 1, 2, 3);
+        assert_eq!(instr, "ldsmaxal x1, x2, [x3]");
+
+        let instr = prod.atomic_rmw(AtomicRmwOp::UMIN, AtomicSize::W, Ordering::SeqCst, 1, 2, 0b11111);
+        assert_eq!(instr, "lduminal w1, w2, [sp]");
+
+        let instr = prod.atomic_rmw(AtomicRmwOp::SWP, AtomicSize::X, Ordering::Acquire, 1, 2, 3);
+        assert_eq!(instr, "swpa x1, x2, [x3]");
+
+        let instr = prod.atomic_rmw(AtomicRmwOp::ADD, AtomicSize::W, Ordering::Release, 1, 31, 3);
+        assert_eq!(instr, "staddl w1, [x3]");
+    }
+
     #[cfg(feature = "arm_feat_lrcpc")]
     #[test]
     fn test_ldapr_32_64() {
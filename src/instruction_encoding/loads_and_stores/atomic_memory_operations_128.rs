@@ -0,0 +1,272 @@
+//! # [Atomic memory operations (128-bit)](https://developer.arm.com/documentation/ddi0602/2022-12/Index-by-Encoding/Loads-and-Stores?lang=en#memop_128)
+//!
+//! Implements the following instructions:
+//!  - [LDCLRP - LDCLRPA - LDCLRPAL - LDCLRPL - Atomic bit clear on quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/LDCLRP--LDCLRPA--LDCLRPAL--LDCLRPL--Atomic-bit-clear-on-quadword-in-memory-?lang=en)
+//!  - [LDSETP - LDSETPA - LDSETPAL - LDSETPL - Atomic bit set on quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/LDSETP--LDSETPA--LDSETPAL--LDSETPL--Atomic-bit-set-on-quadword-in-memory-?lang=en)
+//!  - [SWPP - SWPPA - SWPPAL - SWPPL - Swap quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/SWPP--SWPPA--SWPPAL--SWPPL--Swap-quadword-in-memory-?lang=en)
+
+#[allow(unused_imports)]
+use bit_seq::bseq_32;
+
+use crate::instruction_encoding::InstructionProcessor;
+#[allow(unused_imports)]
+use crate::types::Register;
+
+#[inline(always)]
+#[cfg(feature = "arm_feat_lse128")]
+fn emit_atomic_mem_op_128<P: InstructionProcessor<T>, T>(
+    proc: &mut P,
+    a: u8,
+    r: u8,
+    rt2: Register,
+    o3: u8,
+    opc: u8,
+    rn: Register,
+    rt: Register,
+) -> T {
+    debug_assert!(rt != rt2, "xt1 and xt2 must be different registers");
+    let i = bseq_32!(00011001 a:1 r:1 1 rt2:5 o3:1 opc:3 00 rn:5 rt:5);
+    proc.process(i)
+}
+
+/// # Atomic memory operations (128-bit)
+///
+/// Implements the following instructions:
+///  - [LDCLRP - LDCLRPA - LDCLRPAL - LDCLRPL - Atomic bit clear on quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/LDCLRP--LDCLRPA--LDCLRPAL--LDCLRPL--Atomic-bit-clear-on-quadword-in-memory-?lang=en)
+///  - [LDSETP - LDSETPA - LDSETPAL - LDSETPL - Atomic bit set on quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/LDSETP--LDSETPA--LDSETPAL--LDSETPL--Atomic-bit-set-on-quadword-in-memory-?lang=en)
+///  - [SWPP - SWPPA - SWPPAL - SWPPL - Swap quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/SWPP--SWPPA--SWPPAL--SWPPL--Swap-quadword-in-memory-?lang=en)
+pub trait AtomicMemoryOperations128<T>: InstructionProcessor<T> {
+    /// [LDCLRP - Atomic bit clear on quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/LDCLRP--LDCLRPA--LDCLRPAL--LDCLRPL--Atomic-bit-clear-on-quadword-in-memory-?lang=en)
+    ///
+    /// Atomic bit clear on quadword in memory atomically loads a 128-bit quadword from memory, performs a bitwise AND with the complement of the value held in a pair of registers on it, and stores the result back to memory. The value initially loaded from memory is returned in the same pair of registers.
+    ///
+    /// ```asm
+    /// LDCLRP <Xt1>, <Xt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_LSE128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse128")]
+    fn ldclrp(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        emit_atomic_mem_op_128(self, 0, 0, xt2, 0, 0b001, xn_sp, xt1)
+    }
+
+    /// [LDCLRPA - Atomic bit clear on quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/LDCLRP--LDCLRPA--LDCLRPAL--LDCLRPL--Atomic-bit-clear-on-quadword-in-memory-?lang=en)
+    ///
+    /// Atomic bit clear on quadword in memory atomically loads a 128-bit quadword from memory, performs a bitwise AND with the complement of the value held in a pair of registers on it, and stores the result back to memory. The value initially loaded from memory is returned in the same pair of registers.
+    ///
+    /// The instruction has acquire semantics on the load from memory.
+    ///
+    /// ```asm
+    /// LDCLRPA <Xt1>, <Xt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_LSE128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse128")]
+    fn ldclrpa(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        emit_atomic_mem_op_128(self, 1, 0, xt2, 0, 0b001, xn_sp, xt1)
+    }
+
+    /// [LDCLRPAL - Atomic bit clear on quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/LDCLRP--LDCLRPA--LDCLRPAL--LDCLRPL--Atomic-bit-clear-on-quadword-in-memory-?lang=en)
+    ///
+    /// Atomic bit clear on quadword in memory atomically loads a 128-bit quadword from memory, performs a bitwise AND with the complement of the value held in a pair of registers on it, and stores the result back to memory. The value initially loaded from memory is returned in the same pair of registers.
+    ///
+    /// The instruction has acquire and release semantics on the load from and store to memory.
+    ///
+    /// ```asm
+    /// LDCLRPAL <Xt1>, <Xt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_LSE128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse128")]
+    fn ldclrpal(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        emit_atomic_mem_op_128(self, 1, 1, xt2, 0, 0b001, xn_sp, xt1)
+    }
+
+    /// [LDCLRPL - Atomic bit clear on quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/LDCLRP--LDCLRPA--LDCLRPAL--LDCLRPL--Atomic-bit-clear-on-quadword-in-memory-?lang=en)
+    ///
+    /// Atomic bit clear on quadword in memory atomically loads a 128-bit quadword from memory, performs a bitwise AND with the complement of the value held in a pair of registers on it, and stores the result back to memory. The value initially loaded from memory is returned in the same pair of registers.
+    ///
+    /// The instruction has release semantics on the store to memory.
+    ///
+    /// ```asm
+    /// LDCLRPL <Xt1>, <Xt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_LSE128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse128")]
+    fn ldclrpl(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        emit_atomic_mem_op_128(self, 0, 1, xt2, 0, 0b001, xn_sp, xt1)
+    }
+
+    /// [LDSETP - Atomic bit set on quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/LDSETP--LDSETPA--LDSETPAL--LDSETPL--Atomic-bit-set-on-quadword-in-memory-?lang=en)
+    ///
+    /// Atomic bit set on quadword in memory atomically loads a 128-bit quadword from memory, performs a bitwise OR with the value held in a pair of registers on it, and stores the result back to memory. The value initially loaded from memory is returned in the same pair of registers.
+    ///
+    /// ```asm
+    /// LDSETP <Xt1>, <Xt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_LSE128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse128")]
+    fn ldsetp(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        emit_atomic_mem_op_128(self, 0, 0, xt2, 0, 0b011, xn_sp, xt1)
+    }
+
+    /// [LDSETPA - Atomic bit set on quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/LDSETP--LDSETPA--LDSETPAL--LDSETPL--Atomic-bit-set-on-quadword-in-memory-?lang=en)
+    ///
+    /// Atomic bit set on quadword in memory atomically loads a 128-bit quadword from memory, performs a bitwise OR with the value held in a pair of registers on it, and stores the result back to memory. The value initially loaded from memory is returned in the same pair of registers.
+    ///
+    /// The instruction has acquire semantics on the load from memory.
+    ///
+    /// ```asm
+    /// LDSETPA <Xt1>, <Xt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_LSE128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse128")]
+    fn ldsetpa(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        emit_atomic_mem_op_128(self, 1, 0, xt2, 0, 0b011, xn_sp, xt1)
+    }
+
+    /// [LDSETPAL - Atomic bit set on quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/LDSETP--LDSETPA--LDSETPAL--LDSETPL--Atomic-bit-set-on-quadword-in-memory-?lang=en)
+    ///
+    /// Atomic bit set on quadword in memory atomically loads a 128-bit quadword from memory, performs a bitwise OR with the value held in a pair of registers on it, and stores the result back to memory. The value initially loaded from memory is returned in the same pair of registers.
+    ///
+    /// The instruction has acquire and release semantics on the load from and store to memory.
+    ///
+    /// ```asm
+    /// LDSETPAL <Xt1>, <Xt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_LSE128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse128")]
+    fn ldsetpal(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        emit_atomic_mem_op_128(self, 1, 1, xt2, 0, 0b011, xn_sp, xt1)
+    }
+
+    /// [LDSETPL - Atomic bit set on quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/LDSETP--LDSETPA--LDSETPAL--LDSETPL--Atomic-bit-set-on-quadword-in-memory-?lang=en)
+    ///
+    /// Atomic bit set on quadword in memory atomically loads a 128-bit quadword from memory, performs a bitwise OR with the value held in a pair of registers on it, and stores the result back to memory. The value initially loaded from memory is returned in the same pair of registers.
+    ///
+    /// The instruction has release semantics on the store to memory.
+    ///
+    /// ```asm
+    /// LDSETPL <Xt1>, <Xt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_LSE128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse128")]
+    fn ldsetpl(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        emit_atomic_mem_op_128(self, 0, 1, xt2, 0, 0b011, xn_sp, xt1)
+    }
+
+    /// [SWPP - Swap quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/SWPP--SWPPA--SWPPAL--SWPPL--Swap-quadword-in-memory-?lang=en)
+    ///
+    /// Swap quadword in memory atomically loads a 128-bit quadword from a memory location, and stores the value held in a pair of registers back to the same memory location. The value initially loaded from memory is returned in the same pair of registers.
+    ///
+    /// ```asm
+    /// SWPP <Xt1>, <Xt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_LSE128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse128")]
+    fn swpp(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        emit_atomic_mem_op_128(self, 0, 0, xt2, 1, 0b000, xn_sp, xt1)
+    }
+
+    /// [SWPPA - Swap quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/SWPP--SWPPA--SWPPAL--SWPPL--Swap-quadword-in-memory-?lang=en)
+    ///
+    /// Swap quadword in memory atomically loads a 128-bit quadword from a memory location, and stores the value held in a pair of registers back to the same memory location. The value initially loaded from memory is returned in the same pair of registers.
+    ///
+    /// The instruction has acquire semantics on the load from memory.
+    ///
+    /// ```asm
+    /// SWPPA <Xt1>, <Xt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_LSE128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse128")]
+    fn swppa(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        emit_atomic_mem_op_128(self, 1, 0, xt2, 1, 0b000, xn_sp, xt1)
+    }
+
+    /// [SWPPAL - Swap quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/SWPP--SWPPA--SWPPAL--SWPPL--Swap-quadword-in-memory-?lang=en)
+    ///
+    /// Swap quadword in memory atomically loads a 128-bit quadword from a memory location, and stores the value held in a pair of registers back to the same memory location. The value initially loaded from memory is returned in the same pair of registers.
+    ///
+    /// The instruction has acquire and release semantics on the load from and store to memory.
+    ///
+    /// ```asm
+    /// SWPPAL <Xt1>, <Xt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_LSE128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse128")]
+    fn swppal(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        emit_atomic_mem_op_128(self, 1, 1, xt2, 1, 0b000, xn_sp, xt1)
+    }
+
+    /// [SWPPL - Swap quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/SWPP--SWPPA--SWPPAL--SWPPL--Swap-quadword-in-memory-?lang=en)
+    ///
+    /// Swap quadword in memory atomically loads a 128-bit quadword from a memory location, and stores the value held in a pair of registers back to the same memory location. The value initially loaded from memory is returned in the same pair of registers.
+    ///
+    /// The instruction has release semantics on the store to memory.
+    ///
+    /// ```asm
+    /// SWPPL <Xt1>, <Xt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_LSE128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lse128")]
+    fn swppl(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        emit_atomic_mem_op_128(self, 0, 1, xt2, 1, 0b000, xn_sp, xt1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+    #[allow(unused_imports)]
+    use crate::assert_panic;
+
+    #[cfg(feature = "arm_feat_lse128")]
+    #[test]
+    fn test_atomic_mem_op_128() {
+        use crate::instruction_producer::InstrProducer;
+
+        // the disassembler does not support FEAT_LSE128, so the encodings are compared
+        let mut prod = InstrProducer::new();
+
+        assert_eq!(prod.ldclrp(0, 1, 2).encoding(), 0x19211040);
+        assert_eq!(prod.ldclrpa(0, 1, 2).encoding(), 0x19a11040);
+        assert_eq!(prod.ldclrpal(0, 1, 2).encoding(), 0x19e11040);
+        assert_eq!(prod.ldclrpl(0, 1, 2).encoding(), 0x19611040);
+
+        assert_eq!(prod.ldsetp(0, 1, 2).encoding(), 0x19213040);
+        assert_eq!(prod.ldsetpa(0, 1, 2).encoding(), 0x19a13040);
+        assert_eq!(prod.ldsetpal(0, 1, 2).encoding(), 0x19e13040);
+        assert_eq!(prod.ldsetpl(0, 1, 2).encoding(), 0x19613040);
+
+        assert_eq!(prod.swpp(0, 1, 2).encoding(), 0x19218040);
+        assert_eq!(prod.swppa(0, 1, 2).encoding(), 0x19a18040);
+        assert_eq!(prod.swppal(0, 1, 2).encoding(), 0x19e18040);
+        assert_eq!(prod.swppl(0, 1, 2).encoding(), 0x19618040);
+
+        assert_eq!(prod.swpp(3, 30, 31).encoding(), 0x193e83e3);
+
+        assert_panic!("Should panic: same registers"; prod.ldsetp(1, 1, 2));
+    }
+}
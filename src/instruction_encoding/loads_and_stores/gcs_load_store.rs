@@ -0,0 +1,81 @@
+//! # [GCS load/store](https://developer.arm.com/documentation/ddi0602/2023-12/Index-by-Encoding/Loads-and-Stores?lang=en#ldst_gcs)
+//!
+//! Implements the following instructions:
+//!  - [GCSSTR - Guarded Control Stack Store Register](https://developer.arm.com/documentation/ddi0602/2023-12/Base-Instructions/GCSSTR--Guarded-Control-Stack-Store-Register-?lang=en)
+//!  - [GCSSTTR - Guarded Control Stack unprivileged Store Register](https://developer.arm.com/documentation/ddi0602/2023-12/Base-Instructions/GCSSTTR--Guarded-Control-Stack-unprivileged-Store-Register-?lang=en)
+
+#[allow(unused_imports)]
+use bit_seq::bseq_32;
+
+use crate::instruction_encoding::InstructionProcessor;
+#[allow(unused_imports)]
+use crate::types::Register;
+
+#[inline(always)]
+#[cfg(feature = "arm_feat_gcs")]
+fn emit_gcs_str<P: InstructionProcessor<T>, T>(
+    proc: &mut P,
+    opc: u8,
+    rn: Register,
+    rt: Register,
+) -> T {
+    let i = bseq_32!(11011001 000 11111 000 opc:1 11 rn:5 rt:5);
+    proc.process(i)
+}
+
+/// # [GCS load/store](https://developer.arm.com/documentation/ddi0602/2023-12/Index-by-Encoding/Loads-and-Stores?lang=en#ldst_gcs)
+///
+/// Implements the following instructions:
+///  - [GCSSTR - Guarded Control Stack Store Register](https://developer.arm.com/documentation/ddi0602/2023-12/Base-Instructions/GCSSTR--Guarded-Control-Stack-Store-Register-?lang=en)
+///  - [GCSSTTR - Guarded Control Stack unprivileged Store Register](https://developer.arm.com/documentation/ddi0602/2023-12/Base-Instructions/GCSSTTR--Guarded-Control-Stack-unprivileged-Store-Register-?lang=en)
+pub trait GcsLoadStore<T>: InstructionProcessor<T> {
+    /// [GCSSTR - Guarded Control Stack Store Register](https://developer.arm.com/documentation/ddi0602/2023-12/Base-Instructions/GCSSTR--Guarded-Control-Stack-Store-Register-?lang=en)
+    ///
+    /// Guarded Control Stack Store Register stores a 64-bit doubleword to a Guarded Control Stack memory location, from a register. The address is taken from a base register.
+    ///
+    /// ```asm
+    /// GCSSTR <Xt>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_GCS required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_gcs")]
+    fn gcsstr(&mut self, xt: Register, xn_sp: Register) -> T {
+        emit_gcs_str(self, 0, xn_sp, xt)
+    }
+
+    /// [GCSSTTR - Guarded Control Stack unprivileged Store Register](https://developer.arm.com/documentation/ddi0602/2023-12/Base-Instructions/GCSSTTR--Guarded-Control-Stack-unprivileged-Store-Register-?lang=en)
+    ///
+    /// Guarded Control Stack unprivileged Store Register stores a 64-bit doubleword to a Guarded Control Stack memory location, from a register. The address is taken from a base register. The memory is accessed as if the instruction was executed at EL0.
+    ///
+    /// ```asm
+    /// GCSSTTR <Xt>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_GCS required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_gcs")]
+    fn gcssttr(&mut self, xt: Register, xn_sp: Register) -> T {
+        emit_gcs_str(self, 1, xn_sp, xt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[cfg(feature = "arm_feat_gcs")]
+    #[test]
+    fn test_gcs_str() {
+        use crate::instruction_producer::InstrProducer;
+
+        // the disassembler does not support FEAT_GCS, so the encodings are compared
+        let mut prod = InstrProducer::new();
+
+        assert_eq!(prod.gcsstr(1, 2).encoding(), 0xd91f0c41);
+        assert_eq!(prod.gcsstr(30, 31).encoding(), 0xd91f0ffe);
+        assert_eq!(prod.gcssttr(1, 2).encoding(), 0xd91f1c41);
+        assert_eq!(prod.gcssttr(30, 31).encoding(), 0xd91f1ffe);
+    }
+}
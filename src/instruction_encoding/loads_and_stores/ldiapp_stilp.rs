@@ -0,0 +1,303 @@
+//! # [LDIAPP/STILP](https://developer.arm.com/documentation/ddi0602/2022-12/Index-by-Encoding/Loads-and-Stores?lang=en#ldiappstilp)
+//!
+//! Implements the following instructions:
+//!  - [STILP - Store Release ordered Pair of registers](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/STILP--Store-Release-ordered-Pair-of-registers-?lang=en)
+//!  - [LDIAPP - Load Acquire RCpc ordered Pair of registers](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/LDIAPP--Load-Acquire-RCpc-ordered-Pair-of-registers-?lang=en)
+//!  - [STLR - Store Release Register - pre-index](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/STLR--Store-Release-Register-?lang=en)
+//!  - [LDAPR - Load Acquire RCpc Register - post-index](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/LDAPR--Load-Acquire-RCpc-Register-?lang=en)
+
+#[allow(unused_imports)]
+use bit_seq::bseq_32;
+
+use crate::instruction_encoding::InstructionProcessor;
+#[allow(unused_imports)]
+use crate::types::Register;
+
+#[inline(always)]
+#[cfg(feature = "arm_feat_lrcpc3")]
+fn emit_rcpc3_ld_st<P: InstructionProcessor<T>, T>(
+    proc: &mut P,
+    size: u8,
+    opc: u8,
+    rt2: Register,
+    opc2: u8,
+    rn: Register,
+    rt: Register,
+) -> T {
+    let i = bseq_32!(size:2 011001 opc:2 0 rt2:5 opc2:4 10 rn:5 rt:5);
+    proc.process(i)
+}
+
+/// Asserts that the base register of a writeback form does not overlap with a transfer register.
+#[inline(always)]
+#[cfg(feature = "arm_feat_lrcpc3")]
+fn debug_assert_writeback(rn: Register, rts: &[Register]) {
+    debug_assert!(
+        rn == 31 || !rts.contains(&rn),
+        "xn must differ from the transfer registers if writeback is used"
+    );
+}
+
+/// # [LDIAPP/STILP](https://developer.arm.com/documentation/ddi0602/2022-12/Index-by-Encoding/Loads-and-Stores?lang=en#ldiappstilp)
+///
+/// Implements the following instructions:
+///  - [STILP - Store Release ordered Pair of registers](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/STILP--Store-Release-ordered-Pair-of-registers-?lang=en)
+///  - [LDIAPP - Load Acquire RCpc ordered Pair of registers](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/LDIAPP--Load-Acquire-RCpc-ordered-Pair-of-registers-?lang=en)
+///  - [STLR - Store Release Register - pre-index](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/STLR--Store-Release-Register-?lang=en)
+///  - [LDAPR - Load Acquire RCpc Register - post-index](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/LDAPR--Load-Acquire-RCpc-Register-?lang=en)
+pub trait LdiappStilp<T>: InstructionProcessor<T> {
+    /// [STILP - Store Release ordered Pair of registers](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/STILP--Store-Release-ordered-Pair-of-registers-?lang=en)
+    ///
+    /// Store-Release ordered Pair of registers calculates an address from a base register value and an optional offset, and stores two 32-bit words to the calculated address, from two registers.
+    ///
+    /// ```asm
+    /// STILP <Wt1>, <Wt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_LRCPC3 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lrcpc3")]
+    fn stilp_32(&mut self, wt1: Register, wt2: Register, xn_sp: Register) -> T {
+        emit_rcpc3_ld_st(self, 0b10, 0b00, wt2, 0b0001, xn_sp, wt1)
+    }
+
+    /// [STILP - Store Release ordered Pair of registers](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/STILP--Store-Release-ordered-Pair-of-registers-?lang=en)
+    ///
+    /// Store-Release ordered Pair of registers calculates an address from a base register value and an optional offset, and stores two 32-bit words to the calculated address, from two registers.
+    ///
+    /// The base register is decremented by 8 before the store.
+    ///
+    /// ```asm
+    /// STILP <Wt1>, <Wt2>, [<Xn|SP>, #-8]!
+    /// ```
+    ///
+    /// *Note*: FEAT_LRCPC3 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lrcpc3")]
+    fn stilp_32_pre_index(&mut self, wt1: Register, wt2: Register, xn_sp: Register) -> T {
+        debug_assert_writeback(xn_sp, &[wt1, wt2]);
+        emit_rcpc3_ld_st(self, 0b10, 0b00, wt2, 0b0000, xn_sp, wt1)
+    }
+
+    /// [STILP - Store Release ordered Pair of registers](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/STILP--Store-Release-ordered-Pair-of-registers-?lang=en)
+    ///
+    /// Store-Release ordered Pair of registers calculates an address from a base register value and an optional offset, and stores two 64-bit doublewords to the calculated address, from two registers.
+    ///
+    /// ```asm
+    /// STILP <Xt1>, <Xt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_LRCPC3 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lrcpc3")]
+    fn stilp_64(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        emit_rcpc3_ld_st(self, 0b11, 0b00, xt2, 0b0001, xn_sp, xt1)
+    }
+
+    /// [STILP - Store Release ordered Pair of registers](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/STILP--Store-Release-ordered-Pair-of-registers-?lang=en)
+    ///
+    /// Store-Release ordered Pair of registers calculates an address from a base register value and an optional offset, and stores two 64-bit doublewords to the calculated address, from two registers.
+    ///
+    /// The base register is decremented by 16 before the store.
+    ///
+    /// ```asm
+    /// STILP <Xt1>, <Xt2>, [<Xn|SP>, #-16]!
+    /// ```
+    ///
+    /// *Note*: FEAT_LRCPC3 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lrcpc3")]
+    fn stilp_64_pre_index(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        debug_assert_writeback(xn_sp, &[xt1, xt2]);
+        emit_rcpc3_ld_st(self, 0b11, 0b00, xt2, 0b0000, xn_sp, xt1)
+    }
+
+    /// [LDIAPP - Load Acquire RCpc ordered Pair of registers](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/LDIAPP--Load-Acquire-RCpc-ordered-Pair-of-registers-?lang=en)
+    ///
+    /// Load-Acquire RCpc ordered Pair of registers calculates an address from a base register value and an optional offset, loads two 32-bit words from memory, and writes them to two registers.
+    ///
+    /// ```asm
+    /// LDIAPP <Wt1>, <Wt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_LRCPC3 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lrcpc3")]
+    fn ldiapp_32(&mut self, wt1: Register, wt2: Register, xn_sp: Register) -> T {
+        debug_assert!(wt1 != wt2, "wt1 and wt2 must be different registers");
+        emit_rcpc3_ld_st(self, 0b10, 0b01, wt2, 0b0001, xn_sp, wt1)
+    }
+
+    /// [LDIAPP - Load Acquire RCpc ordered Pair of registers](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/LDIAPP--Load-Acquire-RCpc-ordered-Pair-of-registers-?lang=en)
+    ///
+    /// Load-Acquire RCpc ordered Pair of registers calculates an address from a base register value and an optional offset, loads two 32-bit words from memory, and writes them to two registers.
+    ///
+    /// The base register is incremented by 8 after the load.
+    ///
+    /// ```asm
+    /// LDIAPP <Wt1>, <Wt2>, [<Xn|SP>], #8
+    /// ```
+    ///
+    /// *Note*: FEAT_LRCPC3 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lrcpc3")]
+    fn ldiapp_32_post_index(&mut self, wt1: Register, wt2: Register, xn_sp: Register) -> T {
+        debug_assert!(wt1 != wt2, "wt1 and wt2 must be different registers");
+        debug_assert_writeback(xn_sp, &[wt1, wt2]);
+        emit_rcpc3_ld_st(self, 0b10, 0b01, wt2, 0b0000, xn_sp, wt1)
+    }
+
+    /// [LDIAPP - Load Acquire RCpc ordered Pair of registers](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/LDIAPP--Load-Acquire-RCpc-ordered-Pair-of-registers-?lang=en)
+    ///
+    /// Load-Acquire RCpc ordered Pair of registers calculates an address from a base register value and an optional offset, loads two 64-bit doublewords from memory, and writes them to two registers.
+    ///
+    /// ```asm
+    /// LDIAPP <Xt1>, <Xt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_LRCPC3 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lrcpc3")]
+    fn ldiapp_64(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        debug_assert!(xt1 != xt2, "xt1 and xt2 must be different registers");
+        emit_rcpc3_ld_st(self, 0b11, 0b01, xt2, 0b0001, xn_sp, xt1)
+    }
+
+    /// [LDIAPP - Load Acquire RCpc ordered Pair of registers](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/LDIAPP--Load-Acquire-RCpc-ordered-Pair-of-registers-?lang=en)
+    ///
+    /// Load-Acquire RCpc ordered Pair of registers calculates an address from a base register value and an optional offset, loads two 64-bit doublewords from memory, and writes them to two registers.
+    ///
+    /// The base register is incremented by 16 after the load.
+    ///
+    /// ```asm
+    /// LDIAPP <Xt1>, <Xt2>, [<Xn|SP>], #16
+    /// ```
+    ///
+    /// *Note*: FEAT_LRCPC3 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lrcpc3")]
+    fn ldiapp_64_post_index(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        debug_assert!(xt1 != xt2, "xt1 and xt2 must be different registers");
+        debug_assert_writeback(xn_sp, &[xt1, xt2]);
+        emit_rcpc3_ld_st(self, 0b11, 0b01, xt2, 0b0000, xn_sp, xt1)
+    }
+
+    /// [STLR - Store Release Register](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/STLR--Store-Release-Register-?lang=en)
+    ///
+    /// Store-Release Register stores a 32-bit word to a memory location, from a register.
+    ///
+    /// The base register is decremented by 4 before the store.
+    ///
+    /// ```asm
+    /// STLR <Wt>, [<Xn|SP>, #-4]!
+    /// ```
+    ///
+    /// *Note*: FEAT_LRCPC3 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lrcpc3")]
+    fn stlr_32_pre_index(&mut self, wt: Register, xn_sp: Register) -> T {
+        debug_assert_writeback(xn_sp, &[wt]);
+        emit_rcpc3_ld_st(self, 0b10, 0b10, 0, 0b0000, xn_sp, wt)
+    }
+
+    /// [STLR - Store Release Register](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/STLR--Store-Release-Register-?lang=en)
+    ///
+    /// Store-Release Register stores a 64-bit doubleword to a memory location, from a register.
+    ///
+    /// The base register is decremented by 8 before the store.
+    ///
+    /// ```asm
+    /// STLR <Xt>, [<Xn|SP>, #-8]!
+    /// ```
+    ///
+    /// *Note*: FEAT_LRCPC3 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lrcpc3")]
+    fn stlr_64_pre_index(&mut self, xt: Register, xn_sp: Register) -> T {
+        debug_assert_writeback(xn_sp, &[xt]);
+        emit_rcpc3_ld_st(self, 0b11, 0b10, 0, 0b0000, xn_sp, xt)
+    }
+
+    /// [LDAPR - Load Acquire RCpc Register](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/LDAPR--Load-Acquire-RCpc-Register-?lang=en)
+    ///
+    /// Load-Acquire RCpc Register derives an address from a base register value, loads a 32-bit word from the derived address in memory, and writes it to a register.
+    ///
+    /// The base register is incremented by 4 after the load.
+    ///
+    /// ```asm
+    /// LDAPR <Wt>, [<Xn|SP>], #4
+    /// ```
+    ///
+    /// *Note*: FEAT_LRCPC3 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lrcpc3")]
+    fn ldapr_32_post_index(&mut self, wt: Register, xn_sp: Register) -> T {
+        debug_assert_writeback(xn_sp, &[wt]);
+        emit_rcpc3_ld_st(self, 0b10, 0b11, 0, 0b0000, xn_sp, wt)
+    }
+
+    /// [LDAPR - Load Acquire RCpc Register](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/LDAPR--Load-Acquire-RCpc-Register-?lang=en)
+    ///
+    /// Load-Acquire RCpc Register derives an address from a base register value, loads a 64-bit doubleword from the derived address in memory, and writes it to a register.
+    ///
+    /// The base register is incremented by 8 after the load.
+    ///
+    /// ```asm
+    /// LDAPR <Xt>, [<Xn|SP>], #8
+    /// ```
+    ///
+    /// *Note*: FEAT_LRCPC3 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_lrcpc3")]
+    fn ldapr_64_post_index(&mut self, xt: Register, xn_sp: Register) -> T {
+        debug_assert_writeback(xn_sp, &[xt]);
+        emit_rcpc3_ld_st(self, 0b11, 0b11, 0, 0b0000, xn_sp, xt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+    #[allow(unused_imports)]
+    use crate::assert_panic;
+
+    #[cfg(feature = "arm_feat_lrcpc3")]
+    #[test]
+    fn test_stilp_ldiapp() {
+        use crate::instruction_producer::InstrProducer;
+
+        // the disassembler does not support FEAT_LRCPC3, so the encodings are compared
+        let mut prod = InstrProducer::new();
+
+        assert_eq!(prod.stilp_32_pre_index(24, 0, 16).encoding(), 0x99000a18);
+        assert_eq!(prod.stilp_32(24, 0, 16).encoding(), 0x99001a18);
+        assert_eq!(prod.stilp_64_pre_index(25, 1, 17).encoding(), 0xd9010a39);
+        assert_eq!(prod.stilp_64(25, 1, 17).encoding(), 0xd9011a39);
+
+        assert_eq!(prod.ldiapp_32_post_index(26, 2, 18).encoding(), 0x99420a5a);
+        assert_eq!(prod.ldiapp_32(26, 2, 18).encoding(), 0x99421a5a);
+        assert_eq!(prod.ldiapp_64_post_index(27, 3, 19).encoding(), 0xd9430a7b);
+        assert_eq!(prod.ldiapp_64(27, 3, 19).encoding(), 0xd9431a7b);
+
+        assert_panic!("Should panic: same registers"; prod.ldiapp_64(1, 1, 2));
+        assert_panic!("Should panic: writeback overlap"; prod.stilp_32_pre_index(1, 2, 2));
+        assert_eq!(prod.stilp_64_pre_index(1, 2, 31).encoding(), 0xd9020be1);
+    }
+
+    #[cfg(feature = "arm_feat_lrcpc3")]
+    #[test]
+    fn test_ldapr_stlr_writeback() {
+        use crate::instruction_producer::InstrProducer;
+
+        // the disassembler does not support FEAT_LRCPC3, so the encodings are compared
+        let mut prod = InstrProducer::new();
+
+        assert_eq!(prod.stlr_32_pre_index(3, 15).encoding(), 0x998009e3);
+        assert_eq!(prod.stlr_64_pre_index(3, 15).encoding(), 0xd98009e3);
+        assert_eq!(prod.ldapr_32_post_index(3, 15).encoding(), 0x99c009e3);
+        assert_eq!(prod.ldapr_64_post_index(3, 15).encoding(), 0xd9c009e3);
+
+        assert_panic!("Should panic: writeback overlap"; prod.ldapr_64_post_index(3, 3));
+    }
+}
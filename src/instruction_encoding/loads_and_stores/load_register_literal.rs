@@ -3,9 +3,12 @@
 //! Implements the following instructions:
 //! - LDRSW \<Xt>, \<imm32> - offset instead of label
 //! - LDRSW \<Xt>, \<imm32> - addr instead of label
+//! - LDR \<Xt>, \<global> - ADRP+LDR sequence for addresses within ±4GB
 
 use bit_seq::bseq_32;
 
+use crate::instruction_encoding::data_proc_imm::pc_rel_addr::PcRelAddressingWithAddress;
+use crate::instruction_encoding::loads_and_stores::load_store_register_unsigned_imm::LoadStoreRegisterUnsignedImmediate;
 use crate::instruction_encoding::{AddressableInstructionProcessor, InstructionProcessor};
 use crate::types::prefetch_memory::PrfOp;
 use crate::types::{Offset32, Register, UImm5};
@@ -118,7 +121,10 @@ pub trait LoadRegisterLiteral<T>: InstructionProcessor<T> {
 }
 
 pub trait LoadRegisterLiteralWithAddress<T>:
-    LoadRegisterLiteral<T> + AddressableInstructionProcessor<T>
+    LoadRegisterLiteral<T>
+    + AddressableInstructionProcessor<T>
+    + PcRelAddressingWithAddress<T>
+    + LoadStoreRegisterUnsignedImmediate<T>
 {
     // LDRSW (literal)
 
@@ -178,6 +184,46 @@ pub trait LoadRegisterLiteralWithAddress<T>:
         // LDR (literal)
         emit_ldr_x_addr(self, 0b10, 1, addr, qt)
     }
+
+    // LDR from global
+
+    /// Emits an ADRP+LDR sequence that loads the 32-bit value at `addr`, which must be 4 byte aligned and
+    /// within ±4GB of the instruction. The `:lo12:` offset is scaled by the access size.
+    ///
+    /// ```asm
+    /// ADRP <Xt>, <addr>
+    /// LDR <Wt>, [<Xt>, #:lo12:<addr>]
+    /// ```
+    ///
+    /// Like [`adrp_to_addr`](PcRelAddressingWithAddress::adrp_to_addr), this must not be emitted while a
+    /// branch relaxation may still move it.
+    #[inline(always)]
+    fn ldr_32_from_global(&mut self, wt: Register, addr: usize) -> Vec<T> {
+        debug_assert!(addr % 4 == 0, "Addr must be 4 byte aligned!");
+        vec![
+            self.adrp_to_addr(wt, addr),
+            self.ldr_32_imm_unsigned_offset(wt, wt, (addr & 0xfff) as u16),
+        ]
+    }
+
+    /// Emits an ADRP+LDR sequence that loads the 64-bit value at `addr`, which must be 8 byte aligned and
+    /// within ±4GB of the instruction. The `:lo12:` offset is scaled by the access size.
+    ///
+    /// ```asm
+    /// ADRP <Xt>, <addr>
+    /// LDR <Xt>, [<Xt>, #:lo12:<addr>]
+    /// ```
+    ///
+    /// Like [`adrp_to_addr`](PcRelAddressingWithAddress::adrp_to_addr), this must not be emitted while a
+    /// branch relaxation may still move it.
+    #[inline(always)]
+    fn ldr_64_from_global(&mut self, xt: Register, addr: usize) -> Vec<T> {
+        debug_assert!(addr % 8 == 0, "Addr must be 8 byte aligned!");
+        vec![
+            self.adrp_to_addr(xt, addr),
+            self.ldr_64_imm_unsigned_offset(xt, xt, (addr & 0xfff) as u16),
+        ]
+    }
 }
 
 #[cfg(test)]
@@ -282,6 +328,24 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_ldr_x_from_global() {
+        stream_mock!(stream, {
+            let instrs = stream.ldr_64_from_global(2, 0x1234_5678);
+            assert_eq!(instrs.len(), 2);
+            assert_eq!(instrs[0].to_string(), "adrp x2, 0x12345000");
+            assert_eq!(instrs[1].to_string(), "ldr x2, [x2, #0x678]");
+
+            let instrs = stream.ldr_32_from_global(3, 0x1234_5ffc);
+            assert_eq!(instrs.len(), 2);
+            assert_eq!(instrs[0].to_string(), "adrp x3, 0x12345000");
+            assert_eq!(instrs[1].to_string(), "ldr w3, [x3, #0xffc]");
+
+            assert_panic!("Should panic: addr not aligned"; stream.ldr_64_from_global(2, 0x1234_5674));
+            assert_panic!("Should panic: page out of range"; stream.ldr_32_from_global(2, 1 << 32));
+        })
+    }
+
     #[test]
     fn test_ldr_x_simd_pc_rel_from_byte_offset() {
         stream_mock!(stream, {
@@ -13,13 +13,14 @@
 //!  - [LDR - register](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/LDR--register---Load-Register--register--?lang=en)
 //!  - [LDRSW - register](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/LDRSW--register---Load-Register-Signed-Word--register--?lang=en)
 //!  - [PRFM - register](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/PRFM--register---Prefetch-Memory--register--?lang=en)
+//!  - [RPRFM - Range Prefetch Memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RPRFM--Range-Prefetch-Memory-?lang=en)
 
 use bit_seq::bseq_32;
 
 use crate::instruction_encoding::InstructionProcessor;
-use crate::types::{Register, UImm1, UImm2, UImm3, UImm5};
+use crate::types::{Register, UImm1, UImm2, UImm3, UImm5, UImm6};
 use crate::types::extends::{RegExtend, RegExtendLSL};
-use crate::types::prefetch_memory::PrfOp;
+use crate::types::prefetch_memory::{PrfOp, RprfOp};
 
 #[inline(always)]
 fn emit_ld_st_reg_off<P: InstructionProcessor<T>, T>(
@@ -52,6 +53,7 @@ fn emit_ld_st_reg_off<P: InstructionProcessor<T>, T>(
 ///  - [LDR - register](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/LDR--register---Load-Register--register--?lang=en)
 ///  - [LDRSW - register](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/LDRSW--register---Load-Register-Signed-Word--register--?lang=en)
 ///  - [PRFM - register](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/PRFM--register---Prefetch-Memory--register--?lang=en)
+///  - [RPRFM - Range Prefetch Memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RPRFM--Range-Prefetch-Memory-?lang=en)
 pub trait LoadStoreRegisterRegisterOffset<T>: InstructionProcessor<T> {
     /// [STRB - register](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/STRB--register---Store-Register-Byte--register--?lang=en)
     ///
@@ -876,6 +878,44 @@ pub trait LoadStoreRegisterRegisterOffset<T>: InstructionProcessor<T> {
             imm5,
         )
     }
+
+    /// [RPRFM - Range Prefetch Memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RPRFM--Range-Prefetch-Memory-?lang=en)
+    ///
+    /// Range Prefetch Memory signals the memory system that data memory accesses from a specified range of addresses are likely to occur in the near future. The instruction may also signal to the memory system whether memory accesses are likely to be load or store, and whether they are likely to be streaming or temporal.
+    ///
+    /// `xm` holds the range metadata, `xn_sp` the base address of the range.
+    ///
+    /// The instruction is allocated in the hint space of `PRFM (register)`, so it is treated as a
+    /// prefetch hint by implementations without FEAT_RPRFM.
+    ///
+    /// ```asm
+    /// RPRFM (<rprfop>|#<imm6>), <Xm>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    fn rprfm(&mut self, rprfop: RprfOp, xm: Register, xn_sp: Register) -> T {
+        self.rprfm_custom(rprfop.encode(), xm, xn_sp)
+    }
+
+    /// [RPRFM - Range Prefetch Memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RPRFM--Range-Prefetch-Memory-?lang=en)
+    ///
+    /// Range Prefetch Memory signals the memory system that data memory accesses from a specified range of addresses are likely to occur in the near future. The instruction may also signal to the memory system whether memory accesses are likely to be load or store, and whether they are likely to be streaming or temporal.
+    ///
+    /// `xm` holds the range metadata, `xn_sp` the base address of the range.
+    ///
+    /// The instruction is allocated in the hint space of `PRFM (register)`, so it is treated as a
+    /// prefetch hint by implementations without FEAT_RPRFM.
+    ///
+    /// ```asm
+    /// RPRFM #<imm6>, <Xm>, [<Xn|SP>]
+    /// ```
+    #[inline(always)]
+    fn rprfm_custom(&mut self, imm6: UImm6, xm: Register, xn_sp: Register) -> T {
+        debug_assert!(imm6 < 64, "imm6 must be in range 0 to 63, was {}", imm6);
+        let option = ((imm6 >> 3) & 0b100) | 0b010 | ((imm6 >> 4) & 0b001);
+        let s = (imm6 >> 3) & 1;
+        let rt = 0b11000 | (imm6 & 0b111);
+        emit_ld_st_reg_off(self, 0b11, 0, 0b10, xm, option, s, xn_sp, rt)
+    }
 }
 
 #[cfg(test)]
@@ -883,6 +923,7 @@ mod tests {
     use crate::assert_panic;
     use crate::test_utils::test_producer::TestProducer;
     use crate::types::prefetch_memory::{PrfPolicy, PrfTarget, PrfType};
+    use crate::instruction_producer::InstrProducer;
 
     use super::*;
 
@@ -1156,4 +1197,18 @@ mod tests {
 
         assert_panic!("Should panic: invalid amount"; prod.prfm_reg_prfop(prfop, 3, 4, RegExtendLSL::UXTW, 2));
     }
+
+    #[test]
+    fn test_rprfm() {
+        // the disassembler decodes RPRFM as PRFM hint, so the encodings are compared
+        let mut prod = InstrProducer::new();
+
+        assert_eq!(prod.rprfm(RprfOp::PLDKEEP, 0, 0).encoding(), 0xf8a04818);
+        assert_eq!(prod.rprfm(RprfOp::PSTKEEP, 1, 2).encoding(), 0xf8a14859);
+        assert_eq!(prod.rprfm(RprfOp::PLDSTRM, 3, 31).encoding(), 0xf8a34bfc);
+        assert_eq!(prod.rprfm(RprfOp::PSTSTRM, 4, 5).encoding(), 0xf8a448bd);
+        assert_eq!(prod.rprfm_custom(0b111111, 4, 5).encoding(), 0xf8a4f8bf);
+
+        assert_panic!("Should panic: invalid imm6"; prod.rprfm_custom(64, 4, 5));
+    }
 }
@@ -41,13 +41,21 @@
 //!  - [SETGPT - SETGMT - SETGET - Memory Set with tag setting - unprivileged](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SETGPT--SETGMT--SETGET--Memory-Set-with-tag-setting--unprivileged-?lang=en)
 //!  - [SETGPN - SETGMN - SETGEN - Memory Set with tag setting - non temporal](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SETGPN--SETGMN--SETGEN--Memory-Set-with-tag-setting--non-temporal-?lang=en)
 //!  - [SETGPTN - SETGMTN - SETGETN - Memory Set with tag setting - unprivileged and non temporal](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SETGPTN--SETGMTN--SETGETN--Memory-Set-with-tag-setting--unprivileged-and-non-temporal-?lang=en)
+//!
+//! The macro-operations `memcpy`, `memmove` and `memset` emit the matching prologue, main and
+//! epilogue instructions for the options in [`MopsOptions`](crate::types::mops::MopsOptions).
 
+#[allow(unused_imports)]
 use bit_seq::bseq_32;
 
 use crate::instruction_encoding::InstructionProcessor;
+#[allow(unused_imports)]
+use crate::types::mops::MopsOptions;
+#[allow(unused_imports)]
 use crate::types::Register;
 
 #[inline(always)]
+#[cfg(feature = "arm_feat_mops")]
 fn emit_mem_cpy_mem_set<P: InstructionProcessor<T>, T>(
     proc: &mut P,
     size: u8,
@@ -62,6 +70,23 @@ fn emit_mem_cpy_mem_set<P: InstructionProcessor<T>, T>(
     proc.process(r)
 }
 
+#[inline(always)]
+#[cfg(feature = "arm_feat_mops")]
+fn debug_assert_mem_cpy_regs(xd: Register, xs: Register, xn: Register, opts: MopsOptions) {
+    debug_assert!(
+        xd != xs && xd != xn && xs != xn,
+        "xd, xs and xn must be distinct, was x{}, x{} and x{}",
+        xd,
+        xs,
+        xn
+    );
+    debug_assert!(
+        xd != 31 && xs != 31 && xn != 31,
+        "xd, xs and xn must not be xzr"
+    );
+    debug_assert!(!opts.set_tags, "set_tags is not allowed for memory copies");
+}
+
 /// # [Memory Copy and Memory Set](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/Loads-and-Stores?lang=en#memcms)
 ///
 /// Implements the following instructions:
@@ -105,6 +130,9 @@ fn emit_mem_cpy_mem_set<P: InstructionProcessor<T>, T>(
 ///  - [SETGPT - SETGMT - SETGET - Memory Set with tag setting - unprivileged](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SETGPT--SETGMT--SETGET--Memory-Set-with-tag-setting--unprivileged-?lang=en)
 ///  - [SETGPN - SETGMN - SETGEN - Memory Set with tag setting - non temporal](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SETGPN--SETGMN--SETGEN--Memory-Set-with-tag-setting--non-temporal-?lang=en)
 ///  - [SETGPTN - SETGMTN - SETGETN - Memory Set with tag setting - unprivileged and non temporal](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/SETGPTN--SETGMTN--SETGETN--Memory-Set-with-tag-setting--unprivileged-and-non-temporal-?lang=en)
+///
+/// The macro-operations `memcpy`, `memmove` and `memset` emit the matching prologue, main and
+/// epilogue instructions for the options in [`MopsOptions`](crate::types::mops::MopsOptions).
 pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     /// [CPYFP - CPYFM - CPYFE - Memory Copy Forward only](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CPYFP--CPYFM--CPYFE--Memory-Copy-Forward-only-?lang=en)
     ///
@@ -118,6 +146,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfe(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0, 0, 0b10, xs, 0, xn, xd)
     }
@@ -134,6 +163,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfm(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b01, xs, 0, xn, xd)
     }
@@ -150,6 +180,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfp(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b00, xs, 0, xn, xd)
     }
@@ -166,6 +197,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfewt(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b10, xs, 0b0001, xn, xd)
     }
@@ -182,6 +214,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfmwt(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b01, xs, 0b0001, xn, xd)
     }
@@ -198,6 +231,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfpwt(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b00, xs, 0b0001, xn, xd)
     }
@@ -214,6 +248,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfert(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b10, xs, 0b0010, xn, xd)
     }
@@ -230,6 +265,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfmrt(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b01, xs, 0b0010, xn, xd)
     }
@@ -246,6 +282,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfprt(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b00, xs, 0b0010, xn, xd)
     }
@@ -262,6 +299,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfet(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b10, xs, 0b0011, xn, xd)
     }
@@ -278,6 +316,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfmt(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b01, xs, 0b0011, xn, xd)
     }
@@ -294,6 +333,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfpt(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b00, xs, 0b0011, xn, xd)
     }
@@ -310,6 +350,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfewn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b10, xs, 0b0100, xn, xd)
     }
@@ -326,6 +367,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfmwn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b01, xs, 0b0100, xn, xd)
     }
@@ -342,6 +384,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfpwn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b00, xs, 0b0100, xn, xd)
     }
@@ -358,6 +401,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfewtwn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b10, xs, 0b0101, xn, xd)
     }
@@ -374,6 +418,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfmwtwn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b01, xs, 0b0101, xn, xd)
     }
@@ -390,6 +435,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfpwtwn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b00, xs, 0b0101, xn, xd)
     }
@@ -406,6 +452,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfertwn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b10, xs, 0b0110, xn, xd)
     }
@@ -422,6 +469,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfmrtwn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b01, xs, 0b0110, xn, xd)
     }
@@ -438,6 +486,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfprtwn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b00, xs, 0b0110, xn, xd)
     }
@@ -454,6 +503,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfetwn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b10, xs, 0b0111, xn, xd)
     }
@@ -470,6 +520,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfmtwn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b01, xs, 0b0111, xn, xd)
     }
@@ -486,6 +537,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfptwn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b0, xs, 0b0111, xn, xd)
     }
@@ -502,6 +554,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfern(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b10, xs, 0b1000, xn, xd)
     }
@@ -518,6 +571,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfmrn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b01, xs, 0b1000, xn, xd)
     }
@@ -534,6 +588,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfprn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b0, xs, 0b1000, xn, xd)
     }
@@ -550,6 +605,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfewtrn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b10, xs, 0b1001, xn, xd)
     }
@@ -566,6 +622,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfmwtrn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b01, xs, 0b1001, xn, xd)
     }
@@ -582,6 +639,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfpwtrn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b0, xs, 0b1001, xn, xd)
     }
@@ -598,6 +656,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfertrn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b10, xs, 0b1010, xn, xd)
     }
@@ -614,6 +673,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfmrtrn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b01, xs, 0b1010, xn, xd)
     }
@@ -630,6 +690,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfprtrn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b00, xs, 0b1010, xn, xd)
     }
@@ -646,6 +707,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfetrn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b10, xs, 0b1011, xn, xd)
     }
@@ -662,6 +724,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfmtrn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b01, xs, 0b1011, xn, xd)
     }
@@ -678,6 +741,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfptrn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b0, xs, 0b1011, xn, xd)
     }
@@ -694,6 +758,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfen(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b10, xs, 0b1100, xn, xd)
     }
@@ -710,6 +775,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfmn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b1, xs, 0b1100, xn, xd)
     }
@@ -726,6 +792,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfpn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b0, xs, 0b1100, xn, xd)
     }
@@ -742,6 +809,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfewtn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b10, xs, 0b1101, xn, xd)
     }
@@ -758,6 +826,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfmwtn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b1, xs, 0b1101, xn, xd)
     }
@@ -774,6 +843,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfpwtn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b1, xs, 0b1101, xn, xd)
     }
@@ -790,6 +860,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfertn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b10, xs, 0b1110, xn, xd)
     }
@@ -806,6 +877,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfmrtn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b1, xs, 0b1110, xn, xd)
     }
@@ -822,6 +894,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfprtn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b0, xs, 0b1110, xn, xd)
     }
@@ -838,6 +911,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfetn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b10, xs, 0b1111, xn, xd)
     }
@@ -854,6 +928,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfmtn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b1, xs, 0b1111, xn, xd)
     }
@@ -870,6 +945,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyfptn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b0, xs, 0b1111, xn, xd)
     }
@@ -886,6 +962,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn sete(&mut self, xd: Register, xn: Register, xs: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b11, xs, 0b1000, xn, xd)
     }
@@ -902,6 +979,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn setm(&mut self, xd: Register, xn: Register, xs: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b11, xs, 0b0100, xn, xd)
     }
@@ -918,6 +996,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn setp(&mut self, xd: Register, xn: Register, xs: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b11, xs, 0b0000, xn, xd)
     }
@@ -934,6 +1013,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn setet(&mut self, xd: Register, xn: Register, xs: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b11, xs, 0b1001, xn, xd)
     }
@@ -950,6 +1030,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn setmt(&mut self, xd: Register, xn: Register, xs: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b11, xs, 0b0101, xn, xd)
     }
@@ -966,6 +1047,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn setpt(&mut self, xd: Register, xn: Register, xs: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b11, xs, 0b0001, xn, xd)
     }
@@ -982,6 +1064,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn seten(&mut self, xd: Register, xn: Register, xs: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b11, xs, 0b1010, xn, xd)
     }
@@ -998,6 +1081,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn setmn(&mut self, xd: Register, xn: Register, xs: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b11, xs, 0b0110, xn, xd)
     }
@@ -1014,6 +1098,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn setpn(&mut self, xd: Register, xn: Register, xs: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b11, xs, 0b0010, xn, xd)
     }
@@ -1030,6 +1115,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn setetn(&mut self, xd: Register, xn: Register, xs: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b11, xs, 0b1011, xn, xd)
     }
@@ -1046,6 +1132,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn setmtn(&mut self, xd: Register, xn: Register, xs: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b11, xs, 0b0111, xn, xd)
     }
@@ -1062,6 +1149,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn setptn(&mut self, xd: Register, xn: Register, xs: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 0, 0b11, xs, 0b0011, xn, xd)
     }
@@ -1078,6 +1166,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpye(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b10, xs, 0b0000, xn, xd)
     }
@@ -1094,6 +1183,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpym(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b1, xs, 0b0000, xn, xd)
     }
@@ -1110,6 +1200,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyp(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b0, xs, 0b0000, xn, xd)
     }
@@ -1126,6 +1217,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyewt(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b10, xs, 0b0001, xn, xd)
     }
@@ -1142,6 +1234,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpymwt(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b1, xs, 0b0001, xn, xd)
     }
@@ -1158,6 +1251,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpypwt(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b0, xs, 0b0001, xn, xd)
     }
@@ -1174,6 +1268,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyert(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b10, xs, 0b0010, xn, xd)
     }
@@ -1190,6 +1285,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpymrt(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b1, xs, 0b0010, xn, xd)
     }
@@ -1206,6 +1302,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyprt(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b0, xs, 0b0010, xn, xd)
     }
@@ -1222,6 +1319,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyet(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b10, xs, 0b0011, xn, xd)
     }
@@ -1238,6 +1336,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpymt(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b1, xs, 0b0011, xn, xd)
     }
@@ -1254,6 +1353,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpypt(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b0, xs, 0b0011, xn, xd)
     }
@@ -1270,6 +1370,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyewn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b10, xs, 0b0100, xn, xd)
     }
@@ -1286,6 +1387,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpymwn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b1, xs, 0b0100, xn, xd)
     }
@@ -1302,6 +1404,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpypwn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b0, xs, 0b0100, xn, xd)
     }
@@ -1318,6 +1421,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyewtwn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b10, xs, 0b0101, xn, xd)
     }
@@ -1334,6 +1438,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpymwtwn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b1, xs, 0b0101, xn, xd)
     }
@@ -1350,6 +1455,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpypwtwn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b0, xs, 0b0101, xn, xd)
     }
@@ -1366,6 +1472,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyertwn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b10, xs, 0b0110, xn, xd)
     }
@@ -1382,6 +1489,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpymrtwn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b1, xs, 0b0110, xn, xd)
     }
@@ -1398,6 +1506,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyprtwn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b0, xs, 0b0110, xn, xd)
     }
@@ -1414,6 +1523,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyetwn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b10, xs, 0b0111, xn, xd)
     }
@@ -1430,6 +1540,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpymtwn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b1, xs, 0b0111, xn, xd)
     }
@@ -1446,6 +1557,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyptwn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b0, xs, 0b0111, xn, xd)
     }
@@ -1462,6 +1574,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyern(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b10, xs, 0b1000, xn, xd)
     }
@@ -1478,6 +1591,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpymrn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b1, xs, 0b1000, xn, xd)
     }
@@ -1494,6 +1608,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyprn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b0, xs, 0b1000, xn, xd)
     }
@@ -1510,6 +1625,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyewtrn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b10, xs, 0b1001, xn, xd)
     }
@@ -1526,6 +1642,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpymwtrn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b1, xs, 0b1001, xn, xd)
     }
@@ -1542,6 +1659,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpypwtrn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b0, xs, 0b1001, xn, xd)
     }
@@ -1558,6 +1676,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyertrn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b10, xs, 0b1010, xn, xd)
     }
@@ -1574,6 +1693,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyemtrn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b1, xs, 0b1010, xn, xd)
     }
@@ -1590,6 +1710,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyprtrn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b0, xs, 0b1010, xn, xd)
     }
@@ -1606,6 +1727,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyetrn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b10, xs, 0b1011, xn, xd)
     }
@@ -1622,6 +1744,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpymtrn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b1, xs, 0b1011, xn, xd)
     }
@@ -1638,6 +1761,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyptrn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b0, xs, 0b1011, xn, xd)
     }
@@ -1654,6 +1778,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyen(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b10, xs, 0b1100, xn, xd)
     }
@@ -1670,6 +1795,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpymn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b1, xs, 0b1100, xn, xd)
     }
@@ -1686,6 +1812,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpypn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b0, xs, 0b1100, xn, xd)
     }
@@ -1702,6 +1829,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyewtn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b10, xs, 0b1101, xn, xd)
     }
@@ -1718,6 +1846,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpymwtn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b1, xs, 0b1101, xn, xd)
     }
@@ -1734,6 +1863,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpypwtn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b0, xs, 0b1101, xn, xd)
     }
@@ -1750,6 +1880,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyertn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b10, xs, 0b1110, xn, xd)
     }
@@ -1766,6 +1897,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpymrtn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b1, xs, 0b1110, xn, xd)
     }
@@ -1782,6 +1914,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyprtn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b0, xs, 0b1110, xn, xd)
     }
@@ -1798,6 +1931,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyetn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b10, xs, 0b1111, xn, xd)
     }
@@ -1814,6 +1948,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpymtn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b1, xs, 0b1111, xn, xd)
     }
@@ -1830,6 +1965,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn cpyptn(&mut self, xd: Register, xs: Register, xn: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b0, xs, 0b1111, xn, xd)
     }
@@ -1846,6 +1982,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn setge(&mut self, xd: Register, xn: Register, xs: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b11, xs, 0b1000, xn, xd)
     }
@@ -1862,6 +1999,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn setgm(&mut self, xd: Register, xn: Register, xs: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b11, xs, 0b0100, xn, xd)
     }
@@ -1878,6 +2016,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn setgp(&mut self, xd: Register, xn: Register, xs: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b11, xs, 0b0000, xn, xd)
     }
@@ -1894,6 +2033,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn setget(&mut self, xd: Register, xn: Register, xs: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b11, xs, 0b1001, xn, xd)
     }
@@ -1910,6 +2050,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn setgmt(&mut self, xd: Register, xn: Register, xs: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b11, xs, 0b0101, xn, xd)
     }
@@ -1926,6 +2067,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn setgpt(&mut self, xd: Register, xn: Register, xs: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b11, xs, 0b0001, xn, xd)
     }
@@ -1942,6 +2084,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn setgen(&mut self, xd: Register, xn: Register, xs: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b11, xs, 0b1010, xn, xd)
     }
@@ -1958,6 +2101,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn setgmn(&mut self, xd: Register, xn: Register, xs: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b11, xs, 0b0110, xn, xd)
     }
@@ -1974,6 +2118,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn setgpn(&mut self, xd: Register, xn: Register, xs: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b11, xs, 0b0010, xn, xd)
     }
@@ -1990,6 +2135,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn setgetn(&mut self, xd: Register, xn: Register, xs: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b11, xs, 0b1011, xn, xd)
     }
@@ -2006,6 +2152,7 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn setgmtn(&mut self, xd: Register, xn: Register, xs: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b11, xs, 0b0111, xn, xd)
     }
@@ -2022,7 +2169,174 @@ pub trait MemoryCopyAndMemorySet<T>: InstructionProcessor<T> {
     ///
     /// **Warning**: Not tested
     #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
     fn setgptn(&mut self, xd: Register, xn: Register, xs: Register) -> T {
         emit_mem_cpy_mem_set(self, 0b00, 1, 0b11, xs, 0b0011, xn, xd)
     }
+
+    /// Emits a forward-only memory copy of `xn` bytes from `[xs]` to `[xd]` as the matching
+    /// prologue, main and epilogue instruction triple (CPYFP*, CPYFM*, CPYFE*).
+    ///
+    /// The source and destination must not overlap, use [`memmove`](Self::memmove) otherwise.
+    /// `opts` selects the unprivileged and non-temporal variants, `set_tags` is not allowed.
+    /// All three registers are updated by the copy, they must be distinct and must not be `XZR`.
+    ///
+    /// ```asm
+    /// CPYFP<opts> [<Xd>]!, [<Xs>]!, <Xn>!
+    /// CPYFM<opts> [<Xd>]!, [<Xs>]!, <Xn>!
+    /// CPYFE<opts> [<Xd>]!, [<Xs>]!, <Xn>!
+    /// ```
+    ///
+    /// *Note*: FEAT_MOPS required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
+    fn memcpy(&mut self, xd: Register, xs: Register, xn: Register, opts: MopsOptions) -> Vec<T> {
+        debug_assert_mem_cpy_regs(xd, xs, xn, opts);
+        let op2 = opts.encode_cpy();
+        vec![
+            emit_mem_cpy_mem_set(self, 0, 0, 0b00, xs, op2, xn, xd),
+            emit_mem_cpy_mem_set(self, 0, 0, 0b01, xs, op2, xn, xd),
+            emit_mem_cpy_mem_set(self, 0, 0, 0b10, xs, op2, xn, xd),
+        ]
+    }
+
+    /// Emits a memory copy of `xn` bytes from `[xs]` to `[xd]` that handles overlapping source
+    /// and destination, as the matching prologue, main and epilogue instruction triple
+    /// (CPYP*, CPYM*, CPYE*).
+    ///
+    /// `opts` selects the unprivileged and non-temporal variants, `set_tags` is not allowed.
+    /// All three registers are updated by the copy, they must be distinct and must not be `XZR`.
+    ///
+    /// ```asm
+    /// CPYP<opts> [<Xd>]!, [<Xs>]!, <Xn>!
+    /// CPYM<opts> [<Xd>]!, [<Xs>]!, <Xn>!
+    /// CPYE<opts> [<Xd>]!, [<Xs>]!, <Xn>!
+    /// ```
+    ///
+    /// *Note*: FEAT_MOPS required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
+    fn memmove(&mut self, xd: Register, xs: Register, xn: Register, opts: MopsOptions) -> Vec<T> {
+        debug_assert_mem_cpy_regs(xd, xs, xn, opts);
+        let op2 = opts.encode_cpy();
+        vec![
+            emit_mem_cpy_mem_set(self, 0, 1, 0b00, xs, op2, xn, xd),
+            emit_mem_cpy_mem_set(self, 0, 1, 0b01, xs, op2, xn, xd),
+            emit_mem_cpy_mem_set(self, 0, 1, 0b10, xs, op2, xn, xd),
+        ]
+    }
+
+    /// Emits a memory set of `xn` bytes at `[xd]` to the bottom byte of `xs` as the matching
+    /// prologue, main and epilogue instruction triple (SETP*, SETM*, SETE*, or SETGP*, SETGM*,
+    /// SETGE* if `set_tags` is selected).
+    ///
+    /// `opts` selects the unprivileged and non-temporal write variants, the read options are
+    /// ignored. `xd` and `xn` are updated by the set, they must be distinct from each other and
+    /// from `xs` and must not be `XZR`. `xs` may be `XZR` to set the memory to zero.
+    ///
+    /// ```asm
+    /// SETP<opts> [<Xd>]!, <Xn>!, <Xs>
+    /// SETM<opts> [<Xd>]!, <Xn>!, <Xs>
+    /// SETE<opts> [<Xd>]!, <Xn>!, <Xs>
+    /// ```
+    ///
+    /// *Note*: FEAT_MOPS required, `set_tags` additionally requires FEAT_MTE
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_mops")]
+    fn memset(&mut self, xd: Register, xs: Register, xn: Register, opts: MopsOptions) -> Vec<T> {
+        debug_assert!(
+            xd != xn && xd != xs && xn != xs,
+            "xd, xn and xs must be distinct, was x{}, x{} and x{}",
+            xd,
+            xn,
+            xs
+        );
+        debug_assert!(xd != 31 && xn != 31, "xd and xn must not be xzr");
+        let o0 = opts.set_tags as u8;
+        let op2 = opts.encode_set();
+        vec![
+            emit_mem_cpy_mem_set(self, 0, o0, 0b11, xs, op2, xn, xd),
+            emit_mem_cpy_mem_set(self, 0, o0, 0b11, xs, 0b0100 | op2, xn, xd),
+            emit_mem_cpy_mem_set(self, 0, o0, 0b11, xs, 0b1000 | op2, xn, xd),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use crate::assert_panic;
+
+    #[allow(unused_imports)]
+    use super::*;
+    #[allow(unused_imports)]
+    use crate::types::instruction::Instr;
+
+    #[allow(dead_code)]
+    fn encodings(instrs: Vec<Instr>) -> Vec<u32> {
+        instrs.iter().map(|i| i.encoding()).collect()
+    }
+
+    #[cfg(feature = "arm_feat_mops")]
+    #[test]
+    fn test_memcpy_memmove() {
+        use crate::instruction_producer::InstrProducer;
+
+        // the disassembler does not support FEAT_MOPS, so the encodings are compared
+        let mut prod = InstrProducer::new();
+
+        let instrs = prod.memcpy(0, 1, 2, MopsOptions::default());
+        assert_eq!(encodings(instrs), [0x19010440, 0x19410440, 0x19810440]);
+
+        let opts = MopsOptions {
+            unprivileged_reads: true,
+            non_temporal_writes: true,
+            ..Default::default()
+        };
+        let instrs = prod.memcpy(0, 1, 2, opts);
+        assert_eq!(encodings(instrs), [0x19016440, 0x19416440, 0x19816440]);
+
+        let instrs = prod.memmove(3, 4, 5, MopsOptions::NON_TEMPORAL);
+        assert_eq!(encodings(instrs), [0x1d04c4a3, 0x1d44c4a3, 0x1d84c4a3]);
+
+        let instrs = prod.memmove(3, 4, 5, MopsOptions::UNPRIVILEGED);
+        assert_eq!(encodings(instrs), [0x1d0434a3, 0x1d4434a3, 0x1d8434a3]);
+
+        assert_panic!("Should panic: xd == xn"; prod.memcpy(1, 2, 1, MopsOptions::default()));
+        assert_panic!("Should panic: xs is xzr"; prod.memmove(1, 31, 2, MopsOptions::default()));
+        let opts = MopsOptions {
+            set_tags: true,
+            ..Default::default()
+        };
+        assert_panic!("Should panic: set_tags"; prod.memcpy(0, 1, 2, opts));
+    }
+
+    #[cfg(feature = "arm_feat_mops")]
+    #[test]
+    fn test_memset() {
+        use crate::instruction_producer::InstrProducer;
+
+        // the disassembler does not support FEAT_MOPS, so the encodings are compared
+        let mut prod = InstrProducer::new();
+
+        let instrs = prod.memset(0, 1, 2, MopsOptions::default());
+        assert_eq!(encodings(instrs), [0x19c10440, 0x19c14440, 0x19c18440]);
+
+        let instrs = prod.memset(0, 31, 2, MopsOptions::UNPRIVILEGED);
+        assert_eq!(encodings(instrs), [0x19df1440, 0x19df5440, 0x19df9440]);
+
+        let opts = MopsOptions {
+            non_temporal_writes: true,
+            set_tags: true,
+            ..Default::default()
+        };
+        let instrs = prod.memset(6, 8, 7, opts);
+        assert_eq!(encodings(instrs), [0x1dc824e6, 0x1dc864e6, 0x1dc8a4e6]);
+
+        assert_panic!("Should panic: xd == xs"; prod.memset(1, 1, 2, MopsOptions::default()));
+        assert_panic!("Should panic: xn is xzr"; prod.memset(1, 2, 31, MopsOptions::default()));
+
+        let instrs = prod.memset(0, 1, 2, MopsOptions::NON_TEMPORAL);
+        assert_eq!(encodings(instrs), [0x19c12440, 0x19c16440, 0x19c1a440]);
+    }
 }
@@ -0,0 +1,1390 @@
+//! # Read-check-write
+//!
+//! Implements the following instructions:
+//!  - [RCWCAS - RCWCASA - RCWCASAL - RCWCASL - Read Check Write Compare and Swap doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWCAS--RCWCASA--RCWCASAL--RCWCASL--Read-Check-Write-Compare-and-Swap-doubleword-in-memory-?lang=en)
+//!  - [RCWCASP - RCWCASPA - RCWCASPAL - RCWCASPL - Read Check Write Compare and Swap quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWCASP--RCWCASPA--RCWCASPAL--RCWCASPL--Read-Check-Write-Compare-and-Swap-quadword-in-memory-?lang=en)
+//!  - [RCWCLR - RCWCLRA - RCWCLRAL - RCWCLRL - Read Check Write atomic bit Clear on doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWCLR--RCWCLRA--RCWCLRAL--RCWCLRL--Read-Check-Write-atomic-bit-Clear-on-doubleword-in-memory-?lang=en)
+//!  - [RCWCLRP - RCWCLRPA - RCWCLRPAL - RCWCLRPL - Read Check Write atomic bit Clear on quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWCLRP--RCWCLRPA--RCWCLRPAL--RCWCLRPL--Read-Check-Write-atomic-bit-Clear-on-quadword-in-memory-?lang=en)
+//!  - [RCWSET - RCWSETA - RCWSETAL - RCWSETL - Read Check Write atomic bit Set on doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSET--RCWSETA--RCWSETAL--RCWSETL--Read-Check-Write-atomic-bit-Set-on-doubleword-in-memory-?lang=en)
+//!  - [RCWSETP - RCWSETPA - RCWSETPAL - RCWSETPL - Read Check Write atomic bit Set on quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSETP--RCWSETPA--RCWSETPAL--RCWSETPL--Read-Check-Write-atomic-bit-Set-on-quadword-in-memory-?lang=en)
+//!  - [RCWSWP - RCWSWPA - RCWSWPAL - RCWSWPL - Read Check Write Swap doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSWP--RCWSWPA--RCWSWPAL--RCWSWPL--Read-Check-Write-Swap-doubleword-in-memory-?lang=en)
+//!  - [RCWSWPP - RCWSWPPA - RCWSWPPAL - RCWSWPPL - Read Check Write Swap quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSWPP--RCWSWPPA--RCWSWPPAL--RCWSWPPL--Read-Check-Write-Swap-quadword-in-memory-?lang=en)
+//!  - [RCWSCAS - RCWSCASA - RCWSCASAL - RCWSCASL - Read Check Write Software Compare and Swap doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSCAS--RCWSCASA--RCWSCASAL--RCWSCASL--Read-Check-Write-Software-Compare-and-Swap-doubleword-in-memory-?lang=en)
+//!  - [RCWSCASP - RCWSCASPA - RCWSCASPAL - RCWSCASPL - Read Check Write Software Compare and Swap quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSCASP--RCWSCASPA--RCWSCASPAL--RCWSCASPL--Read-Check-Write-Software-Compare-and-Swap-quadword-in-memory-?lang=en)
+//!  - [RCWSCLR - RCWSCLRA - RCWSCLRAL - RCWSCLRL - Read Check Write Software atomic bit Clear on doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSCLR--RCWSCLRA--RCWSCLRAL--RCWSCLRL--Read-Check-Write-Software-atomic-bit-Clear-on-doubleword-in-memory-?lang=en)
+//!  - [RCWSCLRP - RCWSCLRPA - RCWSCLRPAL - RCWSCLRPL - Read Check Write Software atomic bit Clear on quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSCLRP--RCWSCLRPA--RCWSCLRPAL--RCWSCLRPL--Read-Check-Write-Software-atomic-bit-Clear-on-quadword-in-memory-?lang=en)
+//!  - [RCWSSET - RCWSSETA - RCWSSETAL - RCWSSETL - Read Check Write Software atomic bit Set on doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSSET--RCWSSETA--RCWSSETAL--RCWSSETL--Read-Check-Write-Software-atomic-bit-Set-on-doubleword-in-memory-?lang=en)
+//!  - [RCWSSETP - RCWSSETPA - RCWSSETPAL - RCWSSETPL - Read Check Write Software atomic bit Set on quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSSETP--RCWSSETPA--RCWSSETPAL--RCWSSETPL--Read-Check-Write-Software-atomic-bit-Set-on-quadword-in-memory-?lang=en)
+//!  - [RCWSSWP - RCWSSWPA - RCWSSWPAL - RCWSSWPL - Read Check Write Software Swap doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSSWP--RCWSSWPA--RCWSSWPAL--RCWSSWPL--Read-Check-Write-Software-Swap-doubleword-in-memory-?lang=en)
+//!  - [RCWSSWPP - RCWSSWPPA - RCWSSWPPAL - RCWSSWPPL - Read Check Write Software Swap quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSSWPP--RCWSSWPPA--RCWSSWPPAL--RCWSSWPPL--Read-Check-Write-Software-Swap-quadword-in-memory-?lang=en)
+
+#[allow(unused_imports)]
+use bit_seq::bseq_32;
+
+use crate::instruction_encoding::InstructionProcessor;
+#[allow(unused_imports)]
+use crate::types::Register;
+
+/// Emits a RCW compare and swap instruction, `p` selects the quadword (pair) form.
+#[inline(always)]
+#[cfg(feature = "arm_feat_the")]
+fn emit_rcw_cas<P: InstructionProcessor<T>, T>(
+    proc: &mut P,
+    s: u8,
+    a: u8,
+    r: u8,
+    rs: Register,
+    p: u8,
+    rn: Register,
+    rt: Register,
+) -> T {
+    let i = bseq_32!(0 s:1 011001 a:1 r:1 1 rs:5 00001 p:1 rn:5 rt:5);
+    proc.process(i)
+}
+
+/// Emits a RCW atomic memory operation on a doubleword.
+#[inline(always)]
+#[cfg(feature = "arm_feat_the")]
+fn emit_rcw_mem_op<P: InstructionProcessor<T>, T>(
+    proc: &mut P,
+    s: u8,
+    a: u8,
+    r: u8,
+    rs: Register,
+    opc: u8,
+    rn: Register,
+    rt: Register,
+) -> T {
+    let i = bseq_32!(0 s:1 111000 a:1 r:1 1 rs:5 1 opc:3 00 rn:5 rt:5);
+    proc.process(i)
+}
+
+/// Emits a RCW atomic memory operation on a quadword.
+#[inline(always)]
+#[cfg(feature = "arm_feat_the")]
+fn emit_rcw_mem_op_128<P: InstructionProcessor<T>, T>(
+    proc: &mut P,
+    s: u8,
+    a: u8,
+    r: u8,
+    rt2: Register,
+    opc: u8,
+    rn: Register,
+    rt: Register,
+) -> T {
+    debug_assert!(rt != rt2, "xt1 and xt2 must be different registers");
+    let i = bseq_32!(0 s:1 011001 a:1 r:1 1 rt2:5 1 opc:3 00 rn:5 rt:5);
+    proc.process(i)
+}
+
+/// # Read-check-write
+///
+/// Implements the following instructions:
+///  - [RCWCAS - RCWCASA - RCWCASAL - RCWCASL - Read Check Write Compare and Swap doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWCAS--RCWCASA--RCWCASAL--RCWCASL--Read-Check-Write-Compare-and-Swap-doubleword-in-memory-?lang=en)
+///  - [RCWCASP - RCWCASPA - RCWCASPAL - RCWCASPL - Read Check Write Compare and Swap quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWCASP--RCWCASPA--RCWCASPAL--RCWCASPL--Read-Check-Write-Compare-and-Swap-quadword-in-memory-?lang=en)
+///  - [RCWCLR - RCWCLRA - RCWCLRAL - RCWCLRL - Read Check Write atomic bit Clear on doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWCLR--RCWCLRA--RCWCLRAL--RCWCLRL--Read-Check-Write-atomic-bit-Clear-on-doubleword-in-memory-?lang=en)
+///  - [RCWCLRP - RCWCLRPA - RCWCLRPAL - RCWCLRPL - Read Check Write atomic bit Clear on quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWCLRP--RCWCLRPA--RCWCLRPAL--RCWCLRPL--Read-Check-Write-atomic-bit-Clear-on-quadword-in-memory-?lang=en)
+///  - [RCWSET - RCWSETA - RCWSETAL - RCWSETL - Read Check Write atomic bit Set on doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSET--RCWSETA--RCWSETAL--RCWSETL--Read-Check-Write-atomic-bit-Set-on-doubleword-in-memory-?lang=en)
+///  - [RCWSETP - RCWSETPA - RCWSETPAL - RCWSETPL - Read Check Write atomic bit Set on quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSETP--RCWSETPA--RCWSETPAL--RCWSETPL--Read-Check-Write-atomic-bit-Set-on-quadword-in-memory-?lang=en)
+///  - [RCWSWP - RCWSWPA - RCWSWPAL - RCWSWPL - Read Check Write Swap doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSWP--RCWSWPA--RCWSWPAL--RCWSWPL--Read-Check-Write-Swap-doubleword-in-memory-?lang=en)
+///  - [RCWSWPP - RCWSWPPA - RCWSWPPAL - RCWSWPPL - Read Check Write Swap quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSWPP--RCWSWPPA--RCWSWPPAL--RCWSWPPL--Read-Check-Write-Swap-quadword-in-memory-?lang=en)
+///  - [RCWSCAS - RCWSCASA - RCWSCASAL - RCWSCASL - Read Check Write Software Compare and Swap doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSCAS--RCWSCASA--RCWSCASAL--RCWSCASL--Read-Check-Write-Software-Compare-and-Swap-doubleword-in-memory-?lang=en)
+///  - [RCWSCASP - RCWSCASPA - RCWSCASPAL - RCWSCASPL - Read Check Write Software Compare and Swap quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSCASP--RCWSCASPA--RCWSCASPAL--RCWSCASPL--Read-Check-Write-Software-Compare-and-Swap-quadword-in-memory-?lang=en)
+///  - [RCWSCLR - RCWSCLRA - RCWSCLRAL - RCWSCLRL - Read Check Write Software atomic bit Clear on doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSCLR--RCWSCLRA--RCWSCLRAL--RCWSCLRL--Read-Check-Write-Software-atomic-bit-Clear-on-doubleword-in-memory-?lang=en)
+///  - [RCWSCLRP - RCWSCLRPA - RCWSCLRPAL - RCWSCLRPL - Read Check Write Software atomic bit Clear on quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSCLRP--RCWSCLRPA--RCWSCLRPAL--RCWSCLRPL--Read-Check-Write-Software-atomic-bit-Clear-on-quadword-in-memory-?lang=en)
+///  - [RCWSSET - RCWSSETA - RCWSSETAL - RCWSSETL - Read Check Write Software atomic bit Set on doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSSET--RCWSSETA--RCWSSETAL--RCWSSETL--Read-Check-Write-Software-atomic-bit-Set-on-doubleword-in-memory-?lang=en)
+///  - [RCWSSETP - RCWSSETPA - RCWSSETPAL - RCWSSETPL - Read Check Write Software atomic bit Set on quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSSETP--RCWSSETPA--RCWSSETPAL--RCWSSETPL--Read-Check-Write-Software-atomic-bit-Set-on-quadword-in-memory-?lang=en)
+///  - [RCWSSWP - RCWSSWPA - RCWSSWPAL - RCWSSWPL - Read Check Write Software Swap doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSSWP--RCWSSWPA--RCWSSWPAL--RCWSSWPL--Read-Check-Write-Software-Swap-doubleword-in-memory-?lang=en)
+///  - [RCWSSWPP - RCWSSWPPA - RCWSSWPPAL - RCWSSWPPL - Read Check Write Software Swap quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSSWPP--RCWSSWPPA--RCWSSWPPAL--RCWSSWPPL--Read-Check-Write-Software-Swap-quadword-in-memory-?lang=en)
+pub trait ReadCheckWrite<T>: InstructionProcessor<T> {
+    /// [RCWCAS - Read Check Write Compare and Swap doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWCAS--RCWCASA--RCWCASAL--RCWCASL--Read-Check-Write-Compare-and-Swap-doubleword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Compare and Swap doubleword in memory reads a 64-bit doubleword from memory, and compares it against the value held in a register. If the comparison is equal, the value in a second register is conditionally written to memory. Storing back to memory is conditional on RCW Checks. If the write is performed, the read and the write occur atomically such that no other modification of the memory location can take place between the read and the write.
+    ///
+    /// ```asm
+    /// RCWCAS <Xs>, <Xt>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwcas(&mut self, xs: Register, xt: Register, xn_sp: Register) -> T {
+        emit_rcw_cas(self, 0, 0, 0, xs, 0, xn_sp, xt)
+    }
+
+    /// [RCWCASA - Read Check Write Compare and Swap doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWCAS--RCWCASA--RCWCASAL--RCWCASL--Read-Check-Write-Compare-and-Swap-doubleword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Compare and Swap doubleword in memory reads a 64-bit doubleword from memory, and compares it against the value held in a register. If the comparison is equal, the value in a second register is conditionally written to memory. Storing back to memory is conditional on RCW Checks. If the write is performed, the read and the write occur atomically such that no other modification of the memory location can take place between the read and the write.
+    ///
+    /// The instruction has acquire semantics on the load from memory.
+    ///
+    /// ```asm
+    /// RCWCASA <Xs>, <Xt>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwcasa(&mut self, xs: Register, xt: Register, xn_sp: Register) -> T {
+        emit_rcw_cas(self, 0, 1, 0, xs, 0, xn_sp, xt)
+    }
+
+    /// [RCWCASAL - Read Check Write Compare and Swap doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWCAS--RCWCASA--RCWCASAL--RCWCASL--Read-Check-Write-Compare-and-Swap-doubleword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Compare and Swap doubleword in memory reads a 64-bit doubleword from memory, and compares it against the value held in a register. If the comparison is equal, the value in a second register is conditionally written to memory. Storing back to memory is conditional on RCW Checks. If the write is performed, the read and the write occur atomically such that no other modification of the memory location can take place between the read and the write.
+    ///
+    /// The instruction has acquire and release semantics on the load from and store to memory.
+    ///
+    /// ```asm
+    /// RCWCASAL <Xs>, <Xt>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwcasal(&mut self, xs: Register, xt: Register, xn_sp: Register) -> T {
+        emit_rcw_cas(self, 0, 1, 1, xs, 0, xn_sp, xt)
+    }
+
+    /// [RCWCASL - Read Check Write Compare and Swap doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWCAS--RCWCASA--RCWCASAL--RCWCASL--Read-Check-Write-Compare-and-Swap-doubleword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Compare and Swap doubleword in memory reads a 64-bit doubleword from memory, and compares it against the value held in a register. If the comparison is equal, the value in a second register is conditionally written to memory. Storing back to memory is conditional on RCW Checks. If the write is performed, the read and the write occur atomically such that no other modification of the memory location can take place between the read and the write.
+    ///
+    /// The instruction has release semantics on the store to memory.
+    ///
+    /// ```asm
+    /// RCWCASL <Xs>, <Xt>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwcasl(&mut self, xs: Register, xt: Register, xn_sp: Register) -> T {
+        emit_rcw_cas(self, 0, 0, 1, xs, 0, xn_sp, xt)
+    }
+
+    /// [RCWCASP - Read Check Write Compare and Swap quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWCASP--RCWCASPA--RCWCASPAL--RCWCASPL--Read-Check-Write-Compare-and-Swap-quadword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Compare and Swap quadword in memory reads a 128-bit quadword from memory, and compares it against the value held in a pair of registers. If the comparison is equal, the value in a second pair of registers is conditionally written to memory. Storing back to memory is conditional on RCW Checks. If the write is performed, the read and the write occur atomically such that no other modification of the memory location can take place between the read and the write.
+    ///
+    /// The method asserts that xs1 and xt1 registers are even and that xs2 and xt2 are the consecutive registers of xs1 and xt1.
+    ///
+    /// ```asm
+    /// RCWCASP <Xs>, <X(s+1)>, <Xt>, <X(t+1)>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE and FEAT_D128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwcasp(
+        &mut self,
+        xs1: Register,
+        xs2: Register,
+        xt1: Register,
+        xt2: Register,
+        xn_sp: Register,
+    ) -> T {
+        debug_assert!(xs1 % 2 == 0, "xs1 must be even");
+        debug_assert!(
+            xs2 == xs1 + 1,
+            "xs2 must be the consecutive register of xs1"
+        );
+        debug_assert!(xt1 % 2 == 0, "xt1 must be even");
+        debug_assert!(
+            xt2 == xt1 + 1,
+            "xt2 must be the consecutive register of xt1"
+        );
+
+        emit_rcw_cas(self, 0, 0, 0, xs1, 1, xn_sp, xt1)
+    }
+
+    /// [RCWCASPA - Read Check Write Compare and Swap quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWCASP--RCWCASPA--RCWCASPAL--RCWCASPL--Read-Check-Write-Compare-and-Swap-quadword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Compare and Swap quadword in memory reads a 128-bit quadword from memory, and compares it against the value held in a pair of registers. If the comparison is equal, the value in a second pair of registers is conditionally written to memory. Storing back to memory is conditional on RCW Checks. If the write is performed, the read and the write occur atomically such that no other modification of the memory location can take place between the read and the write.
+    ///
+    /// The instruction has acquire semantics on the load from memory.
+    ///
+    /// The method asserts that xs1 and xt1 registers are even and that xs2 and xt2 are the consecutive registers of xs1 and xt1.
+    ///
+    /// ```asm
+    /// RCWCASPA <Xs>, <X(s+1)>, <Xt>, <X(t+1)>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE and FEAT_D128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwcaspa(
+        &mut self,
+        xs1: Register,
+        xs2: Register,
+        xt1: Register,
+        xt2: Register,
+        xn_sp: Register,
+    ) -> T {
+        debug_assert!(xs1 % 2 == 0, "xs1 must be even");
+        debug_assert!(
+            xs2 == xs1 + 1,
+            "xs2 must be the consecutive register of xs1"
+        );
+        debug_assert!(xt1 % 2 == 0, "xt1 must be even");
+        debug_assert!(
+            xt2 == xt1 + 1,
+            "xt2 must be the consecutive register of xt1"
+        );
+
+        emit_rcw_cas(self, 0, 1, 0, xs1, 1, xn_sp, xt1)
+    }
+
+    /// [RCWCASPAL - Read Check Write Compare and Swap quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWCASP--RCWCASPA--RCWCASPAL--RCWCASPL--Read-Check-Write-Compare-and-Swap-quadword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Compare and Swap quadword in memory reads a 128-bit quadword from memory, and compares it against the value held in a pair of registers. If the comparison is equal, the value in a second pair of registers is conditionally written to memory. Storing back to memory is conditional on RCW Checks. If the write is performed, the read and the write occur atomically such that no other modification of the memory location can take place between the read and the write.
+    ///
+    /// The instruction has acquire and release semantics on the load from and store to memory.
+    ///
+    /// The method asserts that xs1 and xt1 registers are even and that xs2 and xt2 are the consecutive registers of xs1 and xt1.
+    ///
+    /// ```asm
+    /// RCWCASPAL <Xs>, <X(s+1)>, <Xt>, <X(t+1)>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE and FEAT_D128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwcaspal(
+        &mut self,
+        xs1: Register,
+        xs2: Register,
+        xt1: Register,
+        xt2: Register,
+        xn_sp: Register,
+    ) -> T {
+        debug_assert!(xs1 % 2 == 0, "xs1 must be even");
+        debug_assert!(
+            xs2 == xs1 + 1,
+            "xs2 must be the consecutive register of xs1"
+        );
+        debug_assert!(xt1 % 2 == 0, "xt1 must be even");
+        debug_assert!(
+            xt2 == xt1 + 1,
+            "xt2 must be the consecutive register of xt1"
+        );
+
+        emit_rcw_cas(self, 0, 1, 1, xs1, 1, xn_sp, xt1)
+    }
+
+    /// [RCWCASPL - Read Check Write Compare and Swap quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWCASP--RCWCASPA--RCWCASPAL--RCWCASPL--Read-Check-Write-Compare-and-Swap-quadword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Compare and Swap quadword in memory reads a 128-bit quadword from memory, and compares it against the value held in a pair of registers. If the comparison is equal, the value in a second pair of registers is conditionally written to memory. Storing back to memory is conditional on RCW Checks. If the write is performed, the read and the write occur atomically such that no other modification of the memory location can take place between the read and the write.
+    ///
+    /// The instruction has release semantics on the store to memory.
+    ///
+    /// The method asserts that xs1 and xt1 registers are even and that xs2 and xt2 are the consecutive registers of xs1 and xt1.
+    ///
+    /// ```asm
+    /// RCWCASPL <Xs>, <X(s+1)>, <Xt>, <X(t+1)>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE and FEAT_D128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwcaspl(
+        &mut self,
+        xs1: Register,
+        xs2: Register,
+        xt1: Register,
+        xt2: Register,
+        xn_sp: Register,
+    ) -> T {
+        debug_assert!(xs1 % 2 == 0, "xs1 must be even");
+        debug_assert!(
+            xs2 == xs1 + 1,
+            "xs2 must be the consecutive register of xs1"
+        );
+        debug_assert!(xt1 % 2 == 0, "xt1 must be even");
+        debug_assert!(
+            xt2 == xt1 + 1,
+            "xt2 must be the consecutive register of xt1"
+        );
+
+        emit_rcw_cas(self, 0, 0, 1, xs1, 1, xn_sp, xt1)
+    }
+
+    /// [RCWCLR - Read Check Write atomic bit Clear on doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWCLR--RCWCLRA--RCWCLRAL--RCWCLRL--Read-Check-Write-atomic-bit-Clear-on-doubleword-in-memory-?lang=en)
+    ///
+    /// Read Check Write atomic bit Clear on doubleword in memory atomically loads a 64-bit doubleword from memory, performs a bitwise AND with the complement of the value held in a register on it, and conditionally stores the result back to memory. Storing of the result back to memory is conditional on RCW Checks. The value initially loaded from memory is returned in the destination register.
+    ///
+    /// ```asm
+    /// RCWCLR <Xs>, <Xt>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwclr(&mut self, xs: Register, xt: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op(self, 0, 0, 0, xs, 0b001, xn_sp, xt)
+    }
+
+    /// [RCWCLRA - Read Check Write atomic bit Clear on doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWCLR--RCWCLRA--RCWCLRAL--RCWCLRL--Read-Check-Write-atomic-bit-Clear-on-doubleword-in-memory-?lang=en)
+    ///
+    /// Read Check Write atomic bit Clear on doubleword in memory atomically loads a 64-bit doubleword from memory, performs a bitwise AND with the complement of the value held in a register on it, and conditionally stores the result back to memory. Storing of the result back to memory is conditional on RCW Checks. The value initially loaded from memory is returned in the destination register.
+    ///
+    /// The instruction has acquire semantics on the load from memory.
+    ///
+    /// ```asm
+    /// RCWCLRA <Xs>, <Xt>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwclra(&mut self, xs: Register, xt: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op(self, 0, 1, 0, xs, 0b001, xn_sp, xt)
+    }
+
+    /// [RCWCLRAL - Read Check Write atomic bit Clear on doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWCLR--RCWCLRA--RCWCLRAL--RCWCLRL--Read-Check-Write-atomic-bit-Clear-on-doubleword-in-memory-?lang=en)
+    ///
+    /// Read Check Write atomic bit Clear on doubleword in memory atomically loads a 64-bit doubleword from memory, performs a bitwise AND with the complement of the value held in a register on it, and conditionally stores the result back to memory. Storing of the result back to memory is conditional on RCW Checks. The value initially loaded from memory is returned in the destination register.
+    ///
+    /// The instruction has acquire and release semantics on the load from and store to memory.
+    ///
+    /// ```asm
+    /// RCWCLRAL <Xs>, <Xt>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwclral(&mut self, xs: Register, xt: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op(self, 0, 1, 1, xs, 0b001, xn_sp, xt)
+    }
+
+    /// [RCWCLRL - Read Check Write atomic bit Clear on doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWCLR--RCWCLRA--RCWCLRAL--RCWCLRL--Read-Check-Write-atomic-bit-Clear-on-doubleword-in-memory-?lang=en)
+    ///
+    /// Read Check Write atomic bit Clear on doubleword in memory atomically loads a 64-bit doubleword from memory, performs a bitwise AND with the complement of the value held in a register on it, and conditionally stores the result back to memory. Storing of the result back to memory is conditional on RCW Checks. The value initially loaded from memory is returned in the destination register.
+    ///
+    /// The instruction has release semantics on the store to memory.
+    ///
+    /// ```asm
+    /// RCWCLRL <Xs>, <Xt>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwclrl(&mut self, xs: Register, xt: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op(self, 0, 0, 1, xs, 0b001, xn_sp, xt)
+    }
+
+    /// [RCWCLRP - Read Check Write atomic bit Clear on quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWCLRP--RCWCLRPA--RCWCLRPAL--RCWCLRPL--Read-Check-Write-atomic-bit-Clear-on-quadword-in-memory-?lang=en)
+    ///
+    /// Read Check Write atomic bit Clear on quadword in memory atomically loads a 128-bit quadword from memory, performs a bitwise AND with the complement of the value held in a pair of registers on it, and conditionally stores the result back to memory. Storing of the result back to memory is conditional on RCW Checks. The value initially loaded from memory is returned in the same pair of registers.
+    ///
+    /// ```asm
+    /// RCWCLRP <Xt1>, <Xt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE and FEAT_D128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwclrp(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op_128(self, 0, 0, 0, xt2, 0b001, xn_sp, xt1)
+    }
+
+    /// [RCWCLRPA - Read Check Write atomic bit Clear on quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWCLRP--RCWCLRPA--RCWCLRPAL--RCWCLRPL--Read-Check-Write-atomic-bit-Clear-on-quadword-in-memory-?lang=en)
+    ///
+    /// Read Check Write atomic bit Clear on quadword in memory atomically loads a 128-bit quadword from memory, performs a bitwise AND with the complement of the value held in a pair of registers on it, and conditionally stores the result back to memory. Storing of the result back to memory is conditional on RCW Checks. The value initially loaded from memory is returned in the same pair of registers.
+    ///
+    /// The instruction has acquire semantics on the load from memory.
+    ///
+    /// ```asm
+    /// RCWCLRPA <Xt1>, <Xt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE and FEAT_D128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwclrpa(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op_128(self, 0, 1, 0, xt2, 0b001, xn_sp, xt1)
+    }
+
+    /// [RCWCLRPAL - Read Check Write atomic bit Clear on quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWCLRP--RCWCLRPA--RCWCLRPAL--RCWCLRPL--Read-Check-Write-atomic-bit-Clear-on-quadword-in-memory-?lang=en)
+    ///
+    /// Read Check Write atomic bit Clear on quadword in memory atomically loads a 128-bit quadword from memory, performs a bitwise AND with the complement of the value held in a pair of registers on it, and conditionally stores the result back to memory. Storing of the result back to memory is conditional on RCW Checks. The value initially loaded from memory is returned in the same pair of registers.
+    ///
+    /// The instruction has acquire and release semantics on the load from and store to memory.
+    ///
+    /// ```asm
+    /// RCWCLRPAL <Xt1>, <Xt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE and FEAT_D128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwclrpal(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op_128(self, 0, 1, 1, xt2, 0b001, xn_sp, xt1)
+    }
+
+    /// [RCWCLRPL - Read Check Write atomic bit Clear on quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWCLRP--RCWCLRPA--RCWCLRPAL--RCWCLRPL--Read-Check-Write-atomic-bit-Clear-on-quadword-in-memory-?lang=en)
+    ///
+    /// Read Check Write atomic bit Clear on quadword in memory atomically loads a 128-bit quadword from memory, performs a bitwise AND with the complement of the value held in a pair of registers on it, and conditionally stores the result back to memory. Storing of the result back to memory is conditional on RCW Checks. The value initially loaded from memory is returned in the same pair of registers.
+    ///
+    /// The instruction has release semantics on the store to memory.
+    ///
+    /// ```asm
+    /// RCWCLRPL <Xt1>, <Xt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE and FEAT_D128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwclrpl(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op_128(self, 0, 0, 1, xt2, 0b001, xn_sp, xt1)
+    }
+
+    /// [RCWSET - Read Check Write atomic bit Set on doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSET--RCWSETA--RCWSETAL--RCWSETL--Read-Check-Write-atomic-bit-Set-on-doubleword-in-memory-?lang=en)
+    ///
+    /// Read Check Write atomic bit Set on doubleword in memory atomically loads a 64-bit doubleword from memory, performs a bitwise OR with the value held in a register on it, and conditionally stores the result back to memory. Storing of the result back to memory is conditional on RCW Checks. The value initially loaded from memory is returned in the destination register.
+    ///
+    /// ```asm
+    /// RCWSET <Xs>, <Xt>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwset(&mut self, xs: Register, xt: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op(self, 0, 0, 0, xs, 0b011, xn_sp, xt)
+    }
+
+    /// [RCWSETA - Read Check Write atomic bit Set on doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSET--RCWSETA--RCWSETAL--RCWSETL--Read-Check-Write-atomic-bit-Set-on-doubleword-in-memory-?lang=en)
+    ///
+    /// Read Check Write atomic bit Set on doubleword in memory atomically loads a 64-bit doubleword from memory, performs a bitwise OR with the value held in a register on it, and conditionally stores the result back to memory. Storing of the result back to memory is conditional on RCW Checks. The value initially loaded from memory is returned in the destination register.
+    ///
+    /// The instruction has acquire semantics on the load from memory.
+    ///
+    /// ```asm
+    /// RCWSETA <Xs>, <Xt>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwseta(&mut self, xs: Register, xt: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op(self, 0, 1, 0, xs, 0b011, xn_sp, xt)
+    }
+
+    /// [RCWSETAL - Read Check Write atomic bit Set on doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSET--RCWSETA--RCWSETAL--RCWSETL--Read-Check-Write-atomic-bit-Set-on-doubleword-in-memory-?lang=en)
+    ///
+    /// Read Check Write atomic bit Set on doubleword in memory atomically loads a 64-bit doubleword from memory, performs a bitwise OR with the value held in a register on it, and conditionally stores the result back to memory. Storing of the result back to memory is conditional on RCW Checks. The value initially loaded from memory is returned in the destination register.
+    ///
+    /// The instruction has acquire and release semantics on the load from and store to memory.
+    ///
+    /// ```asm
+    /// RCWSETAL <Xs>, <Xt>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwsetal(&mut self, xs: Register, xt: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op(self, 0, 1, 1, xs, 0b011, xn_sp, xt)
+    }
+
+    /// [RCWSETL - Read Check Write atomic bit Set on doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSET--RCWSETA--RCWSETAL--RCWSETL--Read-Check-Write-atomic-bit-Set-on-doubleword-in-memory-?lang=en)
+    ///
+    /// Read Check Write atomic bit Set on doubleword in memory atomically loads a 64-bit doubleword from memory, performs a bitwise OR with the value held in a register on it, and conditionally stores the result back to memory. Storing of the result back to memory is conditional on RCW Checks. The value initially loaded from memory is returned in the destination register.
+    ///
+    /// The instruction has release semantics on the store to memory.
+    ///
+    /// ```asm
+    /// RCWSETL <Xs>, <Xt>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwsetl(&mut self, xs: Register, xt: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op(self, 0, 0, 1, xs, 0b011, xn_sp, xt)
+    }
+
+    /// [RCWSETP - Read Check Write atomic bit Set on quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSETP--RCWSETPA--RCWSETPAL--RCWSETPL--Read-Check-Write-atomic-bit-Set-on-quadword-in-memory-?lang=en)
+    ///
+    /// Read Check Write atomic bit Set on quadword in memory atomically loads a 128-bit quadword from memory, performs a bitwise OR with the value held in a pair of registers on it, and conditionally stores the result back to memory. Storing of the result back to memory is conditional on RCW Checks. The value initially loaded from memory is returned in the same pair of registers.
+    ///
+    /// ```asm
+    /// RCWSETP <Xt1>, <Xt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE and FEAT_D128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwsetp(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op_128(self, 0, 0, 0, xt2, 0b011, xn_sp, xt1)
+    }
+
+    /// [RCWSETPA - Read Check Write atomic bit Set on quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSETP--RCWSETPA--RCWSETPAL--RCWSETPL--Read-Check-Write-atomic-bit-Set-on-quadword-in-memory-?lang=en)
+    ///
+    /// Read Check Write atomic bit Set on quadword in memory atomically loads a 128-bit quadword from memory, performs a bitwise OR with the value held in a pair of registers on it, and conditionally stores the result back to memory. Storing of the result back to memory is conditional on RCW Checks. The value initially loaded from memory is returned in the same pair of registers.
+    ///
+    /// The instruction has acquire semantics on the load from memory.
+    ///
+    /// ```asm
+    /// RCWSETPA <Xt1>, <Xt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE and FEAT_D128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwsetpa(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op_128(self, 0, 1, 0, xt2, 0b011, xn_sp, xt1)
+    }
+
+    /// [RCWSETPAL - Read Check Write atomic bit Set on quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSETP--RCWSETPA--RCWSETPAL--RCWSETPL--Read-Check-Write-atomic-bit-Set-on-quadword-in-memory-?lang=en)
+    ///
+    /// Read Check Write atomic bit Set on quadword in memory atomically loads a 128-bit quadword from memory, performs a bitwise OR with the value held in a pair of registers on it, and conditionally stores the result back to memory. Storing of the result back to memory is conditional on RCW Checks. The value initially loaded from memory is returned in the same pair of registers.
+    ///
+    /// The instruction has acquire and release semantics on the load from and store to memory.
+    ///
+    /// ```asm
+    /// RCWSETPAL <Xt1>, <Xt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE and FEAT_D128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwsetpal(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op_128(self, 0, 1, 1, xt2, 0b011, xn_sp, xt1)
+    }
+
+    /// [RCWSETPL - Read Check Write atomic bit Set on quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSETP--RCWSETPA--RCWSETPAL--RCWSETPL--Read-Check-Write-atomic-bit-Set-on-quadword-in-memory-?lang=en)
+    ///
+    /// Read Check Write atomic bit Set on quadword in memory atomically loads a 128-bit quadword from memory, performs a bitwise OR with the value held in a pair of registers on it, and conditionally stores the result back to memory. Storing of the result back to memory is conditional on RCW Checks. The value initially loaded from memory is returned in the same pair of registers.
+    ///
+    /// The instruction has release semantics on the store to memory.
+    ///
+    /// ```asm
+    /// RCWSETPL <Xt1>, <Xt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE and FEAT_D128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwsetpl(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op_128(self, 0, 0, 1, xt2, 0b011, xn_sp, xt1)
+    }
+
+    /// [RCWSWP - Read Check Write Swap doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSWP--RCWSWPA--RCWSWPAL--RCWSWPL--Read-Check-Write-Swap-doubleword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Swap doubleword in memory atomically loads a 64-bit doubleword from a memory location, and conditionally stores the value held in a register back to the same memory location. Storing back to memory is conditional on RCW Checks. The value initially loaded from memory is returned in the destination register.
+    ///
+    /// ```asm
+    /// RCWSWP <Xs>, <Xt>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwswp(&mut self, xs: Register, xt: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op(self, 0, 0, 0, xs, 0b010, xn_sp, xt)
+    }
+
+    /// [RCWSWPA - Read Check Write Swap doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSWP--RCWSWPA--RCWSWPAL--RCWSWPL--Read-Check-Write-Swap-doubleword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Swap doubleword in memory atomically loads a 64-bit doubleword from a memory location, and conditionally stores the value held in a register back to the same memory location. Storing back to memory is conditional on RCW Checks. The value initially loaded from memory is returned in the destination register.
+    ///
+    /// The instruction has acquire semantics on the load from memory.
+    ///
+    /// ```asm
+    /// RCWSWPA <Xs>, <Xt>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwswpa(&mut self, xs: Register, xt: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op(self, 0, 1, 0, xs, 0b010, xn_sp, xt)
+    }
+
+    /// [RCWSWPAL - Read Check Write Swap doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSWP--RCWSWPA--RCWSWPAL--RCWSWPL--Read-Check-Write-Swap-doubleword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Swap doubleword in memory atomically loads a 64-bit doubleword from a memory location, and conditionally stores the value held in a register back to the same memory location. Storing back to memory is conditional on RCW Checks. The value initially loaded from memory is returned in the destination register.
+    ///
+    /// The instruction has acquire and release semantics on the load from and store to memory.
+    ///
+    /// ```asm
+    /// RCWSWPAL <Xs>, <Xt>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwswpal(&mut self, xs: Register, xt: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op(self, 0, 1, 1, xs, 0b010, xn_sp, xt)
+    }
+
+    /// [RCWSWPL - Read Check Write Swap doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSWP--RCWSWPA--RCWSWPAL--RCWSWPL--Read-Check-Write-Swap-doubleword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Swap doubleword in memory atomically loads a 64-bit doubleword from a memory location, and conditionally stores the value held in a register back to the same memory location. Storing back to memory is conditional on RCW Checks. The value initially loaded from memory is returned in the destination register.
+    ///
+    /// The instruction has release semantics on the store to memory.
+    ///
+    /// ```asm
+    /// RCWSWPL <Xs>, <Xt>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwswpl(&mut self, xs: Register, xt: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op(self, 0, 0, 1, xs, 0b010, xn_sp, xt)
+    }
+
+    /// [RCWSWPP - Read Check Write Swap quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSWPP--RCWSWPPA--RCWSWPPAL--RCWSWPPL--Read-Check-Write-Swap-quadword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Swap quadword in memory atomically loads a 128-bit quadword from a memory location, and conditionally stores the value held in a pair of registers back to the same memory location. Storing back to memory is conditional on RCW Checks. The value initially loaded from memory is returned in the same pair of registers.
+    ///
+    /// ```asm
+    /// RCWSWPP <Xt1>, <Xt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE and FEAT_D128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwswpp(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op_128(self, 0, 0, 0, xt2, 0b010, xn_sp, xt1)
+    }
+
+    /// [RCWSWPPA - Read Check Write Swap quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSWPP--RCWSWPPA--RCWSWPPAL--RCWSWPPL--Read-Check-Write-Swap-quadword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Swap quadword in memory atomically loads a 128-bit quadword from a memory location, and conditionally stores the value held in a pair of registers back to the same memory location. Storing back to memory is conditional on RCW Checks. The value initially loaded from memory is returned in the same pair of registers.
+    ///
+    /// The instruction has acquire semantics on the load from memory.
+    ///
+    /// ```asm
+    /// RCWSWPPA <Xt1>, <Xt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE and FEAT_D128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwswppa(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op_128(self, 0, 1, 0, xt2, 0b010, xn_sp, xt1)
+    }
+
+    /// [RCWSWPPAL - Read Check Write Swap quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSWPP--RCWSWPPA--RCWSWPPAL--RCWSWPPL--Read-Check-Write-Swap-quadword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Swap quadword in memory atomically loads a 128-bit quadword from a memory location, and conditionally stores the value held in a pair of registers back to the same memory location. Storing back to memory is conditional on RCW Checks. The value initially loaded from memory is returned in the same pair of registers.
+    ///
+    /// The instruction has acquire and release semantics on the load from and store to memory.
+    ///
+    /// ```asm
+    /// RCWSWPPAL <Xt1>, <Xt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE and FEAT_D128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwswppal(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op_128(self, 0, 1, 1, xt2, 0b010, xn_sp, xt1)
+    }
+
+    /// [RCWSWPPL - Read Check Write Swap quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSWPP--RCWSWPPA--RCWSWPPAL--RCWSWPPL--Read-Check-Write-Swap-quadword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Swap quadword in memory atomically loads a 128-bit quadword from a memory location, and conditionally stores the value held in a pair of registers back to the same memory location. Storing back to memory is conditional on RCW Checks. The value initially loaded from memory is returned in the same pair of registers.
+    ///
+    /// The instruction has release semantics on the store to memory.
+    ///
+    /// ```asm
+    /// RCWSWPPL <Xt1>, <Xt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE and FEAT_D128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwswppl(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op_128(self, 0, 0, 1, xt2, 0b010, xn_sp, xt1)
+    }
+
+    /// [RCWSCAS - Read Check Write Software Compare and Swap doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSCAS--RCWSCASA--RCWSCASAL--RCWSCASL--Read-Check-Write-Software-Compare-and-Swap-doubleword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Software Compare and Swap doubleword in memory reads a 64-bit doubleword from memory, and compares it against the value held in a register. If the comparison is equal, the value in a second register is conditionally written to memory. Storing back to memory is conditional on RCW Checks and RCWS Checks. If the write is performed, the read and the write occur atomically such that no other modification of the memory location can take place between the read and the write.
+    ///
+    /// ```asm
+    /// RCWSCAS <Xs>, <Xt>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwscas(&mut self, xs: Register, xt: Register, xn_sp: Register) -> T {
+        emit_rcw_cas(self, 1, 0, 0, xs, 0, xn_sp, xt)
+    }
+
+    /// [RCWSCASA - Read Check Write Software Compare and Swap doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSCAS--RCWSCASA--RCWSCASAL--RCWSCASL--Read-Check-Write-Software-Compare-and-Swap-doubleword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Software Compare and Swap doubleword in memory reads a 64-bit doubleword from memory, and compares it against the value held in a register. If the comparison is equal, the value in a second register is conditionally written to memory. Storing back to memory is conditional on RCW Checks and RCWS Checks. If the write is performed, the read and the write occur atomically such that no other modification of the memory location can take place between the read and the write.
+    ///
+    /// The instruction has acquire semantics on the load from memory.
+    ///
+    /// ```asm
+    /// RCWSCASA <Xs>, <Xt>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwscasa(&mut self, xs: Register, xt: Register, xn_sp: Register) -> T {
+        emit_rcw_cas(self, 1, 1, 0, xs, 0, xn_sp, xt)
+    }
+
+    /// [RCWSCASAL - Read Check Write Software Compare and Swap doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSCAS--RCWSCASA--RCWSCASAL--RCWSCASL--Read-Check-Write-Software-Compare-and-Swap-doubleword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Software Compare and Swap doubleword in memory reads a 64-bit doubleword from memory, and compares it against the value held in a register. If the comparison is equal, the value in a second register is conditionally written to memory. Storing back to memory is conditional on RCW Checks and RCWS Checks. If the write is performed, the read and the write occur atomically such that no other modification of the memory location can take place between the read and the write.
+    ///
+    /// The instruction has acquire and release semantics on the load from and store to memory.
+    ///
+    /// ```asm
+    /// RCWSCASAL <Xs>, <Xt>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwscasal(&mut self, xs: Register, xt: Register, xn_sp: Register) -> T {
+        emit_rcw_cas(self, 1, 1, 1, xs, 0, xn_sp, xt)
+    }
+
+    /// [RCWSCASL - Read Check Write Software Compare and Swap doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSCAS--RCWSCASA--RCWSCASAL--RCWSCASL--Read-Check-Write-Software-Compare-and-Swap-doubleword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Software Compare and Swap doubleword in memory reads a 64-bit doubleword from memory, and compares it against the value held in a register. If the comparison is equal, the value in a second register is conditionally written to memory. Storing back to memory is conditional on RCW Checks and RCWS Checks. If the write is performed, the read and the write occur atomically such that no other modification of the memory location can take place between the read and the write.
+    ///
+    /// The instruction has release semantics on the store to memory.
+    ///
+    /// ```asm
+    /// RCWSCASL <Xs>, <Xt>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwscasl(&mut self, xs: Register, xt: Register, xn_sp: Register) -> T {
+        emit_rcw_cas(self, 1, 0, 1, xs, 0, xn_sp, xt)
+    }
+
+    /// [RCWSCASP - Read Check Write Software Compare and Swap quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSCASP--RCWSCASPA--RCWSCASPAL--RCWSCASPL--Read-Check-Write-Software-Compare-and-Swap-quadword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Software Compare and Swap quadword in memory reads a 128-bit quadword from memory, and compares it against the value held in a pair of registers. If the comparison is equal, the value in a second pair of registers is conditionally written to memory. Storing back to memory is conditional on RCW Checks and RCWS Checks. If the write is performed, the read and the write occur atomically such that no other modification of the memory location can take place between the read and the write.
+    ///
+    /// The method asserts that xs1 and xt1 registers are even and that xs2 and xt2 are the consecutive registers of xs1 and xt1.
+    ///
+    /// ```asm
+    /// RCWSCASP <Xs>, <X(s+1)>, <Xt>, <X(t+1)>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE and FEAT_D128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwscasp(
+        &mut self,
+        xs1: Register,
+        xs2: Register,
+        xt1: Register,
+        xt2: Register,
+        xn_sp: Register,
+    ) -> T {
+        debug_assert!(xs1 % 2 == 0, "xs1 must be even");
+        debug_assert!(
+            xs2 == xs1 + 1,
+            "xs2 must be the consecutive register of xs1"
+        );
+        debug_assert!(xt1 % 2 == 0, "xt1 must be even");
+        debug_assert!(
+            xt2 == xt1 + 1,
+            "xt2 must be the consecutive register of xt1"
+        );
+
+        emit_rcw_cas(self, 1, 0, 0, xs1, 1, xn_sp, xt1)
+    }
+
+    /// [RCWSCASPA - Read Check Write Software Compare and Swap quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSCASP--RCWSCASPA--RCWSCASPAL--RCWSCASPL--Read-Check-Write-Software-Compare-and-Swap-quadword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Software Compare and Swap quadword in memory reads a 128-bit quadword from memory, and compares it against the value held in a pair of registers. If the comparison is equal, the value in a second pair of registers is conditionally written to memory. Storing back to memory is conditional on RCW Checks and RCWS Checks. If the write is performed, the read and the write occur atomically such that no other modification of the memory location can take place between the read and the write.
+    ///
+    /// The instruction has acquire semantics on the load from memory.
+    ///
+    /// The method asserts that xs1 and xt1 registers are even and that xs2 and xt2 are the consecutive registers of xs1 and xt1.
+    ///
+    /// ```asm
+    /// RCWSCASPA <Xs>, <X(s+1)>, <Xt>, <X(t+1)>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE and FEAT_D128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwscaspa(
+        &mut self,
+        xs1: Register,
+        xs2: Register,
+        xt1: Register,
+        xt2: Register,
+        xn_sp: Register,
+    ) -> T {
+        debug_assert!(xs1 % 2 == 0, "xs1 must be even");
+        debug_assert!(
+            xs2 == xs1 + 1,
+            "xs2 must be the consecutive register of xs1"
+        );
+        debug_assert!(xt1 % 2 == 0, "xt1 must be even");
+        debug_assert!(
+            xt2 == xt1 + 1,
+            "xt2 must be the consecutive register of xt1"
+        );
+
+        emit_rcw_cas(self, 1, 1, 0, xs1, 1, xn_sp, xt1)
+    }
+
+    /// [RCWSCASPAL - Read Check Write Software Compare and Swap quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSCASP--RCWSCASPA--RCWSCASPAL--RCWSCASPL--Read-Check-Write-Software-Compare-and-Swap-quadword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Software Compare and Swap quadword in memory reads a 128-bit quadword from memory, and compares it against the value held in a pair of registers. If the comparison is equal, the value in a second pair of registers is conditionally written to memory. Storing back to memory is conditional on RCW Checks and RCWS Checks. If the write is performed, the read and the write occur atomically such that no other modification of the memory location can take place between the read and the write.
+    ///
+    /// The instruction has acquire and release semantics on the load from and store to memory.
+    ///
+    /// The method asserts that xs1 and xt1 registers are even and that xs2 and xt2 are the consecutive registers of xs1 and xt1.
+    ///
+    /// ```asm
+    /// RCWSCASPAL <Xs>, <X(s+1)>, <Xt>, <X(t+1)>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE and FEAT_D128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwscaspal(
+        &mut self,
+        xs1: Register,
+        xs2: Register,
+        xt1: Register,
+        xt2: Register,
+        xn_sp: Register,
+    ) -> T {
+        debug_assert!(xs1 % 2 == 0, "xs1 must be even");
+        debug_assert!(
+            xs2 == xs1 + 1,
+            "xs2 must be the consecutive register of xs1"
+        );
+        debug_assert!(xt1 % 2 == 0, "xt1 must be even");
+        debug_assert!(
+            xt2 == xt1 + 1,
+            "xt2 must be the consecutive register of xt1"
+        );
+
+        emit_rcw_cas(self, 1, 1, 1, xs1, 1, xn_sp, xt1)
+    }
+
+    /// [RCWSCASPL - Read Check Write Software Compare and Swap quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSCASP--RCWSCASPA--RCWSCASPAL--RCWSCASPL--Read-Check-Write-Software-Compare-and-Swap-quadword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Software Compare and Swap quadword in memory reads a 128-bit quadword from memory, and compares it against the value held in a pair of registers. If the comparison is equal, the value in a second pair of registers is conditionally written to memory. Storing back to memory is conditional on RCW Checks and RCWS Checks. If the write is performed, the read and the write occur atomically such that no other modification of the memory location can take place between the read and the write.
+    ///
+    /// The instruction has release semantics on the store to memory.
+    ///
+    /// The method asserts that xs1 and xt1 registers are even and that xs2 and xt2 are the consecutive registers of xs1 and xt1.
+    ///
+    /// ```asm
+    /// RCWSCASPL <Xs>, <X(s+1)>, <Xt>, <X(t+1)>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE and FEAT_D128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwscaspl(
+        &mut self,
+        xs1: Register,
+        xs2: Register,
+        xt1: Register,
+        xt2: Register,
+        xn_sp: Register,
+    ) -> T {
+        debug_assert!(xs1 % 2 == 0, "xs1 must be even");
+        debug_assert!(
+            xs2 == xs1 + 1,
+            "xs2 must be the consecutive register of xs1"
+        );
+        debug_assert!(xt1 % 2 == 0, "xt1 must be even");
+        debug_assert!(
+            xt2 == xt1 + 1,
+            "xt2 must be the consecutive register of xt1"
+        );
+
+        emit_rcw_cas(self, 1, 0, 1, xs1, 1, xn_sp, xt1)
+    }
+
+    /// [RCWSCLR - Read Check Write Software atomic bit Clear on doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSCLR--RCWSCLRA--RCWSCLRAL--RCWSCLRL--Read-Check-Write-Software-atomic-bit-Clear-on-doubleword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Software atomic bit Clear on doubleword in memory atomically loads a 64-bit doubleword from memory, performs a bitwise AND with the complement of the value held in a register on it, and conditionally stores the result back to memory. Storing of the result back to memory is conditional on RCW Checks and RCWS Checks. The value initially loaded from memory is returned in the destination register.
+    ///
+    /// ```asm
+    /// RCWSCLR <Xs>, <Xt>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwsclr(&mut self, xs: Register, xt: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op(self, 1, 0, 0, xs, 0b001, xn_sp, xt)
+    }
+
+    /// [RCWSCLRA - Read Check Write Software atomic bit Clear on doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSCLR--RCWSCLRA--RCWSCLRAL--RCWSCLRL--Read-Check-Write-Software-atomic-bit-Clear-on-doubleword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Software atomic bit Clear on doubleword in memory atomically loads a 64-bit doubleword from memory, performs a bitwise AND with the complement of the value held in a register on it, and conditionally stores the result back to memory. Storing of the result back to memory is conditional on RCW Checks and RCWS Checks. The value initially loaded from memory is returned in the destination register.
+    ///
+    /// The instruction has acquire semantics on the load from memory.
+    ///
+    /// ```asm
+    /// RCWSCLRA <Xs>, <Xt>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwsclra(&mut self, xs: Register, xt: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op(self, 1, 1, 0, xs, 0b001, xn_sp, xt)
+    }
+
+    /// [RCWSCLRAL - Read Check Write Software atomic bit Clear on doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSCLR--RCWSCLRA--RCWSCLRAL--RCWSCLRL--Read-Check-Write-Software-atomic-bit-Clear-on-doubleword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Software atomic bit Clear on doubleword in memory atomically loads a 64-bit doubleword from memory, performs a bitwise AND with the complement of the value held in a register on it, and conditionally stores the result back to memory. Storing of the result back to memory is conditional on RCW Checks and RCWS Checks. The value initially loaded from memory is returned in the destination register.
+    ///
+    /// The instruction has acquire and release semantics on the load from and store to memory.
+    ///
+    /// ```asm
+    /// RCWSCLRAL <Xs>, <Xt>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwsclral(&mut self, xs: Register, xt: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op(self, 1, 1, 1, xs, 0b001, xn_sp, xt)
+    }
+
+    /// [RCWSCLRL - Read Check Write Software atomic bit Clear on doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSCLR--RCWSCLRA--RCWSCLRAL--RCWSCLRL--Read-Check-Write-Software-atomic-bit-Clear-on-doubleword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Software atomic bit Clear on doubleword in memory atomically loads a 64-bit doubleword from memory, performs a bitwise AND with the complement of the value held in a register on it, and conditionally stores the result back to memory. Storing of the result back to memory is conditional on RCW Checks and RCWS Checks. The value initially loaded from memory is returned in the destination register.
+    ///
+    /// The instruction has release semantics on the store to memory.
+    ///
+    /// ```asm
+    /// RCWSCLRL <Xs>, <Xt>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwsclrl(&mut self, xs: Register, xt: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op(self, 1, 0, 1, xs, 0b001, xn_sp, xt)
+    }
+
+    /// [RCWSCLRP - Read Check Write Software atomic bit Clear on quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSCLRP--RCWSCLRPA--RCWSCLRPAL--RCWSCLRPL--Read-Check-Write-Software-atomic-bit-Clear-on-quadword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Software atomic bit Clear on quadword in memory atomically loads a 128-bit quadword from memory, performs a bitwise AND with the complement of the value held in a pair of registers on it, and conditionally stores the result back to memory. Storing of the result back to memory is conditional on RCW Checks and RCWS Checks. The value initially loaded from memory is returned in the same pair of registers.
+    ///
+    /// ```asm
+    /// RCWSCLRP <Xt1>, <Xt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE and FEAT_D128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwsclrp(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op_128(self, 1, 0, 0, xt2, 0b001, xn_sp, xt1)
+    }
+
+    /// [RCWSCLRPA - Read Check Write Software atomic bit Clear on quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSCLRP--RCWSCLRPA--RCWSCLRPAL--RCWSCLRPL--Read-Check-Write-Software-atomic-bit-Clear-on-quadword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Software atomic bit Clear on quadword in memory atomically loads a 128-bit quadword from memory, performs a bitwise AND with the complement of the value held in a pair of registers on it, and conditionally stores the result back to memory. Storing of the result back to memory is conditional on RCW Checks and RCWS Checks. The value initially loaded from memory is returned in the same pair of registers.
+    ///
+    /// The instruction has acquire semantics on the load from memory.
+    ///
+    /// ```asm
+    /// RCWSCLRPA <Xt1>, <Xt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE and FEAT_D128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwsclrpa(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op_128(self, 1, 1, 0, xt2, 0b001, xn_sp, xt1)
+    }
+
+    /// [RCWSCLRPAL - Read Check Write Software atomic bit Clear on quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSCLRP--RCWSCLRPA--RCWSCLRPAL--RCWSCLRPL--Read-Check-Write-Software-atomic-bit-Clear-on-quadword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Software atomic bit Clear on quadword in memory atomically loads a 128-bit quadword from memory, performs a bitwise AND with the complement of the value held in a pair of registers on it, and conditionally stores the result back to memory. Storing of the result back to memory is conditional on RCW Checks and RCWS Checks. The value initially loaded from memory is returned in the same pair of registers.
+    ///
+    /// The instruction has acquire and release semantics on the load from and store to memory.
+    ///
+    /// ```asm
+    /// RCWSCLRPAL <Xt1>, <Xt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE and FEAT_D128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwsclrpal(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op_128(self, 1, 1, 1, xt2, 0b001, xn_sp, xt1)
+    }
+
+    /// [RCWSCLRPL - Read Check Write Software atomic bit Clear on quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSCLRP--RCWSCLRPA--RCWSCLRPAL--RCWSCLRPL--Read-Check-Write-Software-atomic-bit-Clear-on-quadword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Software atomic bit Clear on quadword in memory atomically loads a 128-bit quadword from memory, performs a bitwise AND with the complement of the value held in a pair of registers on it, and conditionally stores the result back to memory. Storing of the result back to memory is conditional on RCW Checks and RCWS Checks. The value initially loaded from memory is returned in the same pair of registers.
+    ///
+    /// The instruction has release semantics on the store to memory.
+    ///
+    /// ```asm
+    /// RCWSCLRPL <Xt1>, <Xt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE and FEAT_D128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwsclrpl(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op_128(self, 1, 0, 1, xt2, 0b001, xn_sp, xt1)
+    }
+
+    /// [RCWSSET - Read Check Write Software atomic bit Set on doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSSET--RCWSSETA--RCWSSETAL--RCWSSETL--Read-Check-Write-Software-atomic-bit-Set-on-doubleword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Software atomic bit Set on doubleword in memory atomically loads a 64-bit doubleword from memory, performs a bitwise OR with the value held in a register on it, and conditionally stores the result back to memory. Storing of the result back to memory is conditional on RCW Checks and RCWS Checks. The value initially loaded from memory is returned in the destination register.
+    ///
+    /// ```asm
+    /// RCWSSET <Xs>, <Xt>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwsset(&mut self, xs: Register, xt: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op(self, 1, 0, 0, xs, 0b011, xn_sp, xt)
+    }
+
+    /// [RCWSSETA - Read Check Write Software atomic bit Set on doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSSET--RCWSSETA--RCWSSETAL--RCWSSETL--Read-Check-Write-Software-atomic-bit-Set-on-doubleword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Software atomic bit Set on doubleword in memory atomically loads a 64-bit doubleword from memory, performs a bitwise OR with the value held in a register on it, and conditionally stores the result back to memory. Storing of the result back to memory is conditional on RCW Checks and RCWS Checks. The value initially loaded from memory is returned in the destination register.
+    ///
+    /// The instruction has acquire semantics on the load from memory.
+    ///
+    /// ```asm
+    /// RCWSSETA <Xs>, <Xt>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwsseta(&mut self, xs: Register, xt: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op(self, 1, 1, 0, xs, 0b011, xn_sp, xt)
+    }
+
+    /// [RCWSSETAL - Read Check Write Software atomic bit Set on doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSSET--RCWSSETA--RCWSSETAL--RCWSSETL--Read-Check-Write-Software-atomic-bit-Set-on-doubleword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Software atomic bit Set on doubleword in memory atomically loads a 64-bit doubleword from memory, performs a bitwise OR with the value held in a register on it, and conditionally stores the result back to memory. Storing of the result back to memory is conditional on RCW Checks and RCWS Checks. The value initially loaded from memory is returned in the destination register.
+    ///
+    /// The instruction has acquire and release semantics on the load from and store to memory.
+    ///
+    /// ```asm
+    /// RCWSSETAL <Xs>, <Xt>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwssetal(&mut self, xs: Register, xt: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op(self, 1, 1, 1, xs, 0b011, xn_sp, xt)
+    }
+
+    /// [RCWSSETL - Read Check Write Software atomic bit Set on doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSSET--RCWSSETA--RCWSSETAL--RCWSSETL--Read-Check-Write-Software-atomic-bit-Set-on-doubleword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Software atomic bit Set on doubleword in memory atomically loads a 64-bit doubleword from memory, performs a bitwise OR with the value held in a register on it, and conditionally stores the result back to memory. Storing of the result back to memory is conditional on RCW Checks and RCWS Checks. The value initially loaded from memory is returned in the destination register.
+    ///
+    /// The instruction has release semantics on the store to memory.
+    ///
+    /// ```asm
+    /// RCWSSETL <Xs>, <Xt>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwssetl(&mut self, xs: Register, xt: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op(self, 1, 0, 1, xs, 0b011, xn_sp, xt)
+    }
+
+    /// [RCWSSETP - Read Check Write Software atomic bit Set on quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSSETP--RCWSSETPA--RCWSSETPAL--RCWSSETPL--Read-Check-Write-Software-atomic-bit-Set-on-quadword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Software atomic bit Set on quadword in memory atomically loads a 128-bit quadword from memory, performs a bitwise OR with the value held in a pair of registers on it, and conditionally stores the result back to memory. Storing of the result back to memory is conditional on RCW Checks and RCWS Checks. The value initially loaded from memory is returned in the same pair of registers.
+    ///
+    /// ```asm
+    /// RCWSSETP <Xt1>, <Xt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE and FEAT_D128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwssetp(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op_128(self, 1, 0, 0, xt2, 0b011, xn_sp, xt1)
+    }
+
+    /// [RCWSSETPA - Read Check Write Software atomic bit Set on quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSSETP--RCWSSETPA--RCWSSETPAL--RCWSSETPL--Read-Check-Write-Software-atomic-bit-Set-on-quadword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Software atomic bit Set on quadword in memory atomically loads a 128-bit quadword from memory, performs a bitwise OR with the value held in a pair of registers on it, and conditionally stores the result back to memory. Storing of the result back to memory is conditional on RCW Checks and RCWS Checks. The value initially loaded from memory is returned in the same pair of registers.
+    ///
+    /// The instruction has acquire semantics on the load from memory.
+    ///
+    /// ```asm
+    /// RCWSSETPA <Xt1>, <Xt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE and FEAT_D128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwssetpa(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op_128(self, 1, 1, 0, xt2, 0b011, xn_sp, xt1)
+    }
+
+    /// [RCWSSETPAL - Read Check Write Software atomic bit Set on quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSSETP--RCWSSETPA--RCWSSETPAL--RCWSSETPL--Read-Check-Write-Software-atomic-bit-Set-on-quadword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Software atomic bit Set on quadword in memory atomically loads a 128-bit quadword from memory, performs a bitwise OR with the value held in a pair of registers on it, and conditionally stores the result back to memory. Storing of the result back to memory is conditional on RCW Checks and RCWS Checks. The value initially loaded from memory is returned in the same pair of registers.
+    ///
+    /// The instruction has acquire and release semantics on the load from and store to memory.
+    ///
+    /// ```asm
+    /// RCWSSETPAL <Xt1>, <Xt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE and FEAT_D128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwssetpal(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op_128(self, 1, 1, 1, xt2, 0b011, xn_sp, xt1)
+    }
+
+    /// [RCWSSETPL - Read Check Write Software atomic bit Set on quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSSETP--RCWSSETPA--RCWSSETPAL--RCWSSETPL--Read-Check-Write-Software-atomic-bit-Set-on-quadword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Software atomic bit Set on quadword in memory atomically loads a 128-bit quadword from memory, performs a bitwise OR with the value held in a pair of registers on it, and conditionally stores the result back to memory. Storing of the result back to memory is conditional on RCW Checks and RCWS Checks. The value initially loaded from memory is returned in the same pair of registers.
+    ///
+    /// The instruction has release semantics on the store to memory.
+    ///
+    /// ```asm
+    /// RCWSSETPL <Xt1>, <Xt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE and FEAT_D128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwssetpl(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op_128(self, 1, 0, 1, xt2, 0b011, xn_sp, xt1)
+    }
+
+    /// [RCWSSWP - Read Check Write Software Swap doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSSWP--RCWSSWPA--RCWSSWPAL--RCWSSWPL--Read-Check-Write-Software-Swap-doubleword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Software Swap doubleword in memory atomically loads a 64-bit doubleword from a memory location, and conditionally stores the value held in a register back to the same memory location. Storing back to memory is conditional on RCW Checks and RCWS Checks. The value initially loaded from memory is returned in the destination register.
+    ///
+    /// ```asm
+    /// RCWSSWP <Xs>, <Xt>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwsswp(&mut self, xs: Register, xt: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op(self, 1, 0, 0, xs, 0b010, xn_sp, xt)
+    }
+
+    /// [RCWSSWPA - Read Check Write Software Swap doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSSWP--RCWSSWPA--RCWSSWPAL--RCWSSWPL--Read-Check-Write-Software-Swap-doubleword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Software Swap doubleword in memory atomically loads a 64-bit doubleword from a memory location, and conditionally stores the value held in a register back to the same memory location. Storing back to memory is conditional on RCW Checks and RCWS Checks. The value initially loaded from memory is returned in the destination register.
+    ///
+    /// The instruction has acquire semantics on the load from memory.
+    ///
+    /// ```asm
+    /// RCWSSWPA <Xs>, <Xt>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwsswpa(&mut self, xs: Register, xt: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op(self, 1, 1, 0, xs, 0b010, xn_sp, xt)
+    }
+
+    /// [RCWSSWPAL - Read Check Write Software Swap doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSSWP--RCWSSWPA--RCWSSWPAL--RCWSSWPL--Read-Check-Write-Software-Swap-doubleword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Software Swap doubleword in memory atomically loads a 64-bit doubleword from a memory location, and conditionally stores the value held in a register back to the same memory location. Storing back to memory is conditional on RCW Checks and RCWS Checks. The value initially loaded from memory is returned in the destination register.
+    ///
+    /// The instruction has acquire and release semantics on the load from and store to memory.
+    ///
+    /// ```asm
+    /// RCWSSWPAL <Xs>, <Xt>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwsswpal(&mut self, xs: Register, xt: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op(self, 1, 1, 1, xs, 0b010, xn_sp, xt)
+    }
+
+    /// [RCWSSWPL - Read Check Write Software Swap doubleword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSSWP--RCWSSWPA--RCWSSWPAL--RCWSSWPL--Read-Check-Write-Software-Swap-doubleword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Software Swap doubleword in memory atomically loads a 64-bit doubleword from a memory location, and conditionally stores the value held in a register back to the same memory location. Storing back to memory is conditional on RCW Checks and RCWS Checks. The value initially loaded from memory is returned in the destination register.
+    ///
+    /// The instruction has release semantics on the store to memory.
+    ///
+    /// ```asm
+    /// RCWSSWPL <Xs>, <Xt>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwsswpl(&mut self, xs: Register, xt: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op(self, 1, 0, 1, xs, 0b010, xn_sp, xt)
+    }
+
+    /// [RCWSSWPP - Read Check Write Software Swap quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSSWPP--RCWSSWPPA--RCWSSWPPAL--RCWSSWPPL--Read-Check-Write-Software-Swap-quadword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Software Swap quadword in memory atomically loads a 128-bit quadword from a memory location, and conditionally stores the value held in a pair of registers back to the same memory location. Storing back to memory is conditional on RCW Checks and RCWS Checks. The value initially loaded from memory is returned in the same pair of registers.
+    ///
+    /// ```asm
+    /// RCWSSWPP <Xt1>, <Xt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE and FEAT_D128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwsswpp(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op_128(self, 1, 0, 0, xt2, 0b010, xn_sp, xt1)
+    }
+
+    /// [RCWSSWPPA - Read Check Write Software Swap quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSSWPP--RCWSSWPPA--RCWSSWPPAL--RCWSSWPPL--Read-Check-Write-Software-Swap-quadword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Software Swap quadword in memory atomically loads a 128-bit quadword from a memory location, and conditionally stores the value held in a pair of registers back to the same memory location. Storing back to memory is conditional on RCW Checks and RCWS Checks. The value initially loaded from memory is returned in the same pair of registers.
+    ///
+    /// The instruction has acquire semantics on the load from memory.
+    ///
+    /// ```asm
+    /// RCWSSWPPA <Xt1>, <Xt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE and FEAT_D128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwsswppa(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op_128(self, 1, 1, 0, xt2, 0b010, xn_sp, xt1)
+    }
+
+    /// [RCWSSWPPAL - Read Check Write Software Swap quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSSWPP--RCWSSWPPA--RCWSSWPPAL--RCWSSWPPL--Read-Check-Write-Software-Swap-quadword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Software Swap quadword in memory atomically loads a 128-bit quadword from a memory location, and conditionally stores the value held in a pair of registers back to the same memory location. Storing back to memory is conditional on RCW Checks and RCWS Checks. The value initially loaded from memory is returned in the same pair of registers.
+    ///
+    /// The instruction has acquire and release semantics on the load from and store to memory.
+    ///
+    /// ```asm
+    /// RCWSSWPPAL <Xt1>, <Xt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE and FEAT_D128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwsswppal(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op_128(self, 1, 1, 1, xt2, 0b010, xn_sp, xt1)
+    }
+
+    /// [RCWSSWPPL - Read Check Write Software Swap quadword in memory](https://developer.arm.com/documentation/ddi0602/2022-12/Base-Instructions/RCWSSWPP--RCWSSWPPA--RCWSSWPPAL--RCWSSWPPL--Read-Check-Write-Software-Swap-quadword-in-memory-?lang=en)
+    ///
+    /// Read Check Write Software Swap quadword in memory atomically loads a 128-bit quadword from a memory location, and conditionally stores the value held in a pair of registers back to the same memory location. Storing back to memory is conditional on RCW Checks and RCWS Checks. The value initially loaded from memory is returned in the same pair of registers.
+    ///
+    /// The instruction has release semantics on the store to memory.
+    ///
+    /// ```asm
+    /// RCWSSWPPL <Xt1>, <Xt2>, [<Xn|SP>]
+    /// ```
+    ///
+    /// *Note*: FEAT_THE and FEAT_D128 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_the")]
+    fn rcwsswppl(&mut self, xt1: Register, xt2: Register, xn_sp: Register) -> T {
+        emit_rcw_mem_op_128(self, 1, 0, 1, xt2, 0b010, xn_sp, xt1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+    #[allow(unused_imports)]
+    use crate::assert_panic;
+
+    #[cfg(feature = "arm_feat_the")]
+    #[test]
+    fn test_rcw_cas() {
+        use crate::instruction_producer::InstrProducer;
+
+        // the disassembler does not support FEAT_THE, so the encodings are compared
+        let mut prod = InstrProducer::new();
+
+        assert_eq!(prod.rcwcas(0, 1, 4).encoding(), 0x19200881);
+        assert_eq!(prod.rcwcasa(0, 1, 4).encoding(), 0x19a00881);
+        assert_eq!(prod.rcwcasal(0, 1, 4).encoding(), 0x19e00881);
+        assert_eq!(prod.rcwcasl(0, 1, 4).encoding(), 0x19600881);
+        assert_eq!(prod.rcwscas(0, 1, 4).encoding(), 0x59200881);
+        assert_eq!(prod.rcwscasal(0, 1, 31).encoding(), 0x59e00be1);
+
+        assert_eq!(prod.rcwcasp(0, 1, 6, 7, 4).encoding(), 0x19200c86);
+        assert_eq!(prod.rcwcaspa(0, 1, 6, 7, 4).encoding(), 0x19a00c86);
+        assert_eq!(prod.rcwcaspal(0, 1, 6, 7, 4).encoding(), 0x19e00c86);
+        assert_eq!(prod.rcwcaspl(0, 1, 6, 7, 4).encoding(), 0x19600c86);
+        assert_eq!(prod.rcwscasp(0, 1, 6, 7, 4).encoding(), 0x59200c86);
+
+        assert_panic!("Should panic: odd register pair"; prod.rcwcasp(1, 2, 6, 7, 4));
+        assert_panic!("Should panic: non consecutive pair"; prod.rcwscasp(0, 1, 6, 8, 4));
+    }
+
+    #[cfg(feature = "arm_feat_the")]
+    #[test]
+    fn test_rcw_mem_op() {
+        use crate::instruction_producer::InstrProducer;
+
+        // the disassembler does not support FEAT_THE, so the encodings are compared
+        let mut prod = InstrProducer::new();
+
+        assert_eq!(prod.rcwclr(0, 1, 4).encoding(), 0x38209081);
+        assert_eq!(prod.rcwclra(0, 1, 4).encoding(), 0x38a09081);
+        assert_eq!(prod.rcwclral(0, 1, 4).encoding(), 0x38e09081);
+        assert_eq!(prod.rcwclrl(0, 1, 4).encoding(), 0x38609081);
+        assert_eq!(prod.rcwswp(0, 1, 4).encoding(), 0x3820a081);
+        assert_eq!(prod.rcwset(0, 1, 4).encoding(), 0x3820b081);
+
+        assert_eq!(prod.rcwsclr(0, 1, 4).encoding(), 0x78209081);
+        assert_eq!(prod.rcwsswpa(0, 1, 4).encoding(), 0x78a0a081);
+        assert_eq!(prod.rcwssetl(0, 1, 4).encoding(), 0x7860b081);
+    }
+
+    #[cfg(feature = "arm_feat_the")]
+    #[test]
+    fn test_rcw_mem_op_128() {
+        use crate::instruction_producer::InstrProducer;
+
+        // the disassembler does not support FEAT_THE, so the encodings are compared
+        let mut prod = InstrProducer::new();
+
+        assert_eq!(prod.rcwclrp(1, 0, 4).encoding(), 0x19209081);
+        assert_eq!(prod.rcwclrpa(1, 0, 4).encoding(), 0x19a09081);
+        assert_eq!(prod.rcwclrpal(1, 0, 4).encoding(), 0x19e09081);
+        assert_eq!(prod.rcwclrpl(1, 0, 4).encoding(), 0x19609081);
+        assert_eq!(prod.rcwswpp(1, 0, 4).encoding(), 0x1920a081);
+        assert_eq!(prod.rcwsetp(1, 0, 4).encoding(), 0x1920b081);
+
+        assert_eq!(prod.rcwsclrp(1, 0, 4).encoding(), 0x59209081);
+        assert_eq!(prod.rcwsswppal(1, 0, 4).encoding(), 0x59e0a081);
+        assert_eq!(prod.rcwssetpa(1, 0, 4).encoding(), 0x59a0b081);
+
+        assert_panic!("Should panic: same registers"; prod.rcwsetp(1, 1, 4));
+    }
+}
@@ -0,0 +1,195 @@
+//! # Guarded control stack helpers
+//!
+//! Implements frame setup and teardown sequences for generated functions that keep the hardware
+//! shadow stack (Guarded Control Stack, FEAT_GCS) consistent with the return addresses used by
+//! the generated code.
+//!
+//! With GCS enabled, every `BL`/`BLR` pushes its return address onto the GCS and every `RET`
+//! pops the top entry and faults if it does not match the return address in the link register.
+//! Functions that are entered and left via `BL`/`RET` need no extra instructions. Generated code
+//! that enters a function without `BL` (e.g. `BR` with a prepared `X30`), leaves it without
+//! `RET` or replaces its return address must update the GCS with
+//! [GCSPUSHM](https://developer.arm.com/documentation/ddi0602/2023-12/Base-Instructions/GCSPUSHM--Guarded-Control-Stack-Push--an-alias-of-SYS-?lang=en) and
+//! [GCSPOPM](https://developer.arm.com/documentation/ddi0602/2023-12/Base-Instructions/GCSPOPM--Guarded-Control-Stack-Pop--an-alias-of-SYSL-?lang=en).
+//!
+//! `GCSPUSHM` must be enabled for the current process (`GCSCR_EL0.PUSHMEn`, on Linux
+//! `PR_SHADOW_STACK_PUSH`), otherwise it traps.
+//!
+//! All helpers return the emitted instructions in program order.
+
+use crate::instruction_encoding::branch_exception_system::system_instructions::SystemInstructions;
+use crate::instruction_encoding::branch_exception_system::unconditional_branch_register::UnconditionalBranchRegister;
+use crate::instruction_encoding::common_aliases::CommonAliases;
+use crate::instruction_encoding::data_proc_imm::add_substract_imm::AddSubtractImmediate;
+use crate::instruction_encoding::loads_and_stores::load_store_reg_pair_post_indexed::LoadStoreRegisterPairPostIndexed;
+use crate::instruction_encoding::loads_and_stores::load_store_reg_pair_pre_indexed::LoadStoreRegisterPairPreIndexed;
+#[allow(unused_imports)]
+use crate::types::register::{RSP, XZR};
+#[allow(unused_imports)]
+use crate::types::Register;
+
+/// Frame pointer register
+#[allow(dead_code)]
+const FP: Register = 29;
+/// Link register
+#[allow(dead_code)]
+const LR: Register = 30;
+
+pub trait ShadowStack<T>:
+    SystemInstructions<T>
+    + LoadStoreRegisterPairPreIndexed<T>
+    + LoadStoreRegisterPairPostIndexed<T>
+    + AddSubtractImmediate<T>
+    + UnconditionalBranchRegister<T>
+    + CommonAliases<T>
+{
+    /// Emits the frame setup of a generated function.
+    ///
+    /// If `push_return_address` is set, the return address in `X30` is pushed onto the GCS first.
+    /// This is required for functions that are entered without `BL`/`BLR` but return with `RET`.
+    ///
+    /// ```asm
+    /// GCSPUSHM X30            // only if push_return_address
+    /// STP X29, X30, [SP, #-16]!
+    /// MOV X29, SP
+    /// ```
+    ///
+    /// *Note*: FEAT_GCS required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_gcs")]
+    fn gcs_prologue(&mut self, push_return_address: bool) -> Vec<T> {
+        let mut instrs = Vec::with_capacity(3);
+        if push_return_address {
+            instrs.push(self.gcspushm(LR));
+        }
+        instrs.push(self.stp_64_pre_index(FP, LR, RSP, -16));
+        instrs.push(self.add_64_imm(FP, RSP, 0));
+        instrs
+    }
+
+    /// Emits the frame teardown of a generated function set up by [`gcs_prologue`](Self::gcs_prologue).
+    ///
+    /// Without `return_to` the function returns with `RET`. Otherwise the GCS entry of the
+    /// function is discarded and the function branches to the address in `return_to`, which must
+    /// be neither `X29` nor `X30` as both are restored from the frame.
+    ///
+    /// ```asm
+    /// LDP X29, X30, [SP], #16
+    /// RET                     // without return_to
+    ///
+    /// LDP X29, X30, [SP], #16
+    /// GCSPOPM XZR             // with return_to
+    /// BR <Xn>
+    /// ```
+    ///
+    /// *Note*: FEAT_GCS required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_gcs")]
+    fn gcs_epilogue(&mut self, return_to: Option<Register>) -> Vec<T> {
+        let mut instrs = Vec::with_capacity(3);
+        instrs.push(self.ldp_64_post_index(FP, LR, RSP, 16));
+        match return_to {
+            None => instrs.push(self.ret()),
+            Some(xn) => {
+                debug_assert!(
+                    xn != FP && xn != LR,
+                    "return_to must not be x29 or x30, was x{}",
+                    xn
+                );
+                instrs.push(self.gcspopm(XZR));
+                instrs.push(self.br(xn));
+            }
+        }
+        instrs
+    }
+
+    /// Replaces the return address of the current function with the address in `xt`, both in the
+    /// link register and on the GCS, so that a subsequent `RET` returns to `xt`.
+    ///
+    /// ```asm
+    /// GCSPOPM XZR
+    /// GCSPUSHM <Xt>
+    /// MOV X30, <Xt>           // only if xt is not x30
+    /// ```
+    ///
+    /// *Note*: FEAT_GCS required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_gcs")]
+    fn gcs_replace_return_address(&mut self, xt: Register) -> Vec<T> {
+        debug_assert!(xt != XZR, "xt must not be xzr");
+        let mut instrs = Vec::with_capacity(3);
+        instrs.push(self.gcspopm(XZR));
+        instrs.push(self.gcspushm(xt));
+        if xt != LR {
+            instrs.push(self.mov_64_reg(LR, xt));
+        }
+        instrs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use crate::assert_panic;
+    #[allow(unused_imports)]
+    use crate::test_utils::test_producer::TestProducer;
+
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[cfg(feature = "arm_feat_gcs")]
+    #[test]
+    fn test_gcs_prologue_epilogue() {
+        let mut prod = TestProducer::new();
+
+        let instrs = prod.gcs_prologue(false);
+        assert_eq!(instrs, ["stp x29, x30, [sp, #0xfffffffffffffff0]!", "mov x29, sp"]);
+
+        let instrs = prod.gcs_prologue(true);
+        assert_eq!(
+            instrs,
+            [
+                "sys #0x3, c7, c7, #0x0, x30",
+                "stp x29, x30, [sp, #0xfffffffffffffff0]!",
+                "mov x29, sp"
+            ]
+        );
+
+        let instrs = prod.gcs_epilogue(None);
+        assert_eq!(instrs, ["ldp x29, x30, [sp], #0x10", "ret"]);
+
+        let instrs = prod.gcs_epilogue(Some(9));
+        assert_eq!(
+            instrs,
+            [
+                "ldp x29, x30, [sp], #0x10",
+                "sysl xzr, #0x3, c7, c7, #0x1",
+                "br x9"
+            ]
+        );
+
+        assert_panic!("Should panic: x30 is restored"; prod.gcs_epilogue(Some(30)));
+    }
+
+    #[cfg(feature = "arm_feat_gcs")]
+    #[test]
+    fn test_gcs_replace_return_address() {
+        let mut prod = TestProducer::new();
+
+        let instrs = prod.gcs_replace_return_address(9);
+        assert_eq!(
+            instrs,
+            [
+                "sysl xzr, #0x3, c7, c7, #0x1",
+                "sys #0x3, c7, c7, #0x0, x9",
+                "mov x30, x9"
+            ]
+        );
+
+        let instrs = prod.gcs_replace_return_address(30);
+        assert_eq!(
+            instrs,
+            ["sysl xzr, #0x3, c7, c7, #0x1", "sys #0x3, c7, c7, #0x0, x30"]
+        );
+    }
+}
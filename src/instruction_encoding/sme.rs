@@ -0,0 +1,43 @@
+//! # [SME encodings](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/SME-encodings?lang=en)
+//!
+//! Contains the following instruction types:
+//! - [SME Outer Product](outer_product)
+//! - [SME Streaming Mode](streaming_mode)
+//! - [SME Memory - Tile Slice Load and Store](tile_load_store)
+//! - [SME Move and Zero ZA Tiles](tile_move)
+//!
+//! All instructions require the `arm_feat_sme` feature.
+
+use crate::instruction_encoding::sme::outer_product::SmeOuterProduct;
+use crate::instruction_encoding::sme::streaming_mode::SmeStreamingMode;
+use crate::instruction_encoding::sme::tile_load_store::SmeTileLoadStore;
+use crate::instruction_encoding::sme::tile_move::SmeTileMove;
+#[allow(unused_imports)]
+use crate::types::sme::ZaSliceS;
+#[allow(unused_imports)]
+use crate::types::sve::PReg;
+
+pub mod outer_product;
+pub mod streaming_mode;
+pub mod tile_load_store;
+pub mod tile_move;
+
+pub trait SmeInstructionSet<T>:
+    SmeOuterProduct<T>
+    + SmeStreamingMode<T>
+    + SmeTileLoadStore<T>
+    + SmeTileMove<T>
+{
+}
+
+/// Asserts that the slice offset and `pg` are in range.
+#[inline(always)]
+#[cfg(feature = "arm_feat_sme")]
+pub(crate) fn debug_assert_slice(slice: ZaSliceS, pg: PReg) {
+    debug_assert!(
+        slice.offset <= 3,
+        "slice offset must be in range 0 to 3, was {}",
+        slice.offset
+    );
+    debug_assert!(pg.0 <= 7, "pg must be in range P0 to P7, was {}", pg.0);
+}
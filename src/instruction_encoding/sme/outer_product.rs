@@ -0,0 +1,229 @@
+//! # [SME Outer Product](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/SME-encodings?lang=en)
+//!
+//! Implements the following instructions:
+//!  - [FMOPA - non-widening - Floating-point outer product and accumulate](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/FMOPA--non-widening---Floating-point-outer-product-and-accumulate-?lang=en)
+//!  - [FMOPS - non-widening - Floating-point outer product and subtract](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/FMOPS--non-widening---Floating-point-outer-product-and-subtract-?lang=en)
+//!  - [SMOPA - Signed integer sum of outer products and accumulate](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/SMOPA--Signed-integer-sum-of-outer-products-and-accumulate-?lang=en)
+//!  - [SMOPS - Signed integer sum of outer products and subtract](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/SMOPS--Signed-integer-sum-of-outer-products-and-subtract-?lang=en)
+//!  - [UMOPA - Unsigned integer sum of outer products and accumulate](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/UMOPA--Unsigned-integer-sum-of-outer-products-and-accumulate-?lang=en)
+//!  - [UMOPS - Unsigned integer sum of outer products and subtract](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/UMOPS--Unsigned-integer-sum-of-outer-products-and-subtract-?lang=en)
+
+#[allow(unused_imports)]
+use bit_seq::bseq_32;
+
+use crate::instruction_encoding::InstructionProcessor;
+#[allow(unused_imports)]
+use crate::types::sme::ZaTileS;
+#[allow(unused_imports)]
+use crate::types::sve::{PReg, SveSize, ZReg};
+
+#[inline(always)]
+#[cfg(feature = "arm_feat_sme")]
+fn debug_assert_outer_product(pn: PReg, pm: PReg, zn: ZReg, zm: ZReg, size: SveSize) {
+    debug_assert!(pn.0 <= 7, "pn must be in range P0 to P7, was {}", pn.0);
+    debug_assert!(pm.0 <= 7, "pm must be in range P0 to P7, was {}", pm.0);
+    debug_assert!(zn.0 <= 31, "zn must be in range Z0 to Z31, was {}", zn.0);
+    debug_assert!(zm.0 <= 31, "zm must be in range Z0 to Z31, was {}", zm.0);
+    debug_assert!(
+        zn.1 == size && zm.1 == size,
+        "zn and zm must have element size {}",
+        size
+    );
+}
+
+#[inline(always)]
+#[cfg(feature = "arm_feat_sme")]
+fn emit_fp_outer_product<P: InstructionProcessor<T>, T>(
+    proc: &mut P,
+    zada: ZaTileS,
+    pn: PReg,
+    pm: PReg,
+    zn: ZReg,
+    zm: ZReg,
+    s: u8,
+) -> T {
+    debug_assert_outer_product(pn, pm, zn, zm, SveSize::S);
+    let zada: u8 = zada.into();
+    let (pn, pm, zn, zm) = (pn.0, pm.0, zn.0, zm.0);
+    let i = bseq_32!(10000000100 zm:5 pm:3 pn:3 zn:5 s:1 00 zada:2);
+    proc.process(i)
+}
+
+#[inline(always)]
+#[cfg(feature = "arm_feat_sme")]
+fn emit_int_outer_product<P: InstructionProcessor<T>, T>(
+    proc: &mut P,
+    zada: ZaTileS,
+    pn: PReg,
+    pm: PReg,
+    zn: ZReg,
+    zm: ZReg,
+    u: u8,
+    s: u8,
+) -> T {
+    debug_assert_outer_product(pn, pm, zn, zm, SveSize::B);
+    let zada: u8 = zada.into();
+    let (pn, pm, zn, zm) = (pn.0, pm.0, zn.0, zm.0);
+    let i = bseq_32!(1010000 u:1 10 u:1 zm:5 pm:3 pn:3 zn:5 s:1 00 zada:2);
+    proc.process(i)
+}
+
+/// # [SME Outer Product](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/SME-encodings?lang=en)
+///
+/// Implements the following instructions:
+///  - [FMOPA - non-widening - Floating-point outer product and accumulate](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/FMOPA--non-widening---Floating-point-outer-product-and-accumulate-?lang=en)
+///  - [FMOPS - non-widening - Floating-point outer product and subtract](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/FMOPS--non-widening---Floating-point-outer-product-and-subtract-?lang=en)
+///  - [SMOPA - Signed integer sum of outer products and accumulate](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/SMOPA--Signed-integer-sum-of-outer-products-and-accumulate-?lang=en)
+///  - [SMOPS - Signed integer sum of outer products and subtract](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/SMOPS--Signed-integer-sum-of-outer-products-and-subtract-?lang=en)
+///  - [UMOPA - Unsigned integer sum of outer products and accumulate](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/UMOPA--Unsigned-integer-sum-of-outer-products-and-accumulate-?lang=en)
+///  - [UMOPS - Unsigned integer sum of outer products and subtract](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/UMOPS--Unsigned-integer-sum-of-outer-products-and-subtract-?lang=en)
+pub trait SmeOuterProduct<T>: InstructionProcessor<T> {
+    /// [FMOPA - non-widening - Floating-point outer product and accumulate](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/FMOPA--non-widening---Floating-point-outer-product-and-accumulate-?lang=en)
+    ///
+    /// The single-precision floating-point outer products of the active elements of the first and second source vectors are added to the corresponding elements of the 32-bit element ZA tile. Inactive elements of the source vectors are treated as zero and do not contribute to the result.
+    ///
+    /// `zn` and `zm` must have element size `S`, `pn` and `pm` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// FMOPA <ZAda>.S, <Pn>/M, <Pm>/M, <Zn>.S, <Zm>.S
+    /// ```
+    ///
+    /// *Note*: FEAT_SME required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sme")]
+    fn fmopa(&mut self, zada: ZaTileS, pn: PReg, pm: PReg, zn: ZReg, zm: ZReg) -> T {
+        emit_fp_outer_product(self, zada, pn, pm, zn, zm, 0)
+    }
+
+    /// [FMOPS - non-widening - Floating-point outer product and subtract](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/FMOPS--non-widening---Floating-point-outer-product-and-subtract-?lang=en)
+    ///
+    /// The single-precision floating-point outer products of the active elements of the first and second source vectors are subtracted from the corresponding elements of the 32-bit element ZA tile. Inactive elements of the source vectors are treated as zero and do not contribute to the result.
+    ///
+    /// `zn` and `zm` must have element size `S`, `pn` and `pm` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// FMOPS <ZAda>.S, <Pn>/M, <Pm>/M, <Zn>.S, <Zm>.S
+    /// ```
+    ///
+    /// *Note*: FEAT_SME required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sme")]
+    fn fmops(&mut self, zada: ZaTileS, pn: PReg, pm: PReg, zn: ZReg, zm: ZReg) -> T {
+        emit_fp_outer_product(self, zada, pn, pm, zn, zm, 1)
+    }
+
+    /// [SMOPA - Signed integer sum of outer products and accumulate](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/SMOPA--Signed-integer-sum-of-outer-products-and-accumulate-?lang=en)
+    ///
+    /// The sums of four signed 8-bit integer outer products of the active elements of the first and second source vectors are added to the corresponding 32-bit elements of the ZA tile. Inactive elements of the source vectors are treated as zero and do not contribute to the result.
+    ///
+    /// `zn` and `zm` must have element size `B`, `pn` and `pm` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// SMOPA <ZAda>.S, <Pn>/M, <Pm>/M, <Zn>.B, <Zm>.B
+    /// ```
+    ///
+    /// *Note*: FEAT_SME required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sme")]
+    fn smopa(&mut self, zada: ZaTileS, pn: PReg, pm: PReg, zn: ZReg, zm: ZReg) -> T {
+        emit_int_outer_product(self, zada, pn, pm, zn, zm, 0, 0)
+    }
+
+    /// [SMOPS - Signed integer sum of outer products and subtract](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/SMOPS--Signed-integer-sum-of-outer-products-and-subtract-?lang=en)
+    ///
+    /// The sums of four signed 8-bit integer outer products of the active elements of the first and second source vectors are subtracted from the corresponding 32-bit elements of the ZA tile. Inactive elements of the source vectors are treated as zero and do not contribute to the result.
+    ///
+    /// `zn` and `zm` must have element size `B`, `pn` and `pm` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// SMOPS <ZAda>.S, <Pn>/M, <Pm>/M, <Zn>.B, <Zm>.B
+    /// ```
+    ///
+    /// *Note*: FEAT_SME required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sme")]
+    fn smops(&mut self, zada: ZaTileS, pn: PReg, pm: PReg, zn: ZReg, zm: ZReg) -> T {
+        emit_int_outer_product(self, zada, pn, pm, zn, zm, 0, 1)
+    }
+
+    /// [UMOPA - Unsigned integer sum of outer products and accumulate](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/UMOPA--Unsigned-integer-sum-of-outer-products-and-accumulate-?lang=en)
+    ///
+    /// The sums of four unsigned 8-bit integer outer products of the active elements of the first and second source vectors are added to the corresponding 32-bit elements of the ZA tile. Inactive elements of the source vectors are treated as zero and do not contribute to the result.
+    ///
+    /// `zn` and `zm` must have element size `B`, `pn` and `pm` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// UMOPA <ZAda>.S, <Pn>/M, <Pm>/M, <Zn>.B, <Zm>.B
+    /// ```
+    ///
+    /// *Note*: FEAT_SME required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sme")]
+    fn umopa(&mut self, zada: ZaTileS, pn: PReg, pm: PReg, zn: ZReg, zm: ZReg) -> T {
+        emit_int_outer_product(self, zada, pn, pm, zn, zm, 1, 0)
+    }
+
+    /// [UMOPS - Unsigned integer sum of outer products and subtract](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/UMOPS--Unsigned-integer-sum-of-outer-products-and-subtract-?lang=en)
+    ///
+    /// The sums of four unsigned 8-bit integer outer products of the active elements of the first and second source vectors are subtracted from the corresponding 32-bit elements of the ZA tile. Inactive elements of the source vectors are treated as zero and do not contribute to the result.
+    ///
+    /// `zn` and `zm` must have element size `B`, `pn` and `pm` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// UMOPS <ZAda>.S, <Pn>/M, <Pm>/M, <Zn>.B, <Zm>.B
+    /// ```
+    ///
+    /// *Note*: FEAT_SME required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sme")]
+    fn umops(&mut self, zada: ZaTileS, pn: PReg, pm: PReg, zn: ZReg, zm: ZReg) -> T {
+        emit_int_outer_product(self, zada, pn, pm, zn, zm, 1, 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use crate::assert_panic;
+    #[allow(unused_imports)]
+    use crate::test_utils::test_producer::TestProducer;
+
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[cfg(feature = "arm_feat_sme")]
+    #[test]
+    fn test_fp_outer_product() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.fmopa(ZaTileS::ZA2, PReg::b(1), PReg::b(2), ZReg::s(4), ZReg::s(3));
+        assert_eq!(instr, "fmopa Z2.s, p1/m, p2/m, z4.s, z3.s");
+
+        let instr = prod.fmops(ZaTileS::ZA2, PReg::b(1), PReg::b(2), ZReg::s(4), ZReg::s(3));
+        assert_eq!(instr, "fmops Z2.s, p1/m, p2/m, z4.s, z3.s");
+
+        assert_panic!("Should panic: element size B"; prod.fmopa(ZaTileS::ZA2, PReg::b(1), PReg::b(2), ZReg::b(4), ZReg::b(3)));
+
+        assert_panic!("Should panic: pn out of range"; prod.fmopa(ZaTileS::ZA2, PReg::b(8), PReg::b(2), ZReg::s(4), ZReg::s(3)));
+    }
+
+    #[cfg(feature = "arm_feat_sme")]
+    #[test]
+    fn test_int_outer_product() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.smopa(ZaTileS::ZA2, PReg::b(1), PReg::b(2), ZReg::b(4), ZReg::b(3));
+        assert_eq!(instr, "smopa Z2.s, p1/m, p2/m, z4.b, z3.b");
+
+        let instr = prod.smops(ZaTileS::ZA2, PReg::b(1), PReg::b(2), ZReg::b(4), ZReg::b(3));
+        assert_eq!(instr, "smops Z2.s, p1/m, p2/m, z4.b, z3.b");
+
+        let instr = prod.umopa(ZaTileS::ZA2, PReg::b(1), PReg::b(2), ZReg::b(4), ZReg::b(3));
+        assert_eq!(instr, "umopa Z2.s, p1/m, p2/m, z4.b, z3.b");
+
+        let instr = prod.umops(ZaTileS::ZA2, PReg::b(1), PReg::b(2), ZReg::b(4), ZReg::b(3));
+        assert_eq!(instr, "umops Z2.s, p1/m, p2/m, z4.b, z3.b");
+
+        assert_panic!("Should panic: element size S"; prod.smopa(ZaTileS::ZA2, PReg::b(1), PReg::b(2), ZReg::s(4), ZReg::s(3)));
+    }
+}
@@ -0,0 +1,141 @@
+//! # [SME Streaming Mode](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/SME-encodings?lang=en)
+//!
+//! Implements the following instructions:
+//!  - [SMSTART - Enables access to Streaming SVE mode and SME architectural state](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/SMSTART--Enables-access-to-Streaming-SVE-mode-and-SME-architectural-state--an-alias-of-MSR--immediate---?lang=en)
+//!  - [SMSTOP - Disables access to Streaming SVE mode and SME architectural state](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/SMSTOP--Disables-access-to-Streaming-SVE-mode-and-SME-architectural-state--an-alias-of-MSR--immediate---?lang=en)
+
+use crate::instruction_encoding::branch_exception_system::pstate::PStateInstructions;
+#[allow(unused_imports)]
+use crate::types::pstate::PStateField;
+
+/// # [SME Streaming Mode](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/SME-encodings?lang=en)
+///
+/// Implements the following instructions:
+///  - [SMSTART - Enables access to Streaming SVE mode and SME architectural state](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/SMSTART--Enables-access-to-Streaming-SVE-mode-and-SME-architectural-state--an-alias-of-MSR--immediate---?lang=en)
+///  - [SMSTOP - Disables access to Streaming SVE mode and SME architectural state](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/SMSTOP--Disables-access-to-Streaming-SVE-mode-and-SME-architectural-state--an-alias-of-MSR--immediate---?lang=en)
+pub trait SmeStreamingMode<T>: PStateInstructions<T> {
+    /// [SMSTART - Enables access to Streaming SVE mode and SME architectural state](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/SMSTART--Enables-access-to-Streaming-SVE-mode-and-SME-architectural-state--an-alias-of-MSR--immediate---?lang=en)
+    ///
+    /// Enable Streaming SVE mode and SME architectural state (`PSTATE.SM` and `PSTATE.ZA`). This is an alias of `MSR SVCRSMZA, #1`.
+    ///
+    /// ```asm
+    /// SMSTART
+    /// ```
+    ///
+    /// *Note*: FEAT_SME required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sme")]
+    fn smstart(&mut self) -> T {
+        self.msr_imm(PStateField::SVCRSMZA, 1)
+    }
+
+    /// [SMSTART - Enables access to Streaming SVE mode and SME architectural state](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/SMSTART--Enables-access-to-Streaming-SVE-mode-and-SME-architectural-state--an-alias-of-MSR--immediate---?lang=en)
+    ///
+    /// Enable Streaming SVE mode (`PSTATE.SM`). This is an alias of `MSR SVCRSM, #1`.
+    ///
+    /// ```asm
+    /// SMSTART SM
+    /// ```
+    ///
+    /// *Note*: FEAT_SME required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sme")]
+    fn smstart_sm(&mut self) -> T {
+        self.msr_imm(PStateField::SVCRSM, 1)
+    }
+
+    /// [SMSTART - Enables access to Streaming SVE mode and SME architectural state](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/SMSTART--Enables-access-to-Streaming-SVE-mode-and-SME-architectural-state--an-alias-of-MSR--immediate---?lang=en)
+    ///
+    /// Enable SME architectural state (`PSTATE.ZA`). This is an alias of `MSR SVCRZA, #1`.
+    ///
+    /// ```asm
+    /// SMSTART ZA
+    /// ```
+    ///
+    /// *Note*: FEAT_SME required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sme")]
+    fn smstart_za(&mut self) -> T {
+        self.msr_imm(PStateField::SVCRZA, 1)
+    }
+
+    /// [SMSTOP - Disables access to Streaming SVE mode and SME architectural state](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/SMSTOP--Disables-access-to-Streaming-SVE-mode-and-SME-architectural-state--an-alias-of-MSR--immediate---?lang=en)
+    ///
+    /// Disable Streaming SVE mode and SME architectural state (`PSTATE.SM` and `PSTATE.ZA`). This is an alias of `MSR SVCRSMZA, #0`.
+    ///
+    /// ```asm
+    /// SMSTOP
+    /// ```
+    ///
+    /// *Note*: FEAT_SME required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sme")]
+    fn smstop(&mut self) -> T {
+        self.msr_imm(PStateField::SVCRSMZA, 0)
+    }
+
+    /// [SMSTOP - Disables access to Streaming SVE mode and SME architectural state](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/SMSTOP--Disables-access-to-Streaming-SVE-mode-and-SME-architectural-state--an-alias-of-MSR--immediate---?lang=en)
+    ///
+    /// Disable Streaming SVE mode (`PSTATE.SM`). This is an alias of `MSR SVCRSM, #0`.
+    ///
+    /// ```asm
+    /// SMSTOP SM
+    /// ```
+    ///
+    /// *Note*: FEAT_SME required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sme")]
+    fn smstop_sm(&mut self) -> T {
+        self.msr_imm(PStateField::SVCRSM, 0)
+    }
+
+    /// [SMSTOP - Disables access to Streaming SVE mode and SME architectural state](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/SMSTOP--Disables-access-to-Streaming-SVE-mode-and-SME-architectural-state--an-alias-of-MSR--immediate---?lang=en)
+    ///
+    /// Disable SME architectural state (`PSTATE.ZA`). This is an alias of `MSR SVCRZA, #0`.
+    ///
+    /// ```asm
+    /// SMSTOP ZA
+    /// ```
+    ///
+    /// *Note*: FEAT_SME required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sme")]
+    fn smstop_za(&mut self) -> T {
+        self.msr_imm(PStateField::SVCRZA, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use crate::assert_panic;
+    #[allow(unused_imports)]
+    use crate::test_utils::test_producer::TestProducer;
+
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[cfg(feature = "arm_feat_sme")]
+    #[test]
+    fn test_smstart_smstop() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.smstart();
+        assert_eq!(instr, "smstart");
+
+        let instr = prod.smstart_sm();
+        assert_eq!(instr, "smstart SM");
+
+        let instr = prod.smstart_za();
+        assert_eq!(instr, "smstart ZA");
+
+        let instr = prod.smstop();
+        assert_eq!(instr, "smstop");
+
+        let instr = prod.smstop_sm();
+        assert_eq!(instr, "smstop SM");
+
+        let instr = prod.smstop_za();
+        assert_eq!(instr, "smstop ZA");
+    }
+}
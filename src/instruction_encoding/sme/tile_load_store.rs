@@ -0,0 +1,122 @@
+//! # [SME Memory - Tile Slice Load and Store](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/SME-encodings?lang=en)
+//!
+//! Implements the following instructions:
+//!  - [LD1W - scalar plus scalar - tile slice - Contiguous load of words to 32-bit element ZA tile slice](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/LD1W--scalar-plus-scalar--tile-slice---Contiguous-load-of-words-to-32-bit-element-ZA-tile-slice-?lang=en)
+//!  - [ST1W - scalar plus scalar - tile slice - Contiguous store of words from 32-bit element ZA tile slice](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/ST1W--scalar-plus-scalar--tile-slice---Contiguous-store-of-words-from-32-bit-element-ZA-tile-slice-?lang=en)
+
+#[allow(unused_imports)]
+use bit_seq::bseq_32;
+
+#[cfg(feature = "arm_feat_sme")]
+use crate::instruction_encoding::sme::debug_assert_slice;
+use crate::instruction_encoding::InstructionProcessor;
+#[allow(unused_imports)]
+use crate::types::sme::{ZaDir, ZaSliceS};
+#[allow(unused_imports)]
+use crate::types::sve::PReg;
+#[allow(unused_imports)]
+use crate::types::Register;
+
+#[inline(always)]
+#[cfg(feature = "arm_feat_sme")]
+fn emit_ld_st_tile_slice_s<P: InstructionProcessor<T>, T>(
+    proc: &mut P,
+    st: u8,
+    slice: ZaSliceS,
+    pg: PReg,
+    xn: Register,
+    xm: Register,
+) -> T {
+    debug_assert_slice(slice, pg);
+    let v = (slice.dir == ZaDir::V) as u8;
+    let (rs, tile, off, pg): (u8, u8, u8, u8) = (slice.rs.into(), slice.tile.into(), slice.offset, pg.0);
+    let i = bseq_32!(1110000010 st:1 xm:5 v:1 rs:2 pg:3 xn:5 0 tile:2 off:2);
+    proc.process(i)
+}
+
+/// # [SME Memory - Tile Slice Load and Store](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/SME-encodings?lang=en)
+///
+/// Implements the following instructions:
+///  - [LD1W - scalar plus scalar - tile slice - Contiguous load of words to 32-bit element ZA tile slice](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/LD1W--scalar-plus-scalar--tile-slice---Contiguous-load-of-words-to-32-bit-element-ZA-tile-slice-?lang=en)
+///  - [ST1W - scalar plus scalar - tile slice - Contiguous store of words from 32-bit element ZA tile slice](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/ST1W--scalar-plus-scalar--tile-slice---Contiguous-store-of-words-from-32-bit-element-ZA-tile-slice-?lang=en)
+pub trait SmeTileLoadStore<T>: InstructionProcessor<T> {
+    /// [LD1W - scalar plus scalar - tile slice - Contiguous load of words to 32-bit element ZA tile slice](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/LD1W--scalar-plus-scalar--tile-slice---Contiguous-load-of-words-to-32-bit-element-ZA-tile-slice-?lang=en)
+    ///
+    /// The slice number within the tile is selected by the sum of the slice index register and immediate offset, modulo the number of 32-bit elements. Words are loaded from the address generated by `xn` plus `xm` scaled by 4 to the active elements of the slice. Inactive elements are set to zero.
+    ///
+    /// `pg` must be in range P0 to P7 and the slice offset in range 0 to 3. An `xm` of 31 is `XZR`.
+    ///
+    /// ```asm
+    /// LD1W { <ZAt><HV>.S[<Ws>, <offs>] }, <Pg>/Z, [<Xn|SP>{, <Xm>, LSL #2}]
+    /// ```
+    ///
+    /// *Note*: FEAT_SME required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sme")]
+    fn ld1w_za(&mut self, slice: ZaSliceS, pg: PReg, xn: Register, xm: Register) -> T {
+        emit_ld_st_tile_slice_s(self, 0, slice, pg, xn, xm)
+    }
+
+    /// [ST1W - scalar plus scalar - tile slice - Contiguous store of words from 32-bit element ZA tile slice](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/ST1W--scalar-plus-scalar--tile-slice---Contiguous-store-of-words-from-32-bit-element-ZA-tile-slice-?lang=en)
+    ///
+    /// The slice number within the tile is selected by the sum of the slice index register and immediate offset, modulo the number of 32-bit elements. The active elements of the slice are stored to the address generated by `xn` plus `xm` scaled by 4. Inactive elements are not written to memory.
+    ///
+    /// `pg` must be in range P0 to P7 and the slice offset in range 0 to 3. An `xm` of 31 is `XZR`.
+    ///
+    /// ```asm
+    /// ST1W { <ZAt><HV>.S[<Ws>, <offs>] }, <Pg>, [<Xn|SP>{, <Xm>, LSL #2}]
+    /// ```
+    ///
+    /// *Note*: FEAT_SME required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sme")]
+    fn st1w_za(&mut self, slice: ZaSliceS, pg: PReg, xn: Register, xm: Register) -> T {
+        emit_ld_st_tile_slice_s(self, 1, slice, pg, xn, xm)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use crate::assert_panic;
+    #[allow(unused_imports)]
+    use crate::test_utils::test_producer::TestProducer;
+    #[allow(unused_imports)]
+    use crate::types::sme::{ZaSliceReg, ZaTileS};
+
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[cfg(feature = "arm_feat_sme")]
+    #[test]
+    fn test_ld1w_st1w_za() {
+        let mut prod = TestProducer::new();
+
+        // bad64 does not print the tile number of the tile slice
+        let instr = prod.ld1w_za(ZaSliceS::h(ZaTileS::ZA0, ZaSliceReg::W13, 2), PReg::b(2), 3, 4);
+        assert_eq!(instr, "ld1w Z0H.s[w13, 0x2], p2/z, [x3, x4, lsl #0x2]");
+
+        let instr = prod.ld1w_za(ZaSliceS::v(ZaTileS::ZA0, ZaSliceReg::W13, 2), PReg::b(2), 3, 4);
+        assert_eq!(instr, "ld1w Z0V.s[w13, 0x2], p2/z, [x3, x4, lsl #0x2]");
+
+        let instr = prod.ld1w_za(ZaSliceS::h(ZaTileS::ZA0, ZaSliceReg::W13, 2), PReg::b(2), 3, 31);
+        assert_eq!(instr, "ld1w Z0H.s[w13, 0x2], p2/z, [x3, xzr, lsl #0x2]");
+
+        let instr = prod.st1w_za(ZaSliceS::h(ZaTileS::ZA0, ZaSliceReg::W13, 2), PReg::b(2), 3, 4);
+        assert_eq!(instr, "st1w Z0H.s[w13, 0x2], p2, [x3, x4, lsl #0x2]");
+
+        // the tile number is compared by encoding
+        let mut prod = crate::instruction_producer::InstrProducer::new();
+        let instr = prod.ld1w_za(ZaSliceS::h(ZaTileS::ZA1, ZaSliceReg::W13, 2), PReg::b(2), 3, 4);
+        assert_eq!(instr.encoding(), 0xe0842866);
+        let instr = prod.ld1w_za(ZaSliceS::v(ZaTileS::ZA2, ZaSliceReg::W12, 1), PReg::b(2), 3, 4);
+        assert_eq!(instr.encoding(), 0xe0848869);
+        let instr = prod.st1w_za(ZaSliceS::h(ZaTileS::ZA3, ZaSliceReg::W15, 3), PReg::b(7), 31, 4);
+        assert_eq!(instr.encoding(), 0xe0a47fef);
+
+        let mut prod = TestProducer::new();
+        assert_panic!("Should panic: offset out of range"; prod.ld1w_za(ZaSliceS::h(ZaTileS::ZA0, ZaSliceReg::W13, 4), PReg::b(2), 3, 4));
+
+        assert_panic!("Should panic: pg out of range"; prod.st1w_za(ZaSliceS::h(ZaTileS::ZA0, ZaSliceReg::W13, 2), PReg::b(8), 3, 4));
+    }
+}
@@ -0,0 +1,164 @@
+//! # [SME Move and Zero ZA Tiles](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/SME-encodings?lang=en)
+//!
+//! Implements the following instructions:
+//!  - [MOVA - tile to vector - Move ZA tile slice to vector register](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/MOVA--tile-to-vector---Move-ZA-tile-slice-to-vector-register-?lang=en)
+//!  - [MOVA - vector to tile - Move vector register to ZA tile slice](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/MOVA--vector-to-tile---Move-vector-register-to-ZA-tile-slice-?lang=en)
+//!  - [ZERO - Zero a list of 64-bit element ZA tiles](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/ZERO--Zero-a-list-of-64-bit-element-ZA-tiles-?lang=en)
+
+#[allow(unused_imports)]
+use bit_seq::bseq_32;
+
+#[cfg(feature = "arm_feat_sme")]
+use crate::instruction_encoding::sme::debug_assert_slice;
+use crate::instruction_encoding::InstructionProcessor;
+#[allow(unused_imports)]
+use crate::types::sme::{ZaDir, ZaSliceS, ZaTileD};
+#[allow(unused_imports)]
+use crate::types::sve::{PReg, SveSize, ZReg};
+
+/// # [SME Move and Zero ZA Tiles](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/SME-encodings?lang=en)
+///
+/// Implements the following instructions:
+///  - [MOVA - tile to vector - Move ZA tile slice to vector register](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/MOVA--tile-to-vector---Move-ZA-tile-slice-to-vector-register-?lang=en)
+///  - [MOVA - vector to tile - Move vector register to ZA tile slice](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/MOVA--vector-to-tile---Move-vector-register-to-ZA-tile-slice-?lang=en)
+///  - [ZERO - Zero a list of 64-bit element ZA tiles](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/ZERO--Zero-a-list-of-64-bit-element-ZA-tiles-?lang=en)
+pub trait SmeTileMove<T>: InstructionProcessor<T> {
+    /// [MOVA - tile to vector - Move ZA tile slice to vector register](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/MOVA--tile-to-vector---Move-ZA-tile-slice-to-vector-register-?lang=en)
+    ///
+    /// Move the active elements of a 32-bit element ZA tile slice to the corresponding elements of the destination vector register. Inactive elements in the destination vector register remain unmodified.
+    ///
+    /// `zd` must have element size `S`, `pg` must be in range P0 to P7 and the slice offset in range 0 to 3.
+    ///
+    /// ```asm
+    /// MOVA <Zd>.S, <Pg>/M, <ZAn><HV>.S[<Ws>, <offs>]
+    /// ```
+    ///
+    /// *Note*: FEAT_SME required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sme")]
+    fn mova_tile_to_vec(&mut self, zd: ZReg, pg: PReg, slice: ZaSliceS) -> T {
+        debug_assert_slice(slice, pg);
+        debug_assert!(zd.1 == SveSize::S, "zd must have element size S");
+        let v = (slice.dir == ZaDir::V) as u8;
+        let (rs, tile, off): (u8, u8, u8) = (slice.rs.into(), slice.tile.into(), slice.offset);
+        let (zd, pg) = (zd.0, pg.0);
+        let i = bseq_32!(1100000010 00001 0 v:1 rs:2 pg:3 0 tile:2 off:2 zd:5);
+        self.process(i)
+    }
+
+    /// [MOVA - vector to tile - Move vector register to ZA tile slice](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/MOVA--vector-to-tile---Move-vector-register-to-ZA-tile-slice-?lang=en)
+    ///
+    /// Move the active elements of the source vector register to the corresponding elements of a 32-bit element ZA tile slice. Inactive elements in the destination slice remain unmodified.
+    ///
+    /// `zn` must have element size `S`, `pg` must be in range P0 to P7 and the slice offset in range 0 to 3.
+    ///
+    /// ```asm
+    /// MOVA <ZAd><HV>.S[<Ws>, <offs>], <Pg>/M, <Zn>.S
+    /// ```
+    ///
+    /// *Note*: FEAT_SME required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sme")]
+    fn mova_vec_to_tile(&mut self, slice: ZaSliceS, pg: PReg, zn: ZReg) -> T {
+        debug_assert_slice(slice, pg);
+        debug_assert!(zn.1 == SveSize::S, "zn must have element size S");
+        let v = (slice.dir == ZaDir::V) as u8;
+        let (rs, tile, off): (u8, u8, u8) = (slice.rs.into(), slice.tile.into(), slice.offset);
+        let (zn, pg) = (zn.0, pg.0);
+        let i = bseq_32!(1100000010 00000 0 v:1 rs:2 pg:3 zn:5 0 tile:2 off:2);
+        self.process(i)
+    }
+
+    /// [ZERO - Zero a list of 64-bit element ZA tiles](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/ZERO--Zero-a-list-of-64-bit-element-ZA-tiles-?lang=en)
+    ///
+    /// Zero the whole ZA array.
+    ///
+    /// ```asm
+    /// ZERO { ZA }
+    /// ```
+    ///
+    /// *Note*: FEAT_SME required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sme")]
+    fn zero_za(&mut self) -> T {
+        let i = bseq_32!(11000000 00001000 00000000 11111111);
+        self.process(i)
+    }
+
+    /// [ZERO - Zero a list of 64-bit element ZA tiles](https://developer.arm.com/documentation/ddi0596/2021-12/SME-Instructions/ZERO--Zero-a-list-of-64-bit-element-ZA-tiles-?lang=en)
+    ///
+    /// Zero the listed 64-bit element ZA tiles. Tiles with smaller elements are zeroed by listing all 64-bit element tiles that overlap them.
+    ///
+    /// ```asm
+    /// ZERO { <mask> }
+    /// ```
+    ///
+    /// *Note*: FEAT_SME required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sme")]
+    fn zero_za_tiles(&mut self, tiles: &[ZaTileD]) -> T {
+        let mask = tiles
+            .iter()
+            .fold(0u8, |mask, tile| mask | (1 << u8::from(*tile)));
+        let i = bseq_32!(11000000 00001000 00000000 mask:8);
+        self.process(i)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use crate::assert_panic;
+    #[allow(unused_imports)]
+    use crate::test_utils::test_producer::TestProducer;
+    #[allow(unused_imports)]
+    use crate::types::sme::{ZaSliceReg, ZaTileS};
+
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[cfg(feature = "arm_feat_sme")]
+    #[test]
+    fn test_mova() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.mova_tile_to_vec(ZReg::s(5), PReg::b(2), ZaSliceS::h(ZaTileS::ZA1, ZaSliceReg::W13, 2));
+        assert_eq!(instr, "mova z5.s, p2/m, Z1H.s[w13, 0x2]");
+
+        let instr = prod.mova_tile_to_vec(ZReg::s(5), PReg::b(2), ZaSliceS::v(ZaTileS::ZA1, ZaSliceReg::W13, 2));
+        assert_eq!(instr, "mova z5.s, p2/m, Z1V.s[w13, 0x2]");
+
+        let instr = prod.mova_vec_to_tile(ZaSliceS::h(ZaTileS::ZA1, ZaSliceReg::W13, 2), PReg::b(2), ZReg::s(5));
+        assert_eq!(instr, "mova Z1H.s[w13, 0x2], p2/m, z5.s");
+
+        assert_panic!("Should panic: zd element size D"; prod.mova_tile_to_vec(ZReg::d(5), PReg::b(2), ZaSliceS::h(ZaTileS::ZA1, ZaSliceReg::W13, 2)));
+
+        assert_panic!("Should panic: offset out of range"; prod.mova_vec_to_tile(ZaSliceS::h(ZaTileS::ZA1, ZaSliceReg::W13, 4), PReg::b(2), ZReg::s(5)));
+    }
+
+    #[cfg(feature = "arm_feat_sme")]
+    #[test]
+    fn test_zero_za() {
+        use crate::instruction_producer::InstrProducer;
+
+        // bad64 does not decode ZERO, so the encodings are compared
+        let mut prod = InstrProducer::new();
+
+        assert_eq!(prod.zero_za().encoding(), 0xc00800ff);
+        assert_eq!(prod.zero_za_tiles(&[]).encoding(), 0xc0080000);
+        assert_eq!(prod.zero_za_tiles(&[ZaTileD::ZA3]).encoding(), 0xc0080008);
+        assert_eq!(prod.zero_za_tiles(&[ZaTileD::ZA0, ZaTileD::ZA5]).encoding(), 0xc0080021);
+
+        let all = [
+            ZaTileD::ZA0,
+            ZaTileD::ZA1,
+            ZaTileD::ZA2,
+            ZaTileD::ZA3,
+            ZaTileD::ZA4,
+            ZaTileD::ZA5,
+            ZaTileD::ZA6,
+            ZaTileD::ZA7,
+        ];
+        assert_eq!(prod.zero_za_tiles(&all).encoding(), 0xc00800ff);
+    }
+}
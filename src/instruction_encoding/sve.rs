@@ -0,0 +1,84 @@
+//! # [SVE encodings](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/SVE-encodings?lang=en)
+//!
+//! Contains the following instruction types:
+//! - [SVE Memory - Contiguous Load and Store](contiguous_load_store)
+//! - [SVE Element Count](element_count)
+//! - [SVE Floating Point Arithmetic](fp_arith)
+//! - [SVE Floating Point Compare Vectors](fp_compare)
+//! - [SVE Floating Point Convert and Round](fp_convert)
+//! - [SVE Memory - Gather Load and Scatter Store](gather_scatter)
+//! - [SVE Index Generation](index_generation)
+//! - [SVE Integer Arithmetic and Logical](int_arith)
+//! - [SVE Predicate Generation and Test](predicates)
+//! - [SVE2 Character Match, Histogram and Table Lookup](sve2_match_lookup)
+//! - [SVE2 Integer Add/Subtract - Long, Wide and Narrow](sve2_widening_narrowing)
+//!
+//! All instructions require the `arm_feat_sve` feature, the SVE2 instructions additionally
+//! require the `arm_feat_sve2` feature.
+
+use crate::instruction_encoding::sve::contiguous_load_store::SveContiguousLoadStore;
+use crate::instruction_encoding::sve::element_count::SveElementCount;
+use crate::instruction_encoding::sve::fp_arith::SveFloatingPointArithmetic;
+use crate::instruction_encoding::sve::fp_compare::SveFloatingPointCompare;
+use crate::instruction_encoding::sve::fp_convert::SveFloatingPointConvert;
+use crate::instruction_encoding::sve::gather_scatter::SveGatherScatter;
+use crate::instruction_encoding::sve::index_generation::SveIndexGeneration;
+use crate::instruction_encoding::sve::int_arith::SveIntegerArithmetic;
+use crate::instruction_encoding::sve::predicates::SvePredicates;
+use crate::instruction_encoding::sve::sve2_match_lookup::Sve2MatchAndLookup;
+use crate::instruction_encoding::sve::sve2_widening_narrowing::Sve2WideningNarrowing;
+#[allow(unused_imports)]
+use crate::types::sve::{PReg, SveSize, ZReg};
+
+pub mod contiguous_load_store;
+pub mod element_count;
+pub mod fp_arith;
+pub mod fp_compare;
+pub mod fp_convert;
+pub mod gather_scatter;
+pub mod index_generation;
+pub mod int_arith;
+pub mod predicates;
+pub mod sve2_match_lookup;
+pub mod sve2_widening_narrowing;
+
+pub trait SveInstructionSet<T>:
+    Sve2MatchAndLookup<T>
+    + Sve2WideningNarrowing<T>
+    + SveContiguousLoadStore<T>
+    + SveElementCount<T>
+    + SveFloatingPointArithmetic<T>
+    + SveFloatingPointCompare<T>
+    + SveFloatingPointConvert<T>
+    + SveGatherScatter<T>
+    + SveIndexGeneration<T>
+    + SveIntegerArithmetic<T>
+    + SvePredicates<T>
+{
+}
+
+/// Asserts that all z registers are in range and have the same element size.
+#[inline(always)]
+#[cfg(feature = "arm_feat_sve")]
+pub(crate) fn debug_assert_z_regs(regs: &[ZReg]) {
+    for z in regs {
+        debug_assert!(z.0 <= 31, "z register must be in range Z0 to Z31, was {}", z.0);
+        debug_assert!(
+            z.1 == regs[0].1,
+            "all z registers must have the same element size"
+        );
+    }
+}
+
+/// Asserts that `pg` is in range and that all z registers have the same floating point element
+/// size.
+#[inline(always)]
+#[cfg(feature = "arm_feat_sve")]
+pub(crate) fn debug_assert_fp_regs(pg: PReg, regs: &[ZReg]) {
+    debug_assert!(pg.0 <= 7, "pg must be in range P0 to P7, was {}", pg.0);
+    debug_assert!(
+        regs[0].1 != SveSize::B,
+        "z registers must have element size H, S or D"
+    );
+    debug_assert_z_regs(regs);
+}
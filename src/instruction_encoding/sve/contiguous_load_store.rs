@@ -0,0 +1,645 @@
+//! # [SVE Memory - Contiguous Load and Store](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/SVE-encodings?lang=en)
+//!
+//! Implements the following instructions:
+//!  - [LD1B - scalar plus immediate - Contiguous load unsigned bytes to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LD1B--scalar-plus-immediate---Contiguous-load-unsigned-bytes-to-vector--immediate-index--?lang=en)
+//!  - [LD1H - scalar plus immediate - Contiguous load unsigned halfwords to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LD1H--scalar-plus-immediate---Contiguous-load-unsigned-halfwords-to-vector--immediate-index--?lang=en)
+//!  - [LD1W - scalar plus immediate - Contiguous load unsigned words to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LD1W--scalar-plus-immediate---Contiguous-load-unsigned-words-to-vector--immediate-index--?lang=en)
+//!  - [LD1D - scalar plus immediate - Contiguous load doublewords to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LD1D--scalar-plus-immediate---Contiguous-load-doublewords-to-vector--immediate-index--?lang=en)
+//!  - [LD1B - scalar plus scalar - Contiguous load unsigned bytes to vector - scalar index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LD1B--scalar-plus-scalar---Contiguous-load-unsigned-bytes-to-vector--scalar-index--?lang=en)
+//!  - [LD1H - scalar plus scalar - Contiguous load unsigned halfwords to vector - scalar index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LD1H--scalar-plus-scalar---Contiguous-load-unsigned-halfwords-to-vector--scalar-index--?lang=en)
+//!  - [LD1W - scalar plus scalar - Contiguous load unsigned words to vector - scalar index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LD1W--scalar-plus-scalar---Contiguous-load-unsigned-words-to-vector--scalar-index--?lang=en)
+//!  - [LD1D - scalar plus scalar - Contiguous load doublewords to vector - scalar index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LD1D--scalar-plus-scalar---Contiguous-load-doublewords-to-vector--scalar-index--?lang=en)
+//!  - [LDNF1B - Contiguous load non-fault unsigned bytes to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LDNF1B--Contiguous-load-non-fault-unsigned-bytes-to-vector--immediate-index--?lang=en)
+//!  - [LDNF1H - Contiguous load non-fault unsigned halfwords to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LDNF1H--Contiguous-load-non-fault-unsigned-halfwords-to-vector--immediate-index--?lang=en)
+//!  - [LDNF1W - Contiguous load non-fault unsigned words to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LDNF1W--Contiguous-load-non-fault-unsigned-words-to-vector--immediate-index--?lang=en)
+//!  - [LDNF1D - Contiguous load non-fault doublewords to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LDNF1D--Contiguous-load-non-fault-doublewords-to-vector--immediate-index--?lang=en)
+//!  - [LDFF1B - scalar plus scalar - Contiguous load first-fault unsigned bytes to vector - scalar index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LDFF1B--scalar-plus-scalar---Contiguous-load-first-fault-unsigned-bytes-to-vector--scalar-index--?lang=en)
+//!  - [LDFF1H - scalar plus scalar - Contiguous load first-fault unsigned halfwords to vector - scalar index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LDFF1H--scalar-plus-scalar---Contiguous-load-first-fault-unsigned-halfwords-to-vector--scalar-index--?lang=en)
+//!  - [LDFF1W - scalar plus scalar - Contiguous load first-fault unsigned words to vector - scalar index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LDFF1W--scalar-plus-scalar---Contiguous-load-first-fault-unsigned-words-to-vector--scalar-index--?lang=en)
+//!  - [LDFF1D - scalar plus scalar - Contiguous load first-fault doublewords to vector - scalar index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LDFF1D--scalar-plus-scalar---Contiguous-load-first-fault-doublewords-to-vector--scalar-index--?lang=en)
+//!  - [ST1B - scalar plus immediate - Contiguous store bytes from vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ST1B--scalar-plus-immediate---Contiguous-store-bytes-from-vector--immediate-index--?lang=en)
+//!  - [ST1H - scalar plus immediate - Contiguous store halfwords from vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ST1H--scalar-plus-immediate---Contiguous-store-halfwords-from-vector--immediate-index--?lang=en)
+//!  - [ST1W - scalar plus immediate - Contiguous store words from vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ST1W--scalar-plus-immediate---Contiguous-store-words-from-vector--immediate-index--?lang=en)
+//!  - [ST1D - scalar plus immediate - Contiguous store doublewords from vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ST1D--scalar-plus-immediate---Contiguous-store-doublewords-from-vector--immediate-index--?lang=en)
+//!  - [ST1B - scalar plus scalar - Contiguous store bytes from vector - scalar index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ST1B--scalar-plus-scalar---Contiguous-store-bytes-from-vector--scalar-index--?lang=en)
+//!  - [ST1H - scalar plus scalar - Contiguous store halfwords from vector - scalar index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ST1H--scalar-plus-scalar---Contiguous-store-halfwords-from-vector--scalar-index--?lang=en)
+//!  - [ST1W - scalar plus scalar - Contiguous store words from vector - scalar index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ST1W--scalar-plus-scalar---Contiguous-store-words-from-vector--scalar-index--?lang=en)
+//!  - [ST1D - scalar plus scalar - Contiguous store doublewords from vector - scalar index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ST1D--scalar-plus-scalar---Contiguous-store-doublewords-from-vector--scalar-index--?lang=en)
+
+#[allow(unused_imports)]
+use bit_seq::bseq_32;
+
+use crate::instruction_encoding::InstructionProcessor;
+#[allow(unused_imports)]
+use crate::types::sve::{PReg, ZReg};
+#[allow(unused_imports)]
+use crate::types::{Imm8, Register};
+
+#[inline(always)]
+#[cfg(feature = "arm_feat_sve")]
+fn debug_assert_contiguous(msz: u8, zt: ZReg, pg: PReg) {
+    debug_assert!(zt.0 <= 31, "zt must be in range Z0 to Z31, was {}", zt.0);
+    debug_assert!(pg.0 <= 7, "pg must be in range P0 to P7, was {}", pg.0);
+    debug_assert!(
+        u8::from(zt.1) >= msz,
+        "element size of zt must not be smaller than the memory access size"
+    );
+}
+
+#[inline(always)]
+#[cfg(feature = "arm_feat_sve")]
+fn debug_assert_mul_vl(imm: Imm8) {
+    debug_assert!(
+        (-8..=7).contains(&imm),
+        "imm must be in range -8 to 7, was {}",
+        imm
+    );
+}
+
+/// Emits a contiguous load with scalar plus immediate addressing,
+/// `nf` selects the non-fault variant.
+#[inline(always)]
+#[cfg(feature = "arm_feat_sve")]
+fn emit_ld_contiguous_imm<P: InstructionProcessor<T>, T>(
+    proc: &mut P,
+    msz: u8,
+    nf: u8,
+    zt: ZReg,
+    pg: PReg,
+    rn: Register,
+    imm: Imm8,
+) -> T {
+    debug_assert_contiguous(msz, zt, pg);
+    debug_assert_mul_vl(imm);
+    let esize: u8 = zt.1.into();
+    let imm4 = imm as u8;
+    let (zt, pg) = (zt.0, pg.0);
+    let i = bseq_32!(1010010 msz:2 esize:2 nf:1 imm4:4 101 pg:3 rn:5 zt:5);
+    proc.process(i)
+}
+
+/// Emits a contiguous load with scalar plus scalar addressing,
+/// `op` selects the regular (`0b010`) or first-fault (`0b011`) variant.
+#[inline(always)]
+#[cfg(feature = "arm_feat_sve")]
+fn emit_ld_contiguous_reg<P: InstructionProcessor<T>, T>(
+    proc: &mut P,
+    msz: u8,
+    op: u8,
+    zt: ZReg,
+    pg: PReg,
+    rn: Register,
+    rm: Register,
+) -> T {
+    debug_assert_contiguous(msz, zt, pg);
+    let esize: u8 = zt.1.into();
+    let (zt, pg) = (zt.0, pg.0);
+    let i = bseq_32!(1010010 msz:2 esize:2 rm:5 op:3 pg:3 rn:5 zt:5);
+    proc.process(i)
+}
+
+#[inline(always)]
+#[cfg(feature = "arm_feat_sve")]
+fn emit_st_contiguous_imm<P: InstructionProcessor<T>, T>(
+    proc: &mut P,
+    msz: u8,
+    zt: ZReg,
+    pg: PReg,
+    rn: Register,
+    imm: Imm8,
+) -> T {
+    debug_assert_contiguous(msz, zt, pg);
+    debug_assert_mul_vl(imm);
+    let esize: u8 = zt.1.into();
+    let imm4 = imm as u8;
+    let (zt, pg) = (zt.0, pg.0);
+    let i = bseq_32!(1110010 msz:2 esize:2 0 imm4:4 111 pg:3 rn:5 zt:5);
+    proc.process(i)
+}
+
+#[inline(always)]
+#[cfg(feature = "arm_feat_sve")]
+fn emit_st_contiguous_reg<P: InstructionProcessor<T>, T>(
+    proc: &mut P,
+    msz: u8,
+    zt: ZReg,
+    pg: PReg,
+    rn: Register,
+    rm: Register,
+) -> T {
+    debug_assert_contiguous(msz, zt, pg);
+    debug_assert!(rm != 31, "xm must not be XZR");
+    let esize: u8 = zt.1.into();
+    let (zt, pg) = (zt.0, pg.0);
+    let i = bseq_32!(1110010 msz:2 esize:2 rm:5 010 pg:3 rn:5 zt:5);
+    proc.process(i)
+}
+
+/// # [SVE Memory - Contiguous Load and Store](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/SVE-encodings?lang=en)
+///
+/// Implements the following instructions:
+///  - [LD1B - scalar plus immediate - Contiguous load unsigned bytes to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LD1B--scalar-plus-immediate---Contiguous-load-unsigned-bytes-to-vector--immediate-index--?lang=en)
+///  - [LD1H - scalar plus immediate - Contiguous load unsigned halfwords to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LD1H--scalar-plus-immediate---Contiguous-load-unsigned-halfwords-to-vector--immediate-index--?lang=en)
+///  - [LD1W - scalar plus immediate - Contiguous load unsigned words to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LD1W--scalar-plus-immediate---Contiguous-load-unsigned-words-to-vector--immediate-index--?lang=en)
+///  - [LD1D - scalar plus immediate - Contiguous load doublewords to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LD1D--scalar-plus-immediate---Contiguous-load-doublewords-to-vector--immediate-index--?lang=en)
+///  - [LD1B - scalar plus scalar - Contiguous load unsigned bytes to vector - scalar index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LD1B--scalar-plus-scalar---Contiguous-load-unsigned-bytes-to-vector--scalar-index--?lang=en)
+///  - [LD1H - scalar plus scalar - Contiguous load unsigned halfwords to vector - scalar index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LD1H--scalar-plus-scalar---Contiguous-load-unsigned-halfwords-to-vector--scalar-index--?lang=en)
+///  - [LD1W - scalar plus scalar - Contiguous load unsigned words to vector - scalar index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LD1W--scalar-plus-scalar---Contiguous-load-unsigned-words-to-vector--scalar-index--?lang=en)
+///  - [LD1D - scalar plus scalar - Contiguous load doublewords to vector - scalar index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LD1D--scalar-plus-scalar---Contiguous-load-doublewords-to-vector--scalar-index--?lang=en)
+///  - [LDNF1B - Contiguous load non-fault unsigned bytes to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LDNF1B--Contiguous-load-non-fault-unsigned-bytes-to-vector--immediate-index--?lang=en)
+///  - [LDNF1H - Contiguous load non-fault unsigned halfwords to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LDNF1H--Contiguous-load-non-fault-unsigned-halfwords-to-vector--immediate-index--?lang=en)
+///  - [LDNF1W - Contiguous load non-fault unsigned words to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LDNF1W--Contiguous-load-non-fault-unsigned-words-to-vector--immediate-index--?lang=en)
+///  - [LDNF1D - Contiguous load non-fault doublewords to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LDNF1D--Contiguous-load-non-fault-doublewords-to-vector--immediate-index--?lang=en)
+///  - [LDFF1B - scalar plus scalar - Contiguous load first-fault unsigned bytes to vector - scalar index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LDFF1B--scalar-plus-scalar---Contiguous-load-first-fault-unsigned-bytes-to-vector--scalar-index--?lang=en)
+///  - [LDFF1H - scalar plus scalar - Contiguous load first-fault unsigned halfwords to vector - scalar index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LDFF1H--scalar-plus-scalar---Contiguous-load-first-fault-unsigned-halfwords-to-vector--scalar-index--?lang=en)
+///  - [LDFF1W - scalar plus scalar - Contiguous load first-fault unsigned words to vector - scalar index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LDFF1W--scalar-plus-scalar---Contiguous-load-first-fault-unsigned-words-to-vector--scalar-index--?lang=en)
+///  - [LDFF1D - scalar plus scalar - Contiguous load first-fault doublewords to vector - scalar index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LDFF1D--scalar-plus-scalar---Contiguous-load-first-fault-doublewords-to-vector--scalar-index--?lang=en)
+///  - [ST1B - scalar plus immediate - Contiguous store bytes from vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ST1B--scalar-plus-immediate---Contiguous-store-bytes-from-vector--immediate-index--?lang=en)
+///  - [ST1H - scalar plus immediate - Contiguous store halfwords from vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ST1H--scalar-plus-immediate---Contiguous-store-halfwords-from-vector--immediate-index--?lang=en)
+///  - [ST1W - scalar plus immediate - Contiguous store words from vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ST1W--scalar-plus-immediate---Contiguous-store-words-from-vector--immediate-index--?lang=en)
+///  - [ST1D - scalar plus immediate - Contiguous store doublewords from vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ST1D--scalar-plus-immediate---Contiguous-store-doublewords-from-vector--immediate-index--?lang=en)
+///  - [ST1B - scalar plus scalar - Contiguous store bytes from vector - scalar index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ST1B--scalar-plus-scalar---Contiguous-store-bytes-from-vector--scalar-index--?lang=en)
+///  - [ST1H - scalar plus scalar - Contiguous store halfwords from vector - scalar index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ST1H--scalar-plus-scalar---Contiguous-store-halfwords-from-vector--scalar-index--?lang=en)
+///  - [ST1W - scalar plus scalar - Contiguous store words from vector - scalar index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ST1W--scalar-plus-scalar---Contiguous-store-words-from-vector--scalar-index--?lang=en)
+///  - [ST1D - scalar plus scalar - Contiguous store doublewords from vector - scalar index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ST1D--scalar-plus-scalar---Contiguous-store-doublewords-from-vector--scalar-index--?lang=en)
+pub trait SveContiguousLoadStore<T>: InstructionProcessor<T> {
+    /// [LD1B - scalar plus immediate - Contiguous load unsigned bytes to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LD1B--scalar-plus-immediate---Contiguous-load-unsigned-bytes-to-vector--immediate-index--?lang=en)
+    ///
+    /// Contiguous load of unsigned bytes to elements of a vector register from the memory address generated by a 64-bit scalar base and immediate index which is multiplied by the vector's in-memory size, irrespective of predication, and added to the base address. Inactive elements will not be read from Device memory or signal a fault, and are set to zero in the destination vector.
+    ///
+    /// `zt` must have any element size, `pg` must be in range P0 to P7 and `imm` in range -8 to 7.
+    ///
+    /// ```asm
+    /// LD1B {<Zt>.<T>}, <Pg>/Z, [<Xn|SP>{, #<imm>, MUL VL}]
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn ld1b_imm(&mut self, zt: ZReg, pg: PReg, xn: Register, imm: Imm8) -> T {
+        emit_ld_contiguous_imm(self, 0, 0, zt, pg, xn, imm)
+    }
+
+    /// [LD1B - scalar plus scalar - Contiguous load unsigned bytes to vector - scalar index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LD1B--scalar-plus-scalar---Contiguous-load-unsigned-bytes-to-vector--scalar-index--?lang=en)
+    ///
+    /// Contiguous load of unsigned bytes to elements of a vector register from the memory address generated by a 64-bit scalar base and scalar index which is added to the base address. Inactive elements will not be read from Device memory or signal a fault, and are set to zero in the destination vector.
+    ///
+    /// `zt` must have any element size, `pg` must be in range P0 to P7 and `xm` must not be XZR.
+    ///
+    /// ```asm
+    /// LD1B {<Zt>.<T>}, <Pg>/Z, [<Xn|SP>, <Xm>]
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn ld1b_reg(&mut self, zt: ZReg, pg: PReg, xn: Register, xm: Register) -> T {
+        debug_assert!(xm != 31, "xm must not be XZR");
+        emit_ld_contiguous_reg(self, 0, 0b010, zt, pg, xn, xm)
+    }
+
+    /// [LD1H - scalar plus immediate - Contiguous load unsigned halfwords to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LD1H--scalar-plus-immediate---Contiguous-load-unsigned-halfwords-to-vector--immediate-index--?lang=en)
+    ///
+    /// Contiguous load of unsigned halfwords to elements of a vector register from the memory address generated by a 64-bit scalar base and immediate index which is multiplied by the vector's in-memory size, irrespective of predication, and added to the base address. Inactive elements will not be read from Device memory or signal a fault, and are set to zero in the destination vector.
+    ///
+    /// `zt` must have element size `H`, `S` or `D`, `pg` must be in range P0 to P7 and `imm` in range -8 to 7.
+    ///
+    /// ```asm
+    /// LD1H {<Zt>.<T>}, <Pg>/Z, [<Xn|SP>{, #<imm>, MUL VL}]
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn ld1h_imm(&mut self, zt: ZReg, pg: PReg, xn: Register, imm: Imm8) -> T {
+        emit_ld_contiguous_imm(self, 1, 0, zt, pg, xn, imm)
+    }
+
+    /// [LD1H - scalar plus scalar - Contiguous load unsigned halfwords to vector - scalar index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LD1H--scalar-plus-scalar---Contiguous-load-unsigned-halfwords-to-vector--scalar-index--?lang=en)
+    ///
+    /// Contiguous load of unsigned halfwords to elements of a vector register from the memory address generated by a 64-bit scalar base and scalar index which is multiplied by 2 and added to the base address. Inactive elements will not be read from Device memory or signal a fault, and are set to zero in the destination vector.
+    ///
+    /// `zt` must have element size `H`, `S` or `D`, `pg` must be in range P0 to P7 and `xm` must not be XZR.
+    ///
+    /// ```asm
+    /// LD1H {<Zt>.<T>}, <Pg>/Z, [<Xn|SP>, <Xm>, LSL #1]
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn ld1h_reg(&mut self, zt: ZReg, pg: PReg, xn: Register, xm: Register) -> T {
+        debug_assert!(xm != 31, "xm must not be XZR");
+        emit_ld_contiguous_reg(self, 1, 0b010, zt, pg, xn, xm)
+    }
+
+    /// [LD1W - scalar plus immediate - Contiguous load unsigned words to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LD1W--scalar-plus-immediate---Contiguous-load-unsigned-words-to-vector--immediate-index--?lang=en)
+    ///
+    /// Contiguous load of unsigned words to elements of a vector register from the memory address generated by a 64-bit scalar base and immediate index which is multiplied by the vector's in-memory size, irrespective of predication, and added to the base address. Inactive elements will not be read from Device memory or signal a fault, and are set to zero in the destination vector.
+    ///
+    /// `zt` must have element size `S` or `D`, `pg` must be in range P0 to P7 and `imm` in range -8 to 7.
+    ///
+    /// ```asm
+    /// LD1W {<Zt>.<T>}, <Pg>/Z, [<Xn|SP>{, #<imm>, MUL VL}]
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn ld1w_imm(&mut self, zt: ZReg, pg: PReg, xn: Register, imm: Imm8) -> T {
+        emit_ld_contiguous_imm(self, 2, 0, zt, pg, xn, imm)
+    }
+
+    /// [LD1W - scalar plus scalar - Contiguous load unsigned words to vector - scalar index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LD1W--scalar-plus-scalar---Contiguous-load-unsigned-words-to-vector--scalar-index--?lang=en)
+    ///
+    /// Contiguous load of unsigned words to elements of a vector register from the memory address generated by a 64-bit scalar base and scalar index which is multiplied by 4 and added to the base address. Inactive elements will not be read from Device memory or signal a fault, and are set to zero in the destination vector.
+    ///
+    /// `zt` must have element size `S` or `D`, `pg` must be in range P0 to P7 and `xm` must not be XZR.
+    ///
+    /// ```asm
+    /// LD1W {<Zt>.<T>}, <Pg>/Z, [<Xn|SP>, <Xm>, LSL #2]
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn ld1w_reg(&mut self, zt: ZReg, pg: PReg, xn: Register, xm: Register) -> T {
+        debug_assert!(xm != 31, "xm must not be XZR");
+        emit_ld_contiguous_reg(self, 2, 0b010, zt, pg, xn, xm)
+    }
+
+    /// [LD1D - scalar plus immediate - Contiguous load doublewords to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LD1D--scalar-plus-immediate---Contiguous-load-doublewords-to-vector--immediate-index--?lang=en)
+    ///
+    /// Contiguous load of doublewords to elements of a vector register from the memory address generated by a 64-bit scalar base and immediate index which is multiplied by the vector's in-memory size, irrespective of predication, and added to the base address. Inactive elements will not be read from Device memory or signal a fault, and are set to zero in the destination vector.
+    ///
+    /// `zt` must have element size `D`, `pg` must be in range P0 to P7 and `imm` in range -8 to 7.
+    ///
+    /// ```asm
+    /// LD1D {<Zt>.<T>}, <Pg>/Z, [<Xn|SP>{, #<imm>, MUL VL}]
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn ld1d_imm(&mut self, zt: ZReg, pg: PReg, xn: Register, imm: Imm8) -> T {
+        emit_ld_contiguous_imm(self, 3, 0, zt, pg, xn, imm)
+    }
+
+    /// [LD1D - scalar plus scalar - Contiguous load doublewords to vector - scalar index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LD1D--scalar-plus-scalar---Contiguous-load-doublewords-to-vector--scalar-index--?lang=en)
+    ///
+    /// Contiguous load of doublewords to elements of a vector register from the memory address generated by a 64-bit scalar base and scalar index which is multiplied by 8 and added to the base address. Inactive elements will not be read from Device memory or signal a fault, and are set to zero in the destination vector.
+    ///
+    /// `zt` must have element size `D`, `pg` must be in range P0 to P7 and `xm` must not be XZR.
+    ///
+    /// ```asm
+    /// LD1D {<Zt>.<T>}, <Pg>/Z, [<Xn|SP>, <Xm>, LSL #3]
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn ld1d_reg(&mut self, zt: ZReg, pg: PReg, xn: Register, xm: Register) -> T {
+        debug_assert!(xm != 31, "xm must not be XZR");
+        emit_ld_contiguous_reg(self, 3, 0b010, zt, pg, xn, xm)
+    }
+
+    /// [LDNF1B - Contiguous load non-fault unsigned bytes to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LDNF1B--Contiguous-load-non-fault-unsigned-bytes-to-vector--immediate-index--?lang=en)
+    ///
+    /// Contiguous load with non-faulting behavior of unsigned bytes to elements of a vector register from the memory address generated by a 64-bit scalar base and immediate index which is multiplied by the vector's in-memory size, irrespective of predication, and added to the base address. Inactive elements will not be read from Device memory or signal a fault, and are set to zero in the destination vector.
+    ///
+    /// `zt` must have any element size, `pg` must be in range P0 to P7 and `imm` in range -8 to 7.
+    ///
+    /// ```asm
+    /// LDNF1B {<Zt>.<T>}, <Pg>/Z, [<Xn|SP>{, #<imm>, MUL VL}]
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn ldnf1b(&mut self, zt: ZReg, pg: PReg, xn: Register, imm: Imm8) -> T {
+        emit_ld_contiguous_imm(self, 0, 1, zt, pg, xn, imm)
+    }
+
+    /// [LDNF1H - Contiguous load non-fault unsigned halfwords to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LDNF1H--Contiguous-load-non-fault-unsigned-halfwords-to-vector--immediate-index--?lang=en)
+    ///
+    /// Contiguous load with non-faulting behavior of unsigned halfwords to elements of a vector register from the memory address generated by a 64-bit scalar base and immediate index which is multiplied by the vector's in-memory size, irrespective of predication, and added to the base address. Inactive elements will not be read from Device memory or signal a fault, and are set to zero in the destination vector.
+    ///
+    /// `zt` must have element size `H`, `S` or `D`, `pg` must be in range P0 to P7 and `imm` in range -8 to 7.
+    ///
+    /// ```asm
+    /// LDNF1H {<Zt>.<T>}, <Pg>/Z, [<Xn|SP>{, #<imm>, MUL VL}]
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn ldnf1h(&mut self, zt: ZReg, pg: PReg, xn: Register, imm: Imm8) -> T {
+        emit_ld_contiguous_imm(self, 1, 1, zt, pg, xn, imm)
+    }
+
+    /// [LDNF1W - Contiguous load non-fault unsigned words to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LDNF1W--Contiguous-load-non-fault-unsigned-words-to-vector--immediate-index--?lang=en)
+    ///
+    /// Contiguous load with non-faulting behavior of unsigned words to elements of a vector register from the memory address generated by a 64-bit scalar base and immediate index which is multiplied by the vector's in-memory size, irrespective of predication, and added to the base address. Inactive elements will not be read from Device memory or signal a fault, and are set to zero in the destination vector.
+    ///
+    /// `zt` must have element size `S` or `D`, `pg` must be in range P0 to P7 and `imm` in range -8 to 7.
+    ///
+    /// ```asm
+    /// LDNF1W {<Zt>.<T>}, <Pg>/Z, [<Xn|SP>{, #<imm>, MUL VL}]
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn ldnf1w(&mut self, zt: ZReg, pg: PReg, xn: Register, imm: Imm8) -> T {
+        emit_ld_contiguous_imm(self, 2, 1, zt, pg, xn, imm)
+    }
+
+    /// [LDNF1D - Contiguous load non-fault doublewords to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LDNF1D--Contiguous-load-non-fault-doublewords-to-vector--immediate-index--?lang=en)
+    ///
+    /// Contiguous load with non-faulting behavior of doublewords to elements of a vector register from the memory address generated by a 64-bit scalar base and immediate index which is multiplied by the vector's in-memory size, irrespective of predication, and added to the base address. Inactive elements will not be read from Device memory or signal a fault, and are set to zero in the destination vector.
+    ///
+    /// `zt` must have element size `D`, `pg` must be in range P0 to P7 and `imm` in range -8 to 7.
+    ///
+    /// ```asm
+    /// LDNF1D {<Zt>.<T>}, <Pg>/Z, [<Xn|SP>{, #<imm>, MUL VL}]
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn ldnf1d(&mut self, zt: ZReg, pg: PReg, xn: Register, imm: Imm8) -> T {
+        emit_ld_contiguous_imm(self, 3, 1, zt, pg, xn, imm)
+    }
+
+    /// [LDFF1B - scalar plus scalar - Contiguous load first-fault unsigned bytes to vector - scalar index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LDFF1B--scalar-plus-scalar---Contiguous-load-first-fault-unsigned-bytes-to-vector--scalar-index--?lang=en)
+    ///
+    /// Contiguous load with first-faulting behavior of unsigned bytes to elements of a vector register from the memory address generated by a 64-bit scalar base and scalar index which is added to the base address. Inactive elements will not be read from Device memory or signal a fault, and are set to zero in the destination vector.
+    ///
+    /// `zt` must have any element size and `pg` must be in range P0 to P7. `xm` defaults to XZR in the assembler syntax.
+    ///
+    /// ```asm
+    /// LDFF1B {<Zt>.<T>}, <Pg>/Z, [<Xn|SP>{, <Xm>}]
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn ldff1b(&mut self, zt: ZReg, pg: PReg, xn: Register, xm: Register) -> T {
+        emit_ld_contiguous_reg(self, 0, 0b011, zt, pg, xn, xm)
+    }
+
+    /// [LDFF1H - scalar plus scalar - Contiguous load first-fault unsigned halfwords to vector - scalar index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LDFF1H--scalar-plus-scalar---Contiguous-load-first-fault-unsigned-halfwords-to-vector--scalar-index--?lang=en)
+    ///
+    /// Contiguous load with first-faulting behavior of unsigned halfwords to elements of a vector register from the memory address generated by a 64-bit scalar base and scalar index which is multiplied by 2 and added to the base address. Inactive elements will not be read from Device memory or signal a fault, and are set to zero in the destination vector.
+    ///
+    /// `zt` must have element size `H`, `S` or `D` and `pg` must be in range P0 to P7. `xm` defaults to XZR in the assembler syntax.
+    ///
+    /// ```asm
+    /// LDFF1H {<Zt>.<T>}, <Pg>/Z, [<Xn|SP>{, <Xm>, LSL #1}]
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn ldff1h(&mut self, zt: ZReg, pg: PReg, xn: Register, xm: Register) -> T {
+        emit_ld_contiguous_reg(self, 1, 0b011, zt, pg, xn, xm)
+    }
+
+    /// [LDFF1W - scalar plus scalar - Contiguous load first-fault unsigned words to vector - scalar index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LDFF1W--scalar-plus-scalar---Contiguous-load-first-fault-unsigned-words-to-vector--scalar-index--?lang=en)
+    ///
+    /// Contiguous load with first-faulting behavior of unsigned words to elements of a vector register from the memory address generated by a 64-bit scalar base and scalar index which is multiplied by 4 and added to the base address. Inactive elements will not be read from Device memory or signal a fault, and are set to zero in the destination vector.
+    ///
+    /// `zt` must have element size `S` or `D` and `pg` must be in range P0 to P7. `xm` defaults to XZR in the assembler syntax.
+    ///
+    /// ```asm
+    /// LDFF1W {<Zt>.<T>}, <Pg>/Z, [<Xn|SP>{, <Xm>, LSL #2}]
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn ldff1w(&mut self, zt: ZReg, pg: PReg, xn: Register, xm: Register) -> T {
+        emit_ld_contiguous_reg(self, 2, 0b011, zt, pg, xn, xm)
+    }
+
+    /// [LDFF1D - scalar plus scalar - Contiguous load first-fault doublewords to vector - scalar index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LDFF1D--scalar-plus-scalar---Contiguous-load-first-fault-doublewords-to-vector--scalar-index--?lang=en)
+    ///
+    /// Contiguous load with first-faulting behavior of doublewords to elements of a vector register from the memory address generated by a 64-bit scalar base and scalar index which is multiplied by 8 and added to the base address. Inactive elements will not be read from Device memory or signal a fault, and are set to zero in the destination vector.
+    ///
+    /// `zt` must have element size `D` and `pg` must be in range P0 to P7. `xm` defaults to XZR in the assembler syntax.
+    ///
+    /// ```asm
+    /// LDFF1D {<Zt>.<T>}, <Pg>/Z, [<Xn|SP>{, <Xm>, LSL #3}]
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn ldff1d(&mut self, zt: ZReg, pg: PReg, xn: Register, xm: Register) -> T {
+        emit_ld_contiguous_reg(self, 3, 0b011, zt, pg, xn, xm)
+    }
+
+    /// [ST1B - scalar plus immediate - Contiguous store bytes from vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ST1B--scalar-plus-immediate---Contiguous-store-bytes-from-vector--immediate-index--?lang=en)
+    ///
+    /// Contiguous store of bytes from elements of a vector register to the memory address generated by a 64-bit scalar base and immediate index which is multiplied by the vector's in-memory size, irrespective of predication, and added to the base address. Inactive elements are not written to memory.
+    ///
+    /// `zt` must have any element size, `pg` must be in range P0 to P7 and `imm` in range -8 to 7.
+    ///
+    /// ```asm
+    /// ST1B {<Zt>.<T>}, <Pg>, [<Xn|SP>{, #<imm>, MUL VL}]
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn st1b_imm(&mut self, zt: ZReg, pg: PReg, xn: Register, imm: Imm8) -> T {
+        emit_st_contiguous_imm(self, 0, zt, pg, xn, imm)
+    }
+
+    /// [ST1B - scalar plus scalar - Contiguous store bytes from vector - scalar index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ST1B--scalar-plus-scalar---Contiguous-store-bytes-from-vector--scalar-index--?lang=en)
+    ///
+    /// Contiguous store of bytes from elements of a vector register to the memory address generated by a 64-bit scalar base and scalar index which is added to the base address. Inactive elements are not written to memory.
+    ///
+    /// `zt` must have any element size, `pg` must be in range P0 to P7 and `xm` must not be XZR.
+    ///
+    /// ```asm
+    /// ST1B {<Zt>.<T>}, <Pg>, [<Xn|SP>, <Xm>]
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn st1b_reg(&mut self, zt: ZReg, pg: PReg, xn: Register, xm: Register) -> T {
+        emit_st_contiguous_reg(self, 0, zt, pg, xn, xm)
+    }
+
+    /// [ST1H - scalar plus immediate - Contiguous store halfwords from vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ST1H--scalar-plus-immediate---Contiguous-store-halfwords-from-vector--immediate-index--?lang=en)
+    ///
+    /// Contiguous store of halfwords from elements of a vector register to the memory address generated by a 64-bit scalar base and immediate index which is multiplied by the vector's in-memory size, irrespective of predication, and added to the base address. Inactive elements are not written to memory.
+    ///
+    /// `zt` must have element size `H`, `S` or `D`, `pg` must be in range P0 to P7 and `imm` in range -8 to 7.
+    ///
+    /// ```asm
+    /// ST1H {<Zt>.<T>}, <Pg>, [<Xn|SP>{, #<imm>, MUL VL}]
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn st1h_imm(&mut self, zt: ZReg, pg: PReg, xn: Register, imm: Imm8) -> T {
+        emit_st_contiguous_imm(self, 1, zt, pg, xn, imm)
+    }
+
+    /// [ST1H - scalar plus scalar - Contiguous store halfwords from vector - scalar index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ST1H--scalar-plus-scalar---Contiguous-store-halfwords-from-vector--scalar-index--?lang=en)
+    ///
+    /// Contiguous store of halfwords from elements of a vector register to the memory address generated by a 64-bit scalar base and scalar index which is multiplied by 2 and added to the base address. Inactive elements are not written to memory.
+    ///
+    /// `zt` must have element size `H`, `S` or `D`, `pg` must be in range P0 to P7 and `xm` must not be XZR.
+    ///
+    /// ```asm
+    /// ST1H {<Zt>.<T>}, <Pg>, [<Xn|SP>, <Xm>, LSL #1]
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn st1h_reg(&mut self, zt: ZReg, pg: PReg, xn: Register, xm: Register) -> T {
+        emit_st_contiguous_reg(self, 1, zt, pg, xn, xm)
+    }
+
+    /// [ST1W - scalar plus immediate - Contiguous store words from vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ST1W--scalar-plus-immediate---Contiguous-store-words-from-vector--immediate-index--?lang=en)
+    ///
+    /// Contiguous store of words from elements of a vector register to the memory address generated by a 64-bit scalar base and immediate index which is multiplied by the vector's in-memory size, irrespective of predication, and added to the base address. Inactive elements are not written to memory.
+    ///
+    /// `zt` must have element size `S` or `D`, `pg` must be in range P0 to P7 and `imm` in range -8 to 7.
+    ///
+    /// ```asm
+    /// ST1W {<Zt>.<T>}, <Pg>, [<Xn|SP>{, #<imm>, MUL VL}]
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn st1w_imm(&mut self, zt: ZReg, pg: PReg, xn: Register, imm: Imm8) -> T {
+        emit_st_contiguous_imm(self, 2, zt, pg, xn, imm)
+    }
+
+    /// [ST1W - scalar plus scalar - Contiguous store words from vector - scalar index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ST1W--scalar-plus-scalar---Contiguous-store-words-from-vector--scalar-index--?lang=en)
+    ///
+    /// Contiguous store of words from elements of a vector register to the memory address generated by a 64-bit scalar base and scalar index which is multiplied by 4 and added to the base address. Inactive elements are not written to memory.
+    ///
+    /// `zt` must have element size `S` or `D`, `pg` must be in range P0 to P7 and `xm` must not be XZR.
+    ///
+    /// ```asm
+    /// ST1W {<Zt>.<T>}, <Pg>, [<Xn|SP>, <Xm>, LSL #2]
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn st1w_reg(&mut self, zt: ZReg, pg: PReg, xn: Register, xm: Register) -> T {
+        emit_st_contiguous_reg(self, 2, zt, pg, xn, xm)
+    }
+
+    /// [ST1D - scalar plus immediate - Contiguous store doublewords from vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ST1D--scalar-plus-immediate---Contiguous-store-doublewords-from-vector--immediate-index--?lang=en)
+    ///
+    /// Contiguous store of doublewords from elements of a vector register to the memory address generated by a 64-bit scalar base and immediate index which is multiplied by the vector's in-memory size, irrespective of predication, and added to the base address. Inactive elements are not written to memory.
+    ///
+    /// `zt` must have element size `D`, `pg` must be in range P0 to P7 and `imm` in range -8 to 7.
+    ///
+    /// ```asm
+    /// ST1D {<Zt>.<T>}, <Pg>, [<Xn|SP>{, #<imm>, MUL VL}]
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn st1d_imm(&mut self, zt: ZReg, pg: PReg, xn: Register, imm: Imm8) -> T {
+        emit_st_contiguous_imm(self, 3, zt, pg, xn, imm)
+    }
+
+    /// [ST1D - scalar plus scalar - Contiguous store doublewords from vector - scalar index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ST1D--scalar-plus-scalar---Contiguous-store-doublewords-from-vector--scalar-index--?lang=en)
+    ///
+    /// Contiguous store of doublewords from elements of a vector register to the memory address generated by a 64-bit scalar base and scalar index which is multiplied by 8 and added to the base address. Inactive elements are not written to memory.
+    ///
+    /// `zt` must have element size `D`, `pg` must be in range P0 to P7 and `xm` must not be XZR.
+    ///
+    /// ```asm
+    /// ST1D {<Zt>.<T>}, <Pg>, [<Xn|SP>, <Xm>, LSL #3]
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn st1d_reg(&mut self, zt: ZReg, pg: PReg, xn: Register, xm: Register) -> T {
+        emit_st_contiguous_reg(self, 3, zt, pg, xn, xm)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use crate::assert_panic;
+    #[allow(unused_imports)]
+    use crate::test_utils::test_producer::TestProducer;
+
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[cfg(feature = "arm_feat_sve")]
+    #[test]
+    fn test_ld1_imm() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.ld1b_imm(ZReg::b(1), PReg::b(2), 3, -1);
+        assert_eq!(instr, "ld1b {z1.b}, p2/z, [x3, #0xffffffffffffffff, mul vl]");
+
+        let instr = prod.ld1h_imm(ZReg::d(1), PReg::b(2), 3, 2);
+        assert_eq!(instr, "ld1h {z1.d}, p2/z, [x3, #0x2, mul vl]");
+
+        assert_panic!("Should panic: imm out of range"; prod.ld1b_imm(ZReg::b(1), PReg::b(2), 3, 8));
+        assert_panic!("Should panic: element size too small"; prod.ld1w_imm(ZReg::h(1), PReg::b(2), 3, 0));
+        assert_panic!("Should panic: pg out of range"; prod.ld1b_imm(ZReg::b(1), PReg::b(8), 3, 0));
+    }
+
+    #[cfg(feature = "arm_feat_sve")]
+    #[test]
+    fn test_ld1_reg() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.ld1b_reg(ZReg::b(1), PReg::b(2), 3, 4);
+        assert_eq!(instr, "ld1b {z1.b}, p2/z, [x3, x4]");
+
+        let instr = prod.ld1d_reg(ZReg::d(1), PReg::b(2), 3, 4);
+        assert_eq!(instr, "ld1d {z1.d}, p2/z, [x3, x4, lsl #0x3]");
+
+        assert_panic!("Should panic: xm is xzr"; prod.ld1b_reg(ZReg::b(1), PReg::b(2), 3, 31));
+    }
+
+    #[cfg(feature = "arm_feat_sve")]
+    #[test]
+    fn test_ldnf1_ldff1() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.ldnf1w(ZReg::s(1), PReg::b(2), 3, 1);
+        assert_eq!(instr, "ldnf1w {z1.s}, p2/z, [x3, #0x1, mul vl]");
+
+        let instr = prod.ldff1h(ZReg::h(1), PReg::b(2), 3, 4);
+        assert_eq!(instr, "ldff1h {z1.h}, p2/z, [x3, x4, lsl #0x1]");
+
+        let instr = prod.ldff1h(ZReg::h(1), PReg::b(2), 3, 31);
+        assert_eq!(instr, "ldff1h {z1.h}, p2/z, [x3, xzr, lsl #0x1]");
+    }
+
+    #[cfg(feature = "arm_feat_sve")]
+    #[test]
+    fn test_st1() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.st1h_imm(ZReg::s(1), PReg::b(2), 3, 1);
+        assert_eq!(instr, "st1h {z1.s}, p2, [x3, #0x1, mul vl]");
+
+        let instr = prod.st1d_reg(ZReg::d(1), PReg::b(2), 3, 4);
+        assert_eq!(instr, "st1d {z1.d}, p2, [x3, x4, lsl #0x3]");
+    }
+}
@@ -0,0 +1,285 @@
+//! # [SVE Element Count](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/SVE-encodings?lang=en)
+//!
+//! Implements the following instructions:
+//!  - [CNTB - CNTD - CNTH - CNTW - Set scalar to multiple of predicate constraint element count](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/CNTB--CNTD--CNTH--CNTW--Set-scalar-to-multiple-of-predicate-constraint-element-count-?lang=en)
+//!  - [INCB - INCD - INCH - INCW - scalar - Increment scalar by multiple of predicate constraint element count](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/INCB--INCD--INCH--INCW--scalar---Increment-scalar-by-multiple-of-predicate-constraint-element-count-?lang=en)
+//!  - [DECB - DECD - DECH - DECW - scalar - Decrement scalar by multiple of predicate constraint element count](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/DECB--DECD--DECH--DECW--scalar---Decrement-scalar-by-multiple-of-predicate-constraint-element-count-?lang=en)
+
+#[allow(unused_imports)]
+use bit_seq::bseq_32;
+
+use crate::instruction_encoding::InstructionProcessor;
+#[allow(unused_imports)]
+use crate::types::sve::SvePattern;
+#[allow(unused_imports)]
+use crate::types::{Register, UImm5};
+
+#[inline(always)]
+#[cfg(feature = "arm_feat_sve")]
+fn emit_elem_count<P: InstructionProcessor<T>, T>(
+    proc: &mut P,
+    size: u8,
+    op: u8,
+    d: u8,
+    pattern: SvePattern,
+    mul: UImm5,
+    rd: Register,
+) -> T {
+    debug_assert!(
+        (1..=16).contains(&mul),
+        "mul must be in range 1 to 16, was {}",
+        mul
+    );
+    let imm4 = mul - 1;
+    let pattern: u8 = pattern.into();
+    let i = bseq_32!(00000100 size:2 op:2 imm4:4 11100 d:1 pattern:5 rd:5);
+    proc.process(i)
+}
+
+/// # [SVE Element Count](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/SVE-encodings?lang=en)
+///
+/// Implements the following instructions:
+///  - [CNTB - CNTD - CNTH - CNTW - Set scalar to multiple of predicate constraint element count](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/CNTB--CNTD--CNTH--CNTW--Set-scalar-to-multiple-of-predicate-constraint-element-count-?lang=en)
+///  - [INCB - INCD - INCH - INCW - scalar - Increment scalar by multiple of predicate constraint element count](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/INCB--INCD--INCH--INCW--scalar---Increment-scalar-by-multiple-of-predicate-constraint-element-count-?lang=en)
+///  - [DECB - DECD - DECH - DECW - scalar - Decrement scalar by multiple of predicate constraint element count](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/DECB--DECD--DECH--DECW--scalar---Decrement-scalar-by-multiple-of-predicate-constraint-element-count-?lang=en)
+pub trait SveElementCount<T>: InstructionProcessor<T> {
+    /// [CNTB - CNTD - CNTH - CNTW - Set scalar to multiple of predicate constraint element count](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/CNTB--CNTD--CNTH--CNTW--Set-scalar-to-multiple-of-predicate-constraint-element-count-?lang=en)
+    ///
+    /// Determines the number of active byte elements implied by the named predicate constraint, multiplies that by an immediate in the range 1 to 16 inclusive, and then places the result in the 64-bit destination general-purpose register.
+    ///
+    /// `mul` must be in range 1 to 16.
+    ///
+    /// ```asm
+    /// CNTB <Xd>{, <pattern>{, MUL #<imm>}}
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn cntb(&mut self, xd: Register, pattern: SvePattern, mul: UImm5) -> T {
+        emit_elem_count(self, 0b00, 0b10, 0, pattern, mul, xd)
+    }
+
+    /// [CNTB - CNTD - CNTH - CNTW - Set scalar to multiple of predicate constraint element count](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/CNTB--CNTD--CNTH--CNTW--Set-scalar-to-multiple-of-predicate-constraint-element-count-?lang=en)
+    ///
+    /// Determines the number of active halfword elements implied by the named predicate constraint, multiplies that by an immediate in the range 1 to 16 inclusive, and then places the result in the 64-bit destination general-purpose register.
+    ///
+    /// `mul` must be in range 1 to 16.
+    ///
+    /// ```asm
+    /// CNTH <Xd>{, <pattern>{, MUL #<imm>}}
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn cnth(&mut self, xd: Register, pattern: SvePattern, mul: UImm5) -> T {
+        emit_elem_count(self, 0b01, 0b10, 0, pattern, mul, xd)
+    }
+
+    /// [CNTB - CNTD - CNTH - CNTW - Set scalar to multiple of predicate constraint element count](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/CNTB--CNTD--CNTH--CNTW--Set-scalar-to-multiple-of-predicate-constraint-element-count-?lang=en)
+    ///
+    /// Determines the number of active word elements implied by the named predicate constraint, multiplies that by an immediate in the range 1 to 16 inclusive, and then places the result in the 64-bit destination general-purpose register.
+    ///
+    /// `mul` must be in range 1 to 16.
+    ///
+    /// ```asm
+    /// CNTW <Xd>{, <pattern>{, MUL #<imm>}}
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn cntw(&mut self, xd: Register, pattern: SvePattern, mul: UImm5) -> T {
+        emit_elem_count(self, 0b10, 0b10, 0, pattern, mul, xd)
+    }
+
+    /// [CNTB - CNTD - CNTH - CNTW - Set scalar to multiple of predicate constraint element count](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/CNTB--CNTD--CNTH--CNTW--Set-scalar-to-multiple-of-predicate-constraint-element-count-?lang=en)
+    ///
+    /// Determines the number of active doubleword elements implied by the named predicate constraint, multiplies that by an immediate in the range 1 to 16 inclusive, and then places the result in the 64-bit destination general-purpose register.
+    ///
+    /// `mul` must be in range 1 to 16.
+    ///
+    /// ```asm
+    /// CNTD <Xd>{, <pattern>{, MUL #<imm>}}
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn cntd(&mut self, xd: Register, pattern: SvePattern, mul: UImm5) -> T {
+        emit_elem_count(self, 0b11, 0b10, 0, pattern, mul, xd)
+    }
+
+    /// [INCB - INCD - INCH - INCW - scalar - Increment scalar by multiple of predicate constraint element count](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/INCB--INCD--INCH--INCW--scalar---Increment-scalar-by-multiple-of-predicate-constraint-element-count-?lang=en)
+    ///
+    /// Determines the number of active byte elements implied by the named predicate constraint, multiplies that by an immediate in the range 1 to 16 inclusive, and then uses the result to increment the scalar destination.
+    ///
+    /// `mul` must be in range 1 to 16.
+    ///
+    /// ```asm
+    /// INCB <Xdn>{, <pattern>{, MUL #<imm>}}
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn incb(&mut self, xdn: Register, pattern: SvePattern, mul: UImm5) -> T {
+        emit_elem_count(self, 0b00, 0b11, 0, pattern, mul, xdn)
+    }
+
+    /// [INCB - INCD - INCH - INCW - scalar - Increment scalar by multiple of predicate constraint element count](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/INCB--INCD--INCH--INCW--scalar---Increment-scalar-by-multiple-of-predicate-constraint-element-count-?lang=en)
+    ///
+    /// Determines the number of active halfword elements implied by the named predicate constraint, multiplies that by an immediate in the range 1 to 16 inclusive, and then uses the result to increment the scalar destination.
+    ///
+    /// `mul` must be in range 1 to 16.
+    ///
+    /// ```asm
+    /// INCH <Xdn>{, <pattern>{, MUL #<imm>}}
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn inch(&mut self, xdn: Register, pattern: SvePattern, mul: UImm5) -> T {
+        emit_elem_count(self, 0b01, 0b11, 0, pattern, mul, xdn)
+    }
+
+    /// [INCB - INCD - INCH - INCW - scalar - Increment scalar by multiple of predicate constraint element count](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/INCB--INCD--INCH--INCW--scalar---Increment-scalar-by-multiple-of-predicate-constraint-element-count-?lang=en)
+    ///
+    /// Determines the number of active word elements implied by the named predicate constraint, multiplies that by an immediate in the range 1 to 16 inclusive, and then uses the result to increment the scalar destination.
+    ///
+    /// `mul` must be in range 1 to 16.
+    ///
+    /// ```asm
+    /// INCW <Xdn>{, <pattern>{, MUL #<imm>}}
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn incw(&mut self, xdn: Register, pattern: SvePattern, mul: UImm5) -> T {
+        emit_elem_count(self, 0b10, 0b11, 0, pattern, mul, xdn)
+    }
+
+    /// [INCB - INCD - INCH - INCW - scalar - Increment scalar by multiple of predicate constraint element count](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/INCB--INCD--INCH--INCW--scalar---Increment-scalar-by-multiple-of-predicate-constraint-element-count-?lang=en)
+    ///
+    /// Determines the number of active doubleword elements implied by the named predicate constraint, multiplies that by an immediate in the range 1 to 16 inclusive, and then uses the result to increment the scalar destination.
+    ///
+    /// `mul` must be in range 1 to 16.
+    ///
+    /// ```asm
+    /// INCD <Xdn>{, <pattern>{, MUL #<imm>}}
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn incd(&mut self, xdn: Register, pattern: SvePattern, mul: UImm5) -> T {
+        emit_elem_count(self, 0b11, 0b11, 0, pattern, mul, xdn)
+    }
+
+    /// [DECB - DECD - DECH - DECW - scalar - Decrement scalar by multiple of predicate constraint element count](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/DECB--DECD--DECH--DECW--scalar---Decrement-scalar-by-multiple-of-predicate-constraint-element-count-?lang=en)
+    ///
+    /// Determines the number of active byte elements implied by the named predicate constraint, multiplies that by an immediate in the range 1 to 16 inclusive, and then uses the result to decrement the scalar destination.
+    ///
+    /// `mul` must be in range 1 to 16.
+    ///
+    /// ```asm
+    /// DECB <Xdn>{, <pattern>{, MUL #<imm>}}
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn decb(&mut self, xdn: Register, pattern: SvePattern, mul: UImm5) -> T {
+        emit_elem_count(self, 0b00, 0b11, 1, pattern, mul, xdn)
+    }
+
+    /// [DECB - DECD - DECH - DECW - scalar - Decrement scalar by multiple of predicate constraint element count](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/DECB--DECD--DECH--DECW--scalar---Decrement-scalar-by-multiple-of-predicate-constraint-element-count-?lang=en)
+    ///
+    /// Determines the number of active halfword elements implied by the named predicate constraint, multiplies that by an immediate in the range 1 to 16 inclusive, and then uses the result to decrement the scalar destination.
+    ///
+    /// `mul` must be in range 1 to 16.
+    ///
+    /// ```asm
+    /// DECH <Xdn>{, <pattern>{, MUL #<imm>}}
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn dech(&mut self, xdn: Register, pattern: SvePattern, mul: UImm5) -> T {
+        emit_elem_count(self, 0b01, 0b11, 1, pattern, mul, xdn)
+    }
+
+    /// [DECB - DECD - DECH - DECW - scalar - Decrement scalar by multiple of predicate constraint element count](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/DECB--DECD--DECH--DECW--scalar---Decrement-scalar-by-multiple-of-predicate-constraint-element-count-?lang=en)
+    ///
+    /// Determines the number of active word elements implied by the named predicate constraint, multiplies that by an immediate in the range 1 to 16 inclusive, and then uses the result to decrement the scalar destination.
+    ///
+    /// `mul` must be in range 1 to 16.
+    ///
+    /// ```asm
+    /// DECW <Xdn>{, <pattern>{, MUL #<imm>}}
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn decw(&mut self, xdn: Register, pattern: SvePattern, mul: UImm5) -> T {
+        emit_elem_count(self, 0b10, 0b11, 1, pattern, mul, xdn)
+    }
+
+    /// [DECB - DECD - DECH - DECW - scalar - Decrement scalar by multiple of predicate constraint element count](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/DECB--DECD--DECH--DECW--scalar---Decrement-scalar-by-multiple-of-predicate-constraint-element-count-?lang=en)
+    ///
+    /// Determines the number of active doubleword elements implied by the named predicate constraint, multiplies that by an immediate in the range 1 to 16 inclusive, and then uses the result to decrement the scalar destination.
+    ///
+    /// `mul` must be in range 1 to 16.
+    ///
+    /// ```asm
+    /// DECD <Xdn>{, <pattern>{, MUL #<imm>}}
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn decd(&mut self, xdn: Register, pattern: SvePattern, mul: UImm5) -> T {
+        emit_elem_count(self, 0b11, 0b11, 1, pattern, mul, xdn)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use crate::assert_panic;
+    #[allow(unused_imports)]
+    use crate::test_utils::test_producer::TestProducer;
+
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[cfg(feature = "arm_feat_sve")]
+    #[test]
+    fn test_cnt() {
+        let mut prod = TestProducer::new();
+
+        assert_eq!(prod.cntb(1, SvePattern::ALL, 1), "cntb x1");
+        assert_eq!(prod.cnth(1, SvePattern::ALL, 1), "cnth x1");
+        assert_eq!(prod.cntw(1, SvePattern::ALL, 1), "cntw x1");
+        assert_eq!(prod.cntd(1, SvePattern::VL8, 3), "cntd x1, vl8, mul #0x3");
+
+        assert_panic!("Should panic: mul out of range"; prod.cntb(1, SvePattern::ALL, 0));
+        assert_panic!("Should panic: mul out of range"; prod.cntb(1, SvePattern::ALL, 17));
+    }
+
+    #[cfg(feature = "arm_feat_sve")]
+    #[test]
+    fn test_inc_dec() {
+        let mut prod = TestProducer::new();
+
+        assert_eq!(prod.incb(1, SvePattern::ALL, 1), "incb x1");
+        assert_eq!(prod.incd(2, SvePattern::ALL, 1), "incd x2");
+        assert_eq!(prod.decw(1, SvePattern::ALL, 1), "decw x1");
+        assert_eq!(prod.dech(1, SvePattern::ALL, 1), "dech x1");
+    }
+}
@@ -0,0 +1,213 @@
+//! # [SVE Floating Point Arithmetic](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/SVE-encodings?lang=en)
+//!
+//! Implements the following instructions:
+//!  - [FADD - vectors - predicated - Floating-point add vector - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/FADD--vectors--predicated---Floating-point-add-vector--predicated--?lang=en)
+//!  - [FSUB - vectors - predicated - Floating-point subtract vectors - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/FSUB--vectors--predicated---Floating-point-subtract-vectors--predicated--?lang=en)
+//!  - [FMUL - vectors - predicated - Floating-point multiply vectors - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/FMUL--vectors--predicated---Floating-point-multiply-vectors--predicated--?lang=en)
+//!  - [FDIV - Floating-point divide by vector - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/FDIV--Floating-point-divide-by-vector--predicated--?lang=en)
+//!  - [FMLA - vectors - Floating-point fused multiply-add vectors - predicated writing addend](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/FMLA--vectors---Floating-point-fused-multiply-add-vectors--predicated---writing-addend--Zda---Zda---Zn---Zm--?lang=en)
+//!  - [FMLS - vectors - Floating-point fused multiply-subtract vectors - predicated writing addend](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/FMLS--vectors---Floating-point-fused-multiply-subtract-vectors--predicated---writing-addend--Zda---Zda----Zn---Zm--?lang=en)
+
+#[allow(unused_imports)]
+use bit_seq::bseq_32;
+
+#[cfg(feature = "arm_feat_sve")]
+use crate::instruction_encoding::sve::debug_assert_fp_regs;
+use crate::instruction_encoding::InstructionProcessor;
+#[allow(unused_imports)]
+use crate::types::sve::{PReg, SveSize, ZReg};
+
+#[inline(always)]
+#[cfg(feature = "arm_feat_sve")]
+fn emit_fp_arith_pred<P: InstructionProcessor<T>, T>(
+    proc: &mut P,
+    zdn: ZReg,
+    pg: PReg,
+    zm: ZReg,
+    opc: u8,
+) -> T {
+    debug_assert_fp_regs(pg, &[zdn, zm]);
+    let size: u8 = zdn.1.into();
+    let (zdn, pg, zm) = (zdn.0, pg.0, zm.0);
+    let i = bseq_32!(01100101 size:2 00 opc:4 100 pg:3 zm:5 zdn:5);
+    proc.process(i)
+}
+
+#[inline(always)]
+#[cfg(feature = "arm_feat_sve")]
+fn emit_fp_mul_add<P: InstructionProcessor<T>, T>(
+    proc: &mut P,
+    zda: ZReg,
+    pg: PReg,
+    zn: ZReg,
+    zm: ZReg,
+    opc: u8,
+) -> T {
+    debug_assert_fp_regs(pg, &[zda, zn, zm]);
+    let size: u8 = zda.1.into();
+    let (zda, pg, zn, zm) = (zda.0, pg.0, zn.0, zm.0);
+    let i = bseq_32!(01100101 size:2 1 zm:5 0 opc:2 pg:3 zn:5 zda:5);
+    proc.process(i)
+}
+
+/// # [SVE Floating Point Arithmetic](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/SVE-encodings?lang=en)
+///
+/// Implements the following instructions:
+///  - [FADD - vectors - predicated - Floating-point add vector - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/FADD--vectors--predicated---Floating-point-add-vector--predicated--?lang=en)
+///  - [FSUB - vectors - predicated - Floating-point subtract vectors - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/FSUB--vectors--predicated---Floating-point-subtract-vectors--predicated--?lang=en)
+///  - [FMUL - vectors - predicated - Floating-point multiply vectors - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/FMUL--vectors--predicated---Floating-point-multiply-vectors--predicated--?lang=en)
+///  - [FDIV - Floating-point divide by vector - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/FDIV--Floating-point-divide-by-vector--predicated--?lang=en)
+///  - [FMLA - vectors - Floating-point fused multiply-add vectors - predicated writing addend](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/FMLA--vectors---Floating-point-fused-multiply-add-vectors--predicated---writing-addend--Zda---Zda---Zn---Zm--?lang=en)
+///  - [FMLS - vectors - Floating-point fused multiply-subtract vectors - predicated writing addend](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/FMLS--vectors---Floating-point-fused-multiply-subtract-vectors--predicated---writing-addend--Zda---Zda----Zn---Zm--?lang=en)
+pub trait SveFloatingPointArithmetic<T>: InstructionProcessor<T> {
+    /// [FADD - vectors - predicated - Floating-point add vector - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/FADD--vectors--predicated---Floating-point-add-vector--predicated--?lang=en)
+    ///
+    /// Add active floating-point elements of the second source vector to corresponding floating-point elements of the first source vector and destructively place the results in the corresponding elements of the first source vector. Inactive elements in the destination vector register remain unmodified.
+    ///
+    /// All registers must have the same element size `H`, `S` or `D` and `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// FADD <Zdn>.<T>, <Pg>/M, <Zdn>.<T>, <Zm>.<T>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_fadd_pred(&mut self, zdn: ZReg, pg: PReg, zm: ZReg) -> T {
+        emit_fp_arith_pred(self, zdn, pg, zm, 0b0000)
+    }
+
+    /// [FSUB - vectors - predicated - Floating-point subtract vectors - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/FSUB--vectors--predicated---Floating-point-subtract-vectors--predicated--?lang=en)
+    ///
+    /// Subtract active floating-point elements of the second source vector from corresponding floating-point elements of the first source vector and destructively place the results in the corresponding elements of the first source vector. Inactive elements in the destination vector register remain unmodified.
+    ///
+    /// All registers must have the same element size `H`, `S` or `D` and `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// FSUB <Zdn>.<T>, <Pg>/M, <Zdn>.<T>, <Zm>.<T>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_fsub_pred(&mut self, zdn: ZReg, pg: PReg, zm: ZReg) -> T {
+        emit_fp_arith_pred(self, zdn, pg, zm, 0b0001)
+    }
+
+    /// [FMUL - vectors - predicated - Floating-point multiply vectors - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/FMUL--vectors--predicated---Floating-point-multiply-vectors--predicated--?lang=en)
+    ///
+    /// Multiply active floating-point elements of the first source vector by corresponding floating-point elements of the second source vector and destructively place the results in the corresponding elements of the first source vector. Inactive elements in the destination vector register remain unmodified.
+    ///
+    /// All registers must have the same element size `H`, `S` or `D` and `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// FMUL <Zdn>.<T>, <Pg>/M, <Zdn>.<T>, <Zm>.<T>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_fmul_pred(&mut self, zdn: ZReg, pg: PReg, zm: ZReg) -> T {
+        emit_fp_arith_pred(self, zdn, pg, zm, 0b0010)
+    }
+
+    /// [FDIV - Floating-point divide by vector - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/FDIV--Floating-point-divide-by-vector--predicated--?lang=en)
+    ///
+    /// Divide active floating-point elements of the first source vector by corresponding floating-point elements of the second source vector and destructively place the quotient in the corresponding elements of the first source vector. Inactive elements in the destination vector register remain unmodified.
+    ///
+    /// All registers must have the same element size `H`, `S` or `D` and `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// FDIV <Zdn>.<T>, <Pg>/M, <Zdn>.<T>, <Zm>.<T>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_fdiv_pred(&mut self, zdn: ZReg, pg: PReg, zm: ZReg) -> T {
+        emit_fp_arith_pred(self, zdn, pg, zm, 0b1101)
+    }
+
+    /// [FMLA - vectors - Floating-point fused multiply-add vectors - predicated writing addend](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/FMLA--vectors---Floating-point-fused-multiply-add-vectors--predicated---writing-addend--Zda---Zda---Zn---Zm--?lang=en)
+    ///
+    /// Multiply the corresponding active floating-point elements of the first and second source vectors and add to elements of the third source (addend) vector without intermediate rounding. Destructively place the results in the destination and third source (addend) vector. Inactive elements in the destination vector register remain unmodified.
+    ///
+    /// All registers must have the same element size `H`, `S` or `D` and `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// FMLA <Zda>.<T>, <Pg>/M, <Zn>.<T>, <Zm>.<T>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_fmla(&mut self, zda: ZReg, pg: PReg, zn: ZReg, zm: ZReg) -> T {
+        emit_fp_mul_add(self, zda, pg, zn, zm, 0b00)
+    }
+
+    /// [FMLS - vectors - Floating-point fused multiply-subtract vectors - predicated writing addend](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/FMLS--vectors---Floating-point-fused-multiply-subtract-vectors--predicated---writing-addend--Zda---Zda----Zn---Zm--?lang=en)
+    ///
+    /// Multiply the corresponding active floating-point elements of the first and second source vectors and subtract from elements of the third source (addend) vector without intermediate rounding. Destructively place the results in the destination and third source (addend) vector. Inactive elements in the destination vector register remain unmodified.
+    ///
+    /// All registers must have the same element size `H`, `S` or `D` and `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// FMLS <Zda>.<T>, <Pg>/M, <Zn>.<T>, <Zm>.<T>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_fmls(&mut self, zda: ZReg, pg: PReg, zn: ZReg, zm: ZReg) -> T {
+        emit_fp_mul_add(self, zda, pg, zn, zm, 0b01)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use crate::assert_panic;
+    #[allow(unused_imports)]
+    use crate::test_utils::test_producer::TestProducer;
+
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[cfg(feature = "arm_feat_sve")]
+    #[test]
+    fn test_sve_fp_arith_predicated() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.sve_fadd_pred(ZReg::s(1), PReg::b(2), ZReg::s(4));
+        assert_eq!(instr, "fadd z1.s, p2/m, z1.s, z4.s");
+
+        let instr = prod.sve_fsub_pred(ZReg::s(1), PReg::b(2), ZReg::s(4));
+        assert_eq!(instr, "fsub z1.s, p2/m, z1.s, z4.s");
+
+        let instr = prod.sve_fmul_pred(ZReg::h(1), PReg::b(2), ZReg::h(4));
+        assert_eq!(instr, "fmul z1.h, p2/m, z1.h, z4.h");
+
+        let instr = prod.sve_fdiv_pred(ZReg::d(1), PReg::b(2), ZReg::d(4));
+        assert_eq!(instr, "fdiv z1.d, p2/m, z1.d, z4.d");
+
+        assert_panic!("Should panic: element size B"; prod.sve_fadd_pred(ZReg::b(1), PReg::b(2), ZReg::b(4)));
+
+        assert_panic!("Should panic: mixed element sizes"; prod.sve_fmul_pred(ZReg::s(1), PReg::b(2), ZReg::d(4)));
+
+        assert_panic!("Should panic: pg out of range"; prod.sve_fdiv_pred(ZReg::d(1), PReg::b(8), ZReg::d(4)));
+    }
+
+    #[cfg(feature = "arm_feat_sve")]
+    #[test]
+    fn test_sve_fp_mul_add() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.sve_fmla(ZReg::s(1), PReg::b(2), ZReg::s(4), ZReg::s(3));
+        assert_eq!(instr, "fmla z1.s, p2/m, z4.s, z3.s");
+
+        let instr = prod.sve_fmls(ZReg::s(1), PReg::b(2), ZReg::s(4), ZReg::s(3));
+        assert_eq!(instr, "fmls z1.s, p2/m, z4.s, z3.s");
+
+        assert_panic!("Should panic: mixed element sizes"; prod.sve_fmla(ZReg::s(1), PReg::b(2), ZReg::h(4), ZReg::s(3)));
+    }
+}
@@ -0,0 +1,163 @@
+//! # [SVE Floating Point Compare Vectors](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/SVE-encodings?lang=en)
+//!
+//! Implements the following instructions:
+//!  - [FCM\<cc\> - vectors - Floating-point compare vectors](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/FCM-cc---vectors---Floating-point-compare-vectors-?lang=en)
+//!  - [FCMUO - Floating-point unordered compare vectors](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/FCMUO--Floating-point-unordered-compare-vectors-?lang=en)
+
+#[allow(unused_imports)]
+use bit_seq::bseq_32;
+
+#[cfg(feature = "arm_feat_sve")]
+use crate::instruction_encoding::sve::debug_assert_fp_regs;
+use crate::instruction_encoding::InstructionProcessor;
+#[allow(unused_imports)]
+use crate::types::sve::{PReg, SveSize, ZReg};
+
+#[inline(always)]
+#[cfg(feature = "arm_feat_sve")]
+fn emit_fp_compare<P: InstructionProcessor<T>, T>(
+    proc: &mut P,
+    pd: PReg,
+    pg: PReg,
+    zn: ZReg,
+    zm: ZReg,
+    op: u8,
+    o2: u8,
+    o3: u8,
+) -> T {
+    debug_assert_fp_regs(pg, &[zn, zm]);
+    debug_assert!(pd.0 <= 15, "pd must be in range P0 to P15, was {}", pd.0);
+    debug_assert!(pd.1 == zn.1, "pd must have the element size of zn");
+    let size: u8 = zn.1.into();
+    let (pd, pg, zn, zm) = (pd.0, pg.0, zn.0, zm.0);
+    let i = bseq_32!(01100101 size:2 0 zm:5 op:1 1 o2:1 pg:3 zn:5 o3:1 pd:4);
+    proc.process(i)
+}
+
+/// # [SVE Floating Point Compare Vectors](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/SVE-encodings?lang=en)
+///
+/// Implements the following instructions:
+///  - [FCM\<cc\> - vectors - Floating-point compare vectors](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/FCM-cc---vectors---Floating-point-compare-vectors-?lang=en)
+///  - [FCMUO - Floating-point unordered compare vectors](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/FCMUO--Floating-point-unordered-compare-vectors-?lang=en)
+pub trait SveFloatingPointCompare<T>: InstructionProcessor<T> {
+    /// [FCM\<cc\> - vectors - Floating-point compare vectors](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/FCM-cc---vectors---Floating-point-compare-vectors-?lang=en)
+    ///
+    /// Compare active floating-point elements in the first source vector with corresponding elements in the second source vector for equal to, and place the boolean results of the comparison in the corresponding elements of the destination predicate. Inactive elements in the destination predicate register are set to zero. Does not set the condition flags.
+    ///
+    /// `zn` and `zm` must have the same element size `H`, `S` or `D`, `pd` must have the same element size and `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// FCMEQ <Pd>.<T>, <Pg>/Z, <Zn>.<T>, <Zm>.<T>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_fcmeq(&mut self, pd: PReg, pg: PReg, zn: ZReg, zm: ZReg) -> T {
+        emit_fp_compare(self, pd, pg, zn, zm, 0, 1, 0)
+    }
+
+    /// [FCM\<cc\> - vectors - Floating-point compare vectors](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/FCM-cc---vectors---Floating-point-compare-vectors-?lang=en)
+    ///
+    /// Compare active floating-point elements in the first source vector with corresponding elements in the second source vector for not equal to, and place the boolean results of the comparison in the corresponding elements of the destination predicate. Inactive elements in the destination predicate register are set to zero. Does not set the condition flags.
+    ///
+    /// `zn` and `zm` must have the same element size `H`, `S` or `D`, `pd` must have the same element size and `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// FCMNE <Pd>.<T>, <Pg>/Z, <Zn>.<T>, <Zm>.<T>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_fcmne(&mut self, pd: PReg, pg: PReg, zn: ZReg, zm: ZReg) -> T {
+        emit_fp_compare(self, pd, pg, zn, zm, 0, 1, 1)
+    }
+
+    /// [FCM\<cc\> - vectors - Floating-point compare vectors](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/FCM-cc---vectors---Floating-point-compare-vectors-?lang=en)
+    ///
+    /// Compare active floating-point elements in the first source vector with corresponding elements in the second source vector for greater than or equal to, and place the boolean results of the comparison in the corresponding elements of the destination predicate. Inactive elements in the destination predicate register are set to zero. Does not set the condition flags.
+    ///
+    /// `zn` and `zm` must have the same element size `H`, `S` or `D`, `pd` must have the same element size and `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// FCMGE <Pd>.<T>, <Pg>/Z, <Zn>.<T>, <Zm>.<T>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_fcmge(&mut self, pd: PReg, pg: PReg, zn: ZReg, zm: ZReg) -> T {
+        emit_fp_compare(self, pd, pg, zn, zm, 0, 0, 0)
+    }
+
+    /// [FCM\<cc\> - vectors - Floating-point compare vectors](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/FCM-cc---vectors---Floating-point-compare-vectors-?lang=en)
+    ///
+    /// Compare active floating-point elements in the first source vector with corresponding elements in the second source vector for greater than, and place the boolean results of the comparison in the corresponding elements of the destination predicate. Inactive elements in the destination predicate register are set to zero. Does not set the condition flags.
+    ///
+    /// `zn` and `zm` must have the same element size `H`, `S` or `D`, `pd` must have the same element size and `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// FCMGT <Pd>.<T>, <Pg>/Z, <Zn>.<T>, <Zm>.<T>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_fcmgt(&mut self, pd: PReg, pg: PReg, zn: ZReg, zm: ZReg) -> T {
+        emit_fp_compare(self, pd, pg, zn, zm, 0, 0, 1)
+    }
+
+    /// [FCMUO - Floating-point unordered compare vectors](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/FCMUO--Floating-point-unordered-compare-vectors-?lang=en)
+    ///
+    /// Compare active floating-point elements in the first source vector with corresponding elements in the second source vector for unordered, and place the boolean results of the comparison in the corresponding elements of the destination predicate. Inactive elements in the destination predicate register are set to zero. Does not set the condition flags.
+    ///
+    /// `zn` and `zm` must have the same element size `H`, `S` or `D`, `pd` must have the same element size and `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// FCMUO <Pd>.<T>, <Pg>/Z, <Zn>.<T>, <Zm>.<T>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_fcmuo(&mut self, pd: PReg, pg: PReg, zn: ZReg, zm: ZReg) -> T {
+        emit_fp_compare(self, pd, pg, zn, zm, 1, 0, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use crate::assert_panic;
+    #[allow(unused_imports)]
+    use crate::test_utils::test_producer::TestProducer;
+
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[cfg(feature = "arm_feat_sve")]
+    #[test]
+    fn test_sve_fp_compare() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.sve_fcmeq(PReg::s(1), PReg::b(2), ZReg::s(4), ZReg::s(3));
+        assert_eq!(instr, "fcmeq p1.s, p2/z, z4.s, z3.s");
+
+        let instr = prod.sve_fcmne(PReg::s(1), PReg::b(2), ZReg::s(4), ZReg::s(3));
+        assert_eq!(instr, "fcmne p1.s, p2/z, z4.s, z3.s");
+
+        let instr = prod.sve_fcmge(PReg::s(1), PReg::b(2), ZReg::s(4), ZReg::s(3));
+        assert_eq!(instr, "fcmge p1.s, p2/z, z4.s, z3.s");
+
+        let instr = prod.sve_fcmgt(PReg::s(1), PReg::b(2), ZReg::s(4), ZReg::s(3));
+        assert_eq!(instr, "fcmgt p1.s, p2/z, z4.s, z3.s");
+
+        let instr = prod.sve_fcmuo(PReg::s(1), PReg::b(2), ZReg::s(4), ZReg::s(3));
+        assert_eq!(instr, "fcmuo p1.s, p2/z, z4.s, z3.s");
+
+        assert_panic!("Should panic: pd element size mismatch"; prod.sve_fcmeq(PReg::d(1), PReg::b(2), ZReg::s(4), ZReg::s(3)));
+
+        assert_panic!("Should panic: element size B"; prod.sve_fcmgt(PReg::b(1), PReg::b(2), ZReg::b(4), ZReg::b(3)));
+    }
+}
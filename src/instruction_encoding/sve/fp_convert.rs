@@ -0,0 +1,264 @@
+//! # [SVE Floating Point Convert and Round](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/SVE-encodings?lang=en)
+//!
+//! Implements the following instructions:
+//!  - [FCVT - Floating-point convert precision - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/FCVT--Floating-point-convert-precision--predicated--?lang=en)
+//!  - [FRINT\<r\> - Floating-point round to integral value - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/FRINT-r---Floating-point-round-to-integral-value--predicated--?lang=en)
+
+#[allow(unused_imports)]
+use bit_seq::bseq_32;
+
+use crate::instruction_encoding::InstructionProcessor;
+#[allow(unused_imports)]
+use crate::types::sve::{PReg, SveSize, ZReg};
+
+#[inline(always)]
+#[cfg(feature = "arm_feat_sve")]
+fn emit_fp_convert<P: InstructionProcessor<T>, T>(proc: &mut P, zd: ZReg, pg: PReg, zn: ZReg) -> T {
+    debug_assert!(pg.0 <= 7, "pg must be in range P0 to P7, was {}", pg.0);
+    debug_assert!(
+        zd.1 != zn.1 && zd.1 != SveSize::B && zn.1 != SveSize::B,
+        "zd and zn must be two different element sizes out of H, S and D"
+    );
+    let (opc, opc2): (u8, u8) = match (zd.1, zn.1) {
+        (SveSize::H, SveSize::S) => (0b10, 0b00),
+        (SveSize::S, SveSize::H) => (0b10, 0b01),
+        (SveSize::H, SveSize::D) => (0b11, 0b00),
+        (SveSize::D, SveSize::H) => (0b11, 0b01),
+        (SveSize::S, SveSize::D) => (0b11, 0b10),
+        (SveSize::D, SveSize::S) => (0b11, 0b11),
+        // unallocated encoding
+        _ => (0b00, 0b00),
+    };
+    let (zd, pg, zn) = (zd.0, pg.0, zn.0);
+    let i = bseq_32!(01100101 opc:2 0010 opc2:2 101 pg:3 zn:5 zd:5);
+    proc.process(i)
+}
+
+#[inline(always)]
+#[cfg(feature = "arm_feat_sve")]
+fn emit_fp_round<P: InstructionProcessor<T>, T>(
+    proc: &mut P,
+    zd: ZReg,
+    pg: PReg,
+    zn: ZReg,
+    opc: u8,
+) -> T {
+    debug_assert!(pg.0 <= 7, "pg must be in range P0 to P7, was {}", pg.0);
+    debug_assert!(zd.1 != SveSize::B, "z registers must have element size H, S or D");
+    debug_assert!(zd.1 == zn.1, "zd and zn must have the same element size");
+    let size: u8 = zd.1.into();
+    let (zd, pg, zn) = (zd.0, pg.0, zn.0);
+    let i = bseq_32!(01100101 size:2 000 opc:3 101 pg:3 zn:5 zd:5);
+    proc.process(i)
+}
+
+/// # [SVE Floating Point Convert and Round](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/SVE-encodings?lang=en)
+///
+/// Implements the following instructions:
+///  - [FCVT - Floating-point convert precision - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/FCVT--Floating-point-convert-precision--predicated--?lang=en)
+///  - [FRINT\<r\> - Floating-point round to integral value - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/FRINT-r---Floating-point-round-to-integral-value--predicated--?lang=en)
+pub trait SveFloatingPointConvert<T>: InstructionProcessor<T> {
+    /// [FCVT - Floating-point convert precision - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/FCVT--Floating-point-convert-precision--predicated--?lang=en)
+    ///
+    /// Convert the size and precision of each active floating-point element of the source vector, and place the results in the corresponding elements of the destination vector. Inactive elements in the destination vector register remain unmodified.
+    ///
+    /// The element sizes of `zd` and `zn` determine the conversion and must be two different sizes out of `H`, `S` and `D`. `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// FCVT <Zd>.<T>, <Pg>/M, <Zn>.<Tb>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_fcvt(&mut self, zd: ZReg, pg: PReg, zn: ZReg) -> T {
+        emit_fp_convert(self, zd, pg, zn)
+    }
+
+    /// [FRINT\<r\> - Floating-point round to integral value - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/FRINT-r---Floating-point-round-to-integral-value--predicated--?lang=en)
+    ///
+    /// Round to an integral floating-point value to nearest with ties to even from each active floating-point element of the source vector, and place the results in the corresponding elements of the destination vector. Inactive elements in the destination vector register remain unmodified.
+    ///
+    /// `zd` and `zn` must have the same element size `H`, `S` or `D` and `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// FRINTN <Zd>.<T>, <Pg>/M, <Zn>.<T>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_frintn(&mut self, zd: ZReg, pg: PReg, zn: ZReg) -> T {
+        emit_fp_round(self, zd, pg, zn, 0b000)
+    }
+
+    /// [FRINT\<r\> - Floating-point round to integral value - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/FRINT-r---Floating-point-round-to-integral-value--predicated--?lang=en)
+    ///
+    /// Round to an integral floating-point value towards plus infinity from each active floating-point element of the source vector, and place the results in the corresponding elements of the destination vector. Inactive elements in the destination vector register remain unmodified.
+    ///
+    /// `zd` and `zn` must have the same element size `H`, `S` or `D` and `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// FRINTP <Zd>.<T>, <Pg>/M, <Zn>.<T>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_frintp(&mut self, zd: ZReg, pg: PReg, zn: ZReg) -> T {
+        emit_fp_round(self, zd, pg, zn, 0b001)
+    }
+
+    /// [FRINT\<r\> - Floating-point round to integral value - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/FRINT-r---Floating-point-round-to-integral-value--predicated--?lang=en)
+    ///
+    /// Round to an integral floating-point value towards minus infinity from each active floating-point element of the source vector, and place the results in the corresponding elements of the destination vector. Inactive elements in the destination vector register remain unmodified.
+    ///
+    /// `zd` and `zn` must have the same element size `H`, `S` or `D` and `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// FRINTM <Zd>.<T>, <Pg>/M, <Zn>.<T>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_frintm(&mut self, zd: ZReg, pg: PReg, zn: ZReg) -> T {
+        emit_fp_round(self, zd, pg, zn, 0b010)
+    }
+
+    /// [FRINT\<r\> - Floating-point round to integral value - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/FRINT-r---Floating-point-round-to-integral-value--predicated--?lang=en)
+    ///
+    /// Round to an integral floating-point value towards zero from each active floating-point element of the source vector, and place the results in the corresponding elements of the destination vector. Inactive elements in the destination vector register remain unmodified.
+    ///
+    /// `zd` and `zn` must have the same element size `H`, `S` or `D` and `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// FRINTZ <Zd>.<T>, <Pg>/M, <Zn>.<T>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_frintz(&mut self, zd: ZReg, pg: PReg, zn: ZReg) -> T {
+        emit_fp_round(self, zd, pg, zn, 0b011)
+    }
+
+    /// [FRINT\<r\> - Floating-point round to integral value - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/FRINT-r---Floating-point-round-to-integral-value--predicated--?lang=en)
+    ///
+    /// Round to an integral floating-point value to nearest with ties to away from each active floating-point element of the source vector, and place the results in the corresponding elements of the destination vector. Inactive elements in the destination vector register remain unmodified.
+    ///
+    /// `zd` and `zn` must have the same element size `H`, `S` or `D` and `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// FRINTA <Zd>.<T>, <Pg>/M, <Zn>.<T>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_frinta(&mut self, zd: ZReg, pg: PReg, zn: ZReg) -> T {
+        emit_fp_round(self, zd, pg, zn, 0b100)
+    }
+
+    /// [FRINT\<r\> - Floating-point round to integral value - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/FRINT-r---Floating-point-round-to-integral-value--predicated--?lang=en)
+    ///
+    /// Round to an integral floating-point value using the current rounding mode from each active floating-point element of the source vector, and place the results in the corresponding elements of the destination vector. An Inexact exception is raised if the result differs from the source value. Inactive elements in the destination vector register remain unmodified.
+    ///
+    /// `zd` and `zn` must have the same element size `H`, `S` or `D` and `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// FRINTX <Zd>.<T>, <Pg>/M, <Zn>.<T>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_frintx(&mut self, zd: ZReg, pg: PReg, zn: ZReg) -> T {
+        emit_fp_round(self, zd, pg, zn, 0b110)
+    }
+
+    /// [FRINT\<r\> - Floating-point round to integral value - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/FRINT-r---Floating-point-round-to-integral-value--predicated--?lang=en)
+    ///
+    /// Round to an integral floating-point value using the current rounding mode from each active floating-point element of the source vector, and place the results in the corresponding elements of the destination vector. Inactive elements in the destination vector register remain unmodified.
+    ///
+    /// `zd` and `zn` must have the same element size `H`, `S` or `D` and `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// FRINTI <Zd>.<T>, <Pg>/M, <Zn>.<T>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_frinti(&mut self, zd: ZReg, pg: PReg, zn: ZReg) -> T {
+        emit_fp_round(self, zd, pg, zn, 0b111)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use crate::assert_panic;
+    #[allow(unused_imports)]
+    use crate::test_utils::test_producer::TestProducer;
+
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[cfg(feature = "arm_feat_sve")]
+    #[test]
+    fn test_sve_fcvt() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.sve_fcvt(ZReg::h(1), PReg::b(2), ZReg::s(4));
+        assert_eq!(instr, "fcvt z1.h, p2/m, z4.s");
+
+        let instr = prod.sve_fcvt(ZReg::s(1), PReg::b(2), ZReg::h(4));
+        assert_eq!(instr, "fcvt z1.s, p2/m, z4.h");
+
+        let instr = prod.sve_fcvt(ZReg::h(1), PReg::b(2), ZReg::d(4));
+        assert_eq!(instr, "fcvt z1.h, p2/m, z4.d");
+
+        let instr = prod.sve_fcvt(ZReg::d(1), PReg::b(2), ZReg::h(4));
+        assert_eq!(instr, "fcvt z1.d, p2/m, z4.h");
+
+        let instr = prod.sve_fcvt(ZReg::s(1), PReg::b(2), ZReg::d(4));
+        assert_eq!(instr, "fcvt z1.s, p2/m, z4.d");
+
+        let instr = prod.sve_fcvt(ZReg::d(1), PReg::b(2), ZReg::s(4));
+        assert_eq!(instr, "fcvt z1.d, p2/m, z4.s");
+
+        assert_panic!("Should panic: same element size"; prod.sve_fcvt(ZReg::s(1), PReg::b(2), ZReg::s(4)));
+
+        assert_panic!("Should panic: element size B"; prod.sve_fcvt(ZReg::b(1), PReg::b(2), ZReg::s(4)));
+    }
+
+    #[cfg(feature = "arm_feat_sve")]
+    #[test]
+    fn test_sve_frint() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.sve_frintn(ZReg::s(1), PReg::b(2), ZReg::s(4));
+        assert_eq!(instr, "frintn z1.s, p2/m, z4.s");
+
+        let instr = prod.sve_frintp(ZReg::s(1), PReg::b(2), ZReg::s(4));
+        assert_eq!(instr, "frintp z1.s, p2/m, z4.s");
+
+        let instr = prod.sve_frintm(ZReg::s(1), PReg::b(2), ZReg::s(4));
+        assert_eq!(instr, "frintm z1.s, p2/m, z4.s");
+
+        let instr = prod.sve_frintz(ZReg::s(1), PReg::b(2), ZReg::s(4));
+        assert_eq!(instr, "frintz z1.s, p2/m, z4.s");
+
+        let instr = prod.sve_frinta(ZReg::s(1), PReg::b(2), ZReg::s(4));
+        assert_eq!(instr, "frinta z1.s, p2/m, z4.s");
+
+        let instr = prod.sve_frintx(ZReg::s(1), PReg::b(2), ZReg::s(4));
+        assert_eq!(instr, "frintx z1.s, p2/m, z4.s");
+
+        let instr = prod.sve_frinti(ZReg::s(1), PReg::b(2), ZReg::s(4));
+        assert_eq!(instr, "frinti z1.s, p2/m, z4.s");
+
+        assert_panic!("Should panic: mixed element sizes"; prod.sve_frintz(ZReg::s(1), PReg::b(2), ZReg::d(4)));
+    }
+}
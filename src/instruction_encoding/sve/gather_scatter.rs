@@ -0,0 +1,435 @@
+//! # [SVE Memory - Gather Load and Scatter Store](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/SVE-encodings?lang=en)
+//!
+//! Implements the following instructions:
+//!  - [LD1B - vector plus immediate - Gather load unsigned bytes to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LD1B--vector-plus-immediate---Gather-load-unsigned-bytes-to-vector--immediate-index--?lang=en)
+//!  - [LD1H - vector plus immediate - Gather load unsigned halfwords to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LD1H--vector-plus-immediate---Gather-load-unsigned-halfwords-to-vector--immediate-index--?lang=en)
+//!  - [LD1W - vector plus immediate - Gather load unsigned words to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LD1W--vector-plus-immediate---Gather-load-unsigned-words-to-vector--immediate-index--?lang=en)
+//!  - [LD1D - vector plus immediate - Gather load doublewords to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LD1D--vector-plus-immediate---Gather-load-doublewords-to-vector--immediate-index--?lang=en)
+//!  - [LDFF1B - vector plus immediate - Gather load first-fault unsigned bytes to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LDFF1B--vector-plus-immediate---Gather-load-first-fault-unsigned-bytes-to-vector--immediate-index--?lang=en)
+//!  - [LDFF1H - vector plus immediate - Gather load first-fault unsigned halfwords to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LDFF1H--vector-plus-immediate---Gather-load-first-fault-unsigned-halfwords-to-vector--immediate-index--?lang=en)
+//!  - [LDFF1W - vector plus immediate - Gather load first-fault unsigned words to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LDFF1W--vector-plus-immediate---Gather-load-first-fault-unsigned-words-to-vector--immediate-index--?lang=en)
+//!  - [LDFF1D - vector plus immediate - Gather load first-fault doublewords to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LDFF1D--vector-plus-immediate---Gather-load-first-fault-doublewords-to-vector--immediate-index--?lang=en)
+//!  - [ST1B - vector plus immediate - Scatter store bytes from a vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ST1B--vector-plus-immediate---Scatter-store-bytes-from-a-vector--immediate-index--?lang=en)
+//!  - [ST1H - vector plus immediate - Scatter store halfwords from a vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ST1H--vector-plus-immediate---Scatter-store-halfwords-from-a-vector--immediate-index--?lang=en)
+//!  - [ST1W - vector plus immediate - Scatter store words from a vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ST1W--vector-plus-immediate---Scatter-store-words-from-a-vector--immediate-index--?lang=en)
+//!  - [ST1D - vector plus immediate - Scatter store doublewords from a vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ST1D--vector-plus-immediate---Scatter-store-doublewords-from-a-vector--immediate-index--?lang=en)
+
+#[allow(unused_imports)]
+use bit_seq::bseq_32;
+
+use crate::instruction_encoding::InstructionProcessor;
+#[allow(unused_imports)]
+use crate::types::sve::{PReg, SveExtend, SveSize, SveVecAddr, ZReg};
+
+#[inline(always)]
+#[cfg(feature = "arm_feat_sve")]
+fn debug_assert_gather_scatter(msz: u8, zt: ZReg, pg: PReg, addr: SveVecAddr) {
+    debug_assert!(zt.0 <= 31, "zt must be in range Z0 to Z31, was {}", zt.0);
+    debug_assert!(pg.0 <= 7, "pg must be in range P0 to P7, was {}", pg.0);
+    debug_assert!(
+        zt.1 == SveSize::S || zt.1 == SveSize::D,
+        "zt must have element size S or D"
+    );
+    debug_assert!(
+        u8::from(zt.1) >= msz,
+        "element size of zt must not be smaller than the memory access size"
+    );
+    match addr {
+        SveVecAddr::VecImm(zn, imm) => {
+            debug_assert!(zn.1 == zt.1, "zn must have the element size of zt");
+            debug_assert!(
+                imm % (1 << msz) == 0 && (imm >> msz) <= 31,
+                "imm must be a multiple of the access size in range 0 to 31 times the access size, was {}",
+                imm
+            );
+        }
+        SveVecAddr::ScalarVec64(_, zm, scaled) => {
+            debug_assert!(
+                zt.1 == SveSize::D && zm.1 == SveSize::D,
+                "64-bit vector offsets require element size D"
+            );
+            debug_assert!(!scaled || msz != 0, "byte offsets cannot be scaled");
+        }
+        SveVecAddr::ScalarVec32(_, zm, _, scaled) => {
+            debug_assert!(zm.1 == zt.1, "zm must have the element size of zt");
+            debug_assert!(!scaled || msz != 0, "byte offsets cannot be scaled");
+        }
+    }
+}
+
+#[inline(always)]
+#[cfg(feature = "arm_feat_sve")]
+fn emit_gather<P: InstructionProcessor<T>, T>(
+    proc: &mut P,
+    msz: u8,
+    ff: u8,
+    zt: ZReg,
+    pg: PReg,
+    addr: SveVecAddr,
+) -> T {
+    debug_assert_gather_scatter(msz, zt, pg, addr);
+    let is_64 = zt.1 == SveSize::D;
+    let (zt, pg) = (zt.0, pg.0);
+    let i = match addr {
+        SveVecAddr::VecImm(zn, imm) => {
+            let (zn, imm5) = (zn.0, imm >> msz);
+            if is_64 {
+                bseq_32!(1100010 msz:2 01 imm5:5 11 ff:1 pg:3 zn:5 zt:5)
+            } else {
+                bseq_32!(1000010 msz:2 01 imm5:5 11 ff:1 pg:3 zn:5 zt:5)
+            }
+        }
+        SveVecAddr::ScalarVec64(rn, zm, scaled) => {
+            let (zm, s) = (zm.0, scaled as u8);
+            bseq_32!(1100010 msz:2 1 s:1 zm:5 11 ff:1 pg:3 rn:5 zt:5)
+        }
+        SveVecAddr::ScalarVec32(rn, zm, extend, scaled) => {
+            let (zm, s) = (zm.0, scaled as u8);
+            let xs = (extend == SveExtend::SXTW) as u8;
+            if is_64 {
+                bseq_32!(1100010 msz:2 xs:1 s:1 zm:5 01 ff:1 pg:3 rn:5 zt:5)
+            } else {
+                bseq_32!(1000010 msz:2 xs:1 s:1 zm:5 01 ff:1 pg:3 rn:5 zt:5)
+            }
+        }
+    };
+    proc.process(i)
+}
+
+#[inline(always)]
+#[cfg(feature = "arm_feat_sve")]
+fn emit_scatter<P: InstructionProcessor<T>, T>(
+    proc: &mut P,
+    msz: u8,
+    zt: ZReg,
+    pg: PReg,
+    addr: SveVecAddr,
+) -> T {
+    debug_assert_gather_scatter(msz, zt, pg, addr);
+    let is_32 = (zt.1 == SveSize::S) as u8;
+    let (zt, pg) = (zt.0, pg.0);
+    let i = match addr {
+        SveVecAddr::VecImm(zn, imm) => {
+            let (zn, imm5) = (zn.0, imm >> msz);
+            bseq_32!(1110010 msz:2 1 is_32:1 imm5:5 101 pg:3 zn:5 zt:5)
+        }
+        SveVecAddr::ScalarVec64(rn, zm, scaled) => {
+            let (zm, s) = (zm.0, scaled as u8);
+            bseq_32!(1110010 msz:2 0 s:1 zm:5 101 pg:3 rn:5 zt:5)
+        }
+        SveVecAddr::ScalarVec32(rn, zm, extend, scaled) => {
+            let (zm, s) = (zm.0, scaled as u8);
+            let xs = (extend == SveExtend::SXTW) as u8;
+            bseq_32!(1110010 msz:2 is_32:1 s:1 zm:5 1 xs:1 0 pg:3 rn:5 zt:5)
+        }
+    };
+    proc.process(i)
+}
+
+/// # [SVE Memory - Gather Load and Scatter Store](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/SVE-encodings?lang=en)
+///
+/// Implements the following instructions:
+///  - [LD1B - vector plus immediate - Gather load unsigned bytes to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LD1B--vector-plus-immediate---Gather-load-unsigned-bytes-to-vector--immediate-index--?lang=en)
+///  - [LD1H - vector plus immediate - Gather load unsigned halfwords to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LD1H--vector-plus-immediate---Gather-load-unsigned-halfwords-to-vector--immediate-index--?lang=en)
+///  - [LD1W - vector plus immediate - Gather load unsigned words to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LD1W--vector-plus-immediate---Gather-load-unsigned-words-to-vector--immediate-index--?lang=en)
+///  - [LD1D - vector plus immediate - Gather load doublewords to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LD1D--vector-plus-immediate---Gather-load-doublewords-to-vector--immediate-index--?lang=en)
+///  - [LDFF1B - vector plus immediate - Gather load first-fault unsigned bytes to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LDFF1B--vector-plus-immediate---Gather-load-first-fault-unsigned-bytes-to-vector--immediate-index--?lang=en)
+///  - [LDFF1H - vector plus immediate - Gather load first-fault unsigned halfwords to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LDFF1H--vector-plus-immediate---Gather-load-first-fault-unsigned-halfwords-to-vector--immediate-index--?lang=en)
+///  - [LDFF1W - vector plus immediate - Gather load first-fault unsigned words to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LDFF1W--vector-plus-immediate---Gather-load-first-fault-unsigned-words-to-vector--immediate-index--?lang=en)
+///  - [LDFF1D - vector plus immediate - Gather load first-fault doublewords to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LDFF1D--vector-plus-immediate---Gather-load-first-fault-doublewords-to-vector--immediate-index--?lang=en)
+///  - [ST1B - vector plus immediate - Scatter store bytes from a vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ST1B--vector-plus-immediate---Scatter-store-bytes-from-a-vector--immediate-index--?lang=en)
+///  - [ST1H - vector plus immediate - Scatter store halfwords from a vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ST1H--vector-plus-immediate---Scatter-store-halfwords-from-a-vector--immediate-index--?lang=en)
+///  - [ST1W - vector plus immediate - Scatter store words from a vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ST1W--vector-plus-immediate---Scatter-store-words-from-a-vector--immediate-index--?lang=en)
+///  - [ST1D - vector plus immediate - Scatter store doublewords from a vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ST1D--vector-plus-immediate---Scatter-store-doublewords-from-a-vector--immediate-index--?lang=en)
+pub trait SveGatherScatter<T>: InstructionProcessor<T> {
+    /// [LD1B - vector plus immediate - Gather load unsigned bytes to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LD1B--vector-plus-immediate---Gather-load-unsigned-bytes-to-vector--immediate-index--?lang=en)
+    ///
+    /// Gather load of unsigned bytes to active elements of a vector register from memory addresses generated by the addressing mode `addr`. Inactive elements will not be read from Device memory or signal a fault, and are set to zero in the destination vector.
+    ///
+    /// Supports vector plus immediate, scalar plus 64-bit vector and scalar plus 32-bit vector addressing, see [`SveVecAddr`]. `zt` must have element size `S` or `D` and `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// LD1B {<Zt>.<T>}, <Pg>/Z, <addr>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn ld1b_gather(&mut self, zt: ZReg, pg: PReg, addr: SveVecAddr) -> T {
+        emit_gather(self, 0, 0, zt, pg, addr)
+    }
+
+    /// [LD1H - vector plus immediate - Gather load unsigned halfwords to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LD1H--vector-plus-immediate---Gather-load-unsigned-halfwords-to-vector--immediate-index--?lang=en)
+    ///
+    /// Gather load of unsigned halfwords to active elements of a vector register from memory addresses generated by the addressing mode `addr`. Inactive elements will not be read from Device memory or signal a fault, and are set to zero in the destination vector.
+    ///
+    /// Supports vector plus immediate, scalar plus 64-bit vector and scalar plus 32-bit vector addressing, see [`SveVecAddr`]. `zt` must have element size `S` or `D` and `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// LD1H {<Zt>.<T>}, <Pg>/Z, <addr>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn ld1h_gather(&mut self, zt: ZReg, pg: PReg, addr: SveVecAddr) -> T {
+        emit_gather(self, 1, 0, zt, pg, addr)
+    }
+
+    /// [LD1W - vector plus immediate - Gather load unsigned words to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LD1W--vector-plus-immediate---Gather-load-unsigned-words-to-vector--immediate-index--?lang=en)
+    ///
+    /// Gather load of unsigned words to active elements of a vector register from memory addresses generated by the addressing mode `addr`. Inactive elements will not be read from Device memory or signal a fault, and are set to zero in the destination vector.
+    ///
+    /// Supports vector plus immediate, scalar plus 64-bit vector and scalar plus 32-bit vector addressing, see [`SveVecAddr`]. `zt` must have element size `S` or `D` and `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// LD1W {<Zt>.<T>}, <Pg>/Z, <addr>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn ld1w_gather(&mut self, zt: ZReg, pg: PReg, addr: SveVecAddr) -> T {
+        emit_gather(self, 2, 0, zt, pg, addr)
+    }
+
+    /// [LD1D - vector plus immediate - Gather load doublewords to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LD1D--vector-plus-immediate---Gather-load-doublewords-to-vector--immediate-index--?lang=en)
+    ///
+    /// Gather load of doublewords to active elements of a vector register from memory addresses generated by the addressing mode `addr`. Inactive elements will not be read from Device memory or signal a fault, and are set to zero in the destination vector.
+    ///
+    /// Supports vector plus immediate, scalar plus 64-bit vector and scalar plus 32-bit vector addressing, see [`SveVecAddr`]. `zt` must have element size `S` or `D` and `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// LD1D {<Zt>.<T>}, <Pg>/Z, <addr>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn ld1d_gather(&mut self, zt: ZReg, pg: PReg, addr: SveVecAddr) -> T {
+        emit_gather(self, 3, 0, zt, pg, addr)
+    }
+
+    /// [LDFF1B - vector plus immediate - Gather load first-fault unsigned bytes to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LDFF1B--vector-plus-immediate---Gather-load-first-fault-unsigned-bytes-to-vector--immediate-index--?lang=en)
+    ///
+    /// Gather load with first-faulting behavior of unsigned bytes to active elements of a vector register from memory addresses generated by the addressing mode `addr`. Inactive elements will not be read from Device memory or signal faults, and are set to zero in the destination vector.
+    ///
+    /// Supports vector plus immediate, scalar plus 64-bit vector and scalar plus 32-bit vector addressing, see [`SveVecAddr`]. `zt` must have element size `S` or `D` and `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// LDFF1B {<Zt>.<T>}, <Pg>/Z, <addr>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn ldff1b_gather(&mut self, zt: ZReg, pg: PReg, addr: SveVecAddr) -> T {
+        emit_gather(self, 0, 1, zt, pg, addr)
+    }
+
+    /// [LDFF1H - vector plus immediate - Gather load first-fault unsigned halfwords to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LDFF1H--vector-plus-immediate---Gather-load-first-fault-unsigned-halfwords-to-vector--immediate-index--?lang=en)
+    ///
+    /// Gather load with first-faulting behavior of unsigned halfwords to active elements of a vector register from memory addresses generated by the addressing mode `addr`. Inactive elements will not be read from Device memory or signal faults, and are set to zero in the destination vector.
+    ///
+    /// Supports vector plus immediate, scalar plus 64-bit vector and scalar plus 32-bit vector addressing, see [`SveVecAddr`]. `zt` must have element size `S` or `D` and `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// LDFF1H {<Zt>.<T>}, <Pg>/Z, <addr>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn ldff1h_gather(&mut self, zt: ZReg, pg: PReg, addr: SveVecAddr) -> T {
+        emit_gather(self, 1, 1, zt, pg, addr)
+    }
+
+    /// [LDFF1W - vector plus immediate - Gather load first-fault unsigned words to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LDFF1W--vector-plus-immediate---Gather-load-first-fault-unsigned-words-to-vector--immediate-index--?lang=en)
+    ///
+    /// Gather load with first-faulting behavior of unsigned words to active elements of a vector register from memory addresses generated by the addressing mode `addr`. Inactive elements will not be read from Device memory or signal faults, and are set to zero in the destination vector.
+    ///
+    /// Supports vector plus immediate, scalar plus 64-bit vector and scalar plus 32-bit vector addressing, see [`SveVecAddr`]. `zt` must have element size `S` or `D` and `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// LDFF1W {<Zt>.<T>}, <Pg>/Z, <addr>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn ldff1w_gather(&mut self, zt: ZReg, pg: PReg, addr: SveVecAddr) -> T {
+        emit_gather(self, 2, 1, zt, pg, addr)
+    }
+
+    /// [LDFF1D - vector plus immediate - Gather load first-fault doublewords to vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/LDFF1D--vector-plus-immediate---Gather-load-first-fault-doublewords-to-vector--immediate-index--?lang=en)
+    ///
+    /// Gather load with first-faulting behavior of doublewords to active elements of a vector register from memory addresses generated by the addressing mode `addr`. Inactive elements will not be read from Device memory or signal faults, and are set to zero in the destination vector.
+    ///
+    /// Supports vector plus immediate, scalar plus 64-bit vector and scalar plus 32-bit vector addressing, see [`SveVecAddr`]. `zt` must have element size `S` or `D` and `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// LDFF1D {<Zt>.<T>}, <Pg>/Z, <addr>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn ldff1d_gather(&mut self, zt: ZReg, pg: PReg, addr: SveVecAddr) -> T {
+        emit_gather(self, 3, 1, zt, pg, addr)
+    }
+
+    /// [ST1B - vector plus immediate - Scatter store bytes from a vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ST1B--vector-plus-immediate---Scatter-store-bytes-from-a-vector--immediate-index--?lang=en)
+    ///
+    /// Scatter store of bytes from the active elements of a vector register to the memory addresses generated by the addressing mode `addr`. Inactive elements are not written to memory.
+    ///
+    /// Supports vector plus immediate, scalar plus 64-bit vector and scalar plus 32-bit vector addressing, see [`SveVecAddr`]. `zt` must have element size `S` or `D` and `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// ST1B {<Zt>.<T>}, <Pg>, <addr>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn st1b_scatter(&mut self, zt: ZReg, pg: PReg, addr: SveVecAddr) -> T {
+        emit_scatter(self, 0, zt, pg, addr)
+    }
+
+    /// [ST1H - vector plus immediate - Scatter store halfwords from a vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ST1H--vector-plus-immediate---Scatter-store-halfwords-from-a-vector--immediate-index--?lang=en)
+    ///
+    /// Scatter store of halfwords from the active elements of a vector register to the memory addresses generated by the addressing mode `addr`. Inactive elements are not written to memory.
+    ///
+    /// Supports vector plus immediate, scalar plus 64-bit vector and scalar plus 32-bit vector addressing, see [`SveVecAddr`]. `zt` must have element size `S` or `D` and `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// ST1H {<Zt>.<T>}, <Pg>, <addr>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn st1h_scatter(&mut self, zt: ZReg, pg: PReg, addr: SveVecAddr) -> T {
+        emit_scatter(self, 1, zt, pg, addr)
+    }
+
+    /// [ST1W - vector plus immediate - Scatter store words from a vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ST1W--vector-plus-immediate---Scatter-store-words-from-a-vector--immediate-index--?lang=en)
+    ///
+    /// Scatter store of words from the active elements of a vector register to the memory addresses generated by the addressing mode `addr`. Inactive elements are not written to memory.
+    ///
+    /// Supports vector plus immediate, scalar plus 64-bit vector and scalar plus 32-bit vector addressing, see [`SveVecAddr`]. `zt` must have element size `S` or `D` and `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// ST1W {<Zt>.<T>}, <Pg>, <addr>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn st1w_scatter(&mut self, zt: ZReg, pg: PReg, addr: SveVecAddr) -> T {
+        emit_scatter(self, 2, zt, pg, addr)
+    }
+
+    /// [ST1D - vector plus immediate - Scatter store doublewords from a vector - immediate index](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ST1D--vector-plus-immediate---Scatter-store-doublewords-from-a-vector--immediate-index--?lang=en)
+    ///
+    /// Scatter store of doublewords from the active elements of a vector register to the memory addresses generated by the addressing mode `addr`. Inactive elements are not written to memory.
+    ///
+    /// Supports vector plus immediate, scalar plus 64-bit vector and scalar plus 32-bit vector addressing, see [`SveVecAddr`]. `zt` must have element size `S` or `D` and `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// ST1D {<Zt>.<T>}, <Pg>, <addr>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn st1d_scatter(&mut self, zt: ZReg, pg: PReg, addr: SveVecAddr) -> T {
+        emit_scatter(self, 3, zt, pg, addr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use crate::assert_panic;
+    #[allow(unused_imports)]
+    use crate::test_utils::test_producer::TestProducer;
+    #[allow(unused_imports)]
+    use crate::types::sve::SveExtend::*;
+    #[allow(unused_imports)]
+    use crate::types::sve::SveVecAddr::*;
+
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[cfg(feature = "arm_feat_sve")]
+    #[test]
+    fn test_gather_vec_imm() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.ld1w_gather(ZReg::s(1), PReg::b(2), VecImm(ZReg::s(4), 12));
+        assert_eq!(instr, "ld1w {z1.s}, p2/z, [z4.s, #0xc]");
+
+        let instr = prod.ldff1d_gather(ZReg::d(1), PReg::b(2), VecImm(ZReg::d(4), 24));
+        assert_eq!(instr, "ldff1d {z1.d}, p2/z, [z4.d, #0x18]");
+
+        assert_panic!("Should panic: imm not a multiple of 4"; prod.ld1w_gather(ZReg::s(1), PReg::b(2), VecImm(ZReg::s(4), 13)));
+        assert_panic!("Should panic: imm out of range"; prod.ld1b_gather(ZReg::s(1), PReg::b(2), VecImm(ZReg::s(4), 32)));
+    }
+
+    #[cfg(feature = "arm_feat_sve")]
+    #[test]
+    fn test_gather_scalar_vec() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.ld1h_gather(ZReg::d(1), PReg::b(2), ScalarVec64(3, ZReg::d(4), false));
+        assert_eq!(instr, "ld1h {z1.d}, p2/z, [x3, z4.d]");
+
+        let instr = prod.ld1h_gather(ZReg::d(1), PReg::b(2), ScalarVec64(3, ZReg::d(4), true));
+        assert_eq!(instr, "ld1h {z1.d}, p2/z, [x3, z4.d, lsl #0x1]");
+
+        let instr = prod.ld1w_gather(ZReg::d(1), PReg::b(2), ScalarVec32(3, ZReg::d(4), SXTW, false));
+        assert_eq!(instr, "ld1w {z1.d}, p2/z, [x3, z4.d, sxtw]");
+
+        let instr = prod.ld1w_gather(ZReg::d(1), PReg::b(2), ScalarVec32(3, ZReg::d(4), UXTW, true));
+        assert_eq!(instr, "ld1w {z1.d}, p2/z, [x3, z4.d, uxtw #0x2]");
+
+        let instr = prod.ld1b_gather(ZReg::s(1), PReg::b(2), ScalarVec32(3, ZReg::s(4), UXTW, false));
+        assert_eq!(instr, "ld1b {z1.s}, p2/z, [x3, z4.s, uxtw]");
+
+        let instr = prod.ld1h_gather(ZReg::s(1), PReg::b(2), ScalarVec32(3, ZReg::s(4), SXTW, true));
+        assert_eq!(instr, "ld1h {z1.s}, p2/z, [x3, z4.s, sxtw #0x1]");
+
+        assert_panic!("Should panic: byte offsets cannot be scaled"; prod.ld1b_gather(ZReg::d(1), PReg::b(2), ScalarVec64(3, ZReg::d(4), true)));
+        assert_panic!("Should panic: 64-bit offsets need D"; prod.ld1b_gather(ZReg::s(1), PReg::b(2), ScalarVec64(3, ZReg::s(4), false)));
+    }
+
+    #[cfg(feature = "arm_feat_sve")]
+    #[test]
+    fn test_scatter() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.st1d_scatter(ZReg::d(1), PReg::b(2), VecImm(ZReg::d(4), 24));
+        assert_eq!(instr, "st1d {z1.d}, p2, [z4.d, #0x18]");
+
+        let instr = prod.st1w_scatter(ZReg::s(1), PReg::b(2), VecImm(ZReg::s(4), 12));
+        assert_eq!(instr, "st1w {z1.s}, p2, [z4.s, #0xc]");
+
+        let instr = prod.st1d_scatter(ZReg::d(1), PReg::b(2), ScalarVec64(3, ZReg::d(4), false));
+        assert_eq!(instr, "st1d {z1.d}, p2, [x3, z4.d]");
+
+        let instr = prod.st1d_scatter(ZReg::d(1), PReg::b(2), ScalarVec64(3, ZReg::d(4), true));
+        assert_eq!(instr, "st1d {z1.d}, p2, [x3, z4.d, lsl #0x3]");
+
+        let instr = prod.st1w_scatter(ZReg::d(1), PReg::b(2), ScalarVec32(3, ZReg::d(4), SXTW, false));
+        assert_eq!(instr, "st1w {z1.d}, p2, [x3, z4.d, sxtw]");
+
+        let instr = prod.st1w_scatter(ZReg::d(1), PReg::b(2), ScalarVec32(3, ZReg::d(4), UXTW, true));
+        assert_eq!(instr, "st1w {z1.d}, p2, [x3, z4.d, uxtw #0x2]");
+
+        let instr = prod.st1h_scatter(ZReg::s(1), PReg::b(2), ScalarVec32(3, ZReg::s(4), UXTW, false));
+        assert_eq!(instr, "st1h {z1.s}, p2, [x3, z4.s, uxtw]");
+
+        let instr = prod.st1h_scatter(ZReg::s(1), PReg::b(2), ScalarVec32(3, ZReg::s(4), SXTW, true));
+        assert_eq!(instr, "st1h {z1.s}, p2, [x3, z4.s, sxtw #0x1]");
+    }
+}
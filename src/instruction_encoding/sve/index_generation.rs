@@ -0,0 +1,143 @@
+//! # [SVE Index Generation](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/SVE-encodings?lang=en)
+//!
+//! Implements the following instructions:
+//!  - [INDEX - Create index starting from general-purpose register or immediate and incremented by general-purpose register or immediate](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/INDEX--immediates---Create-index-starting-from-and-incremented-by-immediate-?lang=en)
+
+#[allow(unused_imports)]
+use bit_seq::bseq_32;
+
+use crate::instruction_encoding::InstructionProcessor;
+#[allow(unused_imports)]
+use crate::types::sve::ZReg;
+#[allow(unused_imports)]
+use crate::types::{Imm8, Register};
+
+#[inline(always)]
+#[cfg(feature = "arm_feat_sve")]
+fn debug_assert_imm5(imm: Imm8) {
+    debug_assert!(
+        (-16..=15).contains(&imm),
+        "imm must be in range -16 to 15, was {}",
+        imm
+    );
+}
+
+/// Emits an INDEX instruction. `op` selects whether the start value (bit 0)
+/// and the step (bit 1) are registers.
+#[inline(always)]
+#[cfg(feature = "arm_feat_sve")]
+fn emit_index<P: InstructionProcessor<T>, T>(
+    proc: &mut P,
+    zd: ZReg,
+    step: u8,
+    op: u8,
+    start: u8,
+) -> T {
+    debug_assert!(zd.0 <= 31, "zd must be in range Z0 to Z31, was {}", zd.0);
+    let size: u8 = zd.1.into();
+    let zd = zd.0;
+    let i = bseq_32!(00000100 size:2 1 step:5 0100 op:2 start:5 zd:5);
+    proc.process(i)
+}
+
+/// # [SVE Index Generation](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/SVE-encodings?lang=en)
+///
+/// Implements the following instructions:
+///  - [INDEX - Create index starting from general-purpose register or immediate and incremented by general-purpose register or immediate](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/INDEX--immediates---Create-index-starting-from-and-incremented-by-immediate-?lang=en)
+pub trait SveIndexGeneration<T>: InstructionProcessor<T> {
+    /// [INDEX - Create index starting from general-purpose register or immediate and incremented by general-purpose register or immediate](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/INDEX--immediates---Create-index-starting-from-and-incremented-by-immediate-?lang=en)
+    ///
+    /// Populates each element of the destination vector with a signed immediate start value incremented by a signed immediate step. Both immediates must be in range -16 to 15.
+    ///
+    /// ```asm
+    /// INDEX <Zd>.<T>, #<imm1>, #<imm2>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_index_imm(&mut self, zd: ZReg, start: Imm8, step: Imm8) -> T {
+        debug_assert_imm5(start);
+        debug_assert_imm5(step);
+        emit_index(self, zd, step as u8, 0b00, start as u8)
+    }
+
+    /// [INDEX - Create index starting from general-purpose register or immediate and incremented by general-purpose register or immediate](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/INDEX--immediates---Create-index-starting-from-and-incremented-by-immediate-?lang=en)
+    ///
+    /// Populates each element of the destination vector with a signed immediate start value incremented by a general-purpose register. The immediate must be in range -16 to 15, the register is `W` for element sizes `B`, `H` and `S` and `X` for `D`.
+    ///
+    /// ```asm
+    /// INDEX <Zd>.<T>, #<imm>, <R><m>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_index_imm_reg(&mut self, zd: ZReg, start: Imm8, rm: Register) -> T {
+        debug_assert_imm5(start);
+        emit_index(self, zd, rm, 0b10, start as u8)
+    }
+
+    /// [INDEX - Create index starting from general-purpose register or immediate and incremented by general-purpose register or immediate](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/INDEX--immediates---Create-index-starting-from-and-incremented-by-immediate-?lang=en)
+    ///
+    /// Populates each element of the destination vector with a general-purpose register start value incremented by a signed immediate. The immediate must be in range -16 to 15, the register is `W` for element sizes `B`, `H` and `S` and `X` for `D`.
+    ///
+    /// ```asm
+    /// INDEX <Zd>.<T>, <R><n>, #<imm>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_index_reg_imm(&mut self, zd: ZReg, rn: Register, step: Imm8) -> T {
+        debug_assert_imm5(step);
+        emit_index(self, zd, step as u8, 0b01, rn)
+    }
+
+    /// [INDEX - Create index starting from general-purpose register or immediate and incremented by general-purpose register or immediate](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/INDEX--immediates---Create-index-starting-from-and-incremented-by-immediate-?lang=en)
+    ///
+    /// Populates each element of the destination vector with a general-purpose register start value incremented by a general-purpose register. The registers are `W` for element sizes `B`, `H` and `S` and `X` for `D`.
+    ///
+    /// ```asm
+    /// INDEX <Zd>.<T>, <R><n>, <R><m>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_index_reg(&mut self, zd: ZReg, rn: Register, rm: Register) -> T {
+        emit_index(self, zd, rm, 0b11, rn)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use crate::assert_panic;
+    #[allow(unused_imports)]
+    use crate::test_utils::test_producer::TestProducer;
+
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[cfg(feature = "arm_feat_sve")]
+    #[test]
+    fn test_index() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.sve_index_imm(ZReg::s(1), -1, 2);
+        assert_eq!(instr, "index z1.s, #0xffffffffffffffff, #0x2");
+
+        let instr = prod.sve_index_imm_reg(ZReg::s(1), -1, 3);
+        assert_eq!(instr, "index z1.s, #0xffffffffffffffff, w3");
+
+        let instr = prod.sve_index_reg_imm(ZReg::s(1), 2, -1);
+        assert_eq!(instr, "index z1.s, w2, #0xffffffffffffffff");
+
+        let instr = prod.sve_index_reg(ZReg::d(1), 2, 3);
+        assert_eq!(instr, "index z1.d, x2, x3");
+
+        assert_panic!("Should panic: imm out of range"; prod.sve_index_imm(ZReg::s(1), 16, 2));
+        assert_panic!("Should panic: imm out of range"; prod.sve_index_reg_imm(ZReg::s(1), 2, -17));
+    }
+}
@@ -0,0 +1,541 @@
+//! # [SVE Integer Arithmetic and Logical](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/SVE-encodings?lang=en)
+//!
+//! Implements the following instructions:
+//!  - [ADD - vectors - unpredicated - Add vectors - unpredicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ADD--vectors--unpredicated---Add-vectors--unpredicated--?lang=en)
+//!  - [SUB - vectors - unpredicated - Subtract vectors - unpredicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SUB--vectors--unpredicated---Subtract-vectors--unpredicated--?lang=en)
+//!  - [AND - vectors - unpredicated - Bitwise AND vectors - unpredicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/AND--vectors--unpredicated---Bitwise-AND-vectors--unpredicated--?lang=en)
+//!  - [ORR - vectors - unpredicated - Bitwise inclusive OR vectors - unpredicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ORR--vectors--unpredicated---Bitwise-inclusive-OR-vectors--unpredicated--?lang=en)
+//!  - [EOR - vectors - unpredicated - Bitwise exclusive OR vectors - unpredicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/EOR--vectors--unpredicated---Bitwise-exclusive-OR-vectors--unpredicated--?lang=en)
+//!  - [ADD - vectors - predicated - Add vectors - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ADD--vectors--predicated---Add-vectors--predicated--?lang=en)
+//!  - [SUB - vectors - predicated - Subtract vectors - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SUB--vectors--predicated---Subtract-vectors--predicated--?lang=en)
+//!  - [MUL - vectors - Multiply vectors - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/MUL--vectors---Multiply-vectors--predicated--?lang=en)
+//!  - [AND - vectors - predicated - Bitwise AND vectors - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/AND--vectors--predicated---Bitwise-AND-vectors--predicated--?lang=en)
+//!  - [ORR - vectors - predicated - Bitwise inclusive OR vectors - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ORR--vectors--predicated---Bitwise-inclusive-OR-vectors--predicated--?lang=en)
+//!  - [EOR - vectors - predicated - Bitwise exclusive OR vectors - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/EOR--vectors--predicated---Bitwise-exclusive-OR-vectors--predicated--?lang=en)
+//!  - [SMAX - vectors - Signed maximum vectors - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SMAX--vectors---Signed-maximum-vectors--predicated--?lang=en)
+//!  - [UMIN - vectors - Unsigned minimum vectors - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/UMIN--vectors---Unsigned-minimum-vectors--predicated--?lang=en)
+//!  - [ADD - immediate - Add immediate - unpredicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ADD--immediate---Add-immediate--unpredicated--?lang=en)
+//!  - [SUB - immediate - Subtract immediate - unpredicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SUB--immediate---Subtract-immediate--unpredicated--?lang=en)
+//!  - [MUL - immediate - Multiply by immediate - unpredicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/MUL--immediate---Multiply-by-immediate--unpredicated--?lang=en)
+//!  - [SMAX - immediate - Signed maximum with immediate - unpredicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SMAX--immediate---Signed-maximum-with-immediate--unpredicated--?lang=en)
+//!  - [UMIN - immediate - Unsigned minimum with immediate - unpredicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/UMIN--immediate---Unsigned-minimum-with-immediate--unpredicated--?lang=en)
+
+#[allow(unused_imports)]
+use bit_seq::bseq_32;
+
+#[cfg(feature = "arm_feat_sve")]
+use crate::instruction_encoding::sve::debug_assert_z_regs;
+use crate::instruction_encoding::InstructionProcessor;
+#[allow(unused_imports)]
+use crate::types::sve::{PReg, SveSize, ZReg};
+#[allow(unused_imports)]
+use crate::types::{Imm8, UImm8};
+
+#[inline(always)]
+#[cfg(feature = "arm_feat_sve")]
+fn emit_int_unpred<P: InstructionProcessor<T>, T>(
+    proc: &mut P,
+    zd: ZReg,
+    zn: ZReg,
+    zm: ZReg,
+    opc: u8,
+) -> T {
+    debug_assert_z_regs(&[zd, zn, zm]);
+    let size: u8 = zd.1.into();
+    let (zd, zn, zm) = (zd.0, zn.0, zm.0);
+    let i = bseq_32!(00000100 size:2 1 zm:5 000 opc:3 zn:5 zd:5);
+    proc.process(i)
+}
+
+#[inline(always)]
+#[cfg(feature = "arm_feat_sve")]
+fn emit_logical_unpred<P: InstructionProcessor<T>, T>(
+    proc: &mut P,
+    zd: ZReg,
+    zn: ZReg,
+    zm: ZReg,
+    opc: u8,
+) -> T {
+    debug_assert_z_regs(&[zd, zn, zm]);
+    debug_assert!(zd.1 == SveSize::D, "z registers must have element size D");
+    let (zd, zn, zm) = (zd.0, zn.0, zm.0);
+    let i = bseq_32!(00000100 opc:2 1 zm:5 001100 zn:5 zd:5);
+    proc.process(i)
+}
+
+#[inline(always)]
+#[cfg(feature = "arm_feat_sve")]
+fn emit_int_pred<P: InstructionProcessor<T>, T>(
+    proc: &mut P,
+    zdn: ZReg,
+    pg: PReg,
+    zm: ZReg,
+    opc: u8,
+) -> T {
+    debug_assert_z_regs(&[zdn, zm]);
+    debug_assert!(pg.0 <= 7, "pg must be in range P0 to P7, was {}", pg.0);
+    let size: u8 = zdn.1.into();
+    let (zdn, pg, zm) = (zdn.0, pg.0, zm.0);
+    let i = bseq_32!(00000100 size:2 opc:6 000 pg:3 zm:5 zdn:5);
+    proc.process(i)
+}
+
+#[inline(always)]
+#[cfg(feature = "arm_feat_sve")]
+fn emit_int_imm<P: InstructionProcessor<T>, T>(
+    proc: &mut P,
+    zdn: ZReg,
+    opc: u8,
+    sh: u8,
+    imm8: u8,
+) -> T {
+    debug_assert_z_regs(&[zdn]);
+    debug_assert!(
+        sh == 0 || zdn.1 != SveSize::B,
+        "shifted immediate is not allowed for element size B"
+    );
+    let size: u8 = zdn.1.into();
+    let zdn = zdn.0;
+    let i = bseq_32!(00100101 size:2 opc:6 11 sh:1 imm8:8 zdn:5);
+    proc.process(i)
+}
+
+/// # [SVE Integer Arithmetic and Logical](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/SVE-encodings?lang=en)
+///
+/// Implements the following instructions:
+///  - [ADD - vectors - unpredicated - Add vectors - unpredicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ADD--vectors--unpredicated---Add-vectors--unpredicated--?lang=en)
+///  - [SUB - vectors - unpredicated - Subtract vectors - unpredicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SUB--vectors--unpredicated---Subtract-vectors--unpredicated--?lang=en)
+///  - [AND - vectors - unpredicated - Bitwise AND vectors - unpredicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/AND--vectors--unpredicated---Bitwise-AND-vectors--unpredicated--?lang=en)
+///  - [ORR - vectors - unpredicated - Bitwise inclusive OR vectors - unpredicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ORR--vectors--unpredicated---Bitwise-inclusive-OR-vectors--unpredicated--?lang=en)
+///  - [EOR - vectors - unpredicated - Bitwise exclusive OR vectors - unpredicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/EOR--vectors--unpredicated---Bitwise-exclusive-OR-vectors--unpredicated--?lang=en)
+///  - [ADD - vectors - predicated - Add vectors - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ADD--vectors--predicated---Add-vectors--predicated--?lang=en)
+///  - [SUB - vectors - predicated - Subtract vectors - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SUB--vectors--predicated---Subtract-vectors--predicated--?lang=en)
+///  - [MUL - vectors - Multiply vectors - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/MUL--vectors---Multiply-vectors--predicated--?lang=en)
+///  - [AND - vectors - predicated - Bitwise AND vectors - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/AND--vectors--predicated---Bitwise-AND-vectors--predicated--?lang=en)
+///  - [ORR - vectors - predicated - Bitwise inclusive OR vectors - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ORR--vectors--predicated---Bitwise-inclusive-OR-vectors--predicated--?lang=en)
+///  - [EOR - vectors - predicated - Bitwise exclusive OR vectors - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/EOR--vectors--predicated---Bitwise-exclusive-OR-vectors--predicated--?lang=en)
+///  - [SMAX - vectors - Signed maximum vectors - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SMAX--vectors---Signed-maximum-vectors--predicated--?lang=en)
+///  - [UMIN - vectors - Unsigned minimum vectors - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/UMIN--vectors---Unsigned-minimum-vectors--predicated--?lang=en)
+///  - [ADD - immediate - Add immediate - unpredicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ADD--immediate---Add-immediate--unpredicated--?lang=en)
+///  - [SUB - immediate - Subtract immediate - unpredicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SUB--immediate---Subtract-immediate--unpredicated--?lang=en)
+///  - [MUL - immediate - Multiply by immediate - unpredicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/MUL--immediate---Multiply-by-immediate--unpredicated--?lang=en)
+///  - [SMAX - immediate - Signed maximum with immediate - unpredicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SMAX--immediate---Signed-maximum-with-immediate--unpredicated--?lang=en)
+///  - [UMIN - immediate - Unsigned minimum with immediate - unpredicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/UMIN--immediate---Unsigned-minimum-with-immediate--unpredicated--?lang=en)
+pub trait SveIntegerArithmetic<T>: InstructionProcessor<T> {
+    /// [ADD - vectors - unpredicated - Add vectors - unpredicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ADD--vectors--unpredicated---Add-vectors--unpredicated--?lang=en)
+    ///
+    /// Add all elements of the second source vector to corresponding elements of the first source vector and place the results in the corresponding elements of the destination vector. This instruction is unpredicated.
+    ///
+    /// ```asm
+    /// ADD <Zd>.<T>, <Zn>.<T>, <Zm>.<T>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_add(&mut self, zd: ZReg, zn: ZReg, zm: ZReg) -> T {
+        emit_int_unpred(self, zd, zn, zm, 0b000)
+    }
+
+    /// [SUB - vectors - unpredicated - Subtract vectors - unpredicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SUB--vectors--unpredicated---Subtract-vectors--unpredicated--?lang=en)
+    ///
+    /// Subtract all elements of the second source vector from corresponding elements of the first source vector and place the results in the corresponding elements of the destination vector. This instruction is unpredicated.
+    ///
+    /// ```asm
+    /// SUB <Zd>.<T>, <Zn>.<T>, <Zm>.<T>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_sub(&mut self, zd: ZReg, zn: ZReg, zm: ZReg) -> T {
+        emit_int_unpred(self, zd, zn, zm, 0b001)
+    }
+
+    /// [AND - vectors - unpredicated - Bitwise AND vectors - unpredicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/AND--vectors--unpredicated---Bitwise-AND-vectors--unpredicated--?lang=en)
+    ///
+    /// Bitwise AND of all elements of the two source vectors and place the results in the corresponding elements of the destination vector. This instruction is unpredicated.
+    ///
+    /// All registers must have element size `D`.
+    ///
+    /// ```asm
+    /// AND <Zd>.D, <Zn>.D, <Zm>.D
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_and(&mut self, zd: ZReg, zn: ZReg, zm: ZReg) -> T {
+        emit_logical_unpred(self, zd, zn, zm, 0b00)
+    }
+
+    /// [ORR - vectors - unpredicated - Bitwise inclusive OR vectors - unpredicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ORR--vectors--unpredicated---Bitwise-inclusive-OR-vectors--unpredicated--?lang=en)
+    ///
+    /// Bitwise inclusive OR of all elements of the two source vectors and place the results in the corresponding elements of the destination vector. This instruction is unpredicated.
+    ///
+    /// All registers must have element size `D`.
+    ///
+    /// ```asm
+    /// ORR <Zd>.D, <Zn>.D, <Zm>.D
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_orr(&mut self, zd: ZReg, zn: ZReg, zm: ZReg) -> T {
+        emit_logical_unpred(self, zd, zn, zm, 0b01)
+    }
+
+    /// [EOR - vectors - unpredicated - Bitwise exclusive OR vectors - unpredicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/EOR--vectors--unpredicated---Bitwise-exclusive-OR-vectors--unpredicated--?lang=en)
+    ///
+    /// Bitwise exclusive OR of all elements of the two source vectors and place the results in the corresponding elements of the destination vector. This instruction is unpredicated.
+    ///
+    /// All registers must have element size `D`.
+    ///
+    /// ```asm
+    /// EOR <Zd>.D, <Zn>.D, <Zm>.D
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_eor(&mut self, zd: ZReg, zn: ZReg, zm: ZReg) -> T {
+        emit_logical_unpred(self, zd, zn, zm, 0b10)
+    }
+
+    /// [ADD - vectors - predicated - Add vectors - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ADD--vectors--predicated---Add-vectors--predicated--?lang=en)
+    ///
+    /// Add active elements of the second source vector to corresponding elements of the first source vector and destructively place the results in the corresponding elements of the first source vector. Inactive elements in the destination vector register remain unmodified.
+    ///
+    /// `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// ADD <Zdn>.<T>, <Pg>/M, <Zdn>.<T>, <Zm>.<T>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_add_pred(&mut self, zdn: ZReg, pg: PReg, zm: ZReg) -> T {
+        emit_int_pred(self, zdn, pg, zm, 0b000000)
+    }
+
+    /// [SUB - vectors - predicated - Subtract vectors - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SUB--vectors--predicated---Subtract-vectors--predicated--?lang=en)
+    ///
+    /// Subtract active elements of the second source vector from corresponding elements of the first source vector and destructively place the results in the corresponding elements of the first source vector. Inactive elements in the destination vector register remain unmodified.
+    ///
+    /// `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// SUB <Zdn>.<T>, <Pg>/M, <Zdn>.<T>, <Zm>.<T>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_sub_pred(&mut self, zdn: ZReg, pg: PReg, zm: ZReg) -> T {
+        emit_int_pred(self, zdn, pg, zm, 0b000001)
+    }
+
+    /// [MUL - vectors - Multiply vectors - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/MUL--vectors---Multiply-vectors--predicated--?lang=en)
+    ///
+    /// Multiply active elements of the first source vector by corresponding elements of the second source vector and destructively place the results in the corresponding elements of the first source vector. Inactive elements in the destination vector register remain unmodified.
+    ///
+    /// `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// MUL <Zdn>.<T>, <Pg>/M, <Zdn>.<T>, <Zm>.<T>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_mul_pred(&mut self, zdn: ZReg, pg: PReg, zm: ZReg) -> T {
+        emit_int_pred(self, zdn, pg, zm, 0b010000)
+    }
+
+    /// [AND - vectors - predicated - Bitwise AND vectors - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/AND--vectors--predicated---Bitwise-AND-vectors--predicated--?lang=en)
+    ///
+    /// Bitwise AND active elements of the second source vector with corresponding elements of the first source vector and destructively place the results in the corresponding elements of the first source vector. Inactive elements in the destination vector register remain unmodified.
+    ///
+    /// `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// AND <Zdn>.<T>, <Pg>/M, <Zdn>.<T>, <Zm>.<T>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_and_pred(&mut self, zdn: ZReg, pg: PReg, zm: ZReg) -> T {
+        emit_int_pred(self, zdn, pg, zm, 0b011010)
+    }
+
+    /// [ORR - vectors - predicated - Bitwise inclusive OR vectors - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ORR--vectors--predicated---Bitwise-inclusive-OR-vectors--predicated--?lang=en)
+    ///
+    /// Bitwise inclusive OR active elements of the second source vector with corresponding elements of the first source vector and destructively place the results in the corresponding elements of the first source vector. Inactive elements in the destination vector register remain unmodified.
+    ///
+    /// `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// ORR <Zdn>.<T>, <Pg>/M, <Zdn>.<T>, <Zm>.<T>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_orr_pred(&mut self, zdn: ZReg, pg: PReg, zm: ZReg) -> T {
+        emit_int_pred(self, zdn, pg, zm, 0b011000)
+    }
+
+    /// [EOR - vectors - predicated - Bitwise exclusive OR vectors - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/EOR--vectors--predicated---Bitwise-exclusive-OR-vectors--predicated--?lang=en)
+    ///
+    /// Bitwise exclusive OR active elements of the second source vector with corresponding elements of the first source vector and destructively place the results in the corresponding elements of the first source vector. Inactive elements in the destination vector register remain unmodified.
+    ///
+    /// `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// EOR <Zdn>.<T>, <Pg>/M, <Zdn>.<T>, <Zm>.<T>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_eor_pred(&mut self, zdn: ZReg, pg: PReg, zm: ZReg) -> T {
+        emit_int_pred(self, zdn, pg, zm, 0b011001)
+    }
+
+    /// [SMAX - vectors - Signed maximum vectors - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SMAX--vectors---Signed-maximum-vectors--predicated--?lang=en)
+    ///
+    /// Determine the signed maximum of active elements of the second source vector and corresponding elements of the first source vector and destructively place the results in the corresponding elements of the first source vector. Inactive elements in the destination vector register remain unmodified.
+    ///
+    /// `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// SMAX <Zdn>.<T>, <Pg>/M, <Zdn>.<T>, <Zm>.<T>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_smax_pred(&mut self, zdn: ZReg, pg: PReg, zm: ZReg) -> T {
+        emit_int_pred(self, zdn, pg, zm, 0b001000)
+    }
+
+    /// [UMIN - vectors - Unsigned minimum vectors - predicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/UMIN--vectors---Unsigned-minimum-vectors--predicated--?lang=en)
+    ///
+    /// Determine the unsigned minimum of active elements of the second source vector and corresponding elements of the first source vector and destructively place the results in the corresponding elements of the first source vector. Inactive elements in the destination vector register remain unmodified.
+    ///
+    /// `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// UMIN <Zdn>.<T>, <Pg>/M, <Zdn>.<T>, <Zm>.<T>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_umin_pred(&mut self, zdn: ZReg, pg: PReg, zm: ZReg) -> T {
+        emit_int_pred(self, zdn, pg, zm, 0b001011)
+    }
+
+    /// [ADD - immediate - Add immediate - unpredicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ADD--immediate---Add-immediate--unpredicated--?lang=en)
+    ///
+    /// Add an unsigned immediate to each element of the source vector, and destructively place the results in the corresponding elements of the source vector. This instruction is unpredicated.
+    ///
+    /// ```asm
+    /// ADD <Zdn>.<T>, <Zdn>.<T>, #<imm>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_add_imm(&mut self, zdn: ZReg, imm: UImm8) -> T {
+        emit_int_imm(self, zdn, 0b100000, 0, imm)
+    }
+
+    /// [ADD - immediate - Add immediate - unpredicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ADD--immediate---Add-immediate--unpredicated--?lang=en)
+    ///
+    /// Add an unsigned immediate shifted left by 8 to each element of the source vector, and destructively place the results in the corresponding elements of the source vector. This instruction is unpredicated.
+    ///
+    /// `zdn` must not have element size `B`.
+    ///
+    /// ```asm
+    /// ADD <Zdn>.<T>, <Zdn>.<T>, #<imm>, LSL #8
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_add_imm_lsl8(&mut self, zdn: ZReg, imm: UImm8) -> T {
+        emit_int_imm(self, zdn, 0b100000, 1, imm)
+    }
+
+    /// [SUB - immediate - Subtract immediate - unpredicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SUB--immediate---Subtract-immediate--unpredicated--?lang=en)
+    ///
+    /// Subtract an unsigned immediate from each element of the source vector, and destructively place the results in the corresponding elements of the source vector. This instruction is unpredicated.
+    ///
+    /// ```asm
+    /// SUB <Zdn>.<T>, <Zdn>.<T>, #<imm>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_sub_imm(&mut self, zdn: ZReg, imm: UImm8) -> T {
+        emit_int_imm(self, zdn, 0b100001, 0, imm)
+    }
+
+    /// [SUB - immediate - Subtract immediate - unpredicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SUB--immediate---Subtract-immediate--unpredicated--?lang=en)
+    ///
+    /// Subtract an unsigned immediate shifted left by 8 from each element of the source vector, and destructively place the results in the corresponding elements of the source vector. This instruction is unpredicated.
+    ///
+    /// `zdn` must not have element size `B`.
+    ///
+    /// ```asm
+    /// SUB <Zdn>.<T>, <Zdn>.<T>, #<imm>, LSL #8
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_sub_imm_lsl8(&mut self, zdn: ZReg, imm: UImm8) -> T {
+        emit_int_imm(self, zdn, 0b100001, 1, imm)
+    }
+
+    /// [MUL - immediate - Multiply by immediate - unpredicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/MUL--immediate---Multiply-by-immediate--unpredicated--?lang=en)
+    ///
+    /// Multiply by an immediate each element of the source vector, and destructively place the results in the corresponding elements of the source vector. The immediate is a signed 8-bit value. This instruction is unpredicated.
+    ///
+    /// ```asm
+    /// MUL <Zdn>.<T>, <Zdn>.<T>, #<imm>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_mul_imm(&mut self, zdn: ZReg, imm: Imm8) -> T {
+        emit_int_imm(self, zdn, 0b110000, 0, imm as u8)
+    }
+
+    /// [SMAX - immediate - Signed maximum with immediate - unpredicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SMAX--immediate---Signed-maximum-with-immediate--unpredicated--?lang=en)
+    ///
+    /// Determine the signed maximum of an immediate and each element of the source vector, and destructively place the results in the corresponding elements of the source vector. The immediate is a signed 8-bit value. This instruction is unpredicated.
+    ///
+    /// ```asm
+    /// SMAX <Zdn>.<T>, <Zdn>.<T>, #<imm>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_smax_imm(&mut self, zdn: ZReg, imm: Imm8) -> T {
+        emit_int_imm(self, zdn, 0b101000, 0, imm as u8)
+    }
+
+    /// [UMIN - immediate - Unsigned minimum with immediate - unpredicated](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/UMIN--immediate---Unsigned-minimum-with-immediate--unpredicated--?lang=en)
+    ///
+    /// Determine the unsigned minimum of an immediate and each element of the source vector, and destructively place the results in the corresponding elements of the source vector. The immediate is an unsigned 8-bit value. This instruction is unpredicated.
+    ///
+    /// ```asm
+    /// UMIN <Zdn>.<T>, <Zdn>.<T>, #<imm>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn sve_umin_imm(&mut self, zdn: ZReg, imm: UImm8) -> T {
+        emit_int_imm(self, zdn, 0b101011, 0, imm)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use crate::assert_panic;
+    #[allow(unused_imports)]
+    use crate::test_utils::test_producer::TestProducer;
+
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[cfg(feature = "arm_feat_sve")]
+    #[test]
+    fn test_sve_int_unpredicated() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.sve_add(ZReg::s(1), ZReg::s(2), ZReg::s(3));
+        assert_eq!(instr, "add z1.s, z2.s, z3.s");
+
+        let instr = prod.sve_sub(ZReg::s(1), ZReg::s(2), ZReg::s(3));
+        assert_eq!(instr, "sub z1.s, z2.s, z3.s");
+
+        let instr = prod.sve_and(ZReg::d(1), ZReg::d(2), ZReg::d(3));
+        assert_eq!(instr, "and z1.d, z2.d, z3.d");
+
+        let instr = prod.sve_orr(ZReg::d(1), ZReg::d(2), ZReg::d(3));
+        assert_eq!(instr, "orr z1.d, z2.d, z3.d");
+
+        let instr = prod.sve_eor(ZReg::d(1), ZReg::d(2), ZReg::d(3));
+        assert_eq!(instr, "eor z1.d, z2.d, z3.d");
+
+        assert_panic!("Should panic: mixed element sizes"; prod.sve_add(ZReg::s(1), ZReg::d(2), ZReg::s(3)));
+        assert_panic!("Should panic: logical requires D"; prod.sve_and(ZReg::s(1), ZReg::s(2), ZReg::s(3)));
+    }
+
+    #[cfg(feature = "arm_feat_sve")]
+    #[test]
+    fn test_sve_int_predicated() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.sve_add_pred(ZReg::h(1), PReg::b(2), ZReg::h(3));
+        assert_eq!(instr, "add z1.h, p2/m, z1.h, z3.h");
+
+        let instr = prod.sve_sub_pred(ZReg::h(1), PReg::b(2), ZReg::h(3));
+        assert_eq!(instr, "sub z1.h, p2/m, z1.h, z3.h");
+
+        let instr = prod.sve_mul_pred(ZReg::h(1), PReg::b(2), ZReg::h(3));
+        assert_eq!(instr, "mul z1.h, p2/m, z1.h, z3.h");
+
+        let instr = prod.sve_orr_pred(ZReg::d(1), PReg::b(2), ZReg::d(3));
+        assert_eq!(instr, "orr z1.d, p2/m, z1.d, z3.d");
+
+        let instr = prod.sve_eor_pred(ZReg::d(1), PReg::b(2), ZReg::d(3));
+        assert_eq!(instr, "eor z1.d, p2/m, z1.d, z3.d");
+
+        let instr = prod.sve_and_pred(ZReg::d(1), PReg::b(2), ZReg::d(3));
+        assert_eq!(instr, "and z1.d, p2/m, z1.d, z3.d");
+
+        let instr = prod.sve_smax_pred(ZReg::s(1), PReg::b(2), ZReg::s(3));
+        assert_eq!(instr, "smax z1.s, p2/m, z1.s, z3.s");
+
+        let instr = prod.sve_umin_pred(ZReg::s(1), PReg::b(2), ZReg::s(3));
+        assert_eq!(instr, "umin z1.s, p2/m, z1.s, z3.s");
+
+        assert_panic!("Should panic: pg out of range"; prod.sve_add_pred(ZReg::h(1), PReg::b(8), ZReg::h(3)));
+    }
+
+    #[cfg(feature = "arm_feat_sve")]
+    #[test]
+    fn test_sve_int_immediate() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.sve_add_imm(ZReg::s(1), 5);
+        assert_eq!(instr, "add z1.s, z1.s, #0x5");
+
+        let instr = prod.sve_sub_imm_lsl8(ZReg::s(1), 5);
+        assert_eq!(instr, "sub z1.s, z1.s, #0x500");
+
+        let instr = prod.sve_mul_imm(ZReg::s(1), -5);
+        assert_eq!(instr, "mul z1.s, z1.s, #0xfffffffffffffffb");
+
+        // bad64 prints the signed immediate of smax without sign extension
+        let instr = prod.sve_smax_imm(ZReg::s(1), -5);
+        assert_eq!(instr, "smax z1.s, z1.s, #0xfb");
+
+        let instr = prod.sve_umin_imm(ZReg::s(1), 0xfb);
+        assert_eq!(instr, "umin z1.s, z1.s, #0xfb");
+
+        assert_panic!("Should panic: shifted immediate for B"; prod.sve_add_imm_lsl8(ZReg::b(1), 5));
+    }
+}
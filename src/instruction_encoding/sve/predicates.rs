@@ -0,0 +1,203 @@
+//! # [SVE Predicate Generation and Test](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/SVE-encodings?lang=en)
+//!
+//! Implements the following instructions:
+//!  - [PTRUE - Initialise predicate from named constraint](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/PTRUE--Initialise-predicate-from-named-constraint-?lang=en)
+//!  - [PFALSE - Set all predicate elements to false](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/PFALSE--Set-all-predicate-elements-to-false-?lang=en)
+//!  - [PTEST - Set condition flags for predicate](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/PTEST--Set-condition-flags-for-predicate-?lang=en)
+//!  - [WHILELT - While incrementing signed scalar less than scalar](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/WHILELT--While-incrementing-signed-scalar-less-than-scalar-?lang=en)
+//!  - [WHILELO - While incrementing unsigned scalar lower than scalar](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/WHILELO--While-incrementing-unsigned-scalar-lower-than-scalar-?lang=en)
+
+#[allow(unused_imports)]
+use bit_seq::bseq_32;
+
+use crate::instruction_encoding::InstructionProcessor;
+#[allow(unused_imports)]
+use crate::types::sve::{PReg, SvePattern, SveSize};
+#[allow(unused_imports)]
+use crate::types::Register;
+
+#[inline(always)]
+#[cfg(feature = "arm_feat_sve")]
+fn emit_while<P: InstructionProcessor<T>, T>(
+    proc: &mut P,
+    pd: PReg,
+    sf: u8,
+    u: u8,
+    rn: Register,
+    rm: Register,
+) -> T {
+    debug_assert!(pd.0 <= 15, "pd must be in range P0 to P15, was {}", pd.0);
+    let size: u8 = pd.1.into();
+    let pd = pd.0;
+    let i = bseq_32!(00100101 size:2 1 rm:5 000 sf:1 u:1 1 rn:5 0 pd:4);
+    proc.process(i)
+}
+
+/// # [SVE Predicate Generation and Test](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/SVE-encodings?lang=en)
+///
+/// Implements the following instructions:
+///  - [PTRUE - Initialise predicate from named constraint](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/PTRUE--Initialise-predicate-from-named-constraint-?lang=en)
+///  - [PFALSE - Set all predicate elements to false](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/PFALSE--Set-all-predicate-elements-to-false-?lang=en)
+///  - [PTEST - Set condition flags for predicate](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/PTEST--Set-condition-flags-for-predicate-?lang=en)
+///  - [WHILELT - While incrementing signed scalar less than scalar](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/WHILELT--While-incrementing-signed-scalar-less-than-scalar-?lang=en)
+///  - [WHILELO - While incrementing unsigned scalar lower than scalar](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/WHILELO--While-incrementing-unsigned-scalar-lower-than-scalar-?lang=en)
+pub trait SvePredicates<T>: InstructionProcessor<T> {
+    /// [PTRUE - Initialise predicate from named constraint](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/PTRUE--Initialise-predicate-from-named-constraint-?lang=en)
+    ///
+    /// Initialise predicate from named constraint. Set elements of the destination predicate to true if the element number satisfies the named predicate constraint `pattern`, or to false otherwise.
+    ///
+    /// ```asm
+    /// PTRUE <Pd>.<T>{, <pattern>}
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn ptrue(&mut self, pd: PReg, pattern: SvePattern) -> T {
+        debug_assert!(pd.0 <= 15, "pd must be in range P0 to P15, was {}", pd.0);
+        let size: u8 = pd.1.into();
+        let pattern: u8 = pattern.into();
+        let pd = pd.0;
+        self.process(bseq_32!(00100101 size:2 011000 111000 pattern:5 0 pd:4))
+    }
+
+    /// [PFALSE - Set all predicate elements to false](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/PFALSE--Set-all-predicate-elements-to-false-?lang=en)
+    ///
+    /// Set all elements in the destination predicate to false.
+    ///
+    /// ```asm
+    /// PFALSE <Pd>.B
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn pfalse(&mut self, pd: PReg) -> T {
+        debug_assert!(pd.0 <= 15, "pd must be in range P0 to P15, was {}", pd.0);
+        debug_assert!(pd.1 == SveSize::B, "pd must have element size B");
+        let pd = pd.0;
+        self.process(bseq_32!(00100101 00011000 11100100 0000 pd:4))
+    }
+
+    /// [PTEST - Set condition flags for predicate](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/PTEST--Set-condition-flags-for-predicate-?lang=en)
+    ///
+    /// Sets the First (N), None (Z), !Last (C) condition flags based on the predicate source register, and the V flag to zero.
+    ///
+    /// ```asm
+    /// PTEST <Pg>, <Pn>.B
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn ptest(&mut self, pg: PReg, pn: PReg) -> T {
+        debug_assert!(pg.0 <= 15, "pg must be in range P0 to P15, was {}", pg.0);
+        debug_assert!(pn.0 <= 15, "pn must be in range P0 to P15, was {}", pn.0);
+        debug_assert!(pn.1 == SveSize::B, "pn must have element size B");
+        let (pg, pn) = (pg.0, pn.0);
+        self.process(bseq_32!(00100101 01010000 11 pg:4 0 pn:4 00000))
+    }
+
+    /// [WHILELT - While incrementing signed scalar less than scalar](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/WHILELT--While-incrementing-signed-scalar-less-than-scalar-?lang=en)
+    ///
+    /// Generate a predicate that starting from the lowest numbered element is true while the incrementing value of the first, signed scalar operand is less than the second scalar operand and false thereafter up to the highest numbered element.
+    ///
+    /// ```asm
+    /// WHILELT <Pd>.<T>, <Wn>, <Wm>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn whilelt_32(&mut self, pd: PReg, wn: Register, wm: Register) -> T {
+        emit_while(self, pd, 0, 0, wn, wm)
+    }
+
+    /// [WHILELT - While incrementing signed scalar less than scalar](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/WHILELT--While-incrementing-signed-scalar-less-than-scalar-?lang=en)
+    ///
+    /// Generate a predicate that starting from the lowest numbered element is true while the incrementing value of the first, signed scalar operand is less than the second scalar operand and false thereafter up to the highest numbered element.
+    ///
+    /// ```asm
+    /// WHILELT <Pd>.<T>, <Xn>, <Xm>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn whilelt_64(&mut self, pd: PReg, xn: Register, xm: Register) -> T {
+        emit_while(self, pd, 1, 0, xn, xm)
+    }
+
+    /// [WHILELO - While incrementing unsigned scalar lower than scalar](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/WHILELO--While-incrementing-unsigned-scalar-lower-than-scalar-?lang=en)
+    ///
+    /// Generate a predicate that starting from the lowest numbered element is true while the incrementing value of the first, unsigned scalar operand is lower than the second scalar operand and false thereafter up to the highest numbered element.
+    ///
+    /// ```asm
+    /// WHILELO <Pd>.<T>, <Wn>, <Wm>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn whilelo_32(&mut self, pd: PReg, wn: Register, wm: Register) -> T {
+        emit_while(self, pd, 0, 1, wn, wm)
+    }
+
+    /// [WHILELO - While incrementing unsigned scalar lower than scalar](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/WHILELO--While-incrementing-unsigned-scalar-lower-than-scalar-?lang=en)
+    ///
+    /// Generate a predicate that starting from the lowest numbered element is true while the incrementing value of the first, unsigned scalar operand is lower than the second scalar operand and false thereafter up to the highest numbered element.
+    ///
+    /// ```asm
+    /// WHILELO <Pd>.<T>, <Xn>, <Xm>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve")]
+    fn whilelo_64(&mut self, pd: PReg, xn: Register, xm: Register) -> T {
+        emit_while(self, pd, 1, 1, xn, xm)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use crate::assert_panic;
+    #[allow(unused_imports)]
+    use crate::test_utils::test_producer::TestProducer;
+
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[cfg(feature = "arm_feat_sve")]
+    #[test]
+    fn test_ptrue_pfalse() {
+        let mut prod = TestProducer::new();
+
+        assert_eq!(prod.ptrue(PReg::s(1), SvePattern::ALL), "ptrue p1.s");
+        assert_eq!(prod.ptrue(PReg::b(1), SvePattern::VL1), "ptrue p1.b, vl1");
+        assert_eq!(prod.pfalse(PReg::b(3)), "pfalse p3.b");
+
+        assert_panic!("Should panic: register out of range"; prod.ptrue(PReg::b(16), SvePattern::ALL));
+        assert_panic!("Should panic: wrong element size"; prod.pfalse(PReg::s(3)));
+    }
+
+    #[cfg(feature = "arm_feat_sve")]
+    #[test]
+    fn test_ptest() {
+        let mut prod = TestProducer::new();
+
+        assert_eq!(prod.ptest(PReg::b(2), PReg::b(3)), "ptest p2, p3.b");
+    }
+
+    #[cfg(feature = "arm_feat_sve")]
+    #[test]
+    fn test_while() {
+        let mut prod = TestProducer::new();
+
+        assert_eq!(prod.whilelt_32(PReg::s(1), 2, 3), "whilelt p1.s, w2, w3");
+        assert_eq!(prod.whilelt_64(PReg::b(1), 2, 3), "whilelt p1.b, x2, x3");
+        assert_eq!(prod.whilelo_32(PReg::h(1), 2, 3), "whilelo p1.h, w2, w3");
+        assert_eq!(prod.whilelo_64(PReg::d(1), 2, 3), "whilelo p1.d, x2, x3");
+    }
+}
@@ -0,0 +1,181 @@
+//! # [SVE2 Character Match, Histogram and Table Lookup](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/SVE-encodings?lang=en)
+//!
+//! Implements the following instructions:
+//!  - [MATCH - Detect any matching elements setting the condition flags](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/MATCH--Detect-any-matching-elements--setting-the-condition-flags-?lang=en)
+//!  - [NMATCH - Detect no matching elements setting the condition flags](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/NMATCH--Detect-no-matching-elements--setting-the-condition-flags-?lang=en)
+//!  - [HISTCNT - Count matching elements in vector](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/HISTCNT--Count-matching-elements-in-vector-?lang=en)
+//!  - [TBL - Programmable table lookup in one or two vector table - zeroing](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/TBL--Programmable-table-lookup-in-one-or-two-vector-table--zeroing--?lang=en)
+
+#[allow(unused_imports)]
+use bit_seq::bseq_32;
+
+#[cfg(feature = "arm_feat_sve2")]
+use crate::instruction_encoding::sve::debug_assert_z_regs;
+use crate::instruction_encoding::InstructionProcessor;
+#[allow(unused_imports)]
+use crate::types::sve::{PReg, SveSize, ZReg};
+
+#[inline(always)]
+#[cfg(feature = "arm_feat_sve2")]
+fn emit_char_match<P: InstructionProcessor<T>, T>(
+    proc: &mut P,
+    pd: PReg,
+    pg: PReg,
+    zn: ZReg,
+    zm: ZReg,
+    op: u8,
+) -> T {
+    debug_assert_z_regs(&[zn, zm]);
+    debug_assert!(
+        zn.1 == SveSize::B || zn.1 == SveSize::H,
+        "z registers must have element size B or H"
+    );
+    debug_assert!(pd.0 <= 15, "pd must be in range P0 to P15, was {}", pd.0);
+    debug_assert!(pd.1 == zn.1, "pd must have the element size of zn");
+    debug_assert!(pg.0 <= 7, "pg must be in range P0 to P7, was {}", pg.0);
+    let size: u8 = zn.1.into();
+    let (pd, pg, zn, zm) = (pd.0, pg.0, zn.0, zm.0);
+    let i = bseq_32!(01000101 size:2 1 zm:5 100 pg:3 zn:5 op:1 pd:4);
+    proc.process(i)
+}
+
+/// # [SVE2 Character Match, Histogram and Table Lookup](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/SVE-encodings?lang=en)
+///
+/// Implements the following instructions:
+///  - [MATCH - Detect any matching elements setting the condition flags](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/MATCH--Detect-any-matching-elements--setting-the-condition-flags-?lang=en)
+///  - [NMATCH - Detect no matching elements setting the condition flags](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/NMATCH--Detect-no-matching-elements--setting-the-condition-flags-?lang=en)
+///  - [HISTCNT - Count matching elements in vector](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/HISTCNT--Count-matching-elements-in-vector-?lang=en)
+///  - [TBL - Programmable table lookup in one or two vector table - zeroing](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/TBL--Programmable-table-lookup-in-one-or-two-vector-table--zeroing--?lang=en)
+pub trait Sve2MatchAndLookup<T>: InstructionProcessor<T> {
+    /// [MATCH - Detect any matching elements setting the condition flags](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/MATCH--Detect-any-matching-elements--setting-the-condition-flags-?lang=en)
+    ///
+    /// This instruction compares each active 8-bit or 16-bit character in the first source vector with all of the characters in the corresponding 128-bit segment of the second source vector. Where the first source element detects any matching characters in the second segment it places true in the corresponding element of the destination predicate, otherwise false. Inactive elements in the destination predicate register are set to zero. Sets the `N`, `Z`, `C` and `V` condition flags based on the predicate result.
+    ///
+    /// `zn` and `zm` must have the same element size `B` or `H`, `pd` must have the same element size and `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// MATCH <Pd>.<T>, <Pg>/Z, <Zn>.<T>, <Zm>.<T>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE2 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve2")]
+    fn sve_match(&mut self, pd: PReg, pg: PReg, zn: ZReg, zm: ZReg) -> T {
+        emit_char_match(self, pd, pg, zn, zm, 0)
+    }
+
+    /// [NMATCH - Detect no matching elements setting the condition flags](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/NMATCH--Detect-no-matching-elements--setting-the-condition-flags-?lang=en)
+    ///
+    /// This instruction compares each active 8-bit or 16-bit character in the first source vector with all of the characters in the corresponding 128-bit segment of the second source vector. Where the first source element detects no matching characters in the second segment it places true in the corresponding element of the destination predicate, otherwise false. Inactive elements in the destination predicate register are set to zero. Sets the `N`, `Z`, `C` and `V` condition flags based on the predicate result.
+    ///
+    /// `zn` and `zm` must have the same element size `B` or `H`, `pd` must have the same element size and `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// NMATCH <Pd>.<T>, <Pg>/Z, <Zn>.<T>, <Zm>.<T>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE2 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve2")]
+    fn sve_nmatch(&mut self, pd: PReg, pg: PReg, zn: ZReg, zm: ZReg) -> T {
+        emit_char_match(self, pd, pg, zn, zm, 1)
+    }
+
+    /// [HISTCNT - Count matching elements in vector](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/HISTCNT--Count-matching-elements-in-vector-?lang=en)
+    ///
+    /// This instruction compares each active 32 or 64-bit element of the first source vector with all active elements with an element number less than or equal to its own in the second source vector, and places the count of matching elements in the corresponding element of the destination vector. Inactive elements in the destination vector are set to zero.
+    ///
+    /// All registers must have the same element size `S` or `D` and `pg` must be in range P0 to P7.
+    ///
+    /// ```asm
+    /// HISTCNT <Zd>.<T>, <Pg>/Z, <Zn>.<T>, <Zm>.<T>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE2 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve2")]
+    fn histcnt(&mut self, zd: ZReg, pg: PReg, zn: ZReg, zm: ZReg) -> T {
+        debug_assert_z_regs(&[zd, zn, zm]);
+        debug_assert!(
+            zd.1 == SveSize::S || zd.1 == SveSize::D,
+            "z registers must have element size S or D"
+        );
+        debug_assert!(pg.0 <= 7, "pg must be in range P0 to P7, was {}", pg.0);
+        let size: u8 = zd.1.into();
+        let (zd, pg, zn, zm) = (zd.0, pg.0, zn.0, zm.0);
+        let i = bseq_32!(01000101 size:2 1 zm:5 110 pg:3 zn:5 zd:5);
+        self.process(i)
+    }
+
+    /// [TBL - Programmable table lookup in one or two vector table - zeroing](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/TBL--Programmable-table-lookup-in-one-or-two-vector-table--zeroing--?lang=en)
+    ///
+    /// Reads each element of the index vector `zm` and uses its value to select an element from the table formed by the two consecutive vector registers `zn` and `zn+1`, placing the selected element in the corresponding element of the destination vector. Out of range indices select zero.
+    ///
+    /// All registers must have the same element size and `zn` must be in range Z0 to Z30.
+    ///
+    /// ```asm
+    /// TBL <Zd>.<T>, { <Zn1>.<T>, <Zn2>.<T> }, <Zm>.<T>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE2 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve2")]
+    fn sve_tbl2(&mut self, zd: ZReg, zn: ZReg, zm: ZReg) -> T {
+        debug_assert_z_regs(&[zd, zn, zm]);
+        debug_assert!(zn.0 <= 30, "zn must be in range Z0 to Z30, was {}", zn.0);
+        let size: u8 = zd.1.into();
+        let (zd, zn, zm) = (zd.0, zn.0, zm.0);
+        let i = bseq_32!(00000101 size:2 1 zm:5 001010 zn:5 zd:5);
+        self.process(i)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use crate::assert_panic;
+    #[allow(unused_imports)]
+    use crate::test_utils::test_producer::TestProducer;
+
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[cfg(feature = "arm_feat_sve2")]
+    #[test]
+    fn test_sve2_match() {
+        let mut prod = TestProducer::new();
+
+        // bad64 prints the z register operands of MATCH and NMATCH as p registers
+        let instr = prod.sve_match(PReg::b(1), PReg::b(2), ZReg::b(4), ZReg::b(3));
+        assert_eq!(instr, "match p1.b, p2/z, p4.b, p3.b");
+
+        let instr = prod.sve_nmatch(PReg::b(1), PReg::b(2), ZReg::b(4), ZReg::b(3));
+        assert_eq!(instr, "nmatch p1.b, p2/z, p4.b, p3.b");
+
+        assert_panic!("Should panic: element size S"; prod.sve_match(PReg::s(1), PReg::b(2), ZReg::s(4), ZReg::s(3)));
+
+        assert_panic!("Should panic: pd element size mismatch"; prod.sve_nmatch(PReg::h(1), PReg::b(2), ZReg::b(4), ZReg::b(3)));
+    }
+
+    #[cfg(feature = "arm_feat_sve2")]
+    #[test]
+    fn test_sve2_histcnt() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.histcnt(ZReg::s(1), PReg::b(2), ZReg::s(4), ZReg::s(3));
+        assert_eq!(instr, "histcnt z1.s, p2/z, z4.s, z3.s");
+
+        assert_panic!("Should panic: element size H"; prod.histcnt(ZReg::h(1), PReg::b(2), ZReg::h(4), ZReg::h(3)));
+    }
+
+    #[cfg(feature = "arm_feat_sve2")]
+    #[test]
+    fn test_sve2_tbl() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.sve_tbl2(ZReg::s(1), ZReg::s(4), ZReg::s(3));
+        assert_eq!(instr, "tbl z1.s, {z4.s, z5.s}, z3.s");
+
+        assert_panic!("Should panic: zn out of range"; prod.sve_tbl2(ZReg::s(1), ZReg::s(31), ZReg::s(3)));
+    }
+}
@@ -0,0 +1,658 @@
+//! # [SVE2 Integer Add/Subtract - Long, Wide and Narrow](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/SVE-encodings?lang=en)
+//!
+//! Implements the following instructions:
+//!  - [SADDLB - Signed add long - bottom](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SADDLB--Signed-add-long--bottom--?lang=en)
+//!  - [SADDLT - Signed add long - top](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SADDLT--Signed-add-long--top--?lang=en)
+//!  - [UADDLB - Unsigned add long - bottom](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/UADDLB--Unsigned-add-long--bottom--?lang=en)
+//!  - [UADDLT - Unsigned add long - top](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/UADDLT--Unsigned-add-long--top--?lang=en)
+//!  - [SSUBLB - Signed subtract long - bottom](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SSUBLB--Signed-subtract-long--bottom--?lang=en)
+//!  - [SSUBLT - Signed subtract long - top](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SSUBLT--Signed-subtract-long--top--?lang=en)
+//!  - [USUBLB - Unsigned subtract long - bottom](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/USUBLB--Unsigned-subtract-long--bottom--?lang=en)
+//!  - [USUBLT - Unsigned subtract long - top](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/USUBLT--Unsigned-subtract-long--top--?lang=en)
+//!  - [SADDWB - Signed add wide - bottom](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SADDWB--Signed-add-wide--bottom--?lang=en)
+//!  - [SADDWT - Signed add wide - top](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SADDWT--Signed-add-wide--top--?lang=en)
+//!  - [UADDWB - Unsigned add wide - bottom](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/UADDWB--Unsigned-add-wide--bottom--?lang=en)
+//!  - [UADDWT - Unsigned add wide - top](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/UADDWT--Unsigned-add-wide--top--?lang=en)
+//!  - [SSUBWB - Signed subtract wide - bottom](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SSUBWB--Signed-subtract-wide--bottom--?lang=en)
+//!  - [SSUBWT - Signed subtract wide - top](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SSUBWT--Signed-subtract-wide--top--?lang=en)
+//!  - [USUBWB - Unsigned subtract wide - bottom](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/USUBWB--Unsigned-subtract-wide--bottom--?lang=en)
+//!  - [USUBWT - Unsigned subtract wide - top](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/USUBWT--Unsigned-subtract-wide--top--?lang=en)
+//!  - [ADDHNB - Add narrow high part - bottom](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ADDHNB--Add-narrow-high-part--bottom--?lang=en)
+//!  - [ADDHNT - Add narrow high part - top](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ADDHNT--Add-narrow-high-part--top--?lang=en)
+//!  - [RADDHNB - Rounding add narrow high part - bottom](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/RADDHNB--Rounding-add-narrow-high-part--bottom--?lang=en)
+//!  - [RADDHNT - Rounding add narrow high part - top](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/RADDHNT--Rounding-add-narrow-high-part--top--?lang=en)
+//!  - [SUBHNB - Subtract narrow high part - bottom](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SUBHNB--Subtract-narrow-high-part--bottom--?lang=en)
+//!  - [SUBHNT - Subtract narrow high part - top](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SUBHNT--Subtract-narrow-high-part--top--?lang=en)
+//!  - [RSUBHNB - Rounding subtract narrow high part - bottom](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/RSUBHNB--Rounding-subtract-narrow-high-part--bottom--?lang=en)
+//!  - [RSUBHNT - Rounding subtract narrow high part - top](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/RSUBHNT--Rounding-subtract-narrow-high-part--top--?lang=en)
+
+#[allow(unused_imports)]
+use bit_seq::bseq_32;
+
+use crate::instruction_encoding::InstructionProcessor;
+#[allow(unused_imports)]
+use crate::types::sve::{SveSize, ZReg};
+
+#[inline(always)]
+#[cfg(feature = "arm_feat_sve2")]
+fn debug_assert_z_reg_nums(regs: &[ZReg]) {
+    for z in regs {
+        debug_assert!(z.0 <= 31, "z register must be in range Z0 to Z31, was {}", z.0);
+    }
+}
+
+#[inline(always)]
+#[cfg(feature = "arm_feat_sve2")]
+fn emit_add_sub_long<P: InstructionProcessor<T>, T>(
+    proc: &mut P,
+    zd: ZReg,
+    zn: ZReg,
+    zm: ZReg,
+    sut: u8,
+) -> T {
+    debug_assert_z_reg_nums(&[zd, zn, zm]);
+    debug_assert!(zd.1 != SveSize::B, "zd must have element size H, S or D");
+    debug_assert!(
+        u8::from(zn.1) + 1 == u8::from(zd.1) && zm.1 == zn.1,
+        "zn and zm must have half the element size of zd"
+    );
+    let size: u8 = zd.1.into();
+    let (zd, zn, zm) = (zd.0, zn.0, zm.0);
+    let i = bseq_32!(01000101 size:2 0 zm:5 000 sut:3 zn:5 zd:5);
+    proc.process(i)
+}
+
+#[inline(always)]
+#[cfg(feature = "arm_feat_sve2")]
+fn emit_add_sub_wide<P: InstructionProcessor<T>, T>(
+    proc: &mut P,
+    zd: ZReg,
+    zn: ZReg,
+    zm: ZReg,
+    sut: u8,
+) -> T {
+    debug_assert_z_reg_nums(&[zd, zn, zm]);
+    debug_assert!(zd.1 != SveSize::B, "zd must have element size H, S or D");
+    debug_assert!(zn.1 == zd.1, "zn must have the element size of zd");
+    debug_assert!(
+        u8::from(zm.1) + 1 == u8::from(zd.1),
+        "zm must have half the element size of zd"
+    );
+    let size: u8 = zd.1.into();
+    let (zd, zn, zm) = (zd.0, zn.0, zm.0);
+    let i = bseq_32!(01000101 size:2 0 zm:5 010 sut:3 zn:5 zd:5);
+    proc.process(i)
+}
+
+#[inline(always)]
+#[cfg(feature = "arm_feat_sve2")]
+fn emit_add_sub_narrow<P: InstructionProcessor<T>, T>(
+    proc: &mut P,
+    zd: ZReg,
+    zn: ZReg,
+    zm: ZReg,
+    srt: u8,
+) -> T {
+    debug_assert_z_reg_nums(&[zd, zn, zm]);
+    debug_assert!(zd.1 != SveSize::D, "zd must have element size B, H or S");
+    debug_assert!(
+        u8::from(zd.1) + 1 == u8::from(zn.1) && zm.1 == zn.1,
+        "zn and zm must have twice the element size of zd"
+    );
+    let size: u8 = zn.1.into();
+    let (zd, zn, zm) = (zd.0, zn.0, zm.0);
+    let i = bseq_32!(01000101 size:2 1 zm:5 011 srt:3 zn:5 zd:5);
+    proc.process(i)
+}
+
+/// # [SVE2 Integer Add/Subtract - Long, Wide and Narrow](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/SVE-encodings?lang=en)
+///
+/// Implements the following instructions:
+///  - [SADDLB - Signed add long - bottom](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SADDLB--Signed-add-long--bottom--?lang=en)
+///  - [SADDLT - Signed add long - top](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SADDLT--Signed-add-long--top--?lang=en)
+///  - [UADDLB - Unsigned add long - bottom](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/UADDLB--Unsigned-add-long--bottom--?lang=en)
+///  - [UADDLT - Unsigned add long - top](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/UADDLT--Unsigned-add-long--top--?lang=en)
+///  - [SSUBLB - Signed subtract long - bottom](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SSUBLB--Signed-subtract-long--bottom--?lang=en)
+///  - [SSUBLT - Signed subtract long - top](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SSUBLT--Signed-subtract-long--top--?lang=en)
+///  - [USUBLB - Unsigned subtract long - bottom](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/USUBLB--Unsigned-subtract-long--bottom--?lang=en)
+///  - [USUBLT - Unsigned subtract long - top](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/USUBLT--Unsigned-subtract-long--top--?lang=en)
+///  - [SADDWB - Signed add wide - bottom](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SADDWB--Signed-add-wide--bottom--?lang=en)
+///  - [SADDWT - Signed add wide - top](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SADDWT--Signed-add-wide--top--?lang=en)
+///  - [UADDWB - Unsigned add wide - bottom](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/UADDWB--Unsigned-add-wide--bottom--?lang=en)
+///  - [UADDWT - Unsigned add wide - top](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/UADDWT--Unsigned-add-wide--top--?lang=en)
+///  - [SSUBWB - Signed subtract wide - bottom](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SSUBWB--Signed-subtract-wide--bottom--?lang=en)
+///  - [SSUBWT - Signed subtract wide - top](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SSUBWT--Signed-subtract-wide--top--?lang=en)
+///  - [USUBWB - Unsigned subtract wide - bottom](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/USUBWB--Unsigned-subtract-wide--bottom--?lang=en)
+///  - [USUBWT - Unsigned subtract wide - top](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/USUBWT--Unsigned-subtract-wide--top--?lang=en)
+///  - [ADDHNB - Add narrow high part - bottom](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ADDHNB--Add-narrow-high-part--bottom--?lang=en)
+///  - [ADDHNT - Add narrow high part - top](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ADDHNT--Add-narrow-high-part--top--?lang=en)
+///  - [RADDHNB - Rounding add narrow high part - bottom](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/RADDHNB--Rounding-add-narrow-high-part--bottom--?lang=en)
+///  - [RADDHNT - Rounding add narrow high part - top](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/RADDHNT--Rounding-add-narrow-high-part--top--?lang=en)
+///  - [SUBHNB - Subtract narrow high part - bottom](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SUBHNB--Subtract-narrow-high-part--bottom--?lang=en)
+///  - [SUBHNT - Subtract narrow high part - top](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SUBHNT--Subtract-narrow-high-part--top--?lang=en)
+///  - [RSUBHNB - Rounding subtract narrow high part - bottom](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/RSUBHNB--Rounding-subtract-narrow-high-part--bottom--?lang=en)
+///  - [RSUBHNT - Rounding subtract narrow high part - top](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/RSUBHNT--Rounding-subtract-narrow-high-part--top--?lang=en)
+pub trait Sve2WideningNarrowing<T>: InstructionProcessor<T> {
+    /// [SADDLB - Signed add long - bottom](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SADDLB--Signed-add-long--bottom--?lang=en)
+    ///
+    /// Add the corresponding even-numbered signed elements of the first and second source vectors. The results are placed in the overlapping double-width elements of the destination vector.
+    ///
+    /// `zd` must have element size `H`, `S` or `D`, and `zn` and `zm` must have half of that element size.
+    ///
+    /// ```asm
+    /// SADDLB <Zd>.<T>, <Zn>.<Tb>, <Zm>.<Tb>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE2 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve2")]
+    fn saddlb(&mut self, zd: ZReg, zn: ZReg, zm: ZReg) -> T {
+        emit_add_sub_long(self, zd, zn, zm, 0b000)
+    }
+
+    /// [SADDLT - Signed add long - top](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SADDLT--Signed-add-long--top--?lang=en)
+    ///
+    /// Add the corresponding odd-numbered signed elements of the first and second source vectors. The results are placed in the overlapping double-width elements of the destination vector.
+    ///
+    /// `zd` must have element size `H`, `S` or `D`, and `zn` and `zm` must have half of that element size.
+    ///
+    /// ```asm
+    /// SADDLT <Zd>.<T>, <Zn>.<Tb>, <Zm>.<Tb>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE2 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve2")]
+    fn saddlt(&mut self, zd: ZReg, zn: ZReg, zm: ZReg) -> T {
+        emit_add_sub_long(self, zd, zn, zm, 0b001)
+    }
+
+    /// [UADDLB - Unsigned add long - bottom](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/UADDLB--Unsigned-add-long--bottom--?lang=en)
+    ///
+    /// Add the corresponding even-numbered unsigned elements of the first and second source vectors. The results are placed in the overlapping double-width elements of the destination vector.
+    ///
+    /// `zd` must have element size `H`, `S` or `D`, and `zn` and `zm` must have half of that element size.
+    ///
+    /// ```asm
+    /// UADDLB <Zd>.<T>, <Zn>.<Tb>, <Zm>.<Tb>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE2 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve2")]
+    fn uaddlb(&mut self, zd: ZReg, zn: ZReg, zm: ZReg) -> T {
+        emit_add_sub_long(self, zd, zn, zm, 0b010)
+    }
+
+    /// [UADDLT - Unsigned add long - top](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/UADDLT--Unsigned-add-long--top--?lang=en)
+    ///
+    /// Add the corresponding odd-numbered unsigned elements of the first and second source vectors. The results are placed in the overlapping double-width elements of the destination vector.
+    ///
+    /// `zd` must have element size `H`, `S` or `D`, and `zn` and `zm` must have half of that element size.
+    ///
+    /// ```asm
+    /// UADDLT <Zd>.<T>, <Zn>.<Tb>, <Zm>.<Tb>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE2 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve2")]
+    fn uaddlt(&mut self, zd: ZReg, zn: ZReg, zm: ZReg) -> T {
+        emit_add_sub_long(self, zd, zn, zm, 0b011)
+    }
+
+    /// [SSUBLB - Signed subtract long - bottom](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SSUBLB--Signed-subtract-long--bottom--?lang=en)
+    ///
+    /// Subtract the even-numbered signed elements of the second source vector from the corresponding signed elements of the first source vector. The results are placed in the overlapping double-width elements of the destination vector.
+    ///
+    /// `zd` must have element size `H`, `S` or `D`, and `zn` and `zm` must have half of that element size.
+    ///
+    /// ```asm
+    /// SSUBLB <Zd>.<T>, <Zn>.<Tb>, <Zm>.<Tb>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE2 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve2")]
+    fn ssublb(&mut self, zd: ZReg, zn: ZReg, zm: ZReg) -> T {
+        emit_add_sub_long(self, zd, zn, zm, 0b100)
+    }
+
+    /// [SSUBLT - Signed subtract long - top](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SSUBLT--Signed-subtract-long--top--?lang=en)
+    ///
+    /// Subtract the odd-numbered signed elements of the second source vector from the corresponding signed elements of the first source vector. The results are placed in the overlapping double-width elements of the destination vector.
+    ///
+    /// `zd` must have element size `H`, `S` or `D`, and `zn` and `zm` must have half of that element size.
+    ///
+    /// ```asm
+    /// SSUBLT <Zd>.<T>, <Zn>.<Tb>, <Zm>.<Tb>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE2 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve2")]
+    fn ssublt(&mut self, zd: ZReg, zn: ZReg, zm: ZReg) -> T {
+        emit_add_sub_long(self, zd, zn, zm, 0b101)
+    }
+
+    /// [USUBLB - Unsigned subtract long - bottom](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/USUBLB--Unsigned-subtract-long--bottom--?lang=en)
+    ///
+    /// Subtract the even-numbered unsigned elements of the second source vector from the corresponding unsigned elements of the first source vector. The results are placed in the overlapping double-width elements of the destination vector.
+    ///
+    /// `zd` must have element size `H`, `S` or `D`, and `zn` and `zm` must have half of that element size.
+    ///
+    /// ```asm
+    /// USUBLB <Zd>.<T>, <Zn>.<Tb>, <Zm>.<Tb>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE2 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve2")]
+    fn usublb(&mut self, zd: ZReg, zn: ZReg, zm: ZReg) -> T {
+        emit_add_sub_long(self, zd, zn, zm, 0b110)
+    }
+
+    /// [USUBLT - Unsigned subtract long - top](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/USUBLT--Unsigned-subtract-long--top--?lang=en)
+    ///
+    /// Subtract the odd-numbered unsigned elements of the second source vector from the corresponding unsigned elements of the first source vector. The results are placed in the overlapping double-width elements of the destination vector.
+    ///
+    /// `zd` must have element size `H`, `S` or `D`, and `zn` and `zm` must have half of that element size.
+    ///
+    /// ```asm
+    /// USUBLT <Zd>.<T>, <Zn>.<Tb>, <Zm>.<Tb>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE2 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve2")]
+    fn usublt(&mut self, zd: ZReg, zn: ZReg, zm: ZReg) -> T {
+        emit_add_sub_long(self, zd, zn, zm, 0b111)
+    }
+
+    /// [SADDWB - Signed add wide - bottom](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SADDWB--Signed-add-wide--bottom--?lang=en)
+    ///
+    /// Add the even-numbered signed elements of the second source vector to the overlapping double-width elements of the first source vector and place the results in the corresponding double-width elements of the destination vector.
+    ///
+    /// `zd` and `zn` must have element size `H`, `S` or `D`, and `zm` must have half of that element size.
+    ///
+    /// ```asm
+    /// SADDWB <Zd>.<T>, <Zn>.<T>, <Zm>.<Tb>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE2 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve2")]
+    fn saddwb(&mut self, zd: ZReg, zn: ZReg, zm: ZReg) -> T {
+        emit_add_sub_wide(self, zd, zn, zm, 0b000)
+    }
+
+    /// [SADDWT - Signed add wide - top](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SADDWT--Signed-add-wide--top--?lang=en)
+    ///
+    /// Add the odd-numbered signed elements of the second source vector to the overlapping double-width elements of the first source vector and place the results in the corresponding double-width elements of the destination vector.
+    ///
+    /// `zd` and `zn` must have element size `H`, `S` or `D`, and `zm` must have half of that element size.
+    ///
+    /// ```asm
+    /// SADDWT <Zd>.<T>, <Zn>.<T>, <Zm>.<Tb>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE2 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve2")]
+    fn saddwt(&mut self, zd: ZReg, zn: ZReg, zm: ZReg) -> T {
+        emit_add_sub_wide(self, zd, zn, zm, 0b001)
+    }
+
+    /// [UADDWB - Unsigned add wide - bottom](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/UADDWB--Unsigned-add-wide--bottom--?lang=en)
+    ///
+    /// Add the even-numbered unsigned elements of the second source vector to the overlapping double-width elements of the first source vector and place the results in the corresponding double-width elements of the destination vector.
+    ///
+    /// `zd` and `zn` must have element size `H`, `S` or `D`, and `zm` must have half of that element size.
+    ///
+    /// ```asm
+    /// UADDWB <Zd>.<T>, <Zn>.<T>, <Zm>.<Tb>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE2 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve2")]
+    fn uaddwb(&mut self, zd: ZReg, zn: ZReg, zm: ZReg) -> T {
+        emit_add_sub_wide(self, zd, zn, zm, 0b010)
+    }
+
+    /// [UADDWT - Unsigned add wide - top](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/UADDWT--Unsigned-add-wide--top--?lang=en)
+    ///
+    /// Add the odd-numbered unsigned elements of the second source vector to the overlapping double-width elements of the first source vector and place the results in the corresponding double-width elements of the destination vector.
+    ///
+    /// `zd` and `zn` must have element size `H`, `S` or `D`, and `zm` must have half of that element size.
+    ///
+    /// ```asm
+    /// UADDWT <Zd>.<T>, <Zn>.<T>, <Zm>.<Tb>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE2 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve2")]
+    fn uaddwt(&mut self, zd: ZReg, zn: ZReg, zm: ZReg) -> T {
+        emit_add_sub_wide(self, zd, zn, zm, 0b011)
+    }
+
+    /// [SSUBWB - Signed subtract wide - bottom](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SSUBWB--Signed-subtract-wide--bottom--?lang=en)
+    ///
+    /// Subtract the even-numbered signed elements of the second source vector from the overlapping double-width elements of the first source vector and place the results in the corresponding double-width elements of the destination vector.
+    ///
+    /// `zd` and `zn` must have element size `H`, `S` or `D`, and `zm` must have half of that element size.
+    ///
+    /// ```asm
+    /// SSUBWB <Zd>.<T>, <Zn>.<T>, <Zm>.<Tb>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE2 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve2")]
+    fn ssubwb(&mut self, zd: ZReg, zn: ZReg, zm: ZReg) -> T {
+        emit_add_sub_wide(self, zd, zn, zm, 0b100)
+    }
+
+    /// [SSUBWT - Signed subtract wide - top](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SSUBWT--Signed-subtract-wide--top--?lang=en)
+    ///
+    /// Subtract the odd-numbered signed elements of the second source vector from the overlapping double-width elements of the first source vector and place the results in the corresponding double-width elements of the destination vector.
+    ///
+    /// `zd` and `zn` must have element size `H`, `S` or `D`, and `zm` must have half of that element size.
+    ///
+    /// ```asm
+    /// SSUBWT <Zd>.<T>, <Zn>.<T>, <Zm>.<Tb>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE2 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve2")]
+    fn ssubwt(&mut self, zd: ZReg, zn: ZReg, zm: ZReg) -> T {
+        emit_add_sub_wide(self, zd, zn, zm, 0b101)
+    }
+
+    /// [USUBWB - Unsigned subtract wide - bottom](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/USUBWB--Unsigned-subtract-wide--bottom--?lang=en)
+    ///
+    /// Subtract the even-numbered unsigned elements of the second source vector from the overlapping double-width elements of the first source vector and place the results in the corresponding double-width elements of the destination vector.
+    ///
+    /// `zd` and `zn` must have element size `H`, `S` or `D`, and `zm` must have half of that element size.
+    ///
+    /// ```asm
+    /// USUBWB <Zd>.<T>, <Zn>.<T>, <Zm>.<Tb>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE2 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve2")]
+    fn usubwb(&mut self, zd: ZReg, zn: ZReg, zm: ZReg) -> T {
+        emit_add_sub_wide(self, zd, zn, zm, 0b110)
+    }
+
+    /// [USUBWT - Unsigned subtract wide - top](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/USUBWT--Unsigned-subtract-wide--top--?lang=en)
+    ///
+    /// Subtract the odd-numbered unsigned elements of the second source vector from the overlapping double-width elements of the first source vector and place the results in the corresponding double-width elements of the destination vector.
+    ///
+    /// `zd` and `zn` must have element size `H`, `S` or `D`, and `zm` must have half of that element size.
+    ///
+    /// ```asm
+    /// USUBWT <Zd>.<T>, <Zn>.<T>, <Zm>.<Tb>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE2 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve2")]
+    fn usubwt(&mut self, zd: ZReg, zn: ZReg, zm: ZReg) -> T {
+        emit_add_sub_wide(self, zd, zn, zm, 0b111)
+    }
+
+    /// [ADDHNB - Add narrow high part - bottom](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ADDHNB--Add-narrow-high-part--bottom--?lang=en)
+    ///
+    /// Add each vector element of the second source vector to the corresponding vector element of the first source vector. The most significant half of each result is placed in the even-numbered elements of the destination vector, while setting the odd-numbered elements to zero.
+    ///
+    /// `zd` must have element size `B`, `H` or `S`, and `zn` and `zm` must have twice that element size.
+    ///
+    /// ```asm
+    /// ADDHNB <Zd>.<T>, <Zn>.<Tb>, <Zm>.<Tb>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE2 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve2")]
+    fn addhnb(&mut self, zd: ZReg, zn: ZReg, zm: ZReg) -> T {
+        emit_add_sub_narrow(self, zd, zn, zm, 0b000)
+    }
+
+    /// [ADDHNT - Add narrow high part - top](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/ADDHNT--Add-narrow-high-part--top--?lang=en)
+    ///
+    /// Add each vector element of the second source vector to the corresponding vector element of the first source vector. The most significant half of each result is placed in the odd-numbered elements of the destination vector, leaving the even-numbered elements unchanged.
+    ///
+    /// `zd` must have element size `B`, `H` or `S`, and `zn` and `zm` must have twice that element size.
+    ///
+    /// ```asm
+    /// ADDHNT <Zd>.<T>, <Zn>.<Tb>, <Zm>.<Tb>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE2 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve2")]
+    fn addhnt(&mut self, zd: ZReg, zn: ZReg, zm: ZReg) -> T {
+        emit_add_sub_narrow(self, zd, zn, zm, 0b001)
+    }
+
+    /// [RADDHNB - Rounding add narrow high part - bottom](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/RADDHNB--Rounding-add-narrow-high-part--bottom--?lang=en)
+    ///
+    /// Add each vector element of the second source vector to the corresponding vector element of the first source vector, and round the result. The most significant half of each result is placed in the even-numbered elements of the destination vector, while setting the odd-numbered elements to zero.
+    ///
+    /// `zd` must have element size `B`, `H` or `S`, and `zn` and `zm` must have twice that element size.
+    ///
+    /// ```asm
+    /// RADDHNB <Zd>.<T>, <Zn>.<Tb>, <Zm>.<Tb>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE2 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve2")]
+    fn raddhnb(&mut self, zd: ZReg, zn: ZReg, zm: ZReg) -> T {
+        emit_add_sub_narrow(self, zd, zn, zm, 0b010)
+    }
+
+    /// [RADDHNT - Rounding add narrow high part - top](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/RADDHNT--Rounding-add-narrow-high-part--top--?lang=en)
+    ///
+    /// Add each vector element of the second source vector to the corresponding vector element of the first source vector, and round the result. The most significant half of each result is placed in the odd-numbered elements of the destination vector, leaving the even-numbered elements unchanged.
+    ///
+    /// `zd` must have element size `B`, `H` or `S`, and `zn` and `zm` must have twice that element size.
+    ///
+    /// ```asm
+    /// RADDHNT <Zd>.<T>, <Zn>.<Tb>, <Zm>.<Tb>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE2 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve2")]
+    fn raddhnt(&mut self, zd: ZReg, zn: ZReg, zm: ZReg) -> T {
+        emit_add_sub_narrow(self, zd, zn, zm, 0b011)
+    }
+
+    /// [SUBHNB - Subtract narrow high part - bottom](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SUBHNB--Subtract-narrow-high-part--bottom--?lang=en)
+    ///
+    /// Subtract each vector element of the second source vector from the corresponding vector element of the first source vector. The most significant half of each result is placed in the even-numbered elements of the destination vector, while setting the odd-numbered elements to zero.
+    ///
+    /// `zd` must have element size `B`, `H` or `S`, and `zn` and `zm` must have twice that element size.
+    ///
+    /// ```asm
+    /// SUBHNB <Zd>.<T>, <Zn>.<Tb>, <Zm>.<Tb>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE2 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve2")]
+    fn subhnb(&mut self, zd: ZReg, zn: ZReg, zm: ZReg) -> T {
+        emit_add_sub_narrow(self, zd, zn, zm, 0b100)
+    }
+
+    /// [SUBHNT - Subtract narrow high part - top](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/SUBHNT--Subtract-narrow-high-part--top--?lang=en)
+    ///
+    /// Subtract each vector element of the second source vector from the corresponding vector element of the first source vector. The most significant half of each result is placed in the odd-numbered elements of the destination vector, leaving the even-numbered elements unchanged.
+    ///
+    /// `zd` must have element size `B`, `H` or `S`, and `zn` and `zm` must have twice that element size.
+    ///
+    /// ```asm
+    /// SUBHNT <Zd>.<T>, <Zn>.<Tb>, <Zm>.<Tb>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE2 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve2")]
+    fn subhnt(&mut self, zd: ZReg, zn: ZReg, zm: ZReg) -> T {
+        emit_add_sub_narrow(self, zd, zn, zm, 0b101)
+    }
+
+    /// [RSUBHNB - Rounding subtract narrow high part - bottom](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/RSUBHNB--Rounding-subtract-narrow-high-part--bottom--?lang=en)
+    ///
+    /// Subtract each vector element of the second source vector from the corresponding vector element of the first source vector, and round the result. The most significant half of each result is placed in the even-numbered elements of the destination vector, while setting the odd-numbered elements to zero.
+    ///
+    /// `zd` must have element size `B`, `H` or `S`, and `zn` and `zm` must have twice that element size.
+    ///
+    /// ```asm
+    /// RSUBHNB <Zd>.<T>, <Zn>.<Tb>, <Zm>.<Tb>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE2 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve2")]
+    fn rsubhnb(&mut self, zd: ZReg, zn: ZReg, zm: ZReg) -> T {
+        emit_add_sub_narrow(self, zd, zn, zm, 0b110)
+    }
+
+    /// [RSUBHNT - Rounding subtract narrow high part - top](https://developer.arm.com/documentation/ddi0596/2021-12/SVE-Instructions/RSUBHNT--Rounding-subtract-narrow-high-part--top--?lang=en)
+    ///
+    /// Subtract each vector element of the second source vector from the corresponding vector element of the first source vector, and round the result. The most significant half of each result is placed in the odd-numbered elements of the destination vector, leaving the even-numbered elements unchanged.
+    ///
+    /// `zd` must have element size `B`, `H` or `S`, and `zn` and `zm` must have twice that element size.
+    ///
+    /// ```asm
+    /// RSUBHNT <Zd>.<T>, <Zn>.<Tb>, <Zm>.<Tb>
+    /// ```
+    ///
+    /// *Note*: FEAT_SVE2 required
+    #[inline(always)]
+    #[cfg(feature = "arm_feat_sve2")]
+    fn rsubhnt(&mut self, zd: ZReg, zn: ZReg, zm: ZReg) -> T {
+        emit_add_sub_narrow(self, zd, zn, zm, 0b111)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use crate::assert_panic;
+    #[allow(unused_imports)]
+    use crate::test_utils::test_producer::TestProducer;
+
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[cfg(feature = "arm_feat_sve2")]
+    #[test]
+    fn test_sve2_add_sub_long() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.saddlb(ZReg::s(1), ZReg::h(4), ZReg::h(3));
+        assert_eq!(instr, "saddlb z1.s, z4.h, z3.h");
+
+        let instr = prod.saddlt(ZReg::s(1), ZReg::h(4), ZReg::h(3));
+        assert_eq!(instr, "saddlt z1.s, z4.h, z3.h");
+
+        let instr = prod.uaddlb(ZReg::s(1), ZReg::h(4), ZReg::h(3));
+        assert_eq!(instr, "uaddlb z1.s, z4.h, z3.h");
+
+        let instr = prod.uaddlt(ZReg::s(1), ZReg::h(4), ZReg::h(3));
+        assert_eq!(instr, "uaddlt z1.s, z4.h, z3.h");
+
+        let instr = prod.ssublb(ZReg::s(1), ZReg::h(4), ZReg::h(3));
+        assert_eq!(instr, "ssublb z1.s, z4.h, z3.h");
+
+        let instr = prod.ssublt(ZReg::s(1), ZReg::h(4), ZReg::h(3));
+        assert_eq!(instr, "ssublt z1.s, z4.h, z3.h");
+
+        let instr = prod.usublb(ZReg::s(1), ZReg::h(4), ZReg::h(3));
+        assert_eq!(instr, "usublb z1.s, z4.h, z3.h");
+
+        let instr = prod.usublt(ZReg::s(1), ZReg::h(4), ZReg::h(3));
+        assert_eq!(instr, "usublt z1.s, z4.h, z3.h");
+
+        assert_panic!("Should panic: zd element size B"; prod.saddlb(ZReg::b(1), ZReg::b(4), ZReg::b(3)));
+
+        assert_panic!("Should panic: source not half size"; prod.uaddlb(ZReg::d(1), ZReg::h(4), ZReg::h(3)));
+    }
+
+    #[cfg(feature = "arm_feat_sve2")]
+    #[test]
+    fn test_sve2_add_sub_wide() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.saddwb(ZReg::s(1), ZReg::s(4), ZReg::h(3));
+        assert_eq!(instr, "saddwb z1.s, z4.s, z3.h");
+
+        let instr = prod.saddwt(ZReg::s(1), ZReg::s(4), ZReg::h(3));
+        assert_eq!(instr, "saddwt z1.s, z4.s, z3.h");
+
+        let instr = prod.uaddwb(ZReg::s(1), ZReg::s(4), ZReg::h(3));
+        assert_eq!(instr, "uaddwb z1.s, z4.s, z3.h");
+
+        let instr = prod.uaddwt(ZReg::s(1), ZReg::s(4), ZReg::h(3));
+        assert_eq!(instr, "uaddwt z1.s, z4.s, z3.h");
+
+        let instr = prod.ssubwb(ZReg::s(1), ZReg::s(4), ZReg::h(3));
+        assert_eq!(instr, "ssubwb z1.s, z4.s, z3.h");
+
+        let instr = prod.ssubwt(ZReg::s(1), ZReg::s(4), ZReg::h(3));
+        assert_eq!(instr, "ssubwt z1.s, z4.s, z3.h");
+
+        let instr = prod.usubwb(ZReg::s(1), ZReg::s(4), ZReg::h(3));
+        assert_eq!(instr, "usubwb z1.s, z4.s, z3.h");
+
+        let instr = prod.usubwt(ZReg::s(1), ZReg::s(4), ZReg::h(3));
+        assert_eq!(instr, "usubwt z1.s, z4.s, z3.h");
+
+        assert_panic!("Should panic: zn not the size of zd"; prod.saddwb(ZReg::s(1), ZReg::h(4), ZReg::h(3)));
+    }
+
+    #[cfg(feature = "arm_feat_sve2")]
+    #[test]
+    fn test_sve2_add_sub_narrow() {
+        let mut prod = TestProducer::new();
+
+        let instr = prod.addhnb(ZReg::h(1), ZReg::s(4), ZReg::s(3));
+        assert_eq!(instr, "addhnb z1.h, z4.s, z3.s");
+
+        let instr = prod.addhnt(ZReg::h(1), ZReg::s(4), ZReg::s(3));
+        assert_eq!(instr, "addhnt z1.h, z4.s, z3.s");
+
+        let instr = prod.raddhnb(ZReg::h(1), ZReg::s(4), ZReg::s(3));
+        assert_eq!(instr, "raddhnb z1.h, z4.s, z3.s");
+
+        let instr = prod.raddhnt(ZReg::h(1), ZReg::s(4), ZReg::s(3));
+        assert_eq!(instr, "raddhnt z1.h, z4.s, z3.s");
+
+        let instr = prod.subhnb(ZReg::h(1), ZReg::s(4), ZReg::s(3));
+        assert_eq!(instr, "subhnb z1.h, z4.s, z3.s");
+
+        let instr = prod.subhnt(ZReg::h(1), ZReg::s(4), ZReg::s(3));
+        assert_eq!(instr, "subhnt z1.h, z4.s, z3.s");
+
+        let instr = prod.rsubhnb(ZReg::h(1), ZReg::s(4), ZReg::s(3));
+        assert_eq!(instr, "rsubhnb z1.h, z4.s, z3.s");
+
+        let instr = prod.rsubhnt(ZReg::h(1), ZReg::s(4), ZReg::s(3));
+        assert_eq!(instr, "rsubhnt z1.h, z4.s, z3.s");
+
+        let instr = prod.addhnb(ZReg::b(1), ZReg::h(4), ZReg::h(3));
+        assert_eq!(instr, "addhnb z1.b, z4.h, z3.h");
+
+        let instr = prod.addhnb(ZReg::s(1), ZReg::d(4), ZReg::d(3));
+        assert_eq!(instr, "addhnb z1.s, z4.d, z3.d");
+
+        assert_panic!("Should panic: zd element size D"; prod.addhnb(ZReg::d(1), ZReg::d(4), ZReg::d(3)));
+    }
+}
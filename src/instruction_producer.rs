@@ -4,6 +4,8 @@ use crate::instruction_encoding::branch_exception_system::BranchExceptionSystem;
 use crate::instruction_encoding::branch_exception_system::compare_and_branch_imm::CompareAndBranchImm;
 use crate::instruction_encoding::branch_exception_system::conditional_branch_imm::ConditionalBranchImmediate;
 use crate::instruction_encoding::branch_exception_system::exception_generation::ExceptionGeneration;
+use crate::instruction_encoding::branch_exception_system::hints::Hints;
+use crate::instruction_encoding::branch_exception_system::tme::TransactionalMemoryExtension;
 use crate::instruction_encoding::branch_exception_system::pstate::PStateInstructions;
 use crate::instruction_encoding::branch_exception_system::system_instr_w_register_arg::SystemInstructionsWithRegArg;
 use crate::instruction_encoding::branch_exception_system::system_instructions::SystemInstructions;
@@ -11,12 +13,15 @@ use crate::instruction_encoding::branch_exception_system::system_register_move::
 use crate::instruction_encoding::branch_exception_system::test_and_branch_imm::TestAndBranchImmediate;
 use crate::instruction_encoding::branch_exception_system::unconditional_branch_immediate::UnconditionalBranchImmediate;
 use crate::instruction_encoding::branch_exception_system::unconditional_branch_register::UnconditionalBranchRegister;
+use crate::instruction_encoding::atomic_macro_ops::AtomicMacroOperations;
+use crate::instruction_encoding::shadow_stack::ShadowStack;
 use crate::instruction_encoding::common_aliases::CommonAliases;
 use crate::instruction_encoding::data_proc_imm::add_substract_imm::AddSubtractImmediate;
 use crate::instruction_encoding::data_proc_imm::bitfield::BitfieldInstructions;
 use crate::instruction_encoding::data_proc_imm::DataProcessingImmediate;
 use crate::instruction_encoding::data_proc_imm::extract::ExtractInstructions;
 use crate::instruction_encoding::data_proc_imm::logical_imm::LogicalImmediate;
+use crate::instruction_encoding::data_proc_imm::min_max_imm::MinMaxImmediate;
 use crate::instruction_encoding::data_proc_imm::mov_wide_imm::MovWideImmediate;
 use crate::instruction_encoding::data_proc_imm::pc_rel_addr::PcRelAddressing;
 use crate::instruction_encoding::data_proc_reg::add_sub_carry::AddSubtractWithCarry;
@@ -32,12 +37,22 @@ use crate::instruction_encoding::data_proc_reg::DataProcessingRegister;
 use crate::instruction_encoding::data_proc_reg::evaluate_into_flags::EvaluateIntoFlags;
 use crate::instruction_encoding::data_proc_reg::logical_shift_reg::LogicalShiftRegister;
 use crate::instruction_encoding::data_proc_reg::rotate_right_into_flags::RotateRightIntoFlags;
+use crate::instruction_encoding::data_proc_simd_fp::advanced_simd_three_same::AdvancedSIMDThreeSame;
+use crate::instruction_encoding::data_proc_simd_fp::advanced_simd_three_same_extra::AdvancedSIMDThreeSameExtra;
+use crate::instruction_encoding::data_proc_simd_fp::advanced_simd_two_reg_misc::AdvancedSIMDTwoRegisterMiscellaneous;
+use crate::instruction_encoding::data_proc_simd_fp::advanced_simd_vec_x_indexed_elem::AdvancedSIMDVectorXIndexedElement;
+use crate::instruction_encoding::data_proc_simd_fp::fp_data_proc_one_src::FloatingPointDataProcessingOneSource;
+use crate::instruction_encoding::data_proc_simd_fp::DataProcessingSIMDFloatingPoint;
 use crate::instruction_encoding::loads_and_stores::advanced_simd_ldr_str_multi_structures::AdvancedSIMDLoadStoreMultipleStructures;
 use crate::instruction_encoding::loads_and_stores::advanced_simd_ldr_str_single_structures::AdvancedSIMDLoadStoreSingleStructures;
 use crate::instruction_encoding::loads_and_stores::atomic_memory_operations::AtomicMemoryOperatinos;
+use crate::instruction_encoding::loads_and_stores::atomic_memory_operations_128::AtomicMemoryOperations128;
+use crate::instruction_encoding::loads_and_stores::read_check_write::ReadCheckWrite;
+use crate::instruction_encoding::loads_and_stores::gcs_load_store::GcsLoadStore;
 use crate::instruction_encoding::loads_and_stores::compare_and_swap::CompareAndSwap;
 use crate::instruction_encoding::loads_and_stores::compare_and_swap_pair::CompareAndSwapPair;
 use crate::instruction_encoding::loads_and_stores::ldapr_stlr_unscale_imm::LdaprStlrUnscaleImmediate;
+use crate::instruction_encoding::loads_and_stores::ldiapp_stilp::LdiappStilp;
 use crate::instruction_encoding::loads_and_stores::load_register_literal::LoadRegisterLiteral;
 use crate::instruction_encoding::loads_and_stores::load_store_exclusive_pair::LoadStoreExclusivePair;
 use crate::instruction_encoding::loads_and_stores::load_store_exclusive_register::LoadStoreExclusiveRegister;
@@ -55,6 +70,23 @@ use crate::instruction_encoding::loads_and_stores::load_store_register_regoffset
 use crate::instruction_encoding::loads_and_stores::load_store_register_unsigned_imm::LoadStoreRegisterUnsignedImmediate;
 use crate::instruction_encoding::loads_and_stores::LoadsAndStores;
 use crate::instruction_encoding::loads_and_stores::memory_copy_and_memory_set::MemoryCopyAndMemorySet;
+use crate::instruction_encoding::sme::outer_product::SmeOuterProduct;
+use crate::instruction_encoding::sme::streaming_mode::SmeStreamingMode;
+use crate::instruction_encoding::sme::tile_load_store::SmeTileLoadStore;
+use crate::instruction_encoding::sme::tile_move::SmeTileMove;
+use crate::instruction_encoding::sme::SmeInstructionSet;
+use crate::instruction_encoding::sve::contiguous_load_store::SveContiguousLoadStore;
+use crate::instruction_encoding::sve::gather_scatter::SveGatherScatter;
+use crate::instruction_encoding::sve::fp_arith::SveFloatingPointArithmetic;
+use crate::instruction_encoding::sve::fp_compare::SveFloatingPointCompare;
+use crate::instruction_encoding::sve::fp_convert::SveFloatingPointConvert;
+use crate::instruction_encoding::sve::sve2_match_lookup::Sve2MatchAndLookup;
+use crate::instruction_encoding::sve::sve2_widening_narrowing::Sve2WideningNarrowing;
+use crate::instruction_encoding::sve::element_count::SveElementCount;
+use crate::instruction_encoding::sve::index_generation::SveIndexGeneration;
+use crate::instruction_encoding::sve::int_arith::SveIntegerArithmetic;
+use crate::instruction_encoding::sve::predicates::SvePredicates;
+use crate::instruction_encoding::sve::SveInstructionSet;
 use crate::types::{Instruction, InstructionPointer};
 use crate::types::instruction::Instr;
 
@@ -86,6 +118,8 @@ impl BitfieldInstructions<Instr> for InstrProducer {}
 
 impl ExtractInstructions<Instr> for InstrProducer {}
 
+impl MinMaxImmediate<Instr> for InstrProducer {}
+
 impl TestAndBranchImmediate<Instr> for InstrProducer {}
 
 impl BranchExceptionSystem<Instr> for InstrProducer {}
@@ -98,6 +132,10 @@ impl SystemInstructionsWithRegArg<Instr> for InstrProducer {}
 
 impl Barriers<Instr> for InstrProducer {}
 
+impl Hints<Instr> for InstrProducer {}
+
+impl TransactionalMemoryExtension<Instr> for InstrProducer {}
+
 impl PStateInstructions<Instr> for InstrProducer {}
 
 impl SystemInstructions<Instr> for InstrProducer {}
@@ -119,6 +157,7 @@ impl LoadStoreOrdered<Instr> for InstrProducer {}
 impl CompareAndSwap<Instr> for InstrProducer {}
 
 impl LdaprStlrUnscaleImmediate<Instr> for InstrProducer {}
+impl LdiappStilp<Instr> for InstrProducer {}
 
 impl MemoryCopyAndMemorySet<Instr> for InstrProducer {}
 
@@ -145,6 +184,9 @@ impl AdvancedSIMDLoadStoreMultipleStructures<Instr> for InstrProducer {}
 impl AdvancedSIMDLoadStoreSingleStructures<Instr> for InstrProducer {}
 
 impl AtomicMemoryOperatinos<Instr> for InstrProducer {}
+impl AtomicMemoryOperations128<Instr> for InstrProducer {}
+impl ReadCheckWrite<Instr> for InstrProducer {}
+impl GcsLoadStore<Instr> for InstrProducer {}
 
 impl LoadsAndStores<Instr> for InstrProducer {}
 
@@ -180,8 +222,57 @@ impl DataProcessingRegister<Instr> for InstrProducer {}
 
 impl DataProcessingTwoSource<Instr> for InstrProducer {}
 
+impl AdvancedSIMDThreeSame<Instr> for InstrProducer {}
+
+impl AdvancedSIMDThreeSameExtra<Instr> for InstrProducer {}
+
+impl AdvancedSIMDTwoRegisterMiscellaneous<Instr> for InstrProducer {}
+
+impl AdvancedSIMDVectorXIndexedElement<Instr> for InstrProducer {}
+
+impl FloatingPointDataProcessingOneSource<Instr> for InstrProducer {}
+
+impl DataProcessingSIMDFloatingPoint<Instr> for InstrProducer {}
+
+impl SmeOuterProduct<Instr> for InstrProducer {}
+
+impl SmeStreamingMode<Instr> for InstrProducer {}
+
+impl SmeTileLoadStore<Instr> for InstrProducer {}
+
+impl SmeTileMove<Instr> for InstrProducer {}
+
+impl SmeInstructionSet<Instr> for InstrProducer {}
+
+impl SveContiguousLoadStore<Instr> for InstrProducer {}
+
+impl SveGatherScatter<Instr> for InstrProducer {}
+
+impl SveFloatingPointArithmetic<Instr> for InstrProducer {}
+
+impl SveFloatingPointCompare<Instr> for InstrProducer {}
+
+impl SveFloatingPointConvert<Instr> for InstrProducer {}
+
+impl Sve2MatchAndLookup<Instr> for InstrProducer {}
+
+impl Sve2WideningNarrowing<Instr> for InstrProducer {}
+
+impl SveElementCount<Instr> for InstrProducer {}
+
+impl SveIndexGeneration<Instr> for InstrProducer {}
+
+impl SveIntegerArithmetic<Instr> for InstrProducer {}
+
+impl SvePredicates<Instr> for InstrProducer {}
+
+impl SveInstructionSet<Instr> for InstrProducer {}
+
 impl CommonAliases<Instr> for InstrProducer {}
 
+impl AtomicMacroOperations<Instr> for InstrProducer {}
+impl ShadowStack<Instr> for InstrProducer {}
+
 impl CompareAndBranchImm<Instr> for InstrProducer {}
 
 impl InstructionSet<Instr> for InstrProducer {}
@@ -10,6 +10,8 @@ use crate::instruction_encoding::branch_exception_system::compare_and_branch_imm
 use crate::instruction_encoding::branch_exception_system::conditional_branch_imm::ConditionalBranchImmediate;
 use crate::instruction_encoding::branch_exception_system::conditional_branch_imm::ConditionalBranchImmediateWithAddress;
 use crate::instruction_encoding::branch_exception_system::exception_generation::ExceptionGeneration;
+use crate::instruction_encoding::branch_exception_system::hints::Hints;
+use crate::instruction_encoding::branch_exception_system::tme::TransactionalMemoryExtension;
 use crate::instruction_encoding::branch_exception_system::pstate::PStateInstructions;
 use crate::instruction_encoding::branch_exception_system::system_instr_w_register_arg::SystemInstructionsWithRegArg;
 use crate::instruction_encoding::branch_exception_system::system_instructions::SystemInstructions;
@@ -23,11 +25,14 @@ use crate::instruction_encoding::branch_exception_system::unconditional_branch_i
 use crate::instruction_encoding::branch_exception_system::unconditional_branch_register::UnconditionalBranchRegister;
 use crate::instruction_encoding::branch_exception_system::BranchExceptionSystem;
 use crate::instruction_encoding::branch_exception_system::BranchExceptionSystemWithAddress;
+use crate::instruction_encoding::atomic_macro_ops::AtomicMacroOperations;
+use crate::instruction_encoding::shadow_stack::ShadowStack;
 use crate::instruction_encoding::common_aliases::CommonAliases;
 use crate::instruction_encoding::data_proc_imm::add_substract_imm::AddSubtractImmediate;
 use crate::instruction_encoding::data_proc_imm::bitfield::BitfieldInstructions;
 use crate::instruction_encoding::data_proc_imm::extract::ExtractInstructions;
 use crate::instruction_encoding::data_proc_imm::logical_imm::LogicalImmediate;
+use crate::instruction_encoding::data_proc_imm::min_max_imm::MinMaxImmediate;
 use crate::instruction_encoding::data_proc_imm::mov_wide_imm::MovWideImmediate;
 use crate::instruction_encoding::data_proc_imm::pc_rel_addr::{
     PcRelAddressing, PcRelAddressingWithAddress,
@@ -48,12 +53,22 @@ use crate::instruction_encoding::data_proc_reg::evaluate_into_flags::EvaluateInt
 use crate::instruction_encoding::data_proc_reg::logical_shift_reg::LogicalShiftRegister;
 use crate::instruction_encoding::data_proc_reg::rotate_right_into_flags::RotateRightIntoFlags;
 use crate::instruction_encoding::data_proc_reg::DataProcessingRegister;
+use crate::instruction_encoding::data_proc_simd_fp::advanced_simd_three_same::AdvancedSIMDThreeSame;
+use crate::instruction_encoding::data_proc_simd_fp::advanced_simd_three_same_extra::AdvancedSIMDThreeSameExtra;
+use crate::instruction_encoding::data_proc_simd_fp::advanced_simd_two_reg_misc::AdvancedSIMDTwoRegisterMiscellaneous;
+use crate::instruction_encoding::data_proc_simd_fp::advanced_simd_vec_x_indexed_elem::AdvancedSIMDVectorXIndexedElement;
+use crate::instruction_encoding::data_proc_simd_fp::fp_data_proc_one_src::FloatingPointDataProcessingOneSource;
+use crate::instruction_encoding::data_proc_simd_fp::DataProcessingSIMDFloatingPoint;
 use crate::instruction_encoding::loads_and_stores::advanced_simd_ldr_str_multi_structures::AdvancedSIMDLoadStoreMultipleStructures;
 use crate::instruction_encoding::loads_and_stores::advanced_simd_ldr_str_single_structures::AdvancedSIMDLoadStoreSingleStructures;
 use crate::instruction_encoding::loads_and_stores::atomic_memory_operations::AtomicMemoryOperatinos;
+use crate::instruction_encoding::loads_and_stores::atomic_memory_operations_128::AtomicMemoryOperations128;
+use crate::instruction_encoding::loads_and_stores::read_check_write::ReadCheckWrite;
+use crate::instruction_encoding::loads_and_stores::gcs_load_store::GcsLoadStore;
 use crate::instruction_encoding::loads_and_stores::compare_and_swap::CompareAndSwap;
 use crate::instruction_encoding::loads_and_stores::compare_and_swap_pair::CompareAndSwapPair;
 use crate::instruction_encoding::loads_and_stores::ldapr_stlr_unscale_imm::LdaprStlrUnscaleImmediate;
+use crate::instruction_encoding::loads_and_stores::ldiapp_stilp::LdiappStilp;
 use crate::instruction_encoding::loads_and_stores::load_register_literal::{
     LoadRegisterLiteral, LoadRegisterLiteralWithAddress,
 };
@@ -74,25 +89,58 @@ use crate::instruction_encoding::loads_and_stores::load_store_register_unsigned_
 use crate::instruction_encoding::loads_and_stores::memory_copy_and_memory_set::MemoryCopyAndMemorySet;
 use crate::instruction_encoding::loads_and_stores::LoadsAndStores;
 use crate::instruction_encoding::loads_and_stores::LoadsAndStoresWithAddress;
+use crate::instruction_encoding::sme::outer_product::SmeOuterProduct;
+use crate::instruction_encoding::sme::streaming_mode::SmeStreamingMode;
+use crate::instruction_encoding::sme::tile_load_store::SmeTileLoadStore;
+use crate::instruction_encoding::sme::tile_move::SmeTileMove;
+use crate::instruction_encoding::sme::SmeInstructionSet;
+use crate::instruction_encoding::sve::contiguous_load_store::SveContiguousLoadStore;
+use crate::instruction_encoding::sve::gather_scatter::SveGatherScatter;
+use crate::instruction_encoding::sve::fp_arith::SveFloatingPointArithmetic;
+use crate::instruction_encoding::sve::fp_compare::SveFloatingPointCompare;
+use crate::instruction_encoding::sve::fp_convert::SveFloatingPointConvert;
+use crate::instruction_encoding::sve::sve2_match_lookup::Sve2MatchAndLookup;
+use crate::instruction_encoding::sve::sve2_widening_narrowing::Sve2WideningNarrowing;
+use crate::instruction_encoding::sve::element_count::SveElementCount;
+use crate::instruction_encoding::sve::index_generation::SveIndexGeneration;
+use crate::instruction_encoding::sve::int_arith::SveIntegerArithmetic;
+use crate::instruction_encoding::sve::predicates::SvePredicates;
+use crate::instruction_encoding::sve::SveInstructionSet;
 use crate::instruction_encoding::AddressableInstructionProcessor;
 use crate::instruction_encoding::InstructionSetWithAddress;
 use crate::instruction_encoding::{InstructionProcessor, InstructionSet};
+use crate::instruction_stream::labels::LabelTable;
+use crate::instruction_stream::veneers::VeneerTable;
 use crate::mc_memory::{McMemory, Memory};
 use crate::types::instruction::Instr;
 use crate::types::Offset32;
 use crate::types::{Instruction, InstructionPointer};
 
+#[deprecated(note = "`patch_at` accepts any `FnOnce(&mut InstrStream)`, use a closure instead")]
 pub type PatchFn<M, E> = fn(&mut InstrStream<M, E>) -> ();
 
+mod labels;
+mod patch;
+mod veneers;
+
+pub use patch::{PatchGuard, PatchOverflow};
+
 pub struct InstrStream<'mem, M: Memory, E: Emitter> {
     mem: &'mem mut M,
     emitter: E,
+    labels: LabelTable,
+    veneers: VeneerTable,
 }
 
 impl<'mem> InstrStream<'mem, McMemory, InstrEmitter> {
     pub fn new(mem: &'mem mut McMemory) -> Self {
         let emitter = InstrEmitter::from_mem(mem);
-        Self { mem, emitter }
+        Self {
+            mem,
+            emitter,
+            labels: LabelTable::default(),
+            veneers: VeneerTable::default(),
+        }
     }
 }
 
@@ -116,6 +164,10 @@ impl<'mem, M: Memory, E: Emitter> AddressableInstructionProcessor<Instr>
     for InstrStream<'mem, M, E>
 {
     fn intr_ptr_offset_to(&self, addr: usize) -> Offset32 {
+        debug_assert!(
+            !self.labels.may_relax(),
+            "PC-relative instructions to addresses must not be emitted while a branch may be relaxed"
+        );
         let pc = self.emitter.instr_ptr() as usize;
         let offset_abs = pc
             .checked_sub(addr)
@@ -133,6 +185,14 @@ impl<'mem, M: Memory, E: Emitter> AddressableInstructionProcessor<Instr>
             -(offset_abs as i32)
         }
     }
+
+    fn intr_ptr_addr(&self) -> usize {
+        debug_assert!(
+            !self.labels.may_relax(),
+            "PC-relative instructions to addresses must not be emitted while a branch may be relaxed"
+        );
+        self.emitter.instr_ptr() as usize
+    }
 }
 
 /// Implementation for `InstrStream` struct.
@@ -149,8 +209,8 @@ impl<'mem, M: Memory, E: Emitter> InstrStream<'mem, M, E> {
     /// # Arguments
     ///
     /// `intr_ptr` - Provides the reference to an instruction pointer where the patch has to be applied.
-    /// `patch` - The patch operation to<'mem, M: Memory, E: Emitter> InstrStream<'mem, M, E> {
-    pub fn patch_at(&mut self, intr_ptr: InstructionPointer, patch: PatchFn<M, E>) {
+    /// `patch` - The patch operation to apply, it may capture the values it needs.
+    pub fn patch_at<F: FnOnce(&mut Self)>(&mut self, intr_ptr: InstructionPointer, patch: F) {
         // save instruction pointer
         let iptr = self.emitter.instr_ptr();
         self.emitter.set_instr_ptr(intr_ptr);
@@ -159,6 +219,33 @@ impl<'mem, M: Memory, E: Emitter> InstrStream<'mem, M, E> {
         self.emitter.set_instr_ptr(iptr);
     }
 
+    /// Starts a patch session of the `region_len` instructions at `intr_ptr`.
+    ///
+    /// The returned guard dereferences to the stream and emits into the region. If the memory is
+    /// executable, it is writable until the guard is dropped. The caller is responsible for the
+    /// instruction cache maintenance of the patched region. See [`PatchGuard`].
+    #[must_use = "the patch session ends when the guard is dropped"]
+    pub fn patch_session(
+        &mut self,
+        intr_ptr: InstructionPointer,
+        region_len: usize,
+    ) -> PatchGuard<'_, 'mem, M, E> {
+        PatchGuard::new(self, intr_ptr, region_len)
+    }
+
+    /// Makes the memory of the stream executable. No instructions can be emitted until it is
+    /// writable again.
+    #[inline(always)]
+    pub fn make_executable(&mut self) {
+        self.mem.make_executable();
+    }
+
+    /// Makes the memory of the stream writable.
+    #[inline(always)]
+    pub fn make_writable(&mut self) {
+        self.mem.make_writable();
+    }
+
     #[inline(always)]
     pub fn nullary_fn_ptr(&mut self) -> unsafe extern "C" fn() -> u64 {
         unsafe { mem::transmute(self.base_ptr() as usize) }
@@ -227,6 +314,10 @@ impl<'mem, M: Memory, E: Emitter> ConditionalBranchImmediateWithAddress<Instr>
 
 impl<'mem, M: Memory, E: Emitter> SystemInstructionsWithRegArg<Instr> for InstrStream<'mem, M, E> {}
 
+impl<'mem, M: Memory, E: Emitter> Hints<Instr> for InstrStream<'mem, M, E> {}
+
+impl<'mem, M: Memory, E: Emitter> TransactionalMemoryExtension<Instr> for InstrStream<'mem, M, E> {}
+
 impl<'mem, M: Memory, E: Emitter> PStateInstructions<Instr> for InstrStream<'mem, M, E> {}
 
 impl<'mem, M: Memory, E: Emitter> SystemInstructions<Instr> for InstrStream<'mem, M, E> {}
@@ -268,6 +359,8 @@ impl<'mem, M: Memory, E: Emitter> BitfieldInstructions<Instr> for InstrStream<'m
 
 impl<'mem, M: Memory, E: Emitter> ExtractInstructions<Instr> for InstrStream<'mem, M, E> {}
 
+impl<'mem, M: Memory, E: Emitter> MinMaxImmediate<Instr> for InstrStream<'mem, M, E> {}
+
 impl<'mem, M: Memory, E: Emitter> DataProcessingImmediate<Instr> for InstrStream<'mem, M, E> {}
 
 impl<'mem, M: Memory, E: Emitter> MovWideImmediate<Instr> for InstrStream<'mem, M, E> {}
@@ -300,8 +393,57 @@ impl<'mem, M: Memory, E: Emitter> DataProcessingRegister<Instr> for InstrStream<
 
 impl<'mem, M: Memory, E: Emitter> DataProcessingTwoSource<Instr> for InstrStream<'mem, M, E> {}
 
+impl<'mem, M: Memory, E: Emitter> AdvancedSIMDThreeSame<Instr> for InstrStream<'mem, M, E> {}
+
+impl<'mem, M: Memory, E: Emitter> AdvancedSIMDThreeSameExtra<Instr> for InstrStream<'mem, M, E> {}
+
+impl<'mem, M: Memory, E: Emitter> AdvancedSIMDTwoRegisterMiscellaneous<Instr> for InstrStream<'mem, M, E> {}
+
+impl<'mem, M: Memory, E: Emitter> AdvancedSIMDVectorXIndexedElement<Instr> for InstrStream<'mem, M, E> {}
+
+impl<'mem, M: Memory, E: Emitter> FloatingPointDataProcessingOneSource<Instr> for InstrStream<'mem, M, E> {}
+
+impl<'mem, M: Memory, E: Emitter> DataProcessingSIMDFloatingPoint<Instr> for InstrStream<'mem, M, E> {}
+
+impl<'mem, M: Memory, E: Emitter> SmeOuterProduct<Instr> for InstrStream<'mem, M, E> {}
+
+impl<'mem, M: Memory, E: Emitter> SmeStreamingMode<Instr> for InstrStream<'mem, M, E> {}
+
+impl<'mem, M: Memory, E: Emitter> SmeTileLoadStore<Instr> for InstrStream<'mem, M, E> {}
+
+impl<'mem, M: Memory, E: Emitter> SmeTileMove<Instr> for InstrStream<'mem, M, E> {}
+
+impl<'mem, M: Memory, E: Emitter> SmeInstructionSet<Instr> for InstrStream<'mem, M, E> {}
+
+impl<'mem, M: Memory, E: Emitter> SveContiguousLoadStore<Instr> for InstrStream<'mem, M, E> {}
+
+impl<'mem, M: Memory, E: Emitter> SveGatherScatter<Instr> for InstrStream<'mem, M, E> {}
+
+impl<'mem, M: Memory, E: Emitter> SveFloatingPointArithmetic<Instr> for InstrStream<'mem, M, E> {}
+
+impl<'mem, M: Memory, E: Emitter> SveFloatingPointCompare<Instr> for InstrStream<'mem, M, E> {}
+
+impl<'mem, M: Memory, E: Emitter> SveFloatingPointConvert<Instr> for InstrStream<'mem, M, E> {}
+
+impl<'mem, M: Memory, E: Emitter> Sve2MatchAndLookup<Instr> for InstrStream<'mem, M, E> {}
+
+impl<'mem, M: Memory, E: Emitter> Sve2WideningNarrowing<Instr> for InstrStream<'mem, M, E> {}
+
+impl<'mem, M: Memory, E: Emitter> SveElementCount<Instr> for InstrStream<'mem, M, E> {}
+
+impl<'mem, M: Memory, E: Emitter> SveIndexGeneration<Instr> for InstrStream<'mem, M, E> {}
+
+impl<'mem, M: Memory, E: Emitter> SveIntegerArithmetic<Instr> for InstrStream<'mem, M, E> {}
+
+impl<'mem, M: Memory, E: Emitter> SvePredicates<Instr> for InstrStream<'mem, M, E> {}
+
+impl<'mem, M: Memory, E: Emitter> SveInstructionSet<Instr> for InstrStream<'mem, M, E> {}
+
 impl<'mem, M: Memory, E: Emitter> CommonAliases<Instr> for InstrStream<'mem, M, E> {}
 
+impl<'mem, M: Memory, E: Emitter> AtomicMacroOperations<Instr> for InstrStream<'mem, M, E> {}
+impl<'mem, M: Memory, E: Emitter> ShadowStack<Instr> for InstrStream<'mem, M, E> {}
+
 impl<'mem, M: Memory, E: Emitter> InstructionSet<Instr> for InstrStream<'mem, M, E> {}
 
 impl<'mem, M: Memory, E: Emitter> LoadStoreRegisterPrePostIndexed<Instr>
@@ -322,6 +464,7 @@ impl<'mem, M: Memory, E: Emitter> LoadStoreOrdered<Instr> for InstrStream<'mem,
 impl<'mem, M: Memory, E: Emitter> CompareAndSwap<Instr> for InstrStream<'mem, M, E> {}
 
 impl<'mem, M: Memory, E: Emitter> LdaprStlrUnscaleImmediate<Instr> for InstrStream<'mem, M, E> {}
+impl<'mem, M: Memory, E: Emitter> LdiappStilp<Instr> for InstrStream<'mem, M, E> {}
 
 impl<'mem, M: Memory, E: Emitter> MemoryCopyAndMemorySet<Instr> for InstrStream<'mem, M, E> {}
 
@@ -369,6 +512,9 @@ impl<'mem, M: Memory, E: Emitter> AdvancedSIMDLoadStoreSingleStructures<Instr>
 }
 
 impl<'mem, M: Memory, E: Emitter> AtomicMemoryOperatinos<Instr> for InstrStream<'mem, M, E> {}
+impl<'mem, M: Memory, E: Emitter> AtomicMemoryOperations128<Instr> for InstrStream<'mem, M, E> {}
+impl<'mem, M: Memory, E: Emitter> ReadCheckWrite<Instr> for InstrStream<'mem, M, E> {}
+impl<'mem, M: Memory, E: Emitter> GcsLoadStore<Instr> for InstrStream<'mem, M, E> {}
 
 impl<'mem, M: Memory, E: Emitter> LoadsAndStores<Instr> for InstrStream<'mem, M, E> {}
 
@@ -398,7 +544,12 @@ mod mocking_util {
 
     impl<'mem> InstrStream<'mem, MockMemory, MockEmitter> {
         pub fn new_mocked(mem: &'mem mut MockMemory, emitter: MockEmitter) -> Self {
-            InstrStream { mem, emitter }
+            InstrStream {
+                mem,
+                emitter,
+                labels: Default::default(),
+                veneers: Default::default(),
+            }
         }
     }
 }
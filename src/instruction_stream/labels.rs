@@ -0,0 +1,514 @@
+//! # Labels and branch relaxation
+//!
+//! A [`Label`] is created by [`InstrStream::new_label`] and bound to the current instruction
+//! pointer by [`InstrStream::bind_label`]. Branches to a label that is not yet bound are emitted
+//! with a zero offset and patched as soon as the label is bound.
+//!
+//! `TBZ`/`TBNZ` only reach ±32KB and `CBZ`/`CBNZ`/`B.cond` only ±1MB. If branch relaxation is
+//! enabled by [`InstrStream::set_branch_relaxation`], a conditional branch whose target is out of
+//! range is replaced by the inverted branch jumping over an unconditional `B` to the target:
+//!
+//! ```asm
+//! CBZ X0, <label>     =>  CBNZ X0, #8
+//!                         B <label>
+//! ```
+//!
+//! If the target is not known yet, the `B` is inserted after the branch once the label is bound,
+//! so all code emitted after the branch moves by one instruction. Branches to labels and bound
+//! labels are adjusted accordingly, which may cause further branches to be relaxed.
+//!
+//! **Warning**: Other PC-relative instructions (e.g. `ADR`, `LDR (literal)` or branches to
+//! addresses) are not adjusted. As long as a conditional branch to an unbound label may still be
+//! relaxed, emitting them from an address panics in debug builds, see
+//! [`InstrStream::may_relax`]. Branches to addresses by [`InstrStream::call_addr`] and
+//! [`InstrStream::jump_addr`] are adjusted. The [`Instr`] values returned for moved instructions
+//! point to the old location.
+
+use std::ptr;
+
+use crate::instruction_emitter::Emitter;
+use crate::instruction_encoding::branch_exception_system::compare_and_branch_imm::CompareAndBranchImm;
+use crate::instruction_encoding::branch_exception_system::conditional_branch_imm::ConditionalBranchImmediate;
+use crate::instruction_encoding::branch_exception_system::test_and_branch_imm::TestAndBranchImmediate;
+use crate::instruction_encoding::branch_exception_system::unconditional_branch_immediate::UnconditionalBranchImmediate;
+use crate::instruction_stream::InstrStream;
+use crate::mc_memory::Memory;
+use crate::types::condition::Condition;
+use crate::types::instruction::Instr;
+use crate::types::label::Label;
+use crate::types::register::RegConstr;
+use crate::types::{InstructionPointer, Offset16, Offset32, Register, UImm5};
+
+/// Branch instruction that refers to a label.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) enum BranchKind {
+    B,
+    Bl,
+    BCond(Condition),
+    Cbz32(Register),
+    Cbz64(Register),
+    Cbnz32(Register),
+    Cbnz64(Register),
+    Tbz(RegConstr, UImm5),
+    Tbnz(RegConstr, UImm5),
+}
+
+impl BranchKind {
+    /// Returns true if the branch can reach the byte `offset`.
+    pub(super) fn in_range(&self, offset: i64) -> bool {
+        let range = match self {
+            BranchKind::B | BranchKind::Bl => 1 << 27,
+            BranchKind::Tbz(..) | BranchKind::Tbnz(..) => 1 << 15,
+            _ => 1 << 20,
+        };
+        -range <= offset && offset < range
+    }
+
+    /// Returns the branch with the inverted condition.
+    fn invert(&self) -> BranchKind {
+        match *self {
+            BranchKind::B | BranchKind::Bl => unreachable!("B and BL are never relaxed"),
+            BranchKind::BCond(cond) => BranchKind::BCond(cond.invert()),
+            BranchKind::Cbz32(rt) => BranchKind::Cbnz32(rt),
+            BranchKind::Cbz64(rt) => BranchKind::Cbnz64(rt),
+            BranchKind::Cbnz32(rt) => BranchKind::Cbz32(rt),
+            BranchKind::Cbnz64(rt) => BranchKind::Cbz64(rt),
+            BranchKind::Tbz(rt, imm) => BranchKind::Tbnz(rt, imm),
+            BranchKind::Tbnz(rt, imm) => BranchKind::Tbz(rt, imm),
+        }
+    }
+}
+
+/// Branch to a label, positions are in instructions relative to the base pointer.
+#[derive(Debug, Clone, Copy)]
+struct LabelBranch {
+    pos: usize,
+    kind: BranchKind,
+    label: Label,
+    /// The branch is emitted as inverted branch over a `B` at `pos + 1`
+    relaxed: bool,
+}
+
+/// Bound labels and branches to labels of an [`InstrStream`].
+#[derive(Debug, Default)]
+pub(super) struct LabelTable {
+    /// Positions of the labels in instructions relative to the base pointer, `None` if unbound
+    labels: Vec<Option<usize>>,
+    branches: Vec<LabelBranch>,
+    relaxation: bool,
+}
+
+impl LabelTable {
+    /// Returns true if a branch to an unbound label may still be relaxed, which moves the code
+    /// emitted after it.
+    pub(super) fn may_relax(&self) -> bool {
+        self.relaxation
+            && self.branches.iter().any(|b| {
+                self.labels[b.label.0].is_none()
+                    && !matches!(b.kind, BranchKind::B | BranchKind::Bl)
+            })
+    }
+
+    /// Moves all labels and branches after `pos` by one instruction.
+    ///
+    /// Returns the indices of the branches whose offset changed, i.e. that branch across `pos`.
+    fn insert_after(&mut self, pos: usize) -> Vec<usize> {
+        let moved = |p: usize| p > pos;
+        let labels = &self.labels;
+        let crossing = self
+            .branches
+            .iter()
+            .enumerate()
+            .filter_map(|(i, b)| {
+                let target = labels[b.label.0]?;
+                (moved(b.pos) != moved(target)).then_some(i)
+            })
+            .collect();
+
+        for label in self.labels.iter_mut().flatten() {
+            if moved(*label) {
+                *label += 1;
+            }
+        }
+        for branch in self.branches.iter_mut() {
+            if moved(branch.pos) {
+                branch.pos += 1;
+            }
+        }
+        crossing
+    }
+}
+
+/// Label handling of the `InstrStream`.
+impl<'mem, M: Memory, E: Emitter> InstrStream<'mem, M, E> {
+    /// Creates a new unbound label.
+    pub fn new_label(&mut self) -> Label {
+        self.labels.labels.push(None);
+        Label(self.labels.labels.len() - 1)
+    }
+
+    /// Binds `label` to the current instruction pointer and resolves all branches to it.
+    ///
+    /// Panics if the label is already bound.
+    pub fn bind_label(&mut self, label: Label) {
+        assert!(
+            self.labels.labels[label.0].is_none(),
+            "Label {} is already bound",
+            label.0
+        );
+        self.labels.labels[label.0] = Some(self.current_pos());
+
+        let branches = (0..self.labels.branches.len())
+            .filter(|&i| self.labels.branches[i].label == label)
+            .collect();
+        self.resolve_branches(branches);
+    }
+
+    /// Returns the instruction pointer `label` is bound to, `None` if it is unbound.
+    pub fn label_ptr(&self, label: Label) -> Option<InstructionPointer> {
+        self.labels.labels[label.0].map(|pos| self.ptr_at(pos))
+    }
+
+    /// Returns true if a conditional branch to an unbound label may still be relaxed, which moves
+    /// all code emitted after it by one instruction.
+    ///
+    /// PC-relative instructions to addresses must not be emitted while this is true.
+    pub fn may_relax(&self) -> bool {
+        self.labels.may_relax()
+    }
+
+    /// Enables or disables the relaxation of conditional branches to labels.
+    ///
+    /// If enabled, conditional branches whose label is out of range are replaced by the inverted
+    /// branch over an unconditional `B`. If the label is not bound yet, the `B` is inserted once it
+    /// is bound, which moves all code emitted after the branch, see [`may_relax`](Self::may_relax).
+    /// If disabled, out of range branches panic in debug builds.
+    pub fn set_branch_relaxation(&mut self, enabled: bool) {
+        self.labels.relaxation = enabled;
+    }
+
+    /// [B](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/B--Branch-?lang=en) to `label`
+    ///
+    /// ```asm
+    /// B <label>
+    /// ```
+    pub fn b_to_label(&mut self, label: Label) -> Instr {
+        self.emit_label_branch(BranchKind::B, label)
+    }
+
+    /// [B.cond](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/B-cond--Branch-conditionally-?lang=en) to `label`
+    ///
+    /// ```asm
+    /// B.<cond> <label>
+    /// ```
+    pub fn b_cond_to_label(&mut self, cond: Condition, label: Label) -> Instr {
+        self.emit_label_branch(BranchKind::BCond(cond), label)
+    }
+
+    /// [CBZ](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CBZ--Compare-and-Branch-on-Zero-?lang=en) to `label`
+    ///
+    /// ```asm
+    /// CBZ <Wt>, <label>
+    /// ```
+    pub fn cbz_32_to_label(&mut self, wt: Register, label: Label) -> Instr {
+        self.emit_label_branch(BranchKind::Cbz32(wt), label)
+    }
+
+    /// [CBZ](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CBZ--Compare-and-Branch-on-Zero-?lang=en) to `label`
+    ///
+    /// ```asm
+    /// CBZ <Xt>, <label>
+    /// ```
+    pub fn cbz_64_to_label(&mut self, xt: Register, label: Label) -> Instr {
+        self.emit_label_branch(BranchKind::Cbz64(xt), label)
+    }
+
+    /// [CBNZ](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CBNZ--Compare-and-Branch-on-Nonzero-?lang=en) to `label`
+    ///
+    /// ```asm
+    /// CBNZ <Wt>, <label>
+    /// ```
+    pub fn cbnz_32_to_label(&mut self, wt: Register, label: Label) -> Instr {
+        self.emit_label_branch(BranchKind::Cbnz32(wt), label)
+    }
+
+    /// [CBNZ](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/CBNZ--Compare-and-Branch-on-Nonzero-?lang=en) to `label`
+    ///
+    /// ```asm
+    /// CBNZ <Xt>, <label>
+    /// ```
+    pub fn cbnz_64_to_label(&mut self, xt: Register, label: Label) -> Instr {
+        self.emit_label_branch(BranchKind::Cbnz64(xt), label)
+    }
+
+    /// [TBZ](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/TBZ--Test-bit-and-Branch-if-Zero-?lang=en) to `label`
+    ///
+    /// ```asm
+    /// TBZ <R><t>, #<imm>, <label>
+    /// ```
+    pub fn tbz_to_label(&mut self, rt: RegConstr, imm: UImm5, label: Label) -> Instr {
+        self.emit_label_branch(BranchKind::Tbz(rt, imm), label)
+    }
+
+    /// [TBNZ](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/TBNZ--Test-bit-and-Branch-if-Nonzero-?lang=en) to `label`
+    ///
+    /// ```asm
+    /// TBNZ <R><t>, #<imm>, <label>
+    /// ```
+    pub fn tbnz_to_label(&mut self, rt: RegConstr, imm: UImm5, label: Label) -> Instr {
+        self.emit_label_branch(BranchKind::Tbnz(rt, imm), label)
+    }
+
+    /// Emits the branch with a zero offset, resolves it if the label is already bound and
+    /// returns the resolved branch.
+    pub(super) fn emit_label_branch(&mut self, kind: BranchKind, label: Label) -> Instr {
+        let pos = self.current_pos();
+        self.emit_branch(kind, 0);
+        self.labels.branches.push(LabelBranch {
+            pos,
+            kind,
+            label,
+            relaxed: false,
+        });
+
+        if self.labels.labels[label.0].is_some() {
+            self.resolve_branches(vec![self.labels.branches.len() - 1]);
+        }
+        let pos = self.labels.branches.last().unwrap().pos;
+        self.instr_at(pos)
+    }
+
+    /// Patches the offsets of the given branches and relaxes them if they are out of range.
+    fn resolve_branches(&mut self, mut todo: Vec<usize>) {
+        while let Some(i) = todo.pop() {
+            let branch = self.labels.branches[i];
+            let Some(target) = self.labels.labels[branch.label.0] else {
+                continue;
+            };
+
+            if branch.relaxed {
+                let b_pos = branch.pos + 1;
+                self.patch_branch(b_pos, BranchKind::B, offset_between(b_pos, target));
+                continue;
+            }
+
+            let offset = offset_between(branch.pos, target);
+            let relaxable = !matches!(branch.kind, BranchKind::B | BranchKind::Bl);
+            if branch.kind.in_range(offset) || !self.labels.relaxation || !relaxable {
+                debug_assert!(
+                    branch.kind.in_range(offset),
+                    "Label is out of range of {:?}",
+                    branch.kind
+                );
+                self.patch_branch(branch.pos, branch.kind, offset);
+                continue;
+            }
+
+            if let BranchKind::BCond(Condition::AL | Condition::NV) = branch.kind {
+                // branches always, so it is replaced by `B`
+                self.labels.branches[i].kind = BranchKind::B;
+                self.patch_branch(branch.pos, BranchKind::B, offset);
+                continue;
+            }
+
+            // insert the `B` after the branch and move the following code
+            let end = self.current_pos();
+            self.emitter.emit(0);
+            unsafe {
+                let src = self.ptr_at(branch.pos + 1);
+                ptr::copy(src, src.add(1), end - (branch.pos + 1));
+            }
+            todo.extend(
+                self.labels
+                    .insert_after(branch.pos)
+                    .into_iter()
+                    .filter(|&j| j != i),
+            );
+            self.labels.branches[i].relaxed = true;
+
+            let target = self.labels.labels[branch.label.0].unwrap();
+            self.patch_branch(branch.pos, branch.kind.invert(), 8);
+            self.patch_branch(
+                branch.pos + 1,
+                BranchKind::B,
+                offset_between(branch.pos + 1, target),
+            );
+        }
+    }
+
+    /// Overwrites the instruction at `pos` with the branch to the byte `offset`.
+    fn patch_branch(&mut self, pos: usize, kind: BranchKind, offset: i64) {
+        let iptr = self.emitter.instr_ptr();
+        self.emitter.set_instr_ptr(self.ptr_at(pos));
+        self.emit_branch(kind, offset);
+        self.emitter.set_instr_ptr(iptr);
+    }
+
+    pub(super) fn emit_branch(&mut self, kind: BranchKind, offset: i64) -> Instr {
+        let offset = offset as Offset32;
+        match kind {
+            BranchKind::B => self.b_from_byte_offset(offset),
+            BranchKind::Bl => self.bl_from_byte_offset(offset),
+            BranchKind::BCond(cond) => self.b_cond_from_byte_offset(cond, offset),
+            BranchKind::Cbz32(rt) => self.cbz_32_from_byte_offset(rt, offset),
+            BranchKind::Cbz64(rt) => self.cbz_64_from_byte_offset(rt, offset),
+            BranchKind::Cbnz32(rt) => self.cbnz_32_from_byte_offset(rt, offset),
+            BranchKind::Cbnz64(rt) => self.cbnz_64_from_byte_offset(rt, offset),
+            BranchKind::Tbz(rt, imm) => self.tbz_from_byte_offset(rt, imm, offset as Offset16),
+            BranchKind::Tbnz(rt, imm) => self.tbnz_from_byte_offset(rt, imm, offset as Offset16),
+        }
+    }
+
+    /// Current position in instructions relative to the base pointer.
+    fn current_pos(&self) -> usize {
+        (self.emitter.instr_ptr() as usize - self.base_ptr() as usize) / 4
+    }
+
+    fn ptr_at(&self, pos: usize) -> InstructionPointer {
+        (self.base_ptr() as usize + pos * 4) as InstructionPointer
+    }
+
+    fn instr_at(&self, pos: usize) -> Instr {
+        let ptr = self.ptr_at(pos);
+        Instr::new(unsafe { ptr.read() }, ptr)
+    }
+}
+
+/// Byte offset from the instruction at `from` to the instruction at `to`.
+#[inline(always)]
+fn offset_between(from: usize, to: usize) -> i64 {
+    (to as i64 - from as i64) * 4
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assert_panic;
+    use crate::instruction_encoding::branch_exception_system::unconditional_branch_immediate::UnconditionalBranchImmediateWithAddress;
+    use crate::instruction_encoding::data_proc_imm::pc_rel_addr::PcRelAddressingWithAddress;
+    use crate::instruction_encoding::common_aliases::CommonAliases;
+    use crate::instruction_producer::InstrProducer;
+    use crate::mc_memory::McMemory;
+
+    use super::*;
+
+    /// Returns the instruction at `pos` of the written memory.
+    fn word(stream: &InstrStream<McMemory, crate::instruction_emitter::InstrEmitter>, pos: usize) -> u32 {
+        let mem = stream.written_memory();
+        u32::from_le_bytes(mem[pos * 4..pos * 4 + 4].try_into().unwrap())
+    }
+
+    #[test]
+    fn test_label_branches_in_range() {
+        let mut mem = McMemory::new_pagesize();
+        let mut stream = InstrStream::new(&mut mem);
+        let mut prod = InstrProducer::new();
+
+        let start = stream.new_label();
+        let end = stream.new_label();
+        stream.bind_label(start);
+        stream.cbz_64_to_label(0, end);
+        stream.tbnz_to_label(RegConstr::W(1), 3, end);
+        stream.nop();
+        stream.bind_label(end);
+        let instr = stream.b_cond_to_label(Condition::NE, start);
+
+        assert_eq!(instr.encoding(), prod.b_cond_from_byte_offset(Condition::NE, -12).encoding());
+        assert_eq!(word(&stream, 0), prod.cbz_64_from_byte_offset(0, 12).encoding());
+        assert_eq!(word(&stream, 1), 0x37180041);
+        assert_eq!(stream.label_ptr(end), Some(stream.ptr_at(3)));
+        assert_eq!(stream.written_memory().len(), 16);
+    }
+
+    #[test]
+    fn test_relax_forward_branch() {
+        let mut mem = McMemory::new(0x10000);
+        let mut stream = InstrStream::new(&mut mem);
+        let mut prod = InstrProducer::new();
+        stream.set_branch_relaxation(true);
+
+        let mid = stream.new_label();
+        let far = stream.new_label();
+        stream.cbz_64_to_label(0, mid);
+        stream.tbz_to_label(RegConstr::W(1), 3, far);
+        stream.nop();
+        stream.bind_label(mid);
+        for _ in 0..0x2000 {
+            stream.nop();
+        }
+        stream.b_cond_to_label(Condition::EQ, mid);
+        stream.bind_label(far);
+
+        // the cbz now jumps over the inserted B, the backward b.eq over it is unaffected
+        assert_eq!(word(&stream, 0), prod.cbz_64_from_byte_offset(0, 16).encoding());
+        assert_eq!(word(&stream, 1), 0x37180041);
+        assert_eq!(word(&stream, 2), prod.b_from_byte_offset(0x2003 * 4).encoding());
+        assert_eq!(word(&stream, 3), prod.nop().encoding());
+        assert_eq!(
+            word(&stream, 0x2004),
+            prod.b_cond_from_byte_offset(Condition::EQ, -0x2000 * 4).encoding()
+        );
+        assert_eq!(stream.label_ptr(mid), Some(stream.ptr_at(4)));
+        assert_eq!(stream.label_ptr(far), Some(stream.ptr_at(0x2005)));
+        assert_eq!(stream.written_memory().len(), 0x2005 * 4);
+    }
+
+    #[test]
+    fn test_relax_backward_branch() {
+        let mut mem = McMemory::new(0x110000);
+        let mut stream = InstrStream::new(&mut mem);
+        let mut prod = InstrProducer::new();
+        stream.set_branch_relaxation(true);
+
+        let start = stream.new_label();
+        stream.bind_label(start);
+        for _ in 0..0x2001 {
+            stream.nop();
+        }
+        let instr = stream.tbnz_to_label(RegConstr::X(2), 40, start);
+        assert_eq!(instr.encoding(), prod.tbz_from_byte_offset(RegConstr::X(2), 40, 8).encoding());
+        assert_eq!(word(&stream, 0x2002), prod.b_from_byte_offset(-0x2002 * 4).encoding());
+
+        for _ in 0..0x40000 {
+            stream.nop();
+        }
+        // b.al is replaced by b
+        let instr = stream.b_cond_to_label(Condition::AL, start);
+        assert_eq!(instr.encoding(), prod.b_from_byte_offset(-0x42003 * 4).encoding());
+        assert_eq!(stream.written_memory().len(), 0x42004 * 4);
+    }
+
+    #[test]
+    fn test_relaxation_disabled() {
+        let mut mem = McMemory::new(0x10000);
+        let mut stream = InstrStream::new(&mut mem);
+
+        let far = stream.new_label();
+        stream.tbz_to_label(RegConstr::W(1), 3, far);
+        for _ in 0..0x2000 {
+            stream.nop();
+        }
+        assert_panic!("Should panic: label out of range"; stream.bind_label(far));
+    }
+
+    #[test]
+    fn test_addr_instructions_with_pending_relaxation() {
+        let mut mem = McMemory::new_pagesize();
+        let mut stream = InstrStream::new(&mut mem);
+        let base = stream.base_ptr() as usize;
+        stream.set_branch_relaxation(true);
+
+        let label = stream.new_label();
+        stream.b_to_label(label);
+        assert!(!stream.may_relax());
+
+        stream.cbz_64_to_label(0, label);
+        assert!(stream.may_relax());
+        assert_panic!("Should panic: relaxation pending"; stream.b_to_addr(base));
+        assert_panic!("Should panic: relaxation pending"; stream.adrp_to_addr(0, base));
+        assert_panic!("Should panic: relaxation pending"; stream.load_addr(0, base));
+
+        stream.bind_label(label);
+        assert!(!stream.may_relax());
+        stream.b_to_addr(base);
+    }
+}
@@ -0,0 +1,209 @@
+//! # Patch sessions
+//!
+//! A [`PatchGuard`] created by [`InstrStream::patch_session`] redirects the stream to a region of
+//! already emitted instructions. While the guard is alive, all instructions are emitted into the
+//! region. If the memory is executable, it is made writable for the session. When the guard is
+//! dropped, the instruction pointer and the memory protection are restored.
+//!
+//! The guard does not maintain the instruction cache. If the patched code may already have been
+//! executed, the caller must invalidate the instruction cache for the region before running it
+//! again.
+//!
+//! ```
+//! # use armoured_rust::instruction_encoding::branch_exception_system::unconditional_branch_immediate::{
+//! #     UnconditionalBranchImmediate, UnconditionalBranchImmediateWithAddress,
+//! # };
+//! # use armoured_rust::instruction_stream::InstrStream;
+//! # use armoured_rust::mc_memory::McMemory;
+//! # let mut mem = McMemory::new_pagesize();
+//! # let mut stream = InstrStream::new(&mut mem);
+//! let call = stream.bl_from_byte_offset(0).ptr();
+//! // ...
+//! let target = stream.base_ptr() as usize + 0x40;
+//! let mut patch = stream.patch_session(call, 1);
+//! patch.bl_to_addr(target);
+//! patch.finish().expect("patch exceeds the call");
+//! ```
+
+use std::ops::{Deref, DerefMut};
+
+use crate::instruction_emitter::Emitter;
+use crate::instruction_stream::InstrStream;
+use crate::mc_memory::Memory;
+use crate::types::InstructionPointer;
+
+/// A patch emitted more instructions than the region it replaced.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PatchOverflow {
+    /// Number of instructions of the patched region
+    pub region_len: usize,
+    /// Number of emitted instructions
+    pub emitted: usize,
+}
+
+/// Scoped patch of a region of an [`InstrStream`], created by [`InstrStream::patch_session`].
+///
+/// Dereferences to the patched stream. Dropping the guard without calling
+/// [`finish`](PatchGuard::finish) panics in debug builds if the patch exceeds the region.
+/// The instruction cache is not invalidated when the memory is made executable again.
+#[must_use = "the patch session ends when the guard is dropped"]
+pub struct PatchGuard<'s, 'mem, M: Memory, E: Emitter> {
+    stream: &'s mut InstrStream<'mem, M, E>,
+    /// Instruction pointer of the stream before the session
+    iptr: InstructionPointer,
+    start: InstructionPointer,
+    region_len: usize,
+    was_executable: bool,
+    /// The overflow was already reported by `finish`
+    finished: bool,
+}
+
+impl<'s, 'mem, M: Memory, E: Emitter> PatchGuard<'s, 'mem, M, E> {
+    pub(super) fn new(
+        stream: &'s mut InstrStream<'mem, M, E>,
+        start: InstructionPointer,
+        region_len: usize,
+    ) -> Self {
+        let was_executable = stream.mem.is_executable();
+        if was_executable {
+            stream.mem.make_writable();
+        }
+        let iptr = stream.emitter.instr_ptr();
+        stream.emitter.set_instr_ptr(start);
+
+        PatchGuard {
+            stream,
+            iptr,
+            start,
+            region_len,
+            was_executable,
+            finished: false,
+        }
+    }
+
+    /// Returns the number of instructions emitted in this session.
+    pub fn emitted(&self) -> usize {
+        (self.stream.emitter.instr_ptr() as usize - self.start as usize) / 4
+    }
+
+    /// Ends the session.
+    ///
+    /// Returns an error if more instructions were emitted than the region contains, in which case
+    /// the instructions following the region were overwritten.
+    pub fn finish(mut self) -> Result<(), PatchOverflow> {
+        self.finished = true;
+        let emitted = self.emitted();
+        if emitted > self.region_len {
+            Err(PatchOverflow {
+                region_len: self.region_len,
+                emitted,
+            })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<'s, 'mem, M: Memory, E: Emitter> Deref for PatchGuard<'s, 'mem, M, E> {
+    type Target = InstrStream<'mem, M, E>;
+
+    fn deref(&self) -> &Self::Target {
+        self.stream
+    }
+}
+
+impl<'s, 'mem, M: Memory, E: Emitter> DerefMut for PatchGuard<'s, 'mem, M, E> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.stream
+    }
+}
+
+impl<'s, 'mem, M: Memory, E: Emitter> Drop for PatchGuard<'s, 'mem, M, E> {
+    fn drop(&mut self) {
+        let emitted = self.emitted();
+        self.stream.emitter.set_instr_ptr(self.iptr);
+        if self.was_executable {
+            self.stream.mem.make_executable();
+        }
+        debug_assert!(
+            self.finished || std::thread::panicking() || emitted <= self.region_len,
+            "patch of {} instructions exceeds the region of {} instructions",
+            emitted,
+            self.region_len
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assert_panic;
+    use crate::instruction_encoding::data_proc_imm::mov_wide_imm::MovWideImmediate;
+    use crate::mc_memory::McMemory;
+
+    use super::*;
+
+    #[test]
+    fn test_patch_session() {
+        let mut mem = McMemory::new_pagesize();
+        let mut stream = InstrStream::new(&mut mem);
+
+        let first = stream.movz_64_imm(0, 1);
+        stream.movz_64_imm(1, 2);
+        stream.movz_64_imm(2, 3);
+        stream.make_executable();
+
+        let mut patch = stream.patch_session(first.ptr(), 2);
+        assert!(!patch.mem.is_executable());
+        let instr = patch.movz_64_imm(0, 4);
+        assert_eq!(instr.ptr(), first.ptr());
+        assert_eq!(patch.emitted(), 1);
+        assert_eq!(patch.finish(), Ok(()));
+
+        assert!(stream.mem.is_executable());
+        assert_eq!(stream.written_memory().len(), 12);
+        stream.make_writable();
+        let instr = stream.movz_64_imm(3, 5);
+        assert_eq!(instr.ptr() as usize, stream.base_ptr() as usize + 12);
+    }
+
+    #[test]
+    fn test_patch_session_overflow() {
+        let mut mem = McMemory::new_pagesize();
+        let mut stream = InstrStream::new(&mut mem);
+
+        let first = stream.movz_64_imm(0, 1);
+        stream.movz_64_imm(1, 2);
+
+        let mut patch = stream.patch_session(first.ptr(), 1);
+        patch.movz_64_imm(0, 4);
+        patch.movz_64_imm(1, 5);
+        assert_eq!(
+            patch.finish(),
+            Err(PatchOverflow {
+                region_len: 1,
+                emitted: 2
+            })
+        );
+        assert!(!stream.mem.is_executable());
+        assert_eq!(stream.written_memory().len(), 8);
+    }
+
+    #[test]
+    fn test_patch_session_overflow_on_drop() {
+        let mut mem = McMemory::new_pagesize();
+        let mut stream = InstrStream::new(&mut mem);
+
+        let first = stream.movz_64_imm(0, 1);
+        stream.movz_64_imm(1, 2);
+        stream.make_executable();
+
+        assert_panic!("Should panic: patch exceeds the region"; {
+            let mut patch = stream.patch_session(first.ptr(), 1);
+            patch.movz_64_imm(0, 4);
+            patch.movz_64_imm(1, 5);
+        });
+        // the stream is restored nevertheless
+        assert!(stream.mem.is_executable());
+        assert_eq!(stream.written_memory().len(), 8);
+    }
+}
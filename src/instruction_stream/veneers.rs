@@ -0,0 +1,260 @@
+//! # Veneers
+//!
+//! `B` and `BL` only reach ±128MB. [`InstrStream::call_addr`] and [`InstrStream::jump_addr`] emit
+//! a direct branch if the target is in range. Otherwise they branch to a veneer in a shared veneer
+//! island that is emitted by [`InstrStream::emit_veneers`]. The veneer branches to the target via
+//! `IP0` (`X16`), which may be clobbered by any call according to the procedure call standard:
+//!
+//! ```asm
+//! ADRP X16, <addr>                // if the target is within ±4GB of the veneer
+//! ADD X16, X16, #:lo12:<addr>
+//! BR X16
+//!
+//! LDR X16, <literal>              // otherwise
+//! BR X16
+//! <literal>: .quad <addr>
+//! ```
+//!
+//! Calls and jumps to the same target share a veneer. The veneer island must be emitted where it
+//! is not executed, e.g. after the final `RET`, and within ±128MB of the branches to it. Branches
+//! to new targets after the island was emitted go to the next island.
+//!
+//! If conditional branches to unbound labels may still be relaxed, the branch and the island may
+//! be moved afterwards. So the branch always goes to a veneer, which is adjusted like a branch to
+//! a label, and only the position independent `LDR` veneer is used.
+
+use std::collections::HashMap;
+use std::mem;
+
+use crate::instruction_emitter::Emitter;
+use crate::instruction_encoding::branch_exception_system::unconditional_branch_register::UnconditionalBranchRegister;
+use crate::instruction_encoding::common_aliases::CommonAliases;
+use crate::instruction_encoding::data_proc_imm::add_substract_imm::AddSubtractImmediate;
+use crate::instruction_encoding::data_proc_imm::pc_rel_addr::PcRelAddressingWithAddress;
+use crate::instruction_encoding::loads_and_stores::load_register_literal::LoadRegisterLiteral;
+use crate::instruction_stream::labels::BranchKind;
+use crate::instruction_stream::InstrStream;
+use crate::mc_memory::Memory;
+use crate::types::instruction::Instr;
+use crate::types::label::Label;
+use crate::types::register::IP0;
+
+/// Veneers of an [`InstrStream`].
+#[derive(Debug, Default)]
+pub(super) struct VeneerTable {
+    /// Labels of the veneers by target address
+    targets: HashMap<usize, Label>,
+    /// Targets whose veneers are not emitted yet
+    pending: Vec<usize>,
+}
+
+/// Veneer handling of the `InstrStream`.
+impl<'mem, M: Memory, E: Emitter> InstrStream<'mem, M, E> {
+    /// Calls the function at `addr`.
+    ///
+    /// Emits `BL <addr>` if `addr` is within ±128MB and no branch relaxation is pending, otherwise
+    /// `BL` to the veneer of `addr`.
+    ///
+    /// ```asm
+    /// BL <addr>
+    /// ```
+    pub fn call_addr(&mut self, addr: usize) -> Instr {
+        self.branch_to_addr(BranchKind::Bl, addr)
+    }
+
+    /// Jumps to `addr`.
+    ///
+    /// Emits `B <addr>` if `addr` is within ±128MB and no branch relaxation is pending, otherwise
+    /// `B` to the veneer of `addr`.
+    ///
+    /// ```asm
+    /// B <addr>
+    /// ```
+    pub fn jump_addr(&mut self, addr: usize) -> Instr {
+        self.branch_to_addr(BranchKind::B, addr)
+    }
+
+    /// Emits the veneers of all targets that are branched to since the last veneer island.
+    pub fn emit_veneers(&mut self) {
+        for addr in mem::take(&mut self.veneers.pending) {
+            let label = self.veneers.targets[&addr];
+            self.bind_label(label);
+            self.emit_veneer(addr);
+        }
+    }
+
+    fn branch_to_addr(&mut self, kind: BranchKind, addr: usize) -> Instr {
+        debug_assert!(addr % 4 == 0, "addr must be 4 byte aligned!");
+        let pc = self.emitter.instr_ptr() as usize;
+        let offset = (addr as i64).wrapping_sub(pc as i64);
+        // a direct branch would not be adjusted if the code is moved by a relaxation
+        if kind.in_range(offset) && !self.labels.may_relax() {
+            return self.emit_branch(kind, offset);
+        }
+
+        let reusable = self.veneers.targets.get(&addr).copied().filter(|&label| {
+            self.label_ptr(label)
+                .is_none_or(|ptr| kind.in_range(ptr as i64 - pc as i64))
+        });
+        let label = match reusable {
+            Some(label) => label,
+            None => {
+                let label = self.new_label();
+                self.veneers.targets.insert(addr, label);
+                self.veneers.pending.push(addr);
+                label
+            }
+        };
+        self.emit_label_branch(kind, label)
+    }
+
+    fn emit_veneer(&mut self, addr: usize) {
+        let pc = self.emitter.instr_ptr() as i64;
+        let page_offset = (addr as i64 & !0xfff).wrapping_sub(pc & !0xfff);
+
+        if !self.labels.may_relax() && (-(1 << 32)..(1 << 32)).contains(&page_offset) {
+            self.adrp_to_addr(IP0, addr);
+            self.add_64_imm(IP0, IP0, (addr & 0xfff) as u16);
+            self.br(IP0);
+        } else {
+            // align the literal to 8 bytes
+            if pc % 8 != 0 {
+                self.nop();
+            }
+            self.ldr_64_pc_rel_from_byte_offset(IP0, 8);
+            self.br(IP0);
+            self.emit(addr as u32);
+            self.emit((addr as u64 >> 32) as u32);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::instruction_emitter::InstrEmitter;
+    use crate::instruction_encoding::branch_exception_system::unconditional_branch_immediate::UnconditionalBranchImmediate;
+    use crate::instruction_encoding::branch_exception_system::unconditional_branch_register::UnconditionalBranchRegister;
+    use crate::instruction_encoding::data_proc_imm::pc_rel_addr::PcRelAddressing;
+    use crate::instruction_producer::InstrProducer;
+    use crate::instruction_encoding::branch_exception_system::test_and_branch_imm::TestAndBranchImmediate;
+    use crate::mc_memory::McMemory;
+    use crate::types::register::RegConstr;
+
+    use super::*;
+
+    /// Returns the instruction at `pos` of the written memory.
+    fn word(stream: &InstrStream<McMemory, InstrEmitter>, pos: usize) -> u32 {
+        let mem = stream.written_memory();
+        u32::from_le_bytes(mem[pos * 4..pos * 4 + 4].try_into().unwrap())
+    }
+
+    #[test]
+    fn test_call_addr_in_range() {
+        let mut mem = McMemory::new_pagesize();
+        let mut stream = InstrStream::new(&mut mem);
+        let base = stream.base_ptr() as usize;
+
+        let instr = stream.call_addr(base + 0x100);
+        assert_eq!(instr.to_string(), format!("bl {:#x}", base + 0x100));
+        let instr = stream.jump_addr(base - 0x100);
+        assert_eq!(instr.to_string(), format!("b {:#x}", base - 0x100));
+
+        stream.emit_veneers();
+        assert_eq!(stream.written_memory().len(), 8);
+    }
+
+    #[test]
+    fn test_veneers() {
+        let mut mem = McMemory::new_pagesize();
+        let mut stream = InstrStream::new(&mut mem);
+        let mut prod = InstrProducer::new();
+        let base = stream.base_ptr() as usize;
+        let near = base.wrapping_add(0x1000_1230);
+        let far = base.wrapping_add(0x10_0000_0000);
+
+        stream.call_addr(near);
+        stream.jump_addr(far);
+        stream.call_addr(far);
+        stream.ret();
+        stream.emit_veneers();
+
+        // branches to the veneers, the veneer of far is shared
+        assert_eq!(word(&stream, 0), prod.bl_from_byte_offset(16).encoding());
+        assert_eq!(word(&stream, 1), prod.b_from_byte_offset(24).encoding());
+        assert_eq!(word(&stream, 2), prod.bl_from_byte_offset(20).encoding());
+        assert_eq!(word(&stream, 3), prod.ret().encoding());
+
+        // veneer of near at 4
+        let page_offset = (near & !0xfff) as i64 - (base & !0xfff) as i64;
+        assert_eq!(word(&stream, 4), prod.adrp_from_byte_offset(16, page_offset).encoding());
+        assert_eq!(word(&stream, 5), prod.add_64_imm(16, 16, 0x230).encoding());
+        assert_eq!(word(&stream, 6), prod.br(16).encoding());
+
+        // veneer of far at 7, the literal is aligned to 8 bytes
+        assert_eq!(word(&stream, 7), prod.nop().encoding());
+        assert_eq!(word(&stream, 8), prod.ldr_64_pc_rel_from_byte_offset(16, 8).encoding());
+        assert_eq!(word(&stream, 9), prod.br(16).encoding());
+        assert_eq!(word(&stream, 10), far as u32);
+        assert_eq!(word(&stream, 11), (far as u64 >> 32) as u32);
+
+        // the veneer of an emitted island is reused
+        let instr = stream.jump_addr(near);
+        assert_eq!(instr.encoding(), prod.b_from_byte_offset(-32).encoding());
+        stream.emit_veneers();
+        assert_eq!(stream.written_memory().len(), 13 * 4);
+    }
+
+    #[test]
+    fn test_veneers_with_pending_relaxation() {
+        let mut mem = McMemory::new_pagesize();
+        let mut stream = InstrStream::new(&mut mem);
+        let mut prod = InstrProducer::new();
+        let base = stream.base_ptr() as usize;
+        let near = base.wrapping_add(0x1000_0000);
+        stream.set_branch_relaxation(true);
+
+        let label = stream.new_label();
+        stream.cbz_64_to_label(0, label);
+        stream.call_addr(near);
+        stream.emit_veneers();
+        stream.bind_label(label);
+
+        // the veneer may be moved, so the literal veneer is used
+        assert_eq!(word(&stream, 2), prod.ldr_64_pc_rel_from_byte_offset(16, 8).encoding());
+        assert_eq!(word(&stream, 3), prod.br(16).encoding());
+        assert_eq!(word(&stream, 4), near as u32);
+        assert_eq!(stream.label_ptr(label).map(|ptr| ptr as usize), Some(base + 24));
+    }
+
+    #[test]
+    fn test_call_addr_with_pending_relaxation() {
+        let mut mem = McMemory::new(0x10000);
+        let mut stream = InstrStream::new(&mut mem);
+        let mut prod = InstrProducer::new();
+        let base = stream.base_ptr() as usize;
+        stream.set_branch_relaxation(true);
+
+        let label = stream.new_label();
+        stream.tbz_to_label(RegConstr::W(1), 3, label);
+        stream.call_addr(base + 0x100);
+        for _ in 0..0x2100 {
+            stream.nop();
+        }
+        // relaxes the tbz, which moves the call down by one instruction
+        stream.bind_label(label);
+        stream.emit_veneers();
+
+        // the call was moved and goes through the veneer instead of landing on base + 0x104
+        assert_eq!(word(&stream, 0), prod.tbnz_from_byte_offset(RegConstr::W(1), 3, 8).encoding());
+        let veneer = 0x2103;
+        assert_eq!(word(&stream, 2), prod.bl_from_byte_offset((veneer - 2) * 4).encoding());
+        let page_offset = ((base + 0x100) & !0xfff) as i64 - ((base + veneer as usize * 4) & !0xfff) as i64;
+        assert_eq!(word(&stream, veneer as usize), prod.adrp_from_byte_offset(16, page_offset).encoding());
+        assert_eq!(word(&stream, veneer as usize + 1), prod.add_64_imm(16, 16, ((base + 0x100) & 0xfff) as u16).encoding());
+        assert_eq!(word(&stream, veneer as usize + 2), prod.br(16).encoding());
+
+        // without pending relaxation the call is direct
+        let instr = stream.call_addr(base + 0x100);
+        assert_eq!(instr.to_string(), format!("bl {:#x}", base + 0x100));
+    }
+}
@@ -4,6 +4,8 @@ use crate::instruction_encoding::branch_exception_system::barriers::Barriers;
 use crate::instruction_encoding::branch_exception_system::compare_and_branch_imm::CompareAndBranchImm;
 use crate::instruction_encoding::branch_exception_system::conditional_branch_imm::ConditionalBranchImmediate;
 use crate::instruction_encoding::branch_exception_system::exception_generation::ExceptionGeneration;
+use crate::instruction_encoding::branch_exception_system::hints::Hints;
+use crate::instruction_encoding::branch_exception_system::tme::TransactionalMemoryExtension;
 use crate::instruction_encoding::branch_exception_system::pstate::PStateInstructions;
 use crate::instruction_encoding::branch_exception_system::system_instr_w_register_arg::SystemInstructionsWithRegArg;
 use crate::instruction_encoding::branch_exception_system::system_instructions::SystemInstructions;
@@ -12,11 +14,14 @@ use crate::instruction_encoding::branch_exception_system::test_and_branch_imm::T
 use crate::instruction_encoding::branch_exception_system::unconditional_branch_immediate::UnconditionalBranchImmediate;
 use crate::instruction_encoding::branch_exception_system::unconditional_branch_register::UnconditionalBranchRegister;
 use crate::instruction_encoding::branch_exception_system::BranchExceptionSystem;
+use crate::instruction_encoding::atomic_macro_ops::AtomicMacroOperations;
+use crate::instruction_encoding::shadow_stack::ShadowStack;
 use crate::instruction_encoding::common_aliases::CommonAliases;
 use crate::instruction_encoding::data_proc_imm::add_substract_imm::AddSubtractImmediate;
 use crate::instruction_encoding::data_proc_imm::bitfield::BitfieldInstructions;
 use crate::instruction_encoding::data_proc_imm::extract::ExtractInstructions;
 use crate::instruction_encoding::data_proc_imm::logical_imm::LogicalImmediate;
+use crate::instruction_encoding::data_proc_imm::min_max_imm::MinMaxImmediate;
 use crate::instruction_encoding::data_proc_imm::mov_wide_imm::MovWideImmediate;
 use crate::instruction_encoding::data_proc_imm::pc_rel_addr::PcRelAddressing;
 use crate::instruction_encoding::data_proc_imm::DataProcessingImmediate;
@@ -33,12 +38,22 @@ use crate::instruction_encoding::data_proc_reg::evaluate_into_flags::EvaluateInt
 use crate::instruction_encoding::data_proc_reg::logical_shift_reg::LogicalShiftRegister;
 use crate::instruction_encoding::data_proc_reg::rotate_right_into_flags::RotateRightIntoFlags;
 use crate::instruction_encoding::data_proc_reg::DataProcessingRegister;
+use crate::instruction_encoding::data_proc_simd_fp::advanced_simd_three_same::AdvancedSIMDThreeSame;
+use crate::instruction_encoding::data_proc_simd_fp::advanced_simd_three_same_extra::AdvancedSIMDThreeSameExtra;
+use crate::instruction_encoding::data_proc_simd_fp::advanced_simd_two_reg_misc::AdvancedSIMDTwoRegisterMiscellaneous;
+use crate::instruction_encoding::data_proc_simd_fp::advanced_simd_vec_x_indexed_elem::AdvancedSIMDVectorXIndexedElement;
+use crate::instruction_encoding::data_proc_simd_fp::fp_data_proc_one_src::FloatingPointDataProcessingOneSource;
+use crate::instruction_encoding::data_proc_simd_fp::DataProcessingSIMDFloatingPoint;
 use crate::instruction_encoding::loads_and_stores::advanced_simd_ldr_str_multi_structures::AdvancedSIMDLoadStoreMultipleStructures;
 use crate::instruction_encoding::loads_and_stores::advanced_simd_ldr_str_single_structures::AdvancedSIMDLoadStoreSingleStructures;
 use crate::instruction_encoding::loads_and_stores::atomic_memory_operations::AtomicMemoryOperatinos;
+use crate::instruction_encoding::loads_and_stores::atomic_memory_operations_128::AtomicMemoryOperations128;
+use crate::instruction_encoding::loads_and_stores::read_check_write::ReadCheckWrite;
+use crate::instruction_encoding::loads_and_stores::gcs_load_store::GcsLoadStore;
 use crate::instruction_encoding::loads_and_stores::compare_and_swap::CompareAndSwap;
 use crate::instruction_encoding::loads_and_stores::compare_and_swap_pair::CompareAndSwapPair;
 use crate::instruction_encoding::loads_and_stores::ldapr_stlr_unscale_imm::LdaprStlrUnscaleImmediate;
+use crate::instruction_encoding::loads_and_stores::ldiapp_stilp::LdiappStilp;
 use crate::instruction_encoding::loads_and_stores::load_register_literal::LoadRegisterLiteral;
 use crate::instruction_encoding::loads_and_stores::load_store_exclusive_pair::LoadStoreExclusivePair;
 use crate::instruction_encoding::loads_and_stores::load_store_exclusive_register::LoadStoreExclusiveRegister;
@@ -56,6 +71,23 @@ use crate::instruction_encoding::loads_and_stores::load_store_register_regoffset
 use crate::instruction_encoding::loads_and_stores::load_store_register_unsigned_imm::LoadStoreRegisterUnsignedImmediate;
 use crate::instruction_encoding::loads_and_stores::memory_copy_and_memory_set::MemoryCopyAndMemorySet;
 use crate::instruction_encoding::loads_and_stores::LoadsAndStores;
+use crate::instruction_encoding::sme::outer_product::SmeOuterProduct;
+use crate::instruction_encoding::sme::streaming_mode::SmeStreamingMode;
+use crate::instruction_encoding::sme::tile_load_store::SmeTileLoadStore;
+use crate::instruction_encoding::sme::tile_move::SmeTileMove;
+use crate::instruction_encoding::sme::SmeInstructionSet;
+use crate::instruction_encoding::sve::contiguous_load_store::SveContiguousLoadStore;
+use crate::instruction_encoding::sve::gather_scatter::SveGatherScatter;
+use crate::instruction_encoding::sve::fp_arith::SveFloatingPointArithmetic;
+use crate::instruction_encoding::sve::fp_compare::SveFloatingPointCompare;
+use crate::instruction_encoding::sve::fp_convert::SveFloatingPointConvert;
+use crate::instruction_encoding::sve::sve2_match_lookup::Sve2MatchAndLookup;
+use crate::instruction_encoding::sve::sve2_widening_narrowing::Sve2WideningNarrowing;
+use crate::instruction_encoding::sve::element_count::SveElementCount;
+use crate::instruction_encoding::sve::index_generation::SveIndexGeneration;
+use crate::instruction_encoding::sve::int_arith::SveIntegerArithmetic;
+use crate::instruction_encoding::sve::predicates::SvePredicates;
+use crate::instruction_encoding::sve::SveInstructionSet;
 use crate::instruction_encoding::{InstructionProcessor, InstructionSet};
 use crate::types::Instruction;
 
@@ -96,6 +128,8 @@ impl BitfieldInstructions<InstrRes> for TestProducer {}
 
 impl ExtractInstructions<InstrRes> for TestProducer {}
 
+impl MinMaxImmediate<InstrRes> for TestProducer {}
+
 impl CompareAndBranchImm<InstrRes> for TestProducer {}
 
 impl TestAndBranchImmediate<InstrRes> for TestProducer {}
@@ -110,6 +144,10 @@ impl SystemInstructionsWithRegArg<InstrRes> for TestProducer {}
 
 impl Barriers<InstrRes> for TestProducer {}
 
+impl Hints<InstrRes> for TestProducer {}
+
+impl TransactionalMemoryExtension<InstrRes> for TestProducer {}
+
 impl PStateInstructions<InstrRes> for TestProducer {}
 
 impl SystemInstructions<InstrRes> for TestProducer {}
@@ -131,6 +169,7 @@ impl LoadStoreOrdered<InstrRes> for TestProducer {}
 impl CompareAndSwap<InstrRes> for TestProducer {}
 
 impl LdaprStlrUnscaleImmediate<InstrRes> for TestProducer {}
+impl LdiappStilp<InstrRes> for TestProducer {}
 
 impl MemoryCopyAndMemorySet<InstrRes> for TestProducer {}
 
@@ -157,6 +196,9 @@ impl AdvancedSIMDLoadStoreMultipleStructures<InstrRes> for TestProducer {}
 impl AdvancedSIMDLoadStoreSingleStructures<InstrRes> for TestProducer {}
 
 impl AtomicMemoryOperatinos<InstrRes> for TestProducer {}
+impl AtomicMemoryOperations128<InstrRes> for TestProducer {}
+impl ReadCheckWrite<InstrRes> for TestProducer {}
+impl GcsLoadStore<InstrRes> for TestProducer {}
 
 impl LoadsAndStores<InstrRes> for TestProducer {}
 
@@ -192,6 +234,55 @@ impl DataProcessingRegister<InstrRes> for TestProducer {}
 
 impl DataProcessingTwoSource<InstrRes> for TestProducer {}
 
+impl AdvancedSIMDThreeSame<InstrRes> for TestProducer {}
+
+impl AdvancedSIMDThreeSameExtra<InstrRes> for TestProducer {}
+
+impl AdvancedSIMDTwoRegisterMiscellaneous<InstrRes> for TestProducer {}
+
+impl AdvancedSIMDVectorXIndexedElement<InstrRes> for TestProducer {}
+
+impl FloatingPointDataProcessingOneSource<InstrRes> for TestProducer {}
+
+impl DataProcessingSIMDFloatingPoint<InstrRes> for TestProducer {}
+
+impl SmeOuterProduct<InstrRes> for TestProducer {}
+
+impl SmeStreamingMode<InstrRes> for TestProducer {}
+
+impl SmeTileLoadStore<InstrRes> for TestProducer {}
+
+impl SmeTileMove<InstrRes> for TestProducer {}
+
+impl SmeInstructionSet<InstrRes> for TestProducer {}
+
+impl SveContiguousLoadStore<InstrRes> for TestProducer {}
+
+impl SveGatherScatter<InstrRes> for TestProducer {}
+
+impl SveFloatingPointArithmetic<InstrRes> for TestProducer {}
+
+impl SveFloatingPointCompare<InstrRes> for TestProducer {}
+
+impl SveFloatingPointConvert<InstrRes> for TestProducer {}
+
+impl Sve2MatchAndLookup<InstrRes> for TestProducer {}
+
+impl Sve2WideningNarrowing<InstrRes> for TestProducer {}
+
+impl SveElementCount<InstrRes> for TestProducer {}
+
+impl SveIndexGeneration<InstrRes> for TestProducer {}
+
+impl SveIntegerArithmetic<InstrRes> for TestProducer {}
+
+impl SvePredicates<InstrRes> for TestProducer {}
+
+impl SveInstructionSet<InstrRes> for TestProducer {}
+
 impl CommonAliases<InstrRes> for TestProducer {}
 
+impl AtomicMacroOperations<InstrRes> for TestProducer {}
+impl ShadowStack<InstrRes> for TestProducer {}
+
 impl InstructionSet<InstrRes> for TestProducer {}
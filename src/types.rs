@@ -3,17 +3,25 @@ use crate::types::HW::{LSL0, LSL16, LSL32, LSL48};
 mod type_creation_macro;
 
 pub mod arr_specifier;
+pub mod atomic;
 pub mod bitmask_immediate;
+pub mod bti_target;
 pub mod condition;
 pub mod encodable;
 pub mod extends;
 pub mod instruction;
+pub mod label;
 pub mod mem_barrier_option;
+pub mod mops;
 pub mod prefetch_memory;
 pub mod pstate;
 pub mod register;
+pub mod rotation;
 pub mod shifts;
+pub mod sme;
+pub mod sve;
 pub mod sys_ops;
+pub mod sys_reg;
 
 pub type Instruction = u32;
 pub type InstructionPointer = *mut Instruction;
@@ -31,6 +39,7 @@ pub type Imm12 = u16;
 pub type Imm11 = i16;
 pub type Imm10 = i16;
 pub type Imm9 = i16;
+pub type Imm8 = i8;
 pub type Imm6 = u8;
 pub type Imm5 = u8;
 
@@ -43,6 +52,8 @@ pub type UImm14 = u16;
 pub type UImm13 = u16;
 pub type UImm12 = u16;
 pub type UImm10 = u16;
+pub type UImm8 = u8;
+pub type UImm7 = u8;
 pub type UImm6 = u8;
 pub type UImm5 = u8;
 pub type UImm4 = u8;
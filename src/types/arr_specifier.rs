@@ -48,3 +48,27 @@ make_enum!(
 impl ArrSpec for ArrSpecX {}
 
 impl ArrSpec for ArrSpec1 {}
+
+// Arrangement specifier for vectors of 32-bit elements
+make_enum!(ArrSpecS, [(T2S, 0b100), (T4S, 0b101)]);
+
+impl ArrSpec for ArrSpecS {}
+
+// Arrangement specifier for vectors of 16-bit elements
+make_enum!(ArrSpecH, [(T4H, 0b010), (T8H, 0b011)]);
+
+impl ArrSpec for ArrSpecH {}
+
+// Arrangement specifier for vectors of half-, single- or double-precision floating-point elements
+make_enum!(
+    ArrSpecHSD,
+    [
+        (T4H, 0b010),
+        (T8H, 0b011),
+        (T2S, 0b100),
+        (T4S, 0b101),
+        (T2D, 0b111)
+    ]
+);
+
+impl ArrSpec for ArrSpecHSD {}
@@ -0,0 +1,88 @@
+//! Operands of the generic atomic read-modify-write instruction `atomic_rmw`.
+
+use std::sync::atomic::Ordering;
+
+/// Operation performed by an atomic read-modify-write on memory.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum AtomicRmwOp {
+    ADD,
+    CLR,
+    EOR,
+    SET,
+    SMAX,
+    SMIN,
+    UMAX,
+    UMIN,
+    SWP,
+}
+
+/// Size of the memory location accessed by an atomic read-modify-write.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum AtomicSize {
+    /// 8-bit byte
+    B,
+    /// 16-bit halfword
+    H,
+    /// 32-bit word
+    W,
+    /// 64-bit doubleword
+    X,
+}
+
+impl AtomicRmwOp {
+    /// Returns (o3, opc)
+    pub fn encode(&self) -> (u8, u8) {
+        match self {
+            AtomicRmwOp::ADD => (0, 0b000),
+            AtomicRmwOp::CLR => (0, 0b001),
+            AtomicRmwOp::EOR => (0, 0b010),
+            AtomicRmwOp::SET => (0, 0b011),
+            AtomicRmwOp::SMAX => (0, 0b100),
+            AtomicRmwOp::SMIN => (0, 0b101),
+            AtomicRmwOp::UMAX => (0, 0b110),
+            AtomicRmwOp::UMIN => (0, 0b111),
+            AtomicRmwOp::SWP => (1, 0b000),
+        }
+    }
+}
+
+impl AtomicSize {
+    pub fn encode(&self) -> u8 {
+        match self {
+            AtomicSize::B => 0b00,
+            AtomicSize::H => 0b01,
+            AtomicSize::W => 0b10,
+            AtomicSize::X => 0b11,
+        }
+    }
+}
+
+/// Maps a memory ordering to the acquire and release bits of an atomic instruction.
+///
+/// `Relaxed` selects the plain, `Acquire` the A, `Release` the L and `AcqRel` as well as `SeqCst`
+/// the AL variant of the instruction.
+///
+/// Returns (A, R)
+pub fn encode_ordering(ordering: Ordering) -> (u8, u8) {
+    match ordering {
+        Ordering::Relaxed => (0, 0),
+        Ordering::Acquire => (1, 0),
+        Ordering::Release => (0, 1),
+        Ordering::AcqRel | Ordering::SeqCst => (1, 1),
+        _ => unreachable!("unknown memory ordering"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_ordering() {
+        assert_eq!(encode_ordering(Ordering::Relaxed), (0, 0));
+        assert_eq!(encode_ordering(Ordering::Acquire), (1, 0));
+        assert_eq!(encode_ordering(Ordering::Release), (0, 1));
+        assert_eq!(encode_ordering(Ordering::AcqRel), (1, 1));
+        assert_eq!(encode_ordering(Ordering::SeqCst), (1, 1));
+    }
+}
@@ -0,0 +1,30 @@
+use crate::types::encodable::Encodable;
+use crate::types::UImm2;
+
+/// # BTI Target
+///
+/// Type of indirection that is allowed to land on a
+/// [BTI](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/BTI--Branch-Target-Identification-?lang=en)
+/// instruction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BtiTarget {
+    /// No indirect branch may target this instruction
+    None,
+    /// Indirect calls (`BLR`) and `BR` via X16/X17
+    C,
+    /// Indirect jumps (`BR`)
+    J,
+    /// Indirect calls and jumps
+    JC,
+}
+
+impl Encodable<UImm2> for BtiTarget {
+    fn encode(&self) -> UImm2 {
+        match self {
+            BtiTarget::None => 0b00,
+            BtiTarget::C => 0b01,
+            BtiTarget::J => 0b10,
+            BtiTarget::JC => 0b11,
+        }
+    }
+}
@@ -3,6 +3,7 @@ use Condition::*;
 use crate::types::encodable::Encodable;
 use crate::types::UImm4;
 
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Condition {
     EQ,
     NE,
@@ -24,6 +25,35 @@ pub enum Condition {
     NV,
 }
 
+impl Condition {
+    /// Returns the inverted condition, e.g. `NE` for `EQ`.
+    ///
+    /// The inverted condition is encoded by flipping the least significant bit of the encoding,
+    /// so `AL` and `NV` are inverted into each other, even though both mean *always*.
+    pub fn invert(self) -> Self {
+        match self {
+            EQ => NE,
+            NE => EQ,
+            CS => CC,
+            HS => LO,
+            CC => CS,
+            LO => HS,
+            MI => PL,
+            PL => MI,
+            VS => VC,
+            VC => VS,
+            HI => LS,
+            LS => HI,
+            GE => LT,
+            LT => GE,
+            GT => LE,
+            LE => GT,
+            AL => NV,
+            NV => AL,
+        }
+    }
+}
+
 impl Encodable<UImm4> for Condition {
     fn encode(&self) -> UImm4 {
         match self {
@@ -116,4 +146,19 @@ mod tests {
             assert_eq!(instr.to_string(), "b.nv 0x0");
         })
     }
+
+    #[test]
+    fn test_invert() {
+        let all = [
+            EQ, NE, CS, HS, CC, LO, MI, PL, VS, VC, HI, LS, GE, LT, GT, LE, AL, NV,
+        ];
+        for cond in all {
+            assert_eq!(cond.invert().encode(), cond.encode() ^ 1);
+            assert_eq!(cond.invert().invert(), cond);
+        }
+
+        assert_eq!(EQ.invert(), NE);
+        assert_eq!(HS.invert(), LO);
+        assert_eq!(GE.invert(), LT);
+    }
 }
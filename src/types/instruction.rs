@@ -17,6 +17,10 @@ impl Instr {
         self.enc
     }
 
+    pub fn ptr(&self) -> InstructionPointer {
+        self.ptr
+    }
+
     pub fn to_string(&self) -> String {
         let Ok(decoded) = decode(self.enc, self.ptr as u64) else {
             let encoding = self.enc.to_le_bytes();
@@ -0,0 +1,10 @@
+//! Labels of an [`InstrStream`](crate::instruction_stream::InstrStream).
+
+/// A position in an [`InstrStream`](crate::instruction_stream::InstrStream) that branches can
+/// refer to before it is known.
+///
+/// Labels are created by [`InstrStream::new_label`](crate::instruction_stream::InstrStream::new_label)
+/// and bound to a position by [`InstrStream::bind_label`](crate::instruction_stream::InstrStream::bind_label).
+/// A label must only be used with the stream that created it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Label(pub(crate) usize);
@@ -0,0 +1,76 @@
+//! Options of the memory copy and memory set macro-operations `memcpy`, `memmove` and `memset`.
+
+/// Selects the variant of the memory copy and memory set instructions that is emitted.
+///
+/// The read options only apply to memory copies and are ignored by memory sets, `set_tags` only
+/// applies to memory sets. For memory sets `unprivileged_writes` and `non_temporal_writes` select
+/// the `T` and `N` variants.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct MopsOptions {
+    /// Reads are performed as if executed at EL0 (`RT`)
+    pub unprivileged_reads: bool,
+    /// Writes are performed as if executed at EL0 (`WT`)
+    pub unprivileged_writes: bool,
+    /// Reads are non-temporal (`RN`)
+    pub non_temporal_reads: bool,
+    /// Writes are non-temporal (`WN`)
+    pub non_temporal_writes: bool,
+    /// The memory set also sets the allocation tags (`SETG*`), requires FEAT_MTE
+    pub set_tags: bool,
+}
+
+impl MopsOptions {
+    /// Reads and writes are performed as if executed at EL0.
+    pub const UNPRIVILEGED: MopsOptions = MopsOptions {
+        unprivileged_reads: true,
+        unprivileged_writes: true,
+        non_temporal_reads: false,
+        non_temporal_writes: false,
+        set_tags: false,
+    };
+
+    /// Reads and writes are non-temporal.
+    pub const NON_TEMPORAL: MopsOptions = MopsOptions {
+        unprivileged_reads: false,
+        unprivileged_writes: false,
+        non_temporal_reads: true,
+        non_temporal_writes: true,
+        set_tags: false,
+    };
+
+    /// Returns the `op2` field of a memory copy instruction, `RN:WN:RT:WT`.
+    pub fn encode_cpy(&self) -> u8 {
+        (self.non_temporal_reads as u8) << 3
+            | (self.non_temporal_writes as u8) << 2
+            | (self.unprivileged_reads as u8) << 1
+            | self.unprivileged_writes as u8
+    }
+
+    /// Returns the lower two bits of the `op2` field of a memory set instruction, `N:T`.
+    pub fn encode_set(&self) -> u8 {
+        (self.non_temporal_writes as u8) << 1 | self.unprivileged_writes as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode() {
+        assert_eq!(MopsOptions::default().encode_cpy(), 0b0000);
+        assert_eq!(MopsOptions::UNPRIVILEGED.encode_cpy(), 0b0011);
+        assert_eq!(MopsOptions::NON_TEMPORAL.encode_cpy(), 0b1100);
+
+        let opts = MopsOptions {
+            unprivileged_writes: true,
+            non_temporal_reads: true,
+            ..Default::default()
+        };
+        assert_eq!(opts.encode_cpy(), 0b1001);
+
+        assert_eq!(MopsOptions::default().encode_set(), 0b00);
+        assert_eq!(MopsOptions::UNPRIVILEGED.encode_set(), 0b01);
+        assert_eq!(MopsOptions::NON_TEMPORAL.encode_set(), 0b10);
+    }
+}
@@ -60,3 +60,24 @@ impl PrfPolicy {
         }
     }
 }
+
+/// Range prefetch operation of the `RPRFM` instruction.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum RprfOp {
+    PLDKEEP,
+    PSTKEEP,
+    PLDSTRM,
+    PSTSTRM,
+}
+
+impl RprfOp {
+    /// Returns the 6-bit `rprfop` field, `option<2>:option<0>:S:Rt<2:0>`
+    pub fn encode(&self) -> u8 {
+        match self {
+            RprfOp::PLDKEEP => 0b000000,
+            RprfOp::PSTKEEP => 0b000001,
+            RprfOp::PLDSTRM => 0b000100,
+            RprfOp::PSTSTRM => 0b000101,
+        }
+    }
+}
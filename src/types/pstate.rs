@@ -7,6 +7,9 @@ use crate::types::encodable::Encodable;
 /// For more information check the [arm64 docs](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/MSR--immediate---Move-immediate-value-to-Special-Register-?lang=en#sa_pstatefield).
 ///
 /// **Warning**: `ALLINT` is not tested
+///
+/// The `SVCR*` fields are used by the `SMSTART` and `SMSTOP` aliases and are printed as such by
+/// the disassembler.
 #[derive(Debug, PartialEq)]
 pub enum PStateField {
     UAO,
@@ -19,6 +22,9 @@ pub enum PStateField {
     TCO,
     DAIFSet,
     DAIFClr,
+    SVCRSM,
+    SVCRZA,
+    SVCRSMZA,
 }
 
 impl Encodable<(u8, u8)> for PStateField {
@@ -36,6 +42,7 @@ impl Encodable<(u8, u8)> for PStateField {
             TCO => (0b011, 0b100),
             DAIFSet => (0b011, 0b110),
             DAIFClr => (0b011, 0b111),
+            SVCRSM | SVCRZA | SVCRSMZA => (0b011, 0b011),
         }
     }
 }
@@ -77,6 +84,15 @@ mod tests {
 
             let instr = stream.msr_imm(DAIFClr, 0x0);
             assert_eq!(instr.to_string(), "msr daifclr, #0x0");
+
+            let instr = stream.msr_imm(SVCRSM, 0x1);
+            assert_eq!(instr.to_string(), "smstart SM");
+
+            let instr = stream.msr_imm(SVCRZA, 0x0);
+            assert_eq!(instr.to_string(), "smstop ZA");
+
+            let instr = stream.msr_imm(SVCRSMZA, 0x1);
+            assert_eq!(instr.to_string(), "smstart");
         })
     }
 }
@@ -1,5 +1,6 @@
 use crate::types::Register;
 
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum RegConstr {
     W(Register),
     X(Register),
@@ -8,3 +9,6 @@ pub enum RegConstr {
 pub const WZR: Register = 31;
 pub const XZR: Register = 31;
 pub const RSP: Register = 31;
+
+/// Intra-procedure-call scratch register, may be clobbered by veneers
+pub const IP0: Register = 16;
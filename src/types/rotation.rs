@@ -0,0 +1,44 @@
+use crate::types::encodable::Encodable;
+use crate::types::{UImm1, UImm2};
+
+/// # Complex Rotation
+///
+/// Rotation applied to the complex numbers of the second source operand of
+/// [FCMLA](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/FCMLA--Floating-point-Complex-Multiply-Accumulate-?lang=en).
+#[derive(Debug, Clone, Copy)]
+pub enum ComplexRotation {
+    Rot0,
+    Rot90,
+    Rot180,
+    Rot270,
+}
+
+/// # Complex Add Rotation
+///
+/// Rotation applied to the complex numbers of the second source operand of
+/// [FCADD](https://developer.arm.com/documentation/ddi0596/2021-12/SIMD-FP-Instructions/FCADD--Floating-point-Complex-Add-?lang=en).
+#[derive(Debug, Clone, Copy)]
+pub enum ComplexAddRotation {
+    Rot90,
+    Rot270,
+}
+
+impl Encodable<UImm2> for ComplexRotation {
+    fn encode(&self) -> UImm2 {
+        match self {
+            ComplexRotation::Rot0 => 0b00,
+            ComplexRotation::Rot90 => 0b01,
+            ComplexRotation::Rot180 => 0b10,
+            ComplexRotation::Rot270 => 0b11,
+        }
+    }
+}
+
+impl Encodable<UImm1> for ComplexAddRotation {
+    fn encode(&self) -> UImm1 {
+        match self {
+            ComplexAddRotation::Rot90 => 0b0,
+            ComplexAddRotation::Rot270 => 0b1,
+        }
+    }
+}
@@ -0,0 +1,70 @@
+//! Types used by the [SME encodings](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/SME-encodings?lang=en).
+
+use std::fmt::{Display, Formatter};
+
+use crate::types::type_creation_macro::make_enum;
+use crate::types::UImm2;
+
+// ZA tile with 32-bit elements, `ZA0.S` to `ZA3.S`
+make_enum!(ZaTileS, [(ZA0, 0b00), (ZA1, 0b01), (ZA2, 0b10), (ZA3, 0b11)]);
+
+// ZA tile with 64-bit elements, `ZA0.D` to `ZA7.D`
+make_enum!(
+    ZaTileD,
+    [
+        (ZA0, 0b000),
+        (ZA1, 0b001),
+        (ZA2, 0b010),
+        (ZA3, 0b011),
+        (ZA4, 0b100),
+        (ZA5, 0b101),
+        (ZA6, 0b110),
+        (ZA7, 0b111)
+    ]
+);
+
+// Slice index register of a ZA tile slice, `W12` to `W15`
+make_enum!(ZaSliceReg, [(W12, 0b00), (W13, 0b01), (W14, 0b10), (W15, 0b11)]);
+
+/// # ZA tile slice direction
+///
+/// Horizontal slices are rows, vertical slices are columns of a ZA tile.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ZaDir {
+    H,
+    V,
+}
+
+/// # ZA tile slice with 32-bit elements
+///
+/// Slice of a [`ZaTileS`] selected by the sum of the slice index register and the immediate offset,
+/// e.g. `ZaSliceS::h(ZaTileS::ZA1, ZaSliceReg::W13, 2)` is `ZA1H.S[W13, 2]`.
+///
+/// The offset must be in range 0 to 3.
+#[derive(Debug, Clone, Copy)]
+pub struct ZaSliceS {
+    pub tile: ZaTileS,
+    pub dir: ZaDir,
+    pub rs: ZaSliceReg,
+    pub offset: UImm2,
+}
+
+impl ZaSliceS {
+    pub fn h(tile: ZaTileS, rs: ZaSliceReg, offset: UImm2) -> Self {
+        ZaSliceS {
+            tile,
+            dir: ZaDir::H,
+            rs,
+            offset,
+        }
+    }
+
+    pub fn v(tile: ZaTileS, rs: ZaSliceReg, offset: UImm2) -> Self {
+        ZaSliceS {
+            tile,
+            dir: ZaDir::V,
+            rs,
+            offset,
+        }
+    }
+}
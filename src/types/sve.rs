@@ -0,0 +1,113 @@
+//! Types used by the [SVE encodings](https://developer.arm.com/documentation/ddi0596/2021-12/Index-by-Encoding/SVE-encodings?lang=en).
+
+use std::fmt::{Display, Formatter};
+
+use crate::types::type_creation_macro::make_enum;
+use crate::types::{Register, UImm8};
+
+// Element size of a scalable vector or predicate register
+make_enum!(SveSize, [(B, 0b00), (H, 0b01), (S, 0b10), (D, 0b11)]);
+
+impl PartialEq for SveSize {
+    fn eq(&self, other: &Self) -> bool {
+        u8::from(*self) == u8::from(*other)
+    }
+}
+
+// Predicate constraint pattern used by PTRUE and the element count instructions
+make_enum!(
+    SvePattern,
+    [
+        (POW2, 0b00000),
+        (VL1, 0b00001),
+        (VL2, 0b00010),
+        (VL3, 0b00011),
+        (VL4, 0b00100),
+        (VL5, 0b00101),
+        (VL6, 0b00110),
+        (VL7, 0b00111),
+        (VL8, 0b01000),
+        (VL16, 0b01001),
+        (VL32, 0b01010),
+        (VL64, 0b01011),
+        (VL128, 0b01100),
+        (VL256, 0b01101),
+        (MUL4, 0b11101),
+        (MUL3, 0b11110),
+        (ALL, 0b11111)
+    ]
+);
+
+/// # Scalable vector register
+///
+/// `Z0` to `Z31` together with the size of its elements, e.g. `ZReg(1, SveSize::S)` is `Z1.S`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ZReg(pub Register, pub SveSize);
+
+/// # Scalable predicate register
+///
+/// `P0` to `P15` together with the size of the elements it governs, e.g. `PReg(1, SveSize::B)` is `P1.B`.
+///
+/// If the register is used as governing predicate (`Pg`) the element size is ignored.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PReg(pub Register, pub SveSize);
+
+impl ZReg {
+    pub fn b(num: Register) -> Self {
+        ZReg(num, SveSize::B)
+    }
+
+    pub fn h(num: Register) -> Self {
+        ZReg(num, SveSize::H)
+    }
+
+    pub fn s(num: Register) -> Self {
+        ZReg(num, SveSize::S)
+    }
+
+    pub fn d(num: Register) -> Self {
+        ZReg(num, SveSize::D)
+    }
+}
+
+impl PReg {
+    pub fn b(num: Register) -> Self {
+        PReg(num, SveSize::B)
+    }
+
+    pub fn h(num: Register) -> Self {
+        PReg(num, SveSize::H)
+    }
+
+    pub fn s(num: Register) -> Self {
+        PReg(num, SveSize::S)
+    }
+
+    pub fn d(num: Register) -> Self {
+        PReg(num, SveSize::D)
+    }
+}
+
+/// # Extend of 32-bit vector offsets
+///
+/// Used by gather loads and scatter stores with 32-bit vector offsets.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SveExtend {
+    UXTW,
+    SXTW,
+}
+
+/// # Gather and scatter addressing
+///
+/// Addressing modes of SVE gather loads and scatter stores.
+/// Where an offset can be scaled, `true` scales it by the access size.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SveVecAddr {
+    /// `[<Zn>.<T>{, #<imm>}]`: vector base plus immediate byte offset.
+    /// The offset must be a multiple of the access size in range 0 to 31 times the access size.
+    VecImm(ZReg, UImm8),
+    /// `[<Xn|SP>, <Zm>.D{, LSL #<amount>}]`: scalar base plus 64-bit vector offset.
+    ScalarVec64(Register, ZReg, bool),
+    /// `[<Xn|SP>, <Zm>.<T>, <extend>{ #<amount>}]`: scalar base plus 32-bit vector offset.
+    ScalarVec32(Register, ZReg, SveExtend, bool),
+}
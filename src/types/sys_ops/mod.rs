@@ -1,3 +1,4 @@
 pub mod at_op;
 pub mod dc_op;
-pub mod ic_op;
\ No newline at end of file
+pub mod ic_op;
+pub mod tlbi_op;
\ No newline at end of file
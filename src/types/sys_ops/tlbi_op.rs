@@ -0,0 +1,241 @@
+use TlbiOp::*;
+
+use crate::types::encodable::Encodable;
+
+/// Operation type for [TLBI instruction](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/TLBI--TLB-Invalidate-operation--an-alias-of-SYS-?lang=en).
+///
+/// The `*OS` operations require `FEAT_TLBIOS` and the range operations (`RVA*`, `RIPA*`)
+/// require `FEAT_TLBIRANGE`. The physical address operations (`PAALL*`, `RPA*`) require
+/// `FEAT_RME` and are only available with the `arm_feat_rme` feature. The `VMALLWS2E1*`
+/// operations require `FEAT_TLBIW` and are only available with the `arm_feat_tlbiw` feature.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TlbiOp {
+    VMALLE1OS,
+    VAE1OS,
+    ASIDE1OS,
+    VAAE1OS,
+    VALE1OS,
+    VAALE1OS,
+    RVAE1IS,
+    RVAAE1IS,
+    RVALE1IS,
+    RVAALE1IS,
+    VMALLE1IS,
+    VAE1IS,
+    ASIDE1IS,
+    VAAE1IS,
+    VALE1IS,
+    VAALE1IS,
+    RVAE1OS,
+    RVAAE1OS,
+    RVALE1OS,
+    RVAALE1OS,
+    RVAE1,
+    RVAAE1,
+    RVALE1,
+    RVAALE1,
+    VMALLE1,
+    VAE1,
+    ASIDE1,
+    VAAE1,
+    VALE1,
+    VAALE1,
+    IPAS2E1IS,
+    RIPAS2E1IS,
+    IPAS2LE1IS,
+    RIPAS2LE1IS,
+    ALLE2OS,
+    VAE2OS,
+    ALLE1OS,
+    VALE2OS,
+    VMALLS12E1OS,
+    RVAE2IS,
+    RVALE2IS,
+    ALLE2IS,
+    VAE2IS,
+    ALLE1IS,
+    VALE2IS,
+    VMALLS12E1IS,
+    IPAS2E1OS,
+    IPAS2E1,
+    RIPAS2E1,
+    RIPAS2E1OS,
+    IPAS2LE1OS,
+    IPAS2LE1,
+    RIPAS2LE1,
+    RIPAS2LE1OS,
+    RVAE2OS,
+    RVALE2OS,
+    RVAE2,
+    RVALE2,
+    ALLE2,
+    VAE2,
+    ALLE1,
+    VALE2,
+    VMALLS12E1,
+    ALLE3OS,
+    VAE3OS,
+    VALE3OS,
+    RVAE3IS,
+    RVALE3IS,
+    ALLE3IS,
+    VAE3IS,
+    VALE3IS,
+    RVAE3OS,
+    RVALE3OS,
+    RVAE3,
+    RVALE3,
+    ALLE3,
+    VAE3,
+    VALE3,
+    #[cfg(feature = "arm_feat_rme")]
+    PAALLOS,
+    #[cfg(feature = "arm_feat_rme")]
+    RPAOS,
+    #[cfg(feature = "arm_feat_rme")]
+    RPALOS,
+    #[cfg(feature = "arm_feat_rme")]
+    PAALL,
+    #[cfg(feature = "arm_feat_tlbiw")]
+    VMALLWS2E1IS,
+    #[cfg(feature = "arm_feat_tlbiw")]
+    VMALLWS2E1OS,
+    #[cfg(feature = "arm_feat_tlbiw")]
+    VMALLWS2E1,
+}
+
+impl TlbiOp {
+    /// Returns whether the operation takes an address, ASID or range in `Xt`.
+    ///
+    /// Only the `ALL*`, `VMALL*` and `PAALL*` operations do not use `Xt`.
+    pub fn requires_xt(&self) -> bool {
+        #[cfg(feature = "arm_feat_rme")]
+        if matches!(self, PAALLOS | PAALL) {
+            return false;
+        }
+        #[cfg(feature = "arm_feat_tlbiw")]
+        if matches!(self, VMALLWS2E1IS | VMALLWS2E1OS | VMALLWS2E1) {
+            return false;
+        }
+        !matches!(
+            self,
+            VMALLE1OS
+                | VMALLE1IS
+                | VMALLE1
+                | ALLE2OS
+                | ALLE1OS
+                | VMALLS12E1OS
+                | ALLE2IS
+                | ALLE1IS
+                | VMALLS12E1IS
+                | ALLE2
+                | ALLE1
+                | VMALLS12E1
+                | ALLE3OS
+                | ALLE3IS
+                | ALLE3
+        )
+    }
+}
+
+impl Encodable<(u8, u8, u8)> for TlbiOp {
+    /// Encoding for TLBI Operation. Consult the [arm64 docs](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/TLBI--TLB-Invalidate-operation--an-alias-of-SYS-?lang=en) for more.
+    ///
+    /// Returns `(op1, CRm, op2)`
+    fn encode(&self) -> (u8, u8, u8) {
+        match self {
+            VMALLE1OS => (0b000, 0b0001, 0b000),
+            VAE1OS => (0b000, 0b0001, 0b001),
+            ASIDE1OS => (0b000, 0b0001, 0b010),
+            VAAE1OS => (0b000, 0b0001, 0b011),
+            VALE1OS => (0b000, 0b0001, 0b101),
+            VAALE1OS => (0b000, 0b0001, 0b111),
+            RVAE1IS => (0b000, 0b0010, 0b001),
+            RVAAE1IS => (0b000, 0b0010, 0b011),
+            RVALE1IS => (0b000, 0b0010, 0b101),
+            RVAALE1IS => (0b000, 0b0010, 0b111),
+            VMALLE1IS => (0b000, 0b0011, 0b000),
+            VAE1IS => (0b000, 0b0011, 0b001),
+            ASIDE1IS => (0b000, 0b0011, 0b010),
+            VAAE1IS => (0b000, 0b0011, 0b011),
+            VALE1IS => (0b000, 0b0011, 0b101),
+            VAALE1IS => (0b000, 0b0011, 0b111),
+            RVAE1OS => (0b000, 0b0101, 0b001),
+            RVAAE1OS => (0b000, 0b0101, 0b011),
+            RVALE1OS => (0b000, 0b0101, 0b101),
+            RVAALE1OS => (0b000, 0b0101, 0b111),
+            RVAE1 => (0b000, 0b0110, 0b001),
+            RVAAE1 => (0b000, 0b0110, 0b011),
+            RVALE1 => (0b000, 0b0110, 0b101),
+            RVAALE1 => (0b000, 0b0110, 0b111),
+            VMALLE1 => (0b000, 0b0111, 0b000),
+            VAE1 => (0b000, 0b0111, 0b001),
+            ASIDE1 => (0b000, 0b0111, 0b010),
+            VAAE1 => (0b000, 0b0111, 0b011),
+            VALE1 => (0b000, 0b0111, 0b101),
+            VAALE1 => (0b000, 0b0111, 0b111),
+            IPAS2E1IS => (0b100, 0b0000, 0b001),
+            RIPAS2E1IS => (0b100, 0b0000, 0b010),
+            IPAS2LE1IS => (0b100, 0b0000, 0b101),
+            RIPAS2LE1IS => (0b100, 0b0000, 0b110),
+            ALLE2OS => (0b100, 0b0001, 0b000),
+            VAE2OS => (0b100, 0b0001, 0b001),
+            ALLE1OS => (0b100, 0b0001, 0b100),
+            VALE2OS => (0b100, 0b0001, 0b101),
+            VMALLS12E1OS => (0b100, 0b0001, 0b110),
+            RVAE2IS => (0b100, 0b0010, 0b001),
+            RVALE2IS => (0b100, 0b0010, 0b101),
+            ALLE2IS => (0b100, 0b0011, 0b000),
+            VAE2IS => (0b100, 0b0011, 0b001),
+            ALLE1IS => (0b100, 0b0011, 0b100),
+            VALE2IS => (0b100, 0b0011, 0b101),
+            VMALLS12E1IS => (0b100, 0b0011, 0b110),
+            IPAS2E1OS => (0b100, 0b0100, 0b000),
+            IPAS2E1 => (0b100, 0b0100, 0b001),
+            RIPAS2E1 => (0b100, 0b0100, 0b010),
+            RIPAS2E1OS => (0b100, 0b0100, 0b011),
+            IPAS2LE1OS => (0b100, 0b0100, 0b100),
+            IPAS2LE1 => (0b100, 0b0100, 0b101),
+            RIPAS2LE1 => (0b100, 0b0100, 0b110),
+            RIPAS2LE1OS => (0b100, 0b0100, 0b111),
+            RVAE2OS => (0b100, 0b0101, 0b001),
+            RVALE2OS => (0b100, 0b0101, 0b101),
+            RVAE2 => (0b100, 0b0110, 0b001),
+            RVALE2 => (0b100, 0b0110, 0b101),
+            ALLE2 => (0b100, 0b0111, 0b000),
+            VAE2 => (0b100, 0b0111, 0b001),
+            ALLE1 => (0b100, 0b0111, 0b100),
+            VALE2 => (0b100, 0b0111, 0b101),
+            VMALLS12E1 => (0b100, 0b0111, 0b110),
+            ALLE3OS => (0b110, 0b0001, 0b000),
+            VAE3OS => (0b110, 0b0001, 0b001),
+            VALE3OS => (0b110, 0b0001, 0b101),
+            RVAE3IS => (0b110, 0b0010, 0b001),
+            RVALE3IS => (0b110, 0b0010, 0b101),
+            ALLE3IS => (0b110, 0b0011, 0b000),
+            VAE3IS => (0b110, 0b0011, 0b001),
+            VALE3IS => (0b110, 0b0011, 0b101),
+            RVAE3OS => (0b110, 0b0101, 0b001),
+            RVALE3OS => (0b110, 0b0101, 0b101),
+            RVAE3 => (0b110, 0b0110, 0b001),
+            RVALE3 => (0b110, 0b0110, 0b101),
+            ALLE3 => (0b110, 0b0111, 0b000),
+            VAE3 => (0b110, 0b0111, 0b001),
+            VALE3 => (0b110, 0b0111, 0b101),
+            #[cfg(feature = "arm_feat_rme")]
+            PAALLOS => (0b110, 0b0001, 0b100),
+            #[cfg(feature = "arm_feat_rme")]
+            RPAOS => (0b110, 0b0100, 0b011),
+            #[cfg(feature = "arm_feat_rme")]
+            RPALOS => (0b110, 0b0100, 0b111),
+            #[cfg(feature = "arm_feat_rme")]
+            PAALL => (0b110, 0b0111, 0b100),
+            #[cfg(feature = "arm_feat_tlbiw")]
+            VMALLWS2E1IS => (0b100, 0b0010, 0b010),
+            #[cfg(feature = "arm_feat_tlbiw")]
+            VMALLWS2E1OS => (0b100, 0b0101, 0b010),
+            #[cfg(feature = "arm_feat_tlbiw")]
+            VMALLWS2E1 => (0b100, 0b0110, 0b010),
+        }
+    }
+}
@@ -0,0 +1,206 @@
+//! System registers accessed by [MRS](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/MRS--Move-System-Register-?lang=en)
+//! and [MSR (register)](https://developer.arm.com/documentation/ddi0596/2021-12/Base-Instructions/MSR--register---Move-general-purpose-register-to-System-Register-?lang=en).
+//!
+//! For the encodings check the [arm64 docs](https://developer.arm.com/documentation/ddi0595/2021-12/AArch64-Registers).
+
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::types::encodable::Encodable;
+
+/// `RO` marks a read-only register.
+macro_rules! sys_reg_read_only {
+    (RO) => {
+        true
+    };
+    () => {
+        false
+    };
+}
+
+macro_rules! make_sys_regs {
+    ($(($reg:ident, $op0:expr, $op1:expr, $crn:expr, $crm:expr, $op2:expr $(, $ro:ident)?)),* $(,)?) => {
+        /// Named system register operand of `MRS` and `MSR (register)`.
+        ///
+        /// Each register is encoded as `(op0, op1, CRn, CRm, op2)`, see [`SysReg::from_encoding`]
+        /// for the inverse mapping.
+        #[allow(non_camel_case_types)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum SysReg {
+            $($reg,)*
+        }
+
+        impl SysReg {
+            /// All named system registers.
+            pub const ALL: &'static [SysReg] = &[$(SysReg::$reg,)*];
+
+            /// Returns the register encoded by `(op0, op1, CRn, CRm, op2)`, if it is a named one.
+            pub fn from_encoding(op0: u8, op1: u8, crn: u8, crm: u8, op2: u8) -> Option<SysReg> {
+                match (op0, op1, crn, crm, op2) {
+                    $(($op0, $op1, $crn, $crm, $op2) => Some(SysReg::$reg),)*
+                    _ => None,
+                }
+            }
+
+            /// Returns whether the register is read-only, writes to it by `MSR` are UNDEFINED.
+            pub fn is_read_only(&self) -> bool {
+                match self {
+                    $(SysReg::$reg => sys_reg_read_only!($($ro)?),)*
+                }
+            }
+
+            /// Returns the architectural name of the register, e.g. `TPIDR_EL0`.
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(SysReg::$reg => stringify!($reg),)*
+                }
+            }
+        }
+
+        impl Encodable<(u8, u8, u8, u8, u8)> for SysReg {
+            /// Returns (op0, op1, CRn, CRm, op2)
+            fn encode(&self) -> (u8, u8, u8, u8, u8) {
+                match self {
+                    $(SysReg::$reg => ($op0, $op1, $crn, $crm, $op2),)*
+                }
+            }
+        }
+    };
+}
+
+make_sys_regs!(
+    (NZCV, 3, 3, 4, 2, 0),
+    (DAIF, 3, 3, 4, 2, 1),
+    (FPCR, 3, 3, 4, 4, 0),
+    (FPSR, 3, 3, 4, 4, 1),
+    (CurrentEL, 3, 0, 4, 2, 2, RO),
+    (SPSel, 3, 0, 4, 2, 0),
+    (SP_EL0, 3, 0, 4, 1, 0),
+    (SP_EL1, 3, 4, 4, 1, 0),
+    (SPSR_EL1, 3, 0, 4, 0, 0),
+    (ELR_EL1, 3, 0, 4, 0, 1),
+    (SPSR_EL2, 3, 4, 4, 0, 0),
+    (ELR_EL2, 3, 4, 4, 0, 1),
+    (SPSR_EL3, 3, 6, 4, 0, 0),
+    (ELR_EL3, 3, 6, 4, 0, 1),
+    (TPIDR_EL0, 3, 3, 13, 0, 2),
+    (TPIDRRO_EL0, 3, 3, 13, 0, 3),
+    (TPIDR_EL1, 3, 0, 13, 0, 4),
+    (TPIDR_EL2, 3, 4, 13, 0, 2),
+    (TPIDR_EL3, 3, 6, 13, 0, 2),
+    (CONTEXTIDR_EL1, 3, 0, 13, 0, 1),
+    (CNTFRQ_EL0, 3, 3, 14, 0, 0),
+    (CNTPCT_EL0, 3, 3, 14, 0, 1, RO),
+    (CNTVCT_EL0, 3, 3, 14, 0, 2, RO),
+    (CNTP_CTL_EL0, 3, 3, 14, 2, 1),
+    (CNTP_CVAL_EL0, 3, 3, 14, 2, 2),
+    (CNTV_CTL_EL0, 3, 3, 14, 3, 1),
+    (CNTV_CVAL_EL0, 3, 3, 14, 3, 2),
+    (CNTKCTL_EL1, 3, 0, 14, 1, 0),
+    (DCZID_EL0, 3, 3, 0, 0, 7, RO),
+    (CTR_EL0, 3, 3, 0, 0, 1, RO),
+    (MIDR_EL1, 3, 0, 0, 0, 0, RO),
+    (MPIDR_EL1, 3, 0, 0, 0, 5, RO),
+    (REVIDR_EL1, 3, 0, 0, 0, 6, RO),
+    (ID_AA64PFR0_EL1, 3, 0, 0, 4, 0, RO),
+    (ID_AA64PFR1_EL1, 3, 0, 0, 4, 1, RO),
+    (ID_AA64DFR0_EL1, 3, 0, 0, 5, 0, RO),
+    (ID_AA64ISAR0_EL1, 3, 0, 0, 6, 0, RO),
+    (ID_AA64ISAR1_EL1, 3, 0, 0, 6, 1, RO),
+    (ID_AA64MMFR0_EL1, 3, 0, 0, 7, 0, RO),
+    (ID_AA64MMFR1_EL1, 3, 0, 0, 7, 1, RO),
+    (SCTLR_EL1, 3, 0, 1, 0, 0),
+    (SCTLR_EL2, 3, 4, 1, 0, 0),
+    (SCTLR_EL3, 3, 6, 1, 0, 0),
+    (ACTLR_EL1, 3, 0, 1, 0, 1),
+    (CPACR_EL1, 3, 0, 1, 0, 2),
+    (HCR_EL2, 3, 4, 1, 1, 0),
+    (SCR_EL3, 3, 6, 1, 1, 0),
+    (TTBR0_EL1, 3, 0, 2, 0, 0),
+    (TTBR1_EL1, 3, 0, 2, 0, 1),
+    (TCR_EL1, 3, 0, 2, 0, 2),
+    (TTBR0_EL2, 3, 4, 2, 0, 0),
+    (TTBR1_EL2, 3, 4, 2, 0, 1),
+    (TCR_EL2, 3, 4, 2, 0, 2),
+    (VTTBR_EL2, 3, 4, 2, 1, 0),
+    (VTCR_EL2, 3, 4, 2, 1, 2),
+    (TTBR0_EL3, 3, 6, 2, 0, 0),
+    (TCR_EL3, 3, 6, 2, 0, 2),
+    (AFSR0_EL1, 3, 0, 5, 1, 0),
+    (AFSR0_EL3, 3, 6, 5, 1, 0),
+    (ESR_EL1, 3, 0, 5, 2, 0),
+    (ESR_EL2, 3, 4, 5, 2, 0),
+    (ESR_EL3, 3, 6, 5, 2, 0),
+    (FAR_EL1, 3, 0, 6, 0, 0),
+    (FAR_EL2, 3, 4, 6, 0, 0),
+    (FAR_EL3, 3, 6, 6, 0, 0),
+    (PAR_EL1, 3, 0, 7, 4, 0),
+    (PMCCNTR_EL0, 3, 3, 9, 13, 0),
+    (MAIR_EL1, 3, 0, 10, 2, 0),
+    (MAIR_EL2, 3, 4, 10, 2, 0),
+    (MAIR_EL3, 3, 6, 10, 2, 0),
+    (VBAR_EL1, 3, 0, 12, 0, 0),
+    (VBAR_EL2, 3, 4, 12, 0, 0),
+    (VBAR_EL3, 3, 6, 12, 0, 0),
+    (RNDR, 3, 3, 2, 4, 0, RO),
+    (RNDRRS, 3, 3, 2, 4, 1, RO),
+);
+
+impl Display for SysReg {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for SysReg {
+    type Err = ();
+
+    /// Parses the architectural name of a register, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SysReg::ALL
+            .iter()
+            .find(|reg| reg.name().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_display() {
+        assert_eq!("TPIDR_EL0".parse(), Ok(SysReg::TPIDR_EL0));
+        assert_eq!("cntvct_el0".parse(), Ok(SysReg::CNTVCT_EL0));
+        assert_eq!("CurrentEL".parse(), Ok(SysReg::CurrentEL));
+        assert_eq!("TPIDR_EL4".parse::<SysReg>(), Err(()));
+
+        assert_eq!(SysReg::SCTLR_EL1.to_string(), "SCTLR_EL1");
+        assert_eq!(SysReg::CurrentEL.to_string(), "CurrentEL");
+
+        for reg in SysReg::ALL {
+            assert_eq!(reg.to_string().parse(), Ok(*reg));
+        }
+    }
+
+    #[test]
+    fn test_read_only() {
+        assert!(SysReg::MIDR_EL1.is_read_only());
+        assert!(SysReg::CurrentEL.is_read_only());
+        assert!(SysReg::RNDR.is_read_only());
+        assert!(!SysReg::TPIDR_EL0.is_read_only());
+        assert!(!SysReg::TTBR1_EL2.is_read_only());
+    }
+
+    #[test]
+    fn test_from_encoding() {
+        assert_eq!(SysReg::from_encoding(3, 3, 13, 0, 2), Some(SysReg::TPIDR_EL0));
+        assert_eq!(SysReg::from_encoding(3, 7, 15, 15, 7), None);
+
+        for reg in SysReg::ALL {
+            let (op0, op1, crn, crm, op2) = reg.encode();
+            assert_eq!(SysReg::from_encoding(op0, op1, crn, crm, op2), Some(*reg));
+        }
+    }
+}